@@ -0,0 +1,50 @@
+import { describe, expect, it } from 'vitest';
+import { batchRecordKey, parseBatchFile, splitBatch } from '../src/batch';
+
+describe('parseBatchFile', () => {
+  it('JSON 배열과 JSONL을 모두 읽는다', () => {
+    const array = parseBatchFile('[{"id": "a", "date": "1990-05-20", "time": "08:30"}]');
+    expect(array).toEqual([{ id: 'a', overrides: { date: '1990-05-20', time: '08:30' } }]);
+
+    const lines = parseBatchFile('{"date": "1990-05-20", "tz": "Asia/Seoul"}\n\n{"id": 2, "leapMonth": true}\n');
+    expect(lines).toEqual([
+      { id: null, overrides: { date: '1990-05-20', tz: 'Asia/Seoul' } },
+      { id: '2', overrides: { leapMonth: true } },
+    ]);
+  });
+
+  it('숫자 값은 옵션 문자열로 바꾼다', () => {
+    expect(parseBatchFile('{"longitude": 126.98, "daewonCount": 8}')[0].overrides)
+      .toEqual({ longitude: '126.98', daewonCount: '8' });
+  });
+
+  it('알 수 없는 키·잘못된 값은 레코드 번호와 함께 거부한다', () => {
+    expect(() => parseBatchFile('{"date": "1990-05-20"}\n{"birthday": "x"}')).toThrow(/record 2: unknown key birthday/);
+    expect(() => parseBatchFile('{"leapMonth": "yes"}')).toThrow(/record 1: leapMonth must be true or false/);
+    expect(() => parseBatchFile('{"date": "1990-05-20"}\n{oops')).toThrow(/record 2/);
+    expect(() => parseBatchFile('[1]')).toThrow(/record 1 must be an object/);
+  });
+});
+
+describe('splitBatch', () => {
+  it('레코드를 작업자에게 번갈아 배분한다', () => {
+    expect(splitBatch(5, 2)).toEqual([[0, 2, 4], [1, 3]]);
+    expect(splitBatch(2, 4)).toEqual([[0], [1]]);
+    expect(splitBatch(0, 3)).toEqual([]);
+  });
+});
+
+describe('batchRecordKey', () => {
+  it('날짜·시각 표기가 달라도 같은 입력이면 같은 키다', () => {
+    const base = { date: '1990-03-05', time: '06:30', tz: 'Asia/Seoul', gender: 'male' };
+    expect(batchRecordKey({ ...base, date: '1990.3.5', time: '6:30' })).toBe(batchRecordKey(base));
+    expect(batchRecordKey({ gender: 'male', tz: 'Asia/Seoul', time: '06:30', date: '1990-03-05' })).toBe(batchRecordKey(base));
+    expect(batchRecordKey({ ...base, location: undefined })).toBe(batchRecordKey(base));
+  });
+
+  it('옵션이나 음력 표시가 다르면 다른 키다', () => {
+    const base = { date: '1990-03-05', time: '06:30' };
+    expect(batchRecordKey({ ...base, gender: 'female' })).not.toBe(batchRecordKey({ ...base, gender: 'male' }));
+    expect(batchRecordKey({ ...base, date: '음력 1990-03-05' })).not.toBe(batchRecordKey(base));
+  });
+});
@@ -4,6 +4,9 @@ import {
   parseOptionalIntegerOption,
   parseNumberOption,
   parseOptionalNumberOption,
+  parseChoiceOption,
+  parseYmdArgument,
+  parseRangeOption,
 } from '../src/cliParsing';
 
 describe('cliParsing', () => {
@@ -29,5 +32,22 @@ describe('cliParsing', () => {
     expect(parseOptionalNumberOption(undefined, '--longitude')).toBeNull();
     expect(parseOptionalNumberOption('+127.1', '--longitude')).toBe(127.1);
   });
-});
 
+  it('선택지 옵션은 대소문자를 무시하고 허용 목록만 받는다', () => {
+    expect(parseChoiceOption('Vertical', '--layout', ['list', 'vertical'])).toBe('vertical');
+    expect(() => parseChoiceOption('grid', '--layout', ['list', 'vertical'])).toThrow('--layout must be one of list|vertical');
+  });
+
+  it('범위 옵션은 a..b 형식만 받는다', () => {
+    expect(parseRangeOption('30..60', '--daewon-ages')).toEqual([30, 60]);
+    expect(parseRangeOption('5..5', '--daewon-ages')).toEqual([5, 5]);
+    expect(() => parseRangeOption('30-60', '--daewon-ages')).toThrow('--daewon-ages must be a range like 30..60');
+    expect(() => parseRangeOption('60..30', '--daewon-ages')).toThrow('--daewon-ages start must not exceed its end');
+  });
+
+  it('날짜 인자는 YYYY-MM-DD 형식만 받는다', () => {
+    expect(parseYmdArgument('1990-09-15', 'date')).toEqual({ year: 1990, month: 9, day: 15 });
+    expect(() => parseYmdArgument('1990-9-15', 'date')).toThrow('date must be YYYY-MM-DD');
+    expect(() => parseYmdArgument('19900915', 'date')).toThrow('date must be YYYY-MM-DD');
+  });
+});
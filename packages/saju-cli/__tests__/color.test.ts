@@ -0,0 +1,49 @@
+import { describe, expect, it } from 'vitest';
+import { I18n } from 'saju-lib';
+import { colorizeLines, shouldColor } from '../src/color';
+import { abbreviateLabels, displayWidth } from '../src/textWidth';
+
+const GREEN = '\x1b[32m';
+const BLUE = '\x1b[34m';
+const RESET = '\x1b[0m';
+
+describe('colorizeLines', () => {
+  it('천간·지지 표기를 오행 색으로 감싼다', () => {
+    const [line] = colorizeLines(['- 일간: 갑(甲), 지지 자(子)'], new I18n('Ko'));
+    expect(line).toBe(`- 일간: ${GREEN}갑${RESET}(${GREEN}甲${RESET}), 지지 ${BLUE}자${RESET}(${BLUE}子${RESET})`);
+  });
+
+  it('기둥은 천간과 지지를 따로 칠한다', () => {
+    const [ko] = colorizeLines(['갑자(甲子)'], new I18n('Ko'));
+    expect(ko).toBe(`${GREEN}갑${RESET}${BLUE}자${RESET}(${GREEN}甲${BLUE}子${RESET})`);
+    const [pinyin] = colorizeLines(['Jia Zi(甲子)'], new I18n('En', 'Man', 'Pinyin'));
+    expect(pinyin).toBe(`${GREEN}Jia${RESET} ${BLUE}Zi${RESET}(${GREEN}甲${BLUE}子${RESET})`);
+  });
+
+  it('표시 폭은 그대로이고 축약하면 이름의 색만 남는다', () => {
+    const plain = '| 갑자(甲子) | 병(丙) 편인(偏印)';
+    const [colored] = colorizeLines([plain], new I18n('Ko'));
+    expect(displayWidth(colored)).toBe(displayWidth(plain));
+    expect(abbreviateLabels(colored)).toBe(`| ${GREEN}갑${RESET}${BLUE}자${RESET} | \x1b[31m병${RESET} 편인`);
+  });
+
+  it('천간·지지가 아닌 한자 병기는 칠하지 않는다', () => {
+    expect(colorizeLines(['편인(偏印) 입춘(立春)'], new I18n('Ko'))).toEqual(['편인(偏印) 입춘(立春)']);
+  });
+});
+
+describe('shouldColor', () => {
+  const tty = { isTTY: true, toFile: false, noColor: undefined };
+
+  it('auto는 터미널에 직접 쓸 때만 칠한다', () => {
+    expect(shouldColor('auto', tty)).toBe(true);
+    expect(shouldColor('auto', { ...tty, isTTY: false })).toBe(false);
+    expect(shouldColor('auto', { ...tty, toFile: true })).toBe(false);
+    expect(shouldColor('auto', { ...tty, noColor: '1' })).toBe(false);
+  });
+
+  it('always와 never는 환경과 무관하다', () => {
+    expect(shouldColor('always', { isTTY: false, toFile: true, noColor: '1' })).toBe(true);
+    expect(shouldColor('never', tty)).toBe(false);
+  });
+});
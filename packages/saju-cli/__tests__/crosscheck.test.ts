@@ -0,0 +1,34 @@
+import { describe, expect, it } from 'vitest';
+import { astroLunarDate, astroLunarMonths, dayPillarByEpoch, runCrossCheck } from '../src/crosscheck';
+
+describe('astroLunarMonths', () => {
+  it('동지가 든 달을 11월로 두고 중기 없는 달을 윤달로 삼는다', () => {
+    const months = astroLunarMonths(2020);
+    expect(months).toHaveLength(13);
+    expect(months[0]).toMatchObject({ year: 2019, month: 11, isLeap: false });
+    expect(months.filter((m) => m.isLeap)).toEqual([expect.objectContaining({ year: 2020, month: 4 })]);
+    expect(astroLunarMonths(2021)).toHaveLength(12);
+  });
+
+  it('양력 날짜의 음력을 찾는다', () => {
+    // 2023-01-22 = 음력 2023-01-01
+    const months = [...astroLunarMonths(2023), ...astroLunarMonths(2024)];
+    expect(astroLunarDate(2459967, months)).toEqual({ year: 2023, month: 1, day: 1, isLeap: false });
+  });
+});
+
+describe('dayPillarByEpoch', () => {
+  it('2000-01-01은 무오일이다', () => {
+    expect(dayPillarByEpoch(new Date(Date.UTC(2000, 0, 1)))).toEqual([4, 6]);
+    expect(dayPillarByEpoch(new Date(Date.UTC(1999, 11, 31)))).toEqual([3, 5]);
+  });
+});
+
+describe('runCrossCheck', () => {
+  it('내장 표와 천문 계산이 최근 연도에서 일치한다', () => {
+    const report = runCrossCheck(2020, 2023);
+    expect(report.checked.Lunar).toBe(1461);
+    expect(report.checked.DayPillar).toBe(1461);
+    expect(report.issues).toEqual([]);
+  });
+});
@@ -0,0 +1,41 @@
+import { describe, expect, it } from 'vitest';
+import { CHART_CSV_HEADER, isChartSection, parseLuckSections, renderChartCsv } from '../src/csv';
+import { chart } from './fixtures';
+
+function rowOf(lines: string[]): Record<string, string> {
+  expect(lines).toHaveLength(2);
+  expect(lines[0]).toBe(CHART_CSV_HEADER.join(','));
+  const cells = lines[1].split(',');
+  return Object.fromEntries(CHART_CSV_HEADER.map((column, i) => [column, cells[i]]));
+}
+
+describe('isChartSection', () => {
+  it('chart만 지정했을 때 참이고, 운 섹션과 섞으면 거부한다', () => {
+    expect(isChartSection('chart')).toBe(true);
+    expect(isChartSection(' Chart ')).toBe(true);
+    expect(isChartSection('daewon,yearly')).toBe(false);
+    expect(() => isChartSection('chart,daewon')).toThrow(/cannot be combined/);
+    expect(() => parseLuckSections('chart')).toThrow(/--sections/);
+  });
+});
+
+describe('renderChartCsv', () => {
+  it('명식 하나를 한 행으로 요약한다', () => {
+    const row = rowOf(renderChartCsv(chart()));
+    expect(row.date).toBe('2000-01-15');
+    expect(row.gender).toBe('Male');
+    expect([row.year_stem, row.year_branch, row.month_stem, row.month_branch]).toEqual(['己', '卯', '丁', '丑']);
+    expect(row.hour_branch).toBe('酉');
+    const elements = ['wood', 'fire', 'earth', 'metal', 'water'].map((e) => Number(row[e]));
+    expect(elements.reduce((a, b) => a + b, 0)).toBe(8);
+    expect(row.daewon_direction).toBe('Backward');
+    expect(Number(row.first_daewon_age)).toBeGreaterThan(0);
+  });
+
+  it('성별이 없으면 대운 열을 비운다', () => {
+    const row = rowOf(renderChartCsv(chart(null)));
+    expect(row.gender).toBe('');
+    expect(row.daewon_direction).toBe('');
+    expect(row.first_daewon_age).toBe('');
+  });
+});
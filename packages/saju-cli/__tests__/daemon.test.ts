@@ -0,0 +1,90 @@
+import { describe, expect, it } from 'vitest';
+import { createConnection } from 'node:net';
+import { mkdtempSync, statSync, writeFileSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { answerQuery, defaultSocketPath, startDaemon, type QueryHandler } from '../src/daemon';
+import { SCHEMA_VERSION } from '../src/schema';
+
+/** 레코드의 날짜를 그대로 돌려주는 처리기 (날짜가 없으면 오류) */
+const echo: QueryHandler = (record, index) => (record.overrides.date
+  ? { index, lines: [`${index}:${record.overrides.date}`], sample: null }
+  : { index, error: 'no date' });
+
+/** 소켓에 질의 줄을 보내고 응답 줄 count개를 받는다 */
+function query(path: string, text: string, count: number): Promise<unknown[]> {
+  return new Promise((resolve, reject) => {
+    const socket = createConnection(path);
+    let buffer = '';
+    socket.on('data', (chunk) => {
+      buffer += chunk.toString();
+      const lines = buffer.split('\n').filter((line) => line !== '');
+      if (lines.length >= count) {
+        socket.end();
+        resolve(lines.map((line) => JSON.parse(line) as unknown));
+      }
+    });
+    socket.on('error', reject);
+    socket.write(text);
+  });
+}
+
+describe('answerQuery', () => {
+  it('레코드를 계산해 id와 출력 줄을 돌려준다', () => {
+    expect(JSON.parse(answerQuery('{"id": "a", "date": "1990-05-20"}', 1, echo))).toEqual({
+      schema_version: SCHEMA_VERSION, id: 'a', lines: ['1:1990-05-20'],
+    });
+    expect(JSON.parse(answerQuery('{"time": "08:30"}', 2, echo))).toEqual({
+      schema_version: SCHEMA_VERSION, id: null, error: 'no date',
+    });
+  });
+
+  it('잘못된 질의도 오류 응답으로 돌려준다', () => {
+    expect(JSON.parse(answerQuery('{oops', 3, echo)).error).toMatch(/^query 3: /);
+    expect(JSON.parse(answerQuery('{"birthday": "x"}', 4, echo)).error).toMatch(/^query 4: unknown key birthday/);
+    expect(JSON.parse(answerQuery('[1]', 5, echo)).error).toBe('query 5 must be an object');
+  });
+});
+
+describe('startDaemon', () => {
+  it('연결마다 질의 순서대로 한 줄씩 응답한다', async () => {
+    const path = join(mkdtempSync(join(tmpdir(), 'saju-daemon-')), 'saju.sock');
+    const daemon = await startDaemon(path, echo);
+    try {
+      expect(statSync(path).mode & 0o777).toBe(0o600);
+      const replies = await query(path, '{"date": "2000-01-01"}\n\n{"id": 7, "date": "2000-01-02"}\n', 2);
+      expect(replies).toEqual([
+        { schema_version: SCHEMA_VERSION, id: null, lines: ['1:2000-01-01'] },
+        { schema_version: SCHEMA_VERSION, id: '7', lines: ['2:2000-01-02'] },
+      ]);
+      await expect(startDaemon(path, echo)).rejects.toThrow(/already listening/);
+    } finally {
+      await daemon.close();
+    }
+  });
+
+  it('이전 프로세스가 남긴 소켓 파일은 지우고 연다', async () => {
+    const path = join(mkdtempSync(join(tmpdir(), 'saju-daemon-')), 'saju.sock');
+    writeFileSync(path, '');
+    const daemon = await startDaemon(path, echo);
+    await daemon.close();
+  });
+
+  it('소켓을 연 뒤 umask를 되돌린다', async () => {
+    const path = join(mkdtempSync(join(tmpdir(), 'saju-daemon-')), 'saju.sock');
+    const before = process.umask(0o022);
+    try {
+      const daemon = await startDaemon(path, echo);
+      await daemon.close();
+    } finally {
+      expect(process.umask(before)).toBe(0o022);
+    }
+  });
+});
+
+describe('defaultSocketPath', () => {
+  it('Windows는 이름 있는 파이프를 쓴다', () => {
+    expect(defaultSocketPath('win32')).toBe('\\\\.\\pipe\\saju');
+    expect(defaultSocketPath('linux')).toMatch(/saju-.*\.sock$/);
+  });
+});
@@ -0,0 +1,14 @@
+import { calculate, type Gender } from 'saju-lib';
+
+/**
+ * 테스트 공용 명식: 2000-01-15 17:15 (서울, 양력).
+ * @param gender 성별 (null이면 대운 생략)
+ * @param monthYear 월운을 뽑을 해 (null이면 생략)
+ */
+export function chart(gender: Gender | null = 'Male', monthYear: number | null = null) {
+  return calculate({
+    date: '2000-01-15', time: '17:15', calendar: 'Solar', leapMonth: false, gender,
+    tz: 'Asia/Seoul', useLmt: false, longitude: null, location: null,
+    daewonCount: 10, monthYear, yearStart: 2024, yearCount: 3,
+  });
+}
@@ -0,0 +1,44 @@
+import { describe, expect, it } from 'vitest';
+import { generateBirths, generatedLine, seededRandom } from '../src/gen';
+
+describe('seededRandom', () => {
+  it('같은 시드는 같은 난수열을 낸다', () => {
+    const a = seededRandom(42);
+    const b = seededRandom(42);
+    const c = seededRandom(43);
+    const first = [a(), a(), a()];
+    expect([b(), b(), b()]).toEqual(first);
+    expect([c(), c(), c()]).not.toEqual(first);
+    for (const x of first) {
+      expect(x).toBeGreaterThanOrEqual(0);
+      expect(x).toBeLessThan(1);
+    }
+  });
+});
+
+describe('generateBirths', () => {
+  it('연도 범위 안의 유효한 날짜·시각을 재현 가능하게 뽑는다', () => {
+    const births = [...generateBirths(200, 1950, 2010, seededRandom(7))];
+    expect(births).toHaveLength(200);
+    expect([...generateBirths(200, 1950, 2010, seededRandom(7))]).toEqual(births);
+    for (const b of births) {
+      expect(b.date).toMatch(/^\d{4}-\d{2}-\d{2}$/);
+      expect(b.time).toMatch(/^\d{2}:\d{2}$/);
+      const year = Number(b.date.slice(0, 4));
+      expect(year).toBeGreaterThanOrEqual(1950);
+      expect(year).toBeLessThanOrEqual(2010);
+    }
+    expect(new Set(births.map((b) => b.gender))).toEqual(new Set(['Male', 'Female']));
+  });
+});
+
+describe('generatedLine', () => {
+  it('명식 한 줄을 JSON으로 낸다', () => {
+    const line = generatedLine({ date: '2000-01-15', time: '12:00', gender: 'Male' }, 'Asia/Seoul');
+    const record = JSON.parse(line);
+    expect(record).toMatchObject({ date: '2000-01-15', time: '12:00', gender: 'Male', time_zone: 'Asia/Seoul' });
+    expect(record.fingerprint).toMatch(/^[0-9a-f]+$/);
+    expect(Object.keys(record.pillars)).toEqual(['year', 'month', 'day', 'hour']);
+    expect(Object.values(record.elements as Record<string, number>).reduce((a, b) => a + b, 0)).toBe(8);
+  });
+});
@@ -0,0 +1,65 @@
+import { describe, expect, it } from 'vitest';
+import { calculatePillars } from 'saju-lib';
+import { chartJson, JSON_FORMAT_VERSION, pillarsJson, PILLARS_JSON_FORMAT_VERSION } from '../src/json';
+import { renderLlmExport } from '../src/llm';
+import { SCHEMA_VERSION } from '../src/schema';
+import { chart } from './fixtures';
+
+const SNAKE_CASE = /^[a-z][a-z0-9_]*$/;
+
+/** 중첩된 모든 객체의 키 (열거형 값을 키로 쓰는 맵은 제외) */
+function allKeys(value: unknown, skip: ReadonlySet<string>): string[] {
+  if (Array.isArray(value)) return value.flatMap((v) => allKeys(v, skip));
+  if (typeof value !== 'object' || value === null) return [];
+  return Object.entries(value).flatMap(([key, v]) => (skip.has(key) ? [key] : [key, ...allKeys(v, skip)]));
+}
+
+describe('chartJson', () => {
+  it('명식 전체를 최상위 항목으로 싣는다', () => {
+    const json = chartJson(chart('Male', 2024));
+    expect(Object.keys(json)).toEqual([
+      'schema_version', 'format', 'fingerprint', 'input', 'warnings', 'birth_term', 'pillars', 'day_master', 'strength',
+      'elements', 'ten_gods', 'gyeokguk', 'yongshin', 'stem_interactions', 'branch_interactions', 'shinsal',
+      'daewon', 'yearly', 'monthly', 'solar_terms', 'boundary_sensitivity', 'julian_day',
+    ]);
+    expect(json.schema_version).toBe(SCHEMA_VERSION);
+    expect(json.format).toBe(JSON_FORMAT_VERSION);
+    expect(allKeys(json, new Set(['elements', 'ten_gods'])).every((key) => SNAKE_CASE.test(key))).toBe(true);
+  });
+
+  it('운과 절기는 현지 시각 경계와 함께 싣는다', () => {
+    const json = chartJson(chart('Male', 2024));
+    expect(Object.keys(json.pillars)).toEqual(['year', 'month', 'day', 'hour']);
+    expect(json.pillars.day.hidden_stems.length).toBeGreaterThan(0);
+    expect(json.daewon.items).toHaveLength(10);
+    expect(json.daewon.items[0].start).toMatch(/^\d{4}-\d{2}-\d{2} \d{2}:\d{2}$/);
+    expect(json.yearly.map((y) => y.year)).toEqual([2024, 2025, 2026]);
+    expect(json.monthly[0].months).toHaveLength(12);
+    expect(json.solar_terms).toHaveLength(24);
+    expect(json.julian_day).toBeNull();
+    expect(chartJson(chart('Male', 2024), true).julian_day).not.toBeNull();
+  });
+
+  it('llm 내보내기와 같은 항목은 같은 모양이다', () => {
+    const llm = new Map(renderLlmExport(chart('Male', 2024)).map((line) => {
+      const at = line.indexOf(': ');
+      return [line.slice(0, at), JSON.parse(line.slice(at + 2)) as unknown];
+    }));
+    const json = JSON.parse(JSON.stringify(chartJson(chart('Male', 2024)))) as Record<string, unknown>;
+    for (const key of ['input', 'birth_term', 'day_master', 'yongshin', 'shinsal']) expect(json[key], key).toEqual(llm.get(key));
+    expect((json.pillars as Record<string, unknown>).hour).toEqual(llm.get('pillar.hour'));
+  });
+});
+
+describe('pillarsJson', () => {
+  it('명식 전체 문서와 다른 형식 식별자로 네 기둥 간지만 싣는다', () => {
+    const json = pillarsJson(calculatePillars({
+      date: '2000-01-15', time: '17:15', calendar: 'Solar', leapMonth: false,
+      tz: 'Asia/Seoul', useLmt: false, longitude: null, location: null,
+    }));
+    expect(Object.keys(json)).toEqual(['schema_version', 'format', 'pillars', 'approximate', 'hemisphere', 'warnings']);
+    expect(json.format).toBe(PILLARS_JSON_FORMAT_VERSION);
+    expect(json.format).not.toBe(JSON_FORMAT_VERSION);
+    expect(json.pillars.year).toBe('己卯');
+  });
+});
@@ -0,0 +1,102 @@
+import { describe, expect, it } from 'vitest';
+import { calculate, calculatePillars, type SajuRequest } from 'saju-lib';
+import { chartJson, pillarsJson } from '../src/json';
+import { chartReportSchema, pillarsReportSchema, type JsonSchema } from '../src/jsonSchema';
+
+const SCHEMA = chartReportSchema();
+
+function request(overrides: Partial<SajuRequest> = {}): SajuRequest {
+  return {
+    date: '2000-01-15', time: '17:15', calendar: 'Solar', leapMonth: false, gender: 'Male',
+    tz: 'Asia/Seoul', useLmt: false, longitude: null, location: null,
+    daewonCount: 10, monthYear: 2024, yearStart: 2024, yearCount: 3,
+    ...overrides,
+  };
+}
+
+function typeOf(value: unknown): string {
+  if (value === null) return 'null';
+  if (Array.isArray(value)) return 'array';
+  if (typeof value === 'number') return Number.isInteger(value) ? 'integer' : 'number';
+  return typeof value;
+}
+
+/**
+ * 이 스키마가 쓰는 키워드만 해석하는 작은 검사기. 위반 경로 목록을 돌려준다.
+ * 문서 완전성을 위해 스키마의 properties에 없는 필드도 위반으로 센다.
+ */
+function violations(value: unknown, schema: JsonSchema, path = '$', root: JsonSchema = schema): string[] {
+  const check = (v: unknown, s: JsonSchema, p: string) => violations(v, s, p, root);
+  if (schema.$ref) return check(value, root.$defs![schema.$ref.replace('#/$defs/', '')], path);
+  if (schema.anyOf) {
+    return schema.anyOf.some((s) => check(value, s, path).length === 0) ? [] : [`${path}: no anyOf branch matches`];
+  }
+  if ('const' in schema && value !== schema.const) return [`${path}: expected ${String(schema.const)}`];
+  if (schema.enum && !schema.enum.includes(value)) return [`${path}: ${String(value)} not in enum`];
+  if (schema.type) {
+    const actual = typeOf(value);
+    const ok = schema.type === actual || (schema.type === 'number' && actual === 'integer');
+    if (!ok) return [`${path}: expected ${String(schema.type)}, got ${actual}`];
+  }
+  if (Array.isArray(value) && schema.items) {
+    return value.flatMap((v, i) => check(v, schema.items!, `${path}[${i}]`));
+  }
+  if (typeOf(value) === 'object' && schema.properties) {
+    const obj = value as Record<string, unknown>;
+    return [
+      ...(schema.required ?? []).filter((key) => !(key in obj)).map((key) => `${path}.${key}: missing`),
+      ...Object.keys(obj).filter((key) => !(key in schema.properties!)).map((key) => `${path}.${key}: not in schema`),
+      ...Object.entries(obj).flatMap(([key, v]) => (schema.properties![key] ? check(v, schema.properties![key], `${path}.${key}`) : [])),
+    ];
+  }
+  return [];
+}
+
+function check(req: SajuRequest, showJd = false): string[] {
+  return violations(JSON.parse(JSON.stringify(chartJson(calculate(req), showJd))), SCHEMA);
+}
+
+describe('chartReportSchema', () => {
+  it('draft 2020-12 스키마이고 최상위 필드를 모두 required로 싣는다', () => {
+    expect(SCHEMA.$schema).toBe('https://json-schema.org/draft/2020-12/schema');
+    expect(SCHEMA.required).toEqual(Object.keys(chartJson(calculate(request()))));
+  });
+
+  it('기본 명식이 스키마를 따른다', () => {
+    expect(check(request())).toEqual([]);
+  });
+
+  it('성별 미상·경계 민감도·율리우스일이 있는 명식도 따른다', () => {
+    expect(check(request({ gender: null }))).toEqual([]);
+    expect(check(request({ time: '23:40', boundaryWindow: 60 }), true)).toEqual([]);
+  });
+
+  it('음력·지방시·율리우스력 입력도 따른다', () => {
+    expect(check(request({ calendar: 'Lunar', date: '1985-01-10', useLmt: true, location: 'busan' }))).toEqual([]);
+    expect(check(request({ date: '1917-10-25', time: '12:00', tz: 'Europe/Moscow', historicalCalendar: 'Auto' }))).toEqual([]);
+  });
+
+  it('스키마에 없는 필드와 잘못된 타입을 잡는다', () => {
+    const json = JSON.parse(JSON.stringify(chartJson(calculate(request())))) as Record<string, unknown>;
+    expect(violations({ ...json, extra: 1 }, SCHEMA)).toEqual(['$.extra: not in schema']);
+    expect(violations({ ...json, fingerprint: 1 }, SCHEMA)).toEqual(['$.fingerprint: expected string, got integer']);
+  });
+});
+
+describe('pillarsReportSchema', () => {
+  function pillars(overrides: Partial<SajuRequest> = {}) {
+    return JSON.parse(JSON.stringify(pillarsJson(calculatePillars(request(overrides))))) as unknown;
+  }
+
+  it('--pillars-only 문서가 따로 낸 스키마를 따른다', () => {
+    const schema = pillarsReportSchema();
+    expect(violations(pillars(), schema)).toEqual([]);
+    expect(violations(pillars({ time: '묘시', hemisphere: 'South' }), schema)).toEqual([]);
+  });
+
+  it('명식 전체 스키마와 서로 맞지 않는다', () => {
+    expect(violations(pillars(), SCHEMA)).toContain('$.format: expected saju-json/2');
+    expect(violations(JSON.parse(JSON.stringify(chartJson(calculate(request())))), pillarsReportSchema()))
+      .toContain('$.format: expected saju-pillars-json/2');
+  });
+});
@@ -0,0 +1,61 @@
+import { describe, expect, it } from 'vitest';
+import { KasiClient, parseItems, type Fetcher } from '../src/kasi';
+
+function body(item: unknown, resultCode = '00') {
+  return { response: { header: { resultCode, resultMsg: 'NORMAL SERVICE.' }, body: { items: item === undefined ? '' : { item } } } };
+}
+
+function fakeFetcher(payload: unknown, urls: string[] = []): Fetcher {
+  return async (url) => {
+    urls.push(url);
+    return { ok: true, status: 200, json: async () => payload };
+  };
+}
+
+describe('parseItems', () => {
+  it('단일 객체·배열·빈 결과를 모두 배열로 돌려준다', () => {
+    expect(parseItems(body({ a: 1 }), 'op')).toEqual([{ a: 1 }]);
+    expect(parseItems(body([{ a: 1 }, { a: 2 }]), 'op')).toHaveLength(2);
+    expect(parseItems(body(undefined), 'op')).toEqual([]);
+  });
+
+  it('오류 코드면 예외', () => {
+    expect(() => parseItems(body({}, '30'), 'op')).toThrow(/op failed/);
+    expect(() => parseItems({}, 'op')).toThrow(/malformed/);
+  });
+});
+
+describe('KasiClient', () => {
+  it('음력 날짜를 변환하고 서비스 키를 쿼리에 싣는다', async () => {
+    const urls: string[] = [];
+    const client = new KasiClient({
+      serviceKey: 'key',
+      fetcher: fakeFetcher(body({ lunYear: '2023', lunMonth: '02', lunDay: '01', lunLeapmonth: '윤' }), urls),
+    });
+    expect(await client.lunarDate(2023, 3, 22)).toEqual({
+      solar: '2023-03-22',
+      lunar: { year: 2023, month: 2, day: 1, leap: true },
+    });
+    expect(urls[0]).toContain('LrsrCldInfoService/getLunCalInfo');
+    expect(urls[0]).toContain('ServiceKey=key');
+    expect(urls[0]).toContain('solMonth=03');
+  });
+
+  it('절기 이름을 키로, 시각을 KST 문자열로 바꾼다', async () => {
+    const client = new KasiClient({
+      serviceKey: 'key',
+      fetcher: fakeFetcher(body([
+        { dateName: '입춘', locdate: 20240204, kst: '1727  ', sunLongitude: 315 },
+        { dateName: '우수', locdate: 20240219, kst: '0513', sunLongitude: 330 },
+      ])),
+    });
+    expect(await client.solarTerms(2024, 2)).toEqual([
+      { key: 'lichun', kst: '2024-02-04T17:27' },
+      { key: 'yushui', kst: '2024-02-19T05:13' },
+    ]);
+  });
+
+  it('빈 서비스 키는 거부한다', () => {
+    expect(() => new KasiClient({ serviceKey: ' ' })).toThrow(/service key/);
+  });
+});
@@ -0,0 +1,48 @@
+import { describe, expect, it } from 'vitest';
+import { LLM_FORMAT_VERSION, renderLlmExport } from '../src/llm';
+import { aggregateStats, statsJson } from '../src/stats';
+import { chart } from './fixtures';
+
+const SNAKE_CASE = /^[a-z][a-z0-9_]*(\.[a-z][a-z0-9_]*)?$/;
+
+function entries(lines: string[]): [string, unknown][] {
+  return lines.map((line) => {
+    const at = line.indexOf(': ');
+    return [line.slice(0, at), JSON.parse(line.slice(at + 2))];
+  });
+}
+
+describe('renderLlmExport', () => {
+  it('키 목록과 순서가 고정되어 있다', () => {
+    const keys = entries(renderLlmExport(chart())).map(([key]) => key);
+    expect(keys).toEqual([
+      'format', 'schema_version', 'fingerprint', 'input', 'warnings', 'birth_term',
+      'pillar.year', 'pillar.month', 'pillar.day', 'pillar.hour',
+      'day_master', 'elements', 'ten_gods', 'gyeokguk', 'yongshin',
+      'stem_interactions', 'branch_interactions', 'shinsal', 'daewon', 'yearly',
+    ]);
+    expect(keys.every((key) => SNAKE_CASE.test(key))).toBe(true);
+  });
+
+  it('형식 식별자와 입력 필드 이름을 싣는다', () => {
+    const map = new Map(entries(renderLlmExport(chart())));
+    expect(map.get('format')).toBe(LLM_FORMAT_VERSION);
+    expect(Object.keys(map.get('input') as object)).toEqual([
+      'date', 'time', 'raw_input', 'calendar', 'leap_month', 'time_zone', 'corrected_local_time', 'gender', 'approximate',
+      'hemisphere', 'historical_date',
+    ]);
+  });
+
+  it('julian_day 줄은 showJd일 때만 붙는다', () => {
+    expect(renderLlmExport(chart()).some((l) => l.startsWith('julian_day: '))).toBe(false);
+    expect(renderLlmExport(chart(), true).some((l) => l.startsWith('julian_day: '))).toBe(true);
+  });
+});
+
+describe('statsJson', () => {
+  it('필드 이름이 snake_case로 고정되어 있다', () => {
+    expect(Object.keys(statsJson(aggregateStats([])))).toEqual([
+      'total', 'day_masters', 'element_histogram', 'strength', 'daewon_direction',
+    ]);
+  });
+});
@@ -0,0 +1,79 @@
+import { describe, expect, it } from 'vitest';
+import { calculatePillars, I18n } from 'saju-lib';
+import { markdownCell, renderMarkdown, renderPillarsMarkdown } from '../src/markdown';
+import { chart } from './fixtures';
+
+const OPTIONS = { event: false, asOf: new Date('2024-06-01T00:00:00Z') };
+
+/** '## heading' 아래 첫 빈 줄 다음부터 다음 빈 줄까지 */
+function section(lines: string[], heading: string): string[] {
+  const start = lines.indexOf(`## ${heading}`);
+  expect(start, heading).toBeGreaterThan(0);
+  const body = lines.slice(start + 2);
+  const end = body.indexOf('');
+  return end < 0 ? body : body.slice(0, end);
+}
+
+describe('renderMarkdown', () => {
+  it('제목과 섹션 제목을 마크다운 제목으로 쓴다', () => {
+    const lines = renderMarkdown(chart('Male', 2024), new I18n('Ko'), OPTIONS);
+    expect(lines[0]).toBe('# 사주팔자 (입춘 기준)');
+    expect(lines.filter((line) => line.startsWith('## '))).toEqual([
+      '## 천간/지지', '## 십성(일간 기준)', '## 지장간', '## 신강/신약(간단 판정)',
+      '## 대운', '## 세운 (입춘 기준)', '## 월운 (2024년)',
+    ]);
+    expect(lines[lines.length - 1]).not.toBe('');
+  });
+
+  it('네 기둥·십성·지장간은 연·월·일·시 열의 표로 쓴다', () => {
+    const lines = renderMarkdown(chart('Male', 2024), new I18n('Ko'), OPTIONS);
+    const pillars = section(lines, '천간/지지');
+    expect(pillars[0]).toBe('|  | 연주 | 월주 | 일주 | 시주 |');
+    expect(pillars[1]).toBe('| --- | --- | --- | --- | --- |');
+    expect(pillars[2]).toMatch(/^\| 천간 \| 기\(己\) /);
+    expect(pillars.slice(2).map((row) => row.split(' | ')[0])).toEqual(['| 천간', '| 지지', '| 12운성']);
+    expect(section(lines, '십성(일간 기준)')[2].split(' | ')[3]).toBe('일간');
+    const hidden = section(lines, '지장간');
+    expect(hidden.slice(2).map((row) => row.split(' | ')[0])).toEqual(['| 정기', '| 여기', '| 중기']);
+    // 일지 申: 戊가 여기, 壬이 중기
+    expect(hidden.slice(3).map((row) => row.split(' | ')[3])).toEqual([expect.stringMatching(/^무\(戊\) /), expect.stringMatching(/^임\(壬\) /)]);
+    expect(hidden.every((row) => row.split('|').length === 7)).toBe(true);
+  });
+
+  it('운은 기간이 붙은 목록으로 쓴다', () => {
+    const lines = renderMarkdown(chart('Male', 2024), new I18n('Ko'), OPTIONS);
+    const daewon = lines.slice(lines.indexOf('## 대운'), lines.indexOf('## 세운 (입춘 기준)'));
+    expect(daewon[2]).toMatch(/^역행, 시작 /);
+    expect(daewon.filter((line) => line.startsWith('- **'))).toHaveLength(10);
+    const yearly = section(lines, '세운 (입춘 기준)');
+    expect(yearly).toHaveLength(3);
+    expect(yearly[0]).toMatch(/^- \*\*2024년\*\* 갑진\(甲辰\) \(2024-02-04 ~ 2025-02-03\) — 십성: /);
+    expect(section(lines, '월운 (2024년)')).toHaveLength(12);
+  });
+
+  it('이벤트 차트와 성별 미상은 대운 섹션이 없다', () => {
+    const unknown = renderMarkdown(chart(null, 2024), new I18n('En'), OPTIONS);
+    expect(unknown).not.toContain('## Decennial Luck');
+    expect(unknown).toContain('## Yearly Luck (Lichun)');
+    const event = renderMarkdown(chart('Male', 2024), new I18n('En'), { ...OPTIONS, event: true });
+    expect(event.some((line) => line.startsWith('## Yearly Luck'))).toBe(false);
+  });
+});
+
+describe('renderPillarsMarkdown', () => {
+  it('제목과 네 기둥 표만 쓴다', () => {
+    const result = calculatePillars({
+      date: '2000-01-15', time: '17:15', calendar: 'Solar', leapMonth: false,
+      tz: 'Asia/Seoul', useLmt: false, longitude: null, location: null,
+    });
+    const lines = renderPillarsMarkdown(result, new I18n('Ko'));
+    expect(lines[0]).toBe('# 사주팔자 (입춘 기준)');
+    expect(lines.filter((line) => line.startsWith('## '))).toEqual(['## 천간/지지']);
+  });
+});
+
+describe('markdownCell', () => {
+  it('표를 깨는 문자를 이스케이프한다', () => {
+    expect(markdownCell('a|b\nc')).toBe('a\\|b c');
+  });
+});
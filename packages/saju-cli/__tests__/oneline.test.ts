@@ -0,0 +1,29 @@
+import { describe, expect, it } from 'vitest';
+import { I18n } from 'saju-lib';
+import { renderHanjaLine, renderOneLine } from '../src/report';
+import { chart } from './fixtures';
+
+describe('renderOneLine', () => {
+  it('4기둥, 강약, 용신, 대운 방향과 시작 나이를 한 줄에 싣는다', () => {
+    const line = renderOneLine(chart('Male'), new I18n('Ko'));
+    expect(line).toMatch(/^기묘년 정축월 \S+일 \S+시 \| 신약 \| 용신:수 \| 대운:역행 4세$/);
+  });
+
+  it('성별이 없으면 대운을 생략한다', () => {
+    const line = renderOneLine(chart(null), new I18n('En'));
+    expect(line).toMatch(/^GiMyo year, JeongChuk month, \S+ day, \S+ hour \| Weak \| Yongshin:Water$/);
+  });
+});
+
+describe('renderHanjaLine', () => {
+  it('여덟 글자를 연·월·일·시 순서의 한자로만 쓴다', () => {
+    expect(renderHanjaLine(chart('Male'), null, new I18n('Ko'))).toMatch(/^己卯 丁丑 \S{2} \S{2}$/);
+  });
+
+  it('강약을 주면 언어에 맞춰 덧붙인다', () => {
+    const result = chart(null);
+    const line = renderHanjaLine(result, result.strength, new I18n('En'));
+    expect(line).toBe(`${renderHanjaLine(result, null, new I18n('En'))} | ${new I18n('En').strengthLevelLabel(result.strength.level)}`);
+    expect(renderHanjaLine(result, result.strength, new I18n('Ko'))).toMatch(/ \| (극신강|신강|중화|신약|극신약)$/);
+  });
+});
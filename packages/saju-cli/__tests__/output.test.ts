@@ -0,0 +1,24 @@
+import { describe, expect, it } from 'vitest';
+import { mkdtempSync, readFileSync, writeFileSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { openOutput } from '../src/output';
+
+describe('openOutput', () => {
+  it('줄마다 줄바꿈을 붙여 UTF-8로 쓰고 기존 파일은 덮어쓴다', () => {
+    const path = join(mkdtempSync(join(tmpdir(), 'saju-output-')), 'report.txt');
+    writeFileSync(path, 'old contents that are longer than the report\n');
+    const out = openOutput(path);
+    out.line('사주팔자 (입춘 기준)');
+    out.line('- 연주: 기묘(己卯)');
+    out.close();
+    const bytes = readFileSync(path);
+    expect(bytes[0]).not.toBe(0xef);
+    expect(bytes.toString('utf8')).toBe('사주팔자 (입춘 기준)\n- 연주: 기묘(己卯)\n');
+  });
+
+  it('쓸 수 없는 경로는 옵션 이름과 함께 거부한다', () => {
+    const path = join(mkdtempSync(join(tmpdir(), 'saju-output-')), 'missing', 'report.txt');
+    expect(() => openOutput(path)).toThrow(/^cannot write --output .*report\.txt: ENOENT/);
+  });
+});
@@ -0,0 +1,51 @@
+import { describe, expect, it } from 'vitest';
+import { Progress, formatEta } from '../src/progress';
+
+function capture(isTTY = false) {
+  const chunks: string[] = [];
+  return { chunks, stream: { isTTY, write: (text: string) => chunks.push(text) } };
+}
+
+describe('Progress', () => {
+  it('json 모드는 진행률과 ETA를 JSON 줄로 낸다', () => {
+    const { chunks, stream } = capture();
+    let t = 0;
+    const progress = new Progress('batch', 4, 'json', stream, () => t);
+    t = 1000;
+    progress.tick();
+    t = 1500;
+    progress.tick(); // 간격 1초 미만이라 생략
+    t = 4000;
+    progress.tick(2);
+    expect(chunks.map((c) => JSON.parse(c))).toEqual([
+      { progress: 'batch', done: 1, total: 4, percent: 25, eta_seconds: 3 },
+      { progress: 'batch', done: 4, total: 4, percent: 100, eta_seconds: 0 },
+    ]);
+  });
+
+  it('auto 모드는 터미널이 아니면 아무것도 쓰지 않는다', () => {
+    const { chunks, stream } = capture(false);
+    const progress = new Progress('selfcheck', 2, 'auto', stream);
+    progress.tick();
+    progress.tick();
+    progress.finish();
+    expect(chunks).toEqual([]);
+  });
+
+  it('bar 모드는 한 줄을 덮어쓰고 finish에서 지운다', () => {
+    const { chunks, stream } = capture(true);
+    const progress = new Progress('batch', 2, 'auto', stream, () => 0);
+    progress.tick();
+    expect(chunks[0]).toMatch(/^\rbatch \[#{12}\.{12}\]  50% 1\/2 ETA 0s$/);
+    progress.finish();
+    expect(chunks.at(-1)).toBe('\r\x1b[2K');
+  });
+});
+
+describe('formatEta', () => {
+  it('초·분·시 단위로 줄여 쓴다', () => {
+    expect(formatEta(42.4)).toBe('42s');
+    expect(formatEta(185)).toBe('3m05s');
+    expect(formatEta(3720)).toBe('1h02m');
+  });
+});
@@ -0,0 +1,122 @@
+import { describe, expect, it } from 'vitest';
+import { SCHEMA_VERSION, versioned } from '../src/schema';
+import { renderLlmExport } from '../src/llm';
+import { aggregateStats, statsJson } from '../src/stats';
+import { KASI_SAMPLE, verifyJson, verifyReference } from '../src/verify';
+import { convertDate } from '../src/convert';
+import { datePillarsJson, pillarsOfDate } from '../src/ganji';
+import { runSelfCheck } from '../src/selfcheck';
+import { runCrossCheck } from '../src/crosscheck';
+import { answerQuery } from '../src/daemon';
+import { chartJson } from '../src/json';
+import { chart } from './fixtures';
+
+/**
+ * 스키마 버전 2에서 낸 필드 목록. 필드를 추가하는 것은 자유지만 여기 있는 이름은 지우거나
+ * 바꾸면 안 된다. 호환되지 않는 변경이 필요하면 SCHEMA_VERSION을 올리고 이 목록을 새 버전으로 갱신한다.
+ */
+const FROZEN_V2: Record<string, string[]> = {
+  'llm': [
+    'format', 'schema_version', 'fingerprint', 'input', 'warnings', 'birth_term',
+    'pillar.year', 'pillar.month', 'pillar.day', 'pillar.hour',
+    'day_master', 'elements', 'ten_gods', 'gyeokguk', 'yongshin',
+    'stem_interactions', 'branch_interactions', 'shinsal', 'daewon', 'yearly',
+  ],
+  'llm.input': ['date', 'time', 'raw_input', 'calendar', 'leap_month', 'time_zone', 'corrected_local_time', 'gender', 'approximate'],
+  'llm.birth_term': ['id', 'ko', 'en', 'start', 'days_since', 'next', 'next_start', 'days_until'],
+  'llm.day_master': ['id', 'ko', 'en', 'element', 'strength', 'strength_score'],
+  'llm.yongshin': ['method', 'yongshin', 'heeshin', 'gishin', 'gushin'],
+  'stats': ['schema_version', 'total', 'day_masters', 'element_histogram', 'strength', 'daewon_direction'],
+  'verify': ['schema_version', 'terms', 'max_term_minutes', 'lunar_checked', 'lunar_mismatches'],
+  'verify.terms': ['key', 'kst', 'minutes'],
+  'convert': ['schema_version', 'solar', 'lunar'],
+  'convert.lunar': ['year', 'month', 'day', 'leap'],
+  'ganji': ['schema_version', 'year', 'month', 'day', 'hour'],
+  'selfcheck': ['schema_version', 'from', 'to', 'checked', 'issues'],
+  'crosscheck': ['schema_version', 'from', 'to', 'checked', 'issues'],
+  'json': [
+    'schema_version', 'format', 'fingerprint', 'input', 'warnings', 'birth_term', 'pillars', 'day_master', 'strength',
+    'elements', 'ten_gods', 'gyeokguk', 'yongshin', 'stem_interactions', 'branch_interactions', 'shinsal',
+    'daewon', 'yearly', 'monthly', 'solar_terms', 'boundary_sensitivity', 'julian_day',
+  ],
+  'json.strength': ['level', 'verdict', 'score', 'month_stage', 'month_scoring', 'month_score', 'root_count', 'pillars'],
+  'json.daewon': ['direction', 'direction_mode', 'start_months', 'start_span', 'items'],
+  'daemon': ['schema_version', 'id', 'lines'],
+  'daemon.error': ['schema_version', 'id', 'error'],
+};
+
+function llmEntries(): Map<string, unknown> {
+  return new Map(renderLlmExport(chart()).map((line) => {
+    const at = line.indexOf(': ');
+    return [line.slice(0, at), JSON.parse(line.slice(at + 2))];
+  }));
+}
+
+function keysOf(value: unknown): string[] {
+  return Object.keys(value as object);
+}
+
+function expectFrozen(name: string, keys: string[]): void {
+  expect(keys, name).toEqual(expect.arrayContaining(FROZEN_V2[name]));
+}
+
+describe('versioned', () => {
+  it('schema_version을 맨 앞에 붙인다', () => {
+    expect(versioned({ a: 1 })).toEqual({ schema_version: SCHEMA_VERSION, a: 1 });
+    expect(keysOf(versioned({ a: 1 }))[0]).toBe('schema_version');
+  });
+});
+
+describe('구조화 출력 호환성 (추가만 허용)', () => {
+  it('고정 목록은 현재 스키마 버전의 것이다', () => {
+    expect(SCHEMA_VERSION).toBe(2);
+  });
+
+  it('llm 내보내기', () => {
+    const llm = llmEntries();
+    expectFrozen('llm', [...llm.keys()]);
+    expect(llm.get('schema_version')).toBe(SCHEMA_VERSION);
+    expectFrozen('llm.input', keysOf(llm.get('input')));
+    expectFrozen('llm.birth_term', keysOf(llm.get('birth_term')));
+    expectFrozen('llm.day_master', keysOf(llm.get('day_master')));
+    expectFrozen('llm.yongshin', keysOf(llm.get('yongshin')));
+  });
+
+  it('--format json', () => {
+    const json = chartJson(chart());
+    expectFrozen('json', keysOf(json));
+    expectFrozen('json.strength', keysOf(json.strength));
+    expectFrozen('json.daewon', keysOf(json.daewon));
+  });
+
+  it('stats 줄', () => {
+    expectFrozen('stats', keysOf(versioned(statsJson(aggregateStats([])))));
+  });
+
+  it('verify --json', () => {
+    const json = versioned(verifyJson(verifyReference(KASI_SAMPLE)));
+    expectFrozen('verify', keysOf(json));
+    expectFrozen('verify.terms', keysOf(json.terms[0]));
+  });
+
+  it('convert --json', () => {
+    const json = versioned(convertDate('2020-05-23', 'lunar', false));
+    expectFrozen('convert', keysOf(json));
+    expectFrozen('convert.lunar', keysOf(json.lunar));
+  });
+
+  it('ganji --json', () => {
+    expectFrozen('ganji', keysOf(versioned(datePillarsJson(pillarsOfDate('2020-05-23', '12:00', 'Asia/Seoul')))));
+  });
+
+  it('selfcheck·crosscheck --json', () => {
+    expectFrozen('selfcheck', keysOf(versioned(runSelfCheck(2020, 2020))));
+    expectFrozen('crosscheck', keysOf(versioned(runCrossCheck(2020, 2020))));
+  });
+
+  it('daemon 응답', () => {
+    const answer = (line: string) => keysOf(JSON.parse(answerQuery(line, 1, (_, index) => ({ index, lines: [], sample: null }))));
+    expectFrozen('daemon', answer('{"id": "a"}'));
+    expectFrozen('daemon.error', answer('{oops'));
+  });
+});
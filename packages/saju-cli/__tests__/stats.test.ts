@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vitest';
+import { I18n } from 'saju-lib';
+import { aggregateStats, chartSample, formatStats, type ChartSample } from '../src/stats';
+import { chart } from './fixtures';
+
+function sample(overrides: Partial<ChartSample>): ChartSample {
+  return { dayStem: 0, elements: [2, 2, 2, 1, 1], level: 'Neutral', gender: 'Male', direction: 'Forward', ...overrides };
+}
+
+describe('aggregateStats', () => {
+  it('일간·오행 개수·강약·성별별 대운 방향을 센다', () => {
+    const stats = aggregateStats([
+      sample({}),
+      sample({ dayStem: 3, elements: [0, 3, 2, 2, 1], level: 'Weak', gender: 'Female', direction: 'Backward' }),
+      sample({ gender: null, direction: null }),
+    ]);
+    expect(stats.total).toBe(3);
+    expect(stats.dayMasters[0]).toBe(2);
+    expect(stats.dayMasters[3]).toBe(1);
+    expect(stats.elementHistogram.Wood.slice(0, 3)).toEqual([1, 0, 2]);
+    expect(stats.strength).toMatchObject({ Neutral: 2, Weak: 1, Strong: 0 });
+    expect(stats.daewonDirection).toEqual({
+      Male: { Forward: 1, Backward: 0 },
+      Female: { Forward: 0, Backward: 1 },
+    });
+  });
+});
+
+describe('chartSample', () => {
+  it('계산 결과에서 집계용 요약을 뽑는다', () => {
+    const result = chart();
+    const s = chartSample(result);
+    expect(s.dayStem).toBe(result.dayPillar.stem);
+    expect(s.elements.reduce((a, b) => a + b, 0)).toBe(8);
+    expect(s.direction).toBe(result.daewonDirection);
+  });
+});
+
+describe('formatStats', () => {
+  it('개수와 비율을 표시한다', () => {
+    const lines = formatStats(aggregateStats([sample({}), sample({ level: 'Strong' })]), new I18n('Ko'));
+    expect(lines[0]).toBe('일괄 처리 통계 (n=2)');
+    expect(lines.some((l) => l.includes('50%'))).toBe(true);
+  });
+});
@@ -0,0 +1,67 @@
+import { describe, expect, it } from 'vitest';
+import {
+  abbreviateLabels,
+  alignColumns,
+  displayWidth,
+  fitToWidth,
+  padEndDisplay,
+  wrapLine,
+} from '../src/textWidth';
+
+describe('textWidth', () => {
+  it('한글·한자는 2칸, ASCII는 1칸으로 센다', () => {
+    expect(displayWidth('abc')).toBe(3);
+    expect(displayWidth('갑(甲)')).toBe(6);
+    expect(displayWidth('일주')).toBe(4);
+  });
+
+  it('ANSI 색상 코드는 폭에 포함하지 않는다', () => {
+    expect(displayWidth('\x1b[31m갑\x1b[0m')).toBe(2);
+  });
+
+  it('표시 폭 기준으로 패딩한다', () => {
+    expect(padEndDisplay('갑', 4)).toBe('갑  ');
+    expect(padEndDisplay('abcdef', 4)).toBe('abcdef');
+  });
+
+  it('열 폭을 가장 넓은 셀에 맞추고 줄 끝 공백을 제거한다', () => {
+    const lines = alignColumns([
+      ['', '시주', '일주'],
+      ['천간', '갑(甲)', 'x'],
+    ]);
+    expect(lines).toEqual([
+      '      시주    일주',
+      '천간  갑(甲)  x',
+    ]);
+  });
+
+  it('한자 병기 괄호를 제거해 축약한다', () => {
+    expect(abbreviateLabels('비견(比肩) / Companion (比肩)')).toBe('비견 / Companion');
+    expect(abbreviateLabels('지지(본기)')).toBe('지지(본기)');
+  });
+
+  it('구분자 뒤에서 줄을 나누고 이어지는 줄은 들여쓴다', () => {
+    const line = '- 지지(본기): 연지 비견(比肩) / 월지 편재(偏財) / 일지 정인(正印) / 시지 정재(正財)';
+    const wrapped = wrapLine(line, 40);
+    expect(wrapped).toEqual([
+      '- 지지(본기): 연지 비견(比肩) /',
+      '  월지 편재(偏財) / 일지 정인(正印) /',
+      '  시지 정재(正財)',
+    ]);
+    for (const l of wrapped) expect(displayWidth(l)).toBeLessThanOrEqual(40);
+  });
+
+  it('구분자가 없는 긴 줄은 폭 단위로 자른다', () => {
+    expect(wrapLine('x'.repeat(30), 20)).toEqual(['x'.repeat(20), `  ${'x'.repeat(10)}`]);
+  });
+
+  it('강제로 자를 때 ANSI 색상 코드 중간에서 자르지 않는다', () => {
+    const red = `\x1b[31m${'x'.repeat(30)}\x1b[0m`;
+    expect(wrapLine(red, 20)).toEqual([`\x1b[31m${'x'.repeat(20)}`, `  ${'x'.repeat(10)}\x1b[0m`]);
+  });
+
+  it('폭이 없으면 축약만 적용한다', () => {
+    expect(fitToWidth(['갑자(甲子)'], { width: null, compact: true })).toEqual(['갑자']);
+    expect(fitToWidth(['갑자(甲子)'], { width: null, compact: false })).toEqual(['갑자(甲子)']);
+  });
+});
@@ -0,0 +1,136 @@
+/**
+ * @fileoverview 일괄 처리(--batch) 입력 파일 파서
+ *
+ * JSON 배열 또는 JSONL(한 줄에 레코드 하나) 파일을 읽어 레코드마다
+ * 명령줄 옵션을 덮어쓸 값을 만든다. 키는 옵션의 camelCase 이름이며
+ * (예: "tz", "calendar", "location", "lmtBoundary", "sections"),
+ * 레코드에 없는 옵션은 명령줄 값을 그대로 쓴다.
+ *
+ * 예:
+ *   {"id": "c-001", "date": "1990-05-20", "time": "08:30", "gender": "female"}
+ *   {"id": "c-002", "date": "1985-03-01", "time": "23:40", "gender": "m", "calendar": "lunar", "location": "busan"}
+ */
+
+import { input } from 'saju-lib';
+import type { ChartSample } from './stats.js';
+
+/** 문자열 값으로 덮어쓸 수 있는 옵션 (숫자도 허용해 문자열로 바꾼다) */
+const STRING_KEYS = [
+  'date', 'time', 'datetime', 'gender', 'calendar', 'tz', 'location', 'longitude', 'lmtBoundary',
+  'sections', 'daewonCount', 'daewonRounding', 'daewonDirection', 'daewonYear', 'monthYear', 'yearStart', 'yearCount',
+  'school', 'dayBoundary', 'hemisphere', 'historicalCalendar', 'boundaryWindow', 'shinsalBase', 'name', 'nameHanja', 'nameStrokes',
+] as const;
+
+/** 불리언 값으로 덮어쓸 수 있는 옵션 */
+const BOOLEAN_KEYS = ['event', 'leapMonth', 'localMeanTime', 'force', 'pillarsOnly'] as const;
+
+type StringKey = typeof STRING_KEYS[number];
+type BooleanKey = typeof BOOLEAN_KEYS[number];
+
+/** 레코드 하나가 덮어쓰는 옵션 */
+export type BatchOverrides = Partial<Record<StringKey, string> & Record<BooleanKey, boolean>>;
+
+/** 일괄 처리 레코드 */
+export interface BatchRecord {
+  /** 출력 구분용 식별자 (레코드의 "id", 없으면 null) */
+  id: string | null;
+  overrides: BatchOverrides;
+}
+
+/**
+ * 일괄 처리 파일 내용을 레코드 목록으로 변환한다.
+ * 첫 글자가 '['이면 JSON 배열, 아니면 JSONL로 읽는다 (빈 줄은 건너뜀).
+ *
+ * @param text 파일 내용
+ * @throws Error JSON 문법 오류나 알 수 없는 키·잘못된 값 (레코드 번호 포함)
+ */
+export function parseBatchFile(text: string): BatchRecord[] {
+  const trimmed = text.trim();
+  let raws: unknown[];
+  if (trimmed.startsWith('[')) {
+    const parsed: unknown = JSON.parse(trimmed);
+    if (!Array.isArray(parsed)) throw new Error('batch file must be a JSON array or JSON lines');
+    raws = parsed;
+  } else {
+    raws = trimmed.split(/\r?\n/).filter((line) => line.trim() !== '').map((line, i) => {
+      try {
+        return JSON.parse(line) as unknown;
+      } catch (err: unknown) {
+        const message = err instanceof Error ? err.message : String(err);
+        throw new Error(`batch record ${i + 1}: ${message}`);
+      }
+    });
+  }
+  return raws.map((raw, i) => parseBatchRecord(raw, `batch record ${i + 1}`));
+}
+
+/**
+ * 파싱한 JSON 값 하나를 레코드로 검사한다 (--batch 파일과 daemon 질의가 함께 쓴다).
+ *
+ * @param raw JSON 값
+ * @param label 오류 메시지 앞에 붙일 이름 (예: 'batch record 3')
+ * @throws Error 객체가 아니거나 알 수 없는 키·잘못된 값
+ */
+export function parseBatchRecord(raw: unknown, label: string): BatchRecord {
+  if (typeof raw !== 'object' || raw === null || Array.isArray(raw)) {
+    throw new Error(`${label} must be an object`);
+  }
+  let id: string | null = null;
+  const overrides: BatchOverrides = {};
+  for (const [key, value] of Object.entries(raw as Record<string, unknown>)) {
+    if (key === 'id') {
+      if (typeof value !== 'string' && typeof value !== 'number') throw new Error(`${label}: id must be a string or number`);
+      id = String(value);
+    } else if ((STRING_KEYS as readonly string[]).includes(key)) {
+      if (typeof value !== 'string' && typeof value !== 'number') throw new Error(`${label}: ${key} must be a string`);
+      overrides[key as StringKey] = String(value);
+    } else if ((BOOLEAN_KEYS as readonly string[]).includes(key)) {
+      if (typeof value !== 'boolean') throw new Error(`${label}: ${key} must be true or false`);
+      overrides[key as BooleanKey] = value;
+    } else {
+      throw new Error(`${label}: unknown key ${key} (allowed: id, ${[...STRING_KEYS, ...BOOLEAN_KEYS].join(', ')})`);
+    }
+  }
+  return { id, overrides };
+}
+
+/**
+ * 레코드 하나의 처리 결과 (작업자 스레드에서 부모 스레드로 보내는 메시지).
+ * sample은 --stats 집계용 요약이며, 집계하지 않거나 4기둥만 출력했으면 null이다.
+ */
+export type BatchOutcome =
+  | { index: number; lines: string[]; sample: ChartSample | null }
+  | { index: number; error: string };
+
+/**
+ * 레코드에 적용할 옵션 전체로 같은 입력인지 가리는 키를 만든다.
+ * 날짜·시각은 표준 표기로 바꿔 '1990.3.5'와 '1990-03-05'를 같은 입력으로 보되,
+ * 날짜 문구의 음력·윤달 표시는 남겨 서로 다른 입력으로 구분한다.
+ *
+ * @param options 명령줄 옵션에 레코드 값을 덮어쓴 옵션
+ */
+export function batchRecordKey(options: object): string {
+  const normalized: Record<string, unknown> = { ...options };
+  const { date, time } = normalized;
+  if (typeof date === 'string') normalized.date = input.parseDateInput(date);
+  if (typeof time === 'string') normalized.time = input.normalizeTime(time);
+  const fields = Object.keys(normalized)
+    .filter((k) => normalized[k] !== undefined)
+    .sort()
+    .map((k) => [k, normalized[k]]);
+  return JSON.stringify(fields);
+}
+
+/**
+ * --jobs 작업자마다 맡길 레코드 번호를 나눈다.
+ * 번갈아 배분(i % jobs)해 앞쪽 레코드부터 고르게 끝나므로 순서대로 출력하기 쉽다.
+ *
+ * @param count 레코드 수
+ * @param jobs 작업자 수 (1 이상)
+ * @returns 작업자별 레코드 번호 목록 (빈 목록은 제외)
+ */
+export function splitBatch(count: number, jobs: number): number[][] {
+  const chunks: number[][] = Array.from({ length: Math.min(jobs, count) }, () => []);
+  for (let i = 0; i < count; i++) chunks[i % chunks.length].push(i);
+  return chunks;
+}
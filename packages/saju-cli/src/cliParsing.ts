@@ -37,3 +37,40 @@ export function parseOptionalNumberOption(raw: string | undefined, optionName: s
   return parseNumberOption(raw, optionName);
 }
 
+export function parseChoiceOption<T extends string>(raw: string, optionName: string, choices: readonly T[]): T {
+  const normalized = raw.trim().toLowerCase();
+  const found = choices.find((choice) => choice === normalized);
+  if (found === undefined) {
+    throw new Error(`${optionName} must be one of ${choices.join('|')}`);
+  }
+  return found;
+}
+
+/** 'a..b' 형식 정수 범위 (양 끝 포함) */
+export function parseRangeOption(raw: string, optionName: string): [number, number] {
+  const m = /^(-?\d+)\.\.(-?\d+)$/.exec(raw.trim());
+  if (!m) {
+    throw new Error(`${optionName} must be a range like 30..60`);
+  }
+  const from = parseIntegerOption(m[1], optionName);
+  const to = parseIntegerOption(m[2], optionName);
+  if (from > to) {
+    throw new Error(`${optionName} start must not exceed its end`);
+  }
+  return [from, to];
+}
+
+/** YYYY-MM-DD 형식 날짜 인자 (달력 유효성은 호출 측에서 판단한다) */
+export interface YmdArgument {
+  year: number;
+  month: number;
+  day: number;
+}
+
+export function parseYmdArgument(raw: string, name: string): YmdArgument {
+  const m = /^(\d{4})-(\d{2})-(\d{2})$/.exec(raw.trim());
+  if (!m) {
+    throw new Error(`${name} must be YYYY-MM-DD`);
+  }
+  return { year: Number(m[1]), month: Number(m[2]), day: Number(m[3]) };
+}
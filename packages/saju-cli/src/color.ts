@@ -0,0 +1,98 @@
+/**
+ * @fileoverview --color: 텍스트 리포트의 천간·지지를 오행 색으로 칠한다
+ *
+ * 렌더링을 마친 줄에서 I18n이 만든 천간·지지·기둥 표기('갑(甲)', '자(子)', '갑자(甲子)')를
+ * 찾아 ANSI 색 코드로 감싼다. 색 코드는 표시 폭 0으로 계산되므로(textWidth.ts) 정렬과
+ * 줄바꿈은 그대로이고, 이름과 괄호 안 한자를 따로 감싸므로 --compact가 한자 병기를
+ * 지워도 이름의 색은 남는다.
+ *
+ * - auto: 표준 출력이 터미널이고 --output이 없으며 NO_COLOR가 비어 있을 때만 칠한다
+ * - always / never: 항상 / 칠하지 않음
+ */
+
+import { type Element, type Localizer, bazi } from 'saju-lib';
+
+/** --color 값 */
+export type ColorMode = 'auto' | 'always' | 'never';
+
+export const COLOR_MODES: readonly ColorMode[] = ['auto', 'always', 'never'];
+
+/**
+ * 오행별 SGR 코드: 목 초록, 화 빨강, 토 노랑, 수 파랑.
+ * 금(흰색)은 밝은 배경에서 보이지 않으므로 글자색을 바꾸지 않고 굵게만 한다.
+ */
+const ELEMENT_SGR: Record<Element, string> = {
+  Wood: '32',
+  Fire: '31',
+  Earth: '33',
+  Metal: '1',
+  Water: '34',
+};
+
+const RESET = '\x1b[0m';
+
+/** 색을 칠할지 정할 때 보는 환경 */
+export interface ColorEnv {
+  /** 표준 출력이 터미널인지 */
+  isTTY: boolean
+  /** --output 파일로 쓰는지 */
+  toFile: boolean
+  /** NO_COLOR 환경 변수 값 */
+  noColor: string | undefined
+}
+
+/** --color 값과 출력 환경으로 색을 칠할지 정한다 */
+export function shouldColor(mode: ColorMode, env: ColorEnv): boolean {
+  if (mode !== 'auto') return mode === 'always';
+  return env.isTTY && !env.toFile && !env.noColor;
+}
+
+/** 텍스트를 오행 색으로 감싼다 */
+function paint(text: string, element: Element): string {
+  return `\x1b[${ELEMENT_SGR[element]}m${text}${RESET}`;
+}
+
+/** 'name(漢)' 표기를 이름과 한자로 나눈다 */
+function splitLabel(label: string): [name: string, hanja: string] {
+  return [label.slice(0, -3), label.slice(-2, -1)];
+}
+
+/** 원래 표기 → 색을 칠한 표기 (긴 표기가 먼저 맞도록 기둥 60개를 앞에 둔다) */
+function colorTable(i18n: Localizer): Map<string, string> {
+  const table = new Map<string, string>();
+  for (let index = 0; index < 60; index++) {
+    const pillar = bazi.pillarFromSexagenary(index);
+    const plain = i18n.pillarLabel(pillar);
+    const [stemName, stemHanja] = splitLabel(i18n.stemLabel(pillar.stem));
+    const [branchName, branchHanja] = splitLabel(i18n.branchLabel(pillar.branch));
+    const stemEl = bazi.stemElement(pillar.stem);
+    const branchEl = bazi.branchElement(pillar.branch);
+    // 병음 표기는 이름 사이에 공백이 있다 ('Jia Zi(甲子)')
+    const sep = plain.slice(stemName.length, plain.length - branchName.length - 4);
+    const hanja = `\x1b[${ELEMENT_SGR[stemEl]}m${stemHanja}${paint(branchHanja, branchEl)}`;
+    table.set(plain, `${paint(stemName, stemEl)}${sep}${paint(branchName, branchEl)}(${hanja})`);
+  }
+  for (let stem = 0; stem < 10; stem++) {
+    const [name, hanja] = splitLabel(i18n.stemLabel(stem));
+    const el = bazi.stemElement(stem);
+    table.set(i18n.stemLabel(stem), `${paint(name, el)}(${paint(hanja, el)})`);
+  }
+  for (let branch = 0; branch < 12; branch++) {
+    const [name, hanja] = splitLabel(i18n.branchLabel(branch));
+    const el = bazi.branchElement(branch);
+    table.set(i18n.branchLabel(branch), `${paint(name, el)}(${paint(hanja, el)})`);
+  }
+  return table;
+}
+
+/**
+ * 줄 목록의 천간·지지·기둥 표기를 오행 색으로 칠한다.
+ * @param lines 렌더링한 텍스트 리포트 줄
+ * @param i18n 리포트를 렌더링한 레이블 제공자 (같은 언어·로마자 표기)
+ */
+export function colorizeLines(lines: string[], i18n: Localizer): string[] {
+  const table = colorTable(i18n);
+  const escape = (text: string) => text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  const pattern = new RegExp([...table.keys()].map(escape).join('|'), 'g');
+  return lines.map((line) => line.replace(pattern, (label) => table.get(label) ?? label));
+}
@@ -0,0 +1,62 @@
+/**
+ * @fileoverview `saju convert` 서브커맨드 — 양력 ↔ 음력 변환
+ *
+ * 명식 계산 없이 lunar 모듈의 변환만 노출한다.
+ *
+ * 사용 예:
+ *   saju convert --to lunar 1990-09-15
+ *   saju convert --to solar --leap 2023-02-01 --json
+ */
+
+import { lunar, I18n } from 'saju-lib';
+import { parseYmdArgument } from './cliParsing.js';
+
+/** 변환 방향 */
+export type ConvertTarget = 'lunar' | 'solar';
+
+/** 변환 결과 (양력·음력 양쪽을 함께 담는다) */
+export interface ConversionResult {
+  /** 양력 날짜 (YYYY-MM-DD) */
+  solar: string;
+  /** 음력 날짜 */
+  lunar: { year: number; month: number; day: number; leap: boolean };
+}
+
+/**
+ * 날짜 문자열을 지정 방향으로 변환한다.
+ *
+ * @param input YYYY-MM-DD 형식 날짜 (to=lunar면 양력, to=solar면 음력)
+ * @param to 변환 방향
+ * @param leap 음력 입력의 윤달 여부 (to=solar에서만 유효)
+ */
+export function convertDate(input: string, to: ConvertTarget, leap: boolean): ConversionResult {
+  const { year, month, day } = parseYmdArgument(input, 'date');
+  if (to === 'lunar') {
+    if (leap) throw new Error('--leap is only valid with --to solar');
+    const date = new Date(Date.UTC(year, month - 1, day));
+    if (date.getUTCFullYear() !== year || date.getUTCMonth() + 1 !== month || date.getUTCDate() !== day) {
+      throw new Error('solar date must be a valid calendar date');
+    }
+    const l = lunar.solarToLunar(date);
+    return {
+      solar: formatYmd(year, month, day),
+      lunar: { year: l.year, month: l.month, day: l.day, leap: l.isLeap },
+    };
+  }
+  const solar = lunar.lunarToSolar(year, month, day, leap);
+  return {
+    solar: formatYmd(solar.getUTCFullYear(), solar.getUTCMonth() + 1, solar.getUTCDate()),
+    lunar: { year, month, day, leap },
+  };
+}
+
+/** 변환 결과를 텍스트 한 줄(변환된 쪽 날짜)로 표시한다 */
+export function formatConversion(result: ConversionResult, to: ConvertTarget, i18n: I18n): string {
+  if (to === 'solar') return result.solar;
+  const { year, month, day, leap } = result.lunar;
+  return formatYmd(year, month, day) + (leap ? i18n.leapSuffix() : '');
+}
+
+function formatYmd(year: number, month: number, day: number): string {
+  return `${String(year).padStart(4, '0')}-${String(month).padStart(2, '0')}-${String(day).padStart(2, '0')}`;
+}
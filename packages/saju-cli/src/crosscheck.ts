@@ -0,0 +1,243 @@
+/**
+ * @fileoverview `saju crosscheck` 서브커맨드 — 독립 엔진과의 교차 검증 (개발용)
+ *
+ * 내장 음력 표와 JDN 기반 일주 계산을, 표를 쓰지 않는 별도 구현과 날마다 대조한다:
+ * - 음력: 천문 계산(astro의 태양·달 황경)으로 합삭과 중기를 구해 한국 시각(UTC+9) 날짜로
+ *   달을 나누고, 동지가 든 달을 11월로, 동지~동지 사이 13달이면 처음으로 중기가 없는 달을
+ *   윤달로 삼는다 (정기법 무중치윤).
+ * - 일주: JDN을 거치지 않고 2000-01-01(무오일)부터의 경과 일수로 60갑자를 센다.
+ *
+ * 두 구현이 어긋나는 날을 모두 보고해 표 오류를 일찍 잡는다. 1912년 이전처럼 역법 기준
+ * 자오선이 달랐던 시기의 차이는 표 오류가 아닐 수 있으므로 사람이 확인한다.
+ * 개발용이라 SAJU_DEV=1일 때만 명령이 등록된다.
+ *
+ * 사용 예:
+ *   SAJU_DEV=1 saju crosscheck --years 1900..2100
+ */
+
+import { astro, bazi, lunar, type LunarDate } from 'saju-lib';
+
+/** 검증 항목 */
+export type CrossCheckKind = 'Lunar' | 'DayPillar';
+
+export const CROSS_CHECK_KINDS: readonly CrossCheckKind[] = ['Lunar', 'DayPillar'];
+
+/** 불일치 하나 */
+export interface CrossCheckIssue {
+  kind: CrossCheckKind;
+  message: string;
+}
+
+/** 교차 검증 결과 */
+export interface CrossCheckReport {
+  from: number;
+  to: number;
+  /** 항목별 대조 일수 */
+  checked: Record<CrossCheckKind, number>;
+  issues: CrossCheckIssue[];
+}
+
+/** 천문 계산으로 구한 음력 달 하나 */
+export interface AstroLunarMonth {
+  year: number;
+  month: number;
+  isLeap: boolean;
+  /** 초하루의 JDN (한국 시각 날짜) */
+  startJdn: number;
+}
+
+/** 평균 삭망월 (일) */
+const SYNODIC_MONTH = 29.530588853;
+/** 역법 기준 시각의 UTC 오프셋 (시간) */
+const KOREA_UTC_OFFSET = 9;
+/** 2000-01-01(무오일)의 60갑자 순번 */
+const EPOCH_CYCLE_INDEX = 54;
+const EPOCH_MS = Date.UTC(2000, 0, 1);
+const DAY_MS = 86_400_000;
+
+/** 음력 표 범위 밖을 뜻하는 변환 오류 */
+const OUT_OF_RANGE: readonly lunar.LunarErrorCode[] = ['LUNAR_YEAR_RANGE', 'SOLAR_BEFORE_RANGE', 'SOLAR_AFTER_RANGE'];
+
+/** 중기(中氣): 황경이 30°의 배수인 절기 */
+const PRINCIPAL_TERMS = astro.TERM_DEFS.filter((def) => def.angle % 30 === 0);
+
+/**
+ * 지정 연도 범위를 날마다 교차 검증한다.
+ *
+ * @param from 첫 연도
+ * @param to 마지막 연도 (포함)
+ * @param onYear 한 해의 대조를 마칠 때마다 호출 (진행 표시용)
+ */
+export function runCrossCheck(from: number, to: number, onYear?: (year: number) => void): CrossCheckReport {
+  const report: CrossCheckReport = { from, to, checked: { Lunar: 0, DayPillar: 0 }, issues: [] };
+  const spans = new Map<number, AstroLunarMonth[]>();
+  const span = (year: number): AstroLunarMonth[] => {
+    let months = spans.get(year);
+    if (months === undefined) {
+      months = astroLunarMonths(year);
+      spans.set(year, months);
+    }
+    return months;
+  };
+  for (let year = from; year <= to; year++) {
+    const months = [...span(year), ...span(year + 1)];
+    for (const date of daysOfYear(year)) {
+      checkLunar(date, months, report);
+      checkDayPillar(date, report);
+    }
+    spans.delete(year);
+    onYear?.(year);
+  }
+  return report;
+}
+
+/** 교차 검증 결과를 텍스트 줄로 표시한다 */
+export function formatCrossCheck(report: CrossCheckReport): string[] {
+  const lines = [`crosscheck ${report.from}..${report.to}`];
+  for (const kind of CROSS_CHECK_KINDS) {
+    const count = report.issues.filter((issue) => issue.kind === kind).length;
+    lines.push(`${kind}: ${report.checked[kind]} days checked, ${count} disagreement${count === 1 ? '' : 's'}`);
+  }
+  for (const issue of report.issues) lines.push(`- [${issue.kind}] ${issue.message}`);
+  lines.push(report.issues.length === 0 ? 'OK' : `FAILED (${report.issues.length})`);
+  return lines;
+}
+
+/**
+ * 천문 계산으로 (year-1)년 동지가 든 달부터 year년 동지가 든 달 직전까지의 음력 달을 구한다.
+ * 앞의 두 달은 year-1년 11·12월이다.
+ */
+export function astroLunarMonths(year: number): AstroLunarMonth[] {
+  const first = newMoonOnOrBefore(localJdn(astro.termJd(year - 1, 'dongzhi')));
+  const lastJdn = localJdn(newMoonOnOrBefore(localJdn(astro.termJd(year, 'dongzhi'))));
+  const starts = [localJdn(first)];
+  for (let jd = first; starts[starts.length - 1] < lastJdn;) {
+    jd = newMoonNear(jd + SYNODIC_MONTH);
+    starts.push(localJdn(jd));
+  }
+  const principal = [year - 1, year].flatMap((y) => PRINCIPAL_TERMS.map((def) => localJdn(astro.termJd(y, def.key))));
+  const hasPrincipal = (i: number) => principal.some((jdn) => jdn >= starts[i] && jdn < starts[i + 1]);
+
+  // starts의 마지막은 다음 11월 초하루이므로 달 수는 하나 적다
+  const leapSpan = starts.length - 1 === 13;
+  let leapUsed = false;
+  let lunarYear = year - 1;
+  let month = 11;
+  const months: AstroLunarMonth[] = [{ year: lunarYear, month, isLeap: false, startJdn: starts[0] }];
+  for (let i = 1; i < starts.length - 1; i++) {
+    if (leapSpan && !leapUsed && !hasPrincipal(i)) {
+      leapUsed = true;
+      months.push({ year: lunarYear, month, isLeap: true, startJdn: starts[i] });
+      continue;
+    }
+    month++;
+    if (month > 12) {
+      month = 1;
+      lunarYear++;
+    }
+    months.push({ year: lunarYear, month, isLeap: false, startJdn: starts[i] });
+  }
+  return months;
+}
+
+/** JDN 순서로 정렬된 달 목록에서 그날의 음력 날짜를 찾는다 */
+export function astroLunarDate(jdn: number, months: readonly AstroLunarMonth[]): LunarDate | null {
+  for (let i = months.length - 1; i >= 0; i--) {
+    const m = months[i];
+    if (m.startJdn <= jdn) return { year: m.year, month: m.month, day: jdn - m.startJdn + 1, isLeap: m.isLeap };
+  }
+  return null;
+}
+
+/** 2000-01-01부터의 경과 일수로 일주를 센다 (JDN을 쓰지 않는 독립 경로) */
+export function dayPillarByEpoch(date: Date): [number, number] {
+  const days = Math.round((date.getTime() - EPOCH_MS) / DAY_MS);
+  const index = (((EPOCH_CYCLE_INDEX + days) % 60) + 60) % 60;
+  return [index % 10, index % 12];
+}
+
+function checkLunar(date: Date, months: readonly AstroLunarMonth[], report: CrossCheckReport): void {
+  let table: LunarDate;
+  try {
+    table = lunar.solarToLunar(date);
+  } catch (err: unknown) {
+    // 음력 표 범위 밖은 대조 대상이 아니다
+    if (lunar.isLunarConversionError(err) && OUT_OF_RANGE.includes(err.code)) return;
+    throw err;
+  }
+  report.checked.Lunar++;
+  const astroDate = astroLunarDate(jdnOf(date), months);
+  if (astroDate === null || !sameLunarDate(table, astroDate)) {
+    report.issues.push({
+      kind: 'Lunar',
+      message: `${formatDate(date)}: table ${formatLunar(table)}, astronomical ${astroDate ? formatLunar(astroDate) : '-'}`,
+    });
+  }
+}
+
+function checkDayPillar(date: Date, report: CrossCheckReport): void {
+  report.checked.DayPillar++;
+  const [stem, branch] = bazi.dayPillarFromJdn(jdnOf(date));
+  const [epochStem, epochBranch] = dayPillarByEpoch(date);
+  if (stem !== epochStem || branch !== epochBranch) {
+    report.issues.push({
+      kind: 'DayPillar',
+      message: `${formatDate(date)}: jdn ${stem}/${branch}, epoch count ${epochStem}/${epochBranch}`,
+    });
+  }
+}
+
+/** 합삭 시각을 이각 0°로 풀어 찾는다 (근처 추정값에서 출발) */
+function newMoonNear(guess: number): number {
+  const rate = 360 / SYNODIC_MONTH;
+  let jd = guess;
+  for (let i = 0; i < 30; i++) {
+    let elongation = astro.moonElongation(jd);
+    if (elongation > 180) elongation -= 360;
+    const step = elongation / rate;
+    jd -= step;
+    if (Math.abs(step) < 1e-6) break;
+  }
+  return jd;
+}
+
+/** 한국 시각 날짜로 jdn일이나 그 전에 든 마지막 합삭 시각 */
+function newMoonOnOrBefore(jdn: number): number {
+  // jdn일 자정(한국 시각)의 JD에서 이각만큼 거슬러 올라가 추정한다
+  const midnight = jdn + 0.5 - KOREA_UTC_OFFSET / 24;
+  let jd = newMoonNear(midnight - astro.moonElongation(midnight) / (360 / SYNODIC_MONTH));
+  if (localJdn(jd) > jdn) jd = newMoonNear(jd - SYNODIC_MONTH);
+  const next = newMoonNear(jd + SYNODIC_MONTH);
+  return localJdn(next) <= jdn ? next : jd;
+}
+
+/** JD가 속한 한국 시각 날짜의 JDN */
+function localJdn(jd: number): number {
+  return Math.floor(jd + 0.5 + KOREA_UTC_OFFSET / 24);
+}
+
+function jdnOf(date: Date): number {
+  return bazi.jdnFromDate(date.getUTCFullYear(), date.getUTCMonth() + 1, date.getUTCDate());
+}
+
+function sameLunarDate(a: LunarDate, b: LunarDate): boolean {
+  return a.year === b.year && a.month === b.month && a.day === b.day && a.isLeap === b.isLeap;
+}
+
+/** 한 해의 양력 날짜 (UTC 자정) */
+function* daysOfYear(year: number): Generator<Date> {
+  const date = new Date(Date.UTC(year, 0, 1));
+  date.setUTCFullYear(year);
+  while (date.getUTCFullYear() === year) {
+    yield new Date(date.getTime());
+    date.setUTCDate(date.getUTCDate() + 1);
+  }
+}
+
+function formatDate(date: Date): string {
+  return date.toISOString().slice(0, 10);
+}
+
+function formatLunar(l: LunarDate): string {
+  return `${l.year}-${l.month}-${l.day}${l.isLeap ? ' (leap)' : ''}`;
+}
@@ -0,0 +1,145 @@
+/**
+ * @fileoverview 운(運) 타임라인·명식 요약 CSV 내보내기
+ *
+ * 대운·세운·월운을 한 행에 한 구간씩 정규화된(tidy) CSV로 출력한다.
+ * --sections chart는 대신 명식 하나를 한 행으로 요약해, --batch로 여러 사람을
+ * 한 표(한 사람 한 행)로 모을 수 있게 한다.
+ * 스프레드시트·BI 도구에서 바로 읽을 수 있도록 식별자는 영문 ID,
+ * 기둥은 한자, 시각은 현지 시간대 'YYYY-MM-DD HH:mm'으로 쓴다.
+ */
+
+import {
+  type SajuResult,
+  type Pillar,
+  type TimeZoneSpec,
+  astro,
+  bazi,
+  ilju,
+  luck,
+  timezone,
+} from 'saju-lib';
+
+/** CSV로 내보낼 구간 종류 */
+export type LuckSection = 'daewon' | 'yearly' | 'monthly';
+
+export const LUCK_SECTIONS: readonly LuckSection[] = ['daewon', 'yearly', 'monthly'];
+
+const HEADER = ['period', 'label', 'start', 'end', 'pillar', 'stem_ten_god', 'branch_ten_god', 'score'];
+
+/** 명식 요약 행으로 출력하는 --sections 값 (운 섹션과 함께 쓸 수 없다) */
+export const CHART_SECTION = 'chart';
+
+/** 명식 요약 행의 열 */
+export const CHART_CSV_HEADER = [
+  'date', 'time', 'calendar', 'gender',
+  'year_stem', 'year_branch', 'month_stem', 'month_branch', 'day_stem', 'day_branch', 'hour_stem', 'hour_branch',
+  'day_master_element', 'strength', 'strength_score', 'wood', 'fire', 'earth', 'metal', 'water',
+  'yongshin', 'daewon_direction', 'first_daewon_age',
+];
+
+/**
+ * 쉼표로 구분한 섹션 목록을 파싱한다 (중복 제거, 입력 순서 유지).
+ * @throws 알 수 없는 섹션 이름
+ */
+export function parseLuckSections(raw: string): LuckSection[] {
+  const sections: LuckSection[] = [];
+  for (const part of raw.split(',')) {
+    const name = part.trim().toLowerCase();
+    if (!name) continue;
+    const found = LUCK_SECTIONS.find((s) => s === name);
+    if (!found) throw new Error(`--sections must be a comma list of ${LUCK_SECTIONS.join('|')}`);
+    if (!sections.includes(found)) sections.push(found);
+  }
+  if (sections.length === 0) throw new Error('--sections must not be empty');
+  return sections;
+}
+
+/**
+ * --sections가 명식 요약 행(chart)인지 확인한다.
+ * @throws chart를 운 섹션과 함께 지정한 경우
+ */
+export function isChartSection(raw: string): boolean {
+  const parts = raw.split(',').map((part) => part.trim().toLowerCase()).filter((part) => part !== '');
+  if (!parts.includes(CHART_SECTION)) return false;
+  if (parts.some((part) => part !== CHART_SECTION)) throw new Error('--sections chart cannot be combined with luck sections');
+  return true;
+}
+
+/** RFC 4180 필드 인용 (쉼표·따옴표·줄바꿈이 있을 때만) */
+export function csvField(value: string | number): string {
+  const text = String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+/**
+ * 계산 결과의 운 타임라인을 CSV 줄 목록으로 변환한다.
+ * 성별이 없어 대운 방향이 정해지지 않으면 대운 행은 생략된다.
+ */
+export function renderLuckCsv(result: SajuResult, sections: readonly LuckSection[]): string[] {
+  const rows: (string | number)[][] = [HEADER];
+  const dayStem = result.dayPillar.stem;
+  const tzSpec = result.tzSpec;
+  const row = (period: LuckSection, label: string, startJd: number, endJd: number, pillar: Pillar) => [
+    period,
+    label,
+    formatJd(startJd, tzSpec),
+    formatJd(endJd, tzSpec),
+    ilju.iljuKey(pillar),
+    bazi.tenGod(dayStem, pillar.stem),
+    bazi.tenGodBranch(dayStem, pillar.branch),
+    luck.pillarFavorability(pillar, result.yongshin),
+  ];
+
+  for (const section of sections) {
+    if (section === 'daewon') {
+      for (const item of result.daewonItems) {
+        const startJd = luck.daewonStartJd(result.birthJd, luck.exactStartMonths(item));
+        const endJd = luck.daewonStartJd(result.birthJd, luck.exactStartMonths(item) + 120);
+        // 레이블: 대운 시작 나이 (세, 소수 1자리)
+        rows.push(row('daewon', (item.startMonths / 12).toFixed(1), startJd, endJd, item.pillar));
+      }
+    } else if (section === 'yearly') {
+      for (const y of result.yearlyLuck) {
+        rows.push(row('yearly', String(y.year), y.startJd, y.endJd, y.pillar));
+      }
+    } else {
+      for (const monthly of result.monthlyLuckYears) {
+        for (const m of monthly.months) {
+          // 레이블: 입춘 기준 연도 + 월지 (예: '2025-寅')
+          const label = `${monthly.year}-${ilju.iljuKey(m.pillar).charAt(1)}`;
+          rows.push(row('monthly', label, m.startJd, m.endJd, m.pillar));
+        }
+      }
+    }
+  }
+  return rows.map((r) => r.map(csvField).join(','));
+}
+
+/**
+ * 명식 하나를 CHART_CSV_HEADER 열의 한 행으로 요약한다 (머리글 줄 포함).
+ * 여덟 글자는 한자, 판정은 라이브러리 식별자로 쓴다. 성별이 없어 대운이 정해지지 않으면
+ * 대운 열은 비운다. 첫 대운 나이는 세 단위 소수 1자리다.
+ */
+export function renderChartCsv(result: SajuResult): string[] {
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  const counts = bazi.elementsCount(natal, result.elementOverrides);
+  const row = [
+    result.inputDate,
+    result.inputTime,
+    result.calendarIsLunar ? 'lunar' : 'solar',
+    result.gender ?? '',
+    ...natal.flatMap((pillar) => [...ilju.iljuKey(pillar)]),
+    bazi.stemElement(result.dayPillar.stem),
+    result.strength.level,
+    result.strength.total,
+    ...counts,
+    result.yongshin.yongshin,
+    result.daewonDirection ?? '',
+    result.daewonStartMonths != null ? (result.daewonStartMonths / 12).toFixed(1) : '',
+  ];
+  return [CHART_CSV_HEADER.join(','), row.map(csvField).join(',')];
+}
+
+function formatJd(jd: number, tzSpec: TimeZoneSpec): string {
+  return timezone.toLocal(tzSpec, astro.datetimeFromJd(jd)).format('YYYY-MM-DD HH:mm');
+}
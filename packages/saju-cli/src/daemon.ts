@@ -0,0 +1,142 @@
+/**
+ * @fileoverview `saju daemon` 서브커맨드 — 상주 프로세스의 소켓 질의
+ *
+ * 프로세스 하나를 띄워 둔 채 유닉스 소켓(Windows는 이름 있는 파이프)으로 질의를 받는다.
+ * 한 줄에 JSON 레코드 하나를 보내면 한 줄에 JSON 응답 하나가 같은 순서로 돌아온다.
+ * 레코드는 --batch 레코드와 같은 키를 쓰며, `daemon` 앞에 준 옵션을 덮어쓴다.
+ * 절기·결과 캐시가 프로세스에 남아 있어 질의마다 프로세스를 띄우는 비용이 없다.
+ *
+ * 응답:
+ *   {"schema_version":2,"id":"c-001","lines":["..."]}
+ *   {"schema_version":2,"id":"c-001","error":"..."}
+ *
+ * 사용 예:
+ *   saju --format llm daemon --socket /tmp/saju.sock &
+ *   echo '{"date":"1990-05-20","time":"08:30","gender":"f"}' | nc -U /tmp/saju.sock
+ */
+
+import { unlinkSync } from 'node:fs';
+import { createConnection, createServer, type Server, type Socket } from 'node:net';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { createInterface } from 'node:readline';
+import { parseBatchRecord, type BatchOutcome, type BatchRecord } from './batch.js';
+import { versioned } from './schema.js';
+
+/** 레코드 하나를 계산한다 (index는 연결마다 1부터 세는 질의 번호) */
+export type QueryHandler = (record: BatchRecord, index: number) => BatchOutcome;
+
+/** 실행 중인 데몬 */
+export interface Daemon {
+  /** 듣고 있는 소켓 경로 */
+  path: string;
+  /** 새 연결을 막고 열린 연결을 끊은 뒤 소켓을 닫는다 */
+  close(): Promise<void>;
+}
+
+/** 플랫폼별 기본 소켓 경로 (사용자마다 따로) */
+export function defaultSocketPath(platform: NodeJS.Platform = process.platform): string {
+  if (platform === 'win32') return '\\\\.\\pipe\\saju';
+  return join(tmpdir(), `saju-${process.getuid?.() ?? 'user'}.sock`);
+}
+
+/**
+ * 질의 한 줄에 대한 응답 한 줄을 만든다.
+ * JSON 문법 오류·알 수 없는 키도 연결을 끊지 않고 error 응답으로 돌려준다.
+ */
+export function answerQuery(line: string, index: number, handle: QueryHandler): string {
+  let record: BatchRecord;
+  try {
+    let raw: unknown;
+    try {
+      raw = JSON.parse(line);
+    } catch (err: unknown) {
+      throw new Error(`query ${index}: ${err instanceof Error ? err.message : String(err)}`);
+    }
+    record = parseBatchRecord(raw, `query ${index}`);
+  } catch (err: unknown) {
+    return JSON.stringify(versioned({ id: null, error: err instanceof Error ? err.message : String(err) }));
+  }
+  const outcome = handle(record, index);
+  return JSON.stringify(versioned('error' in outcome
+    ? { id: record.id, error: outcome.error }
+    : { id: record.id, lines: outcome.lines }));
+}
+
+/**
+ * 소켓을 열고 질의를 받기 시작한다.
+ * 같은 경로에 이전 프로세스가 남긴 소켓 파일이 있으면 지우고 연다.
+ *
+ * @throws Error 같은 경로에서 다른 데몬이 이미 듣고 있을 때
+ */
+export async function startDaemon(path: string, handle: QueryHandler): Promise<Daemon> {
+  const sockets = new Set<Socket>();
+  const server = createServer((socket) => {
+    sockets.add(socket);
+    socket.on('close', () => sockets.delete(socket));
+    serveConnection(socket, handle);
+  });
+  try {
+    await listen(server, path);
+  } catch (err: unknown) {
+    if ((err as NodeJS.ErrnoException).code !== 'EADDRINUSE' || isPipePath(path)) throw err;
+    if (await isListening(path)) throw new Error(`a daemon is already listening on ${path}`);
+    unlinkSync(path);
+    await listen(server, path);
+  }
+  return {
+    path,
+    close: () => new Promise((resolve) => {
+      server.close(() => resolve());
+      for (const socket of sockets) socket.destroy();
+    }),
+  };
+}
+
+/** 연결 하나의 질의를 차례로 계산해 같은 순서로 응답한다 (빈 줄은 건너뜀) */
+function serveConnection(socket: Socket, handle: QueryHandler): void {
+  const reader = createInterface({ input: socket, crlfDelay: Infinity });
+  let count = 0;
+  reader.on('line', (line) => {
+    if (line.trim() === '' || socket.destroyed) return;
+    socket.write(`${answerQuery(line, ++count, handle)}\n`);
+  });
+  // 응답 전에 클라이언트가 끊어도 데몬은 계속 돈다
+  socket.on('error', () => reader.close());
+}
+
+/**
+ * 소켓을 연다. 소켓 파일은 처음부터 만든 사용자만 읽고 쓸 수 있도록(0600)
+ * 여는 동안 umask를 좁혔다가 되돌린다 (열고 나서 chmod하면 그 사이에 다른 사용자가 붙을 수 있다).
+ */
+function listen(server: Server, path: string): Promise<void> {
+  return new Promise((resolve, reject) => {
+    server.once('error', reject);
+    const previousUmask = isPipePath(path) ? null : process.umask(0o177);
+    // bind는 listen() 안에서 동기로 끝나므로 콜백을 기다리지 않고 바로 되돌린다
+    try {
+      server.listen(path, () => {
+        server.off('error', reject);
+        resolve();
+      });
+    } finally {
+      if (previousUmask !== null) process.umask(previousUmask);
+    }
+  });
+}
+
+/** 그 경로의 소켓에 실제로 듣고 있는 프로세스가 있는지 */
+function isListening(path: string): Promise<boolean> {
+  return new Promise((resolve) => {
+    const probe = createConnection(path);
+    probe.once('connect', () => {
+      probe.end();
+      resolve(true);
+    });
+    probe.once('error', () => resolve(false));
+  });
+}
+
+function isPipePath(path: string): boolean {
+  return path.startsWith('\\\\.\\pipe\\') || path.startsWith('\\\\?\\pipe\\');
+}
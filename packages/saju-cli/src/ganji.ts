@@ -0,0 +1,127 @@
+/**
+ * @fileoverview `saju ganji` 서브커맨드 — 날짜 ↔ 간지 변환
+ *
+ * 날짜(와 선택적 시각)의 연·월·일·시주를 출력하거나,
+ * 반대로 주어진 간지가 나타나는 연도/월/일을 나열한다.
+ *
+ * 사용 예:
+ *   saju ganji 2025-10-03 --time 14:00
+ *   saju ganji --find 을사 --kind year --from 1900 --to 2100
+ */
+
+import { bazi, calculate, ilju, type I18n, type Pillar, type PillarKind } from 'saju-lib';
+
+/** 역검색 대상 기둥 종류 */
+export type GanjiKind = 'year' | 'month' | 'day';
+
+/** 날짜의 네 기둥 (시각을 주지 않으면 시주는 null) */
+export interface DatePillars {
+  year: Pillar;
+  month: Pillar;
+  day: Pillar;
+  hour: Pillar | null;
+}
+
+/** 시각 미지정 시 연·월·일주 계산에 쓰는 기준 시각 */
+const NOON = '12:00';
+
+/**
+ * 날짜(와 시각)의 네 기둥을 계산한다.
+ * 절기·자시 경계는 명식 계산과 동일한 규칙을 따른다.
+ */
+export function pillarsOfDate(date: string, time: string | undefined, tz: string): DatePillars {
+  const result = calculate({
+    date,
+    time: time ?? NOON,
+    calendar: 'Solar',
+    leapMonth: false,
+    gender: null,
+    tz,
+    useLmt: false,
+    longitude: null,
+    location: null,
+    daewonCount: 1,
+    monthYear: null,
+    yearStart: null,
+    yearCount: 1,
+  });
+  return {
+    year: result.yearPillar,
+    month: result.monthPillar,
+    day: result.dayPillar,
+    hour: time == null ? null : result.hourPillar,
+  };
+}
+
+/** 네 기둥을 텍스트 줄로 표시한다 */
+export function formatDatePillars(pillars: DatePillars, i18n: I18n): string[] {
+  const kinds: [PillarKind, Pillar | null][] = [
+    ['Year', pillars.year],
+    ['Month', pillars.month],
+    ['Day', pillars.day],
+    ['Hour', pillars.hour],
+  ];
+  return kinds
+    .filter((entry): entry is [PillarKind, Pillar] => entry[1] !== null)
+    .map(([kind, pillar]) => `${i18n.pillarKindLabel(kind)}: ${i18n.pillarLabel(pillar)}`);
+}
+
+/** 네 기둥을 JSON용 한자 표기 객체로 변환한다 */
+export function datePillarsJson(pillars: DatePillars): Record<string, string | null> {
+  return {
+    year: ilju.iljuKey(pillars.year),
+    month: ilju.iljuKey(pillars.month),
+    day: ilju.iljuKey(pillars.day),
+    hour: pillars.hour ? ilju.iljuKey(pillars.hour) : null,
+  };
+}
+
+/**
+ * 간지가 나타나는 시기를 [from, to] 연도 범위에서 나열한다.
+ *
+ * - year: 해당 간지의 연도 (입춘 기준 연도, YYYY)
+ * - month: 해당 간지 월이 시작되는 양력 월 (YYYY-MM, 절입일이 속한 달)
+ * - day: 해당 간지의 양력 날짜 (YYYY-MM-DD)
+ */
+export function findOccurrences(pillar: Pillar, kind: GanjiKind, from: number, to: number): string[] {
+  bazi.sexagenaryIndex(pillar); // 음양 불일치 조합 검증
+  const out: string[] = [];
+  if (kind === 'year') {
+    for (let y = from; y <= to; y++) {
+      const [stem, branch] = bazi.yearPillar(y);
+      if (stem === pillar.stem && branch === pillar.branch) out.push(String(y));
+    }
+  } else if (kind === 'month') {
+    // 寅월(2월)~子월(12월)은 같은 해, 丑월은 이듬해 1월에 시작한다
+    for (let y = from - 1; y <= to; y++) {
+      const [yearStem] = bazi.yearPillar(y);
+      if (bazi.monthStemFromYear(yearStem, pillar.branch) !== pillar.stem) continue;
+      const [gy, gm] = pillar.branch === 1 ? [y + 1, 1] : [y, pillar.branch === 0 ? 12 : pillar.branch];
+      if (gy >= from && gy <= to) out.push(`${pad(gy, 4)}-${pad(gm, 2)}`);
+    }
+  } else {
+    const start = bazi.jdnFromDate(from, 1, 1);
+    const end = bazi.jdnFromDate(to, 12, 31);
+    for (let jdn = start; jdn <= end; jdn++) {
+      const [stem, branch] = bazi.dayPillarFromJdn(jdn);
+      if (stem === pillar.stem && branch === pillar.branch) {
+        for (let d = jdn; d <= end; d += 60) out.push(formatJdn(d));
+        break;
+      }
+    }
+  }
+  return out;
+}
+
+/** JDN 2440588 = 1970-01-01 */
+const UNIX_EPOCH_JDN = 2440588;
+
+function formatJdn(jdn: number): string {
+  const d = new Date((jdn - UNIX_EPOCH_JDN) * 86400000);
+  return `${pad(d.getUTCFullYear(), 4)}-${pad(d.getUTCMonth() + 1, 2)}-${pad(d.getUTCDate(), 2)}`;
+}
+
+function pad(n: number, width: number): string {
+  return String(n).padStart(width, '0');
+}
+
@@ -0,0 +1,138 @@
+/**
+ * @fileoverview --format json: 명식 전체를 JSON 문서 하나로 내보낸다
+ *
+ * --format llm이 프롬프트용으로 고른 항목 외에 강약 판정 상세, 대운 시작 시각,
+ * 월운, 절기 시각까지 모두 싣는다. 필드 이름 규칙과 항목별 모양은 llm.ts의 변환 함수를
+ * 그대로 써서 두 형식이 같은 항목을 같은 모양으로 낸다. 시각은 출생지 현지 시각이다.
+ */
+
+import { ilju, luck, tenGodStats, type PillarPosition, type PillarsResult, type SajuResult } from 'saju-lib';
+import {
+  birthTermData,
+  boundaryData,
+  dayMasterData,
+  elementsData,
+  gyeokgukData,
+  inputData,
+  interactionsData,
+  julianDayData,
+  localTime,
+  luckPillarData,
+  pillarData,
+  term,
+  warningData,
+  yongshinData,
+} from './llm.js';
+import { SCHEMA_VERSION, versioned } from './schema.js';
+
+/** 문서 형식 식별자 (스키마 버전을 따른다) */
+export const JSON_FORMAT_VERSION = `saju-json/${SCHEMA_VERSION}`;
+
+/** --pillars-only 문서의 형식 식별자 (명식 전체 문서와 모양이 달라 따로 둔다) */
+export const PILLARS_JSON_FORMAT_VERSION = `saju-pillars-json/${SCHEMA_VERSION}`;
+
+/** 신강·신약 판정 상세 */
+function strengthData(result: SajuResult) {
+  const s = result.strength;
+  return {
+    level: term(s.level, (i) => i.strengthLevelLabel(s.level)),
+    verdict: s.verdict,
+    score: s.total,
+    month_stage: term(String(s.stageIndex), (i) => i.stageLabel(s.stageIndex)),
+    month_scoring: s.monthScoring,
+    month_score: s.monthScore,
+    root_count: s.rootCount,
+    support_stems: s.supportStems,
+    support_hidden: s.supportHidden,
+    drain_stems: s.drainStems,
+    drain_hidden: s.drainHidden,
+    pillars: s.pillars.map((p) => ({ position: p.position, stem: p.stem, hidden: p.hidden, root: p.root, total: p.total })),
+  };
+}
+
+/** 대운 방향·시작 시기와 대운 목록 (시작 시각 포함) */
+function daewonData(result: SajuResult) {
+  const span = result.daewonStartSpan;
+  return {
+    direction: result.daewonDirection,
+    direction_mode: result.daewonDirectionMode,
+    start_months: result.daewonStartMonths,
+    start_span: span && { years: span.years, months: span.months, days: span.days, hours: span.hours },
+    items: result.daewonItems.map((item) => ({
+      start_age: Math.floor(item.startMonths / 12),
+      start_months: item.startMonths,
+      start: localTime(result, luck.daewonStartJd(result.birthJd, luck.exactStartMonths(item))),
+      ...luckPillarData(item.pillar, result),
+    })),
+  };
+}
+
+/**
+ * --format json 문서. 필드 이름과 모양은 schema_version 안에서 바뀌지 않고 늘기만 한다
+ * (schema.ts). `saju schema`가 이 모양의 JSON Schema를 출력한다 (jsonSchema.ts).
+ */
+export type ChartReport = ReturnType<typeof chartJson>;
+
+/**
+ * 계산 결과를 JSON 문서로 변환한다.
+ * @param result 사주 계산 결과
+ * @param showJd 율리우스일(julian_day)을 덧붙인다
+ */
+export function chartJson(result: SajuResult, showJd = false) {
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  const positions: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = Object.fromEntries(positions.map((pos, i) => [pos.toLowerCase(), pillarData(natal[i], pos, result)]));
+  return versioned({
+    format: JSON_FORMAT_VERSION,
+    fingerprint: result.fingerprint,
+    input: inputData(result),
+    warnings: result.warnings.map(warningData),
+    birth_term: birthTermData(result),
+    pillars,
+    day_master: dayMasterData(result),
+    strength: strengthData(result),
+    elements: elementsData(result),
+    ten_gods: tenGodStats.tenGodDistribution(natal, result.dayPillar.stem).counts,
+    gyeokguk: gyeokgukData(result),
+    yongshin: yongshinData(result),
+    ...interactionsData(result),
+    daewon: daewonData(result),
+    yearly: result.yearlyLuck.map((yl) => ({
+      year: yl.year,
+      start: localTime(result, yl.startJd),
+      end: localTime(result, yl.endJd),
+      ...luckPillarData(yl.pillar, result),
+    })),
+    monthly: result.monthlyLuckYears.map((m) => ({
+      year: m.year,
+      year_ganji: ilju.iljuKey(m.yearPillar),
+      months: m.months.map((ml) => ({
+        branch: term(String(ml.branch), (i) => i.branchLabel(ml.branch)),
+        start: localTime(result, ml.startJd),
+        end: localTime(result, ml.endJd),
+        ...luckPillarData(ml.pillar, result),
+      })),
+    })),
+    solar_terms: result.solarTerms.map((t) => ({
+      ...term(t.def.key, (i) => i.termName(t.def)),
+      start: localTime(result, t.jd),
+    })),
+    boundary_sensitivity: result.boundaryWindow !== null ? boundaryData(result) : null,
+    julian_day: showJd ? julianDayData(result) : null,
+  });
+}
+
+/**
+ * --pillars-only 결과를 JSON 문서로 변환한다. 네 기둥은 한자 간지 문자열이다.
+ * @param result 4기둥 계산 결과
+ */
+export function pillarsJson(result: PillarsResult) {
+  const [year, month, day, hour] = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar].map(ilju.iljuKey);
+  return versioned({
+    format: PILLARS_JSON_FORMAT_VERSION,
+    pillars: { year, month, day, hour },
+    approximate: result.approximate,
+    hemisphere: result.hemisphere === 'South' ? 'south' : 'north',
+    warnings: result.warnings.map(warningData),
+  });
+}
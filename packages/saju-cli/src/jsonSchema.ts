@@ -0,0 +1,205 @@
+/**
+ * @fileoverview `saju schema`: --format json 문서(ChartReport)의 JSON Schema
+ *
+ * 다른 프로그램이 출력을 검증할 수 있도록 json.ts가 내는 문서의 모양을 JSON Schema
+ * (draft 2020-12)로 적는다. required에는 이 스키마 버전에서 낸 필드를 모두 싣고,
+ * 같은 버전 안에서 필드가 늘어도 기존 검증이 깨지지 않도록 추가 필드는 허용한다
+ * (additionalProperties를 막지 않는다). 닫힌 집합(오행·십성·기둥 위치 등)만 enum으로 묶고,
+ * 경고 코드·신살·관계처럼 늘어날 수 있는 식별자는 문자열로 둔다.
+ *
+ * --pillars-only 문서(saju-pillars-json)는 모양이 달라 pillarsReportSchema()로 따로 적는다.
+ *
+ * json.ts의 모양을 바꾸면 이 파일도 함께 고친다. __tests__/jsonSchema.test.ts가 실제 출력을
+ * 이 스키마로 검사하고, 스키마에 없는 필드가 나오면 실패한다.
+ */
+
+import { tenGodStats } from 'saju-lib';
+import { JSON_FORMAT_VERSION, PILLARS_JSON_FORMAT_VERSION } from './json.js';
+import { SCHEMA_VERSION } from './schema.js';
+
+/** JSON Schema 노드 (이 파일이 쓰는 키워드만) */
+export interface JsonSchema {
+  $schema?: string
+  $defs?: Record<string, JsonSchema>
+  $ref?: string
+  title?: string
+  description?: string
+  type?: string | string[]
+  const?: unknown
+  enum?: readonly unknown[]
+  properties?: Record<string, JsonSchema>
+  required?: string[]
+  items?: JsonSchema
+  anyOf?: JsonSchema[]
+}
+
+const ELEMENTS = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+const POSITIONS = ['Year', 'Month', 'Day', 'Hour'];
+
+const string: JsonSchema = { type: 'string' };
+const integer: JsonSchema = { type: 'integer' };
+const number: JsonSchema = { type: 'number' };
+const boolean: JsonSchema = { type: 'boolean' };
+const ref = (name: string): JsonSchema => ({ $ref: `#/$defs/${name}` });
+const array = (items: JsonSchema): JsonSchema => ({ type: 'array', items });
+const nullable = (schema: JsonSchema): JsonSchema => ({ anyOf: [schema, { type: 'null' }] });
+
+/** 모든 속성을 required로 싣는 객체 */
+function object(properties: Record<string, JsonSchema>, description?: string): JsonSchema {
+  return { ...(description ? { description } : {}), type: 'object', properties, required: Object.keys(properties) };
+}
+
+/** 용어 객체 {id, ko, en}에 속성을 덧붙인 객체 */
+function termWith(properties: Record<string, JsonSchema>, description?: string): JsonSchema {
+  return object({ id: string, ko: string, en: string, ...properties }, description);
+}
+
+/** 'YYYY-MM-DD HH:mm' 현지 시각 */
+const localTime: JsonSchema = { type: 'string', description: 'Local time at the birthplace, YYYY-MM-DD HH:mm' };
+
+const DEFS: Record<string, JsonSchema> = {
+  term: termWith({}, 'Library identifier with Korean and English labels'),
+  element: { enum: ELEMENTS },
+  ten_god: { enum: tenGodStats.TEN_GOD_ORDER },
+  position: { enum: POSITIONS },
+  warning: object({ code: string, year: integer, message: string, resolved_time: nullable(string) }),
+  pillar: object({
+    ganji: { type: 'string', description: 'Stem and branch in hanja' },
+    stem: termWith({ element: ref('element'), yang: boolean, ten_god: nullable(ref('term')) }),
+    branch: termWith({ element: ref('element'), yang: boolean, ten_god: ref('term') }),
+    hidden_stems: array(object({ stem: ref('term'), rank: { enum: ['JeongGi', 'YeoGi', 'JungGi'] }, ten_god: ref('term') })),
+    twelve_stage: ref('term'),
+  }),
+  interaction: termWith({ positions: array(ref('position')) }),
+};
+
+/** 운 기둥 하나 (대운·세운·월운 공통, json.ts의 luckPillarData) */
+const LUCK_PILLAR: Record<string, JsonSchema> = {
+  ganji: string,
+  stem_ten_god: ref('ten_god'),
+  branch_ten_god: ref('ten_god'),
+  favorability: { type: 'integer', description: 'Yongshin favorability, -4 to +4' },
+};
+
+/** 4기둥 간지 (한자) */
+const ganjiPillars = object({ year: string, month: string, day: string, hour: string });
+
+/** 한 시점의 율리우스일 구간 */
+const jdSpan = (key: string, type: JsonSchema): JsonSchema => object({ [key]: type, start: number, end: number });
+
+const PROPERTIES: Record<string, JsonSchema> = {
+  schema_version: { const: SCHEMA_VERSION },
+  format: { const: JSON_FORMAT_VERSION },
+  fingerprint: { type: 'string', description: 'Hash of the normalized inputs and conventions' },
+  input: object({
+    date: string,
+    time: string,
+    raw_input: nullable(object({ date: string, time: string })),
+    calendar: { enum: ['solar', 'lunar'] },
+    leap_month: boolean,
+    time_zone: string,
+    corrected_local_time: nullable(string),
+    gender: nullable({ enum: ['Male', 'Female'] }),
+    approximate: boolean,
+    hemisphere: { enum: ['north', 'south'] },
+    historical_date: nullable(object({
+      calendar: { const: 'julian' },
+      julian_date: string,
+      gregorian_date: string,
+      region: nullable(string),
+      gregorian_from: nullable(string),
+    })),
+  }),
+  warnings: array(ref('warning')),
+  birth_term: termWith({ start: localTime, days_since: number, next: ref('term'), next_start: localTime, days_until: number }),
+  pillars: object(Object.fromEntries(POSITIONS.map((pos) => [pos.toLowerCase(), ref('pillar')]))),
+  day_master: termWith({ element: ref('element'), strength: ref('term'), strength_score: number }),
+  strength: object({
+    level: ref('term'),
+    verdict: { enum: ['Strong', 'Weak', 'Neutral'] },
+    score: number,
+    month_stage: ref('term'),
+    month_scoring: { enum: ['Stage', 'Matrix'] },
+    month_score: number,
+    root_count: integer,
+    support_stems: integer,
+    support_hidden: integer,
+    drain_stems: integer,
+    drain_hidden: integer,
+    pillars: array(object({ position: ref('position'), stem: number, hidden: number, root: boolean, total: number })),
+  }),
+  elements: object(Object.fromEntries(ELEMENTS.map((el) => [el, integer])), 'Element counts, transformations applied'),
+  ten_gods: object(Object.fromEntries(tenGodStats.TEN_GOD_ORDER.map((god) => [god, integer]))),
+  gyeokguk: ref('term'),
+  yongshin: object({
+    method: { enum: ['suppress', 'support'] },
+    yongshin: ref('term'),
+    heeshin: ref('term'),
+    gishin: ref('term'),
+    gushin: ref('term'),
+  }),
+  stem_interactions: array(ref('interaction')),
+  branch_interactions: array(ref('interaction')),
+  shinsal: array(termWith({ found_at: array(ref('position')) })),
+  daewon: object({
+    direction: nullable({ enum: ['Forward', 'Backward'] }),
+    direction_mode: { enum: ['Auto', 'Forward', 'Backward'] },
+    start_months: nullable(integer),
+    start_span: nullable(object({ years: integer, months: integer, days: integer, hours: integer })),
+    items: array(object({ start_age: integer, start_months: integer, start: localTime, ...LUCK_PILLAR })),
+  }, 'Empty items and null direction when gender is unknown'),
+  yearly: array(object({ year: integer, start: localTime, end: localTime, ...LUCK_PILLAR })),
+  monthly: array(object({
+    year: integer,
+    year_ganji: string,
+    months: array(object({ branch: ref('term'), start: localTime, end: localTime, ...LUCK_PILLAR })),
+  })),
+  solar_terms: array(termWith({ start: localTime })),
+  boundary_sensitivity: nullable(object({
+    window_minutes: integer,
+    alternatives: array(object({
+      side: { enum: ['earlier', 'later'] },
+      minutes: number,
+      boundary: string,
+      changed: array(ref('position')),
+      pillars: ganjiPillars,
+    })),
+  }, 'Present with --boundary-window')),
+  julian_day: nullable(object({
+    birth: number,
+    birth_term: object({ start: number, next_start: number }),
+    daewon: array(object({ start_months: integer, start: number })),
+    yearly: array(jdSpan('year', integer)),
+    monthly: array(object({ year: integer, months: array(jdSpan('branch', integer)) })),
+    terms: array(object({ key: string, jd: number })),
+  }, 'Present with --show-jd (UT, unrounded)')),
+};
+
+/** --format json 문서(ChartReport)의 JSON Schema */
+export function chartReportSchema(): JsonSchema {
+  return {
+    $schema: 'https://json-schema.org/draft/2020-12/schema',
+    title: JSON_FORMAT_VERSION,
+    description: 'saju --format json chart document. Fields are only added within a schema_version.',
+    ...object(PROPERTIES),
+    $defs: DEFS,
+  };
+}
+
+/** --pillars-only --format json 문서(json.ts의 pillarsJson)의 JSON Schema */
+export function pillarsReportSchema(): JsonSchema {
+  return {
+    $schema: 'https://json-schema.org/draft/2020-12/schema',
+    title: PILLARS_JSON_FORMAT_VERSION,
+    description: 'saju --pillars-only --format json document. Fields are only added within a schema_version.',
+    ...object({
+      schema_version: { const: SCHEMA_VERSION },
+      format: { const: PILLARS_JSON_FORMAT_VERSION },
+      pillars: ganjiPillars,
+      approximate: boolean,
+      hemisphere: { enum: ['north', 'south'] },
+      warnings: array(ref('warning')),
+    }),
+    $defs: { warning: DEFS.warning },
+  };
+}
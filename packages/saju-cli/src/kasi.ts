@@ -0,0 +1,149 @@
+/**
+ * @fileoverview 한국천문연구원(KASI) 공공데이터 API 클라이언트
+ *
+ * 공공데이터포털(data.go.kr)의 KASI 음양력·특일 정보 API로 음력 날짜와
+ * 24절기 시각을 받아 `saju verify --kasi`의 기준 자료로 쓴다. 명식 계산은 이 자료를
+ * 쓰지 않는다: 계산은 동기식·오프라인이어야 해서 절기는 내장 천문 계산, 음력은
+ * 내장 표(KASI 자료로 만든 것)를 그대로 쓰고, 공식 역서와의 차이는 verify로 확인한다.
+ *
+ * 서비스 키(KASI_SERVICE_KEY 환경 변수 또는 --kasi-key)가 있을 때만 동작하며,
+ * 응답은 요청 단위로 캐시 디렉터리에 JSON으로 저장해 같은 요청을 다시 보내지 않는다.
+ */
+
+import { mkdirSync, readFileSync, writeFileSync } from 'node:fs';
+import { join } from 'node:path';
+import { astro } from 'saju-lib';
+import type { ReferenceData, ReferenceLunarDate, ReferenceTerm } from './verify.js';
+
+/** 공공데이터포털 KASI API 기본 주소 */
+export const KASI_API_BASE = 'https://apis.data.go.kr/B090041/openapi/service';
+
+/** 서비스 키를 읽는 환경 변수 */
+export const KASI_KEY_ENV = 'KASI_SERVICE_KEY';
+
+/** API 응답의 item 하나 (필드는 모두 문자열 또는 숫자) */
+type KasiItem = Record<string, string | number>;
+
+/** fetch 호환 함수 (테스트에서 대체 가능) */
+export type Fetcher = (url: string) => Promise<{ ok: boolean; status: number; json(): Promise<unknown> }>;
+
+/** 클라이언트 설정 */
+export interface KasiClientOptions {
+  /** 공공데이터포털 서비스 키 (디코딩된 값) */
+  serviceKey: string;
+  /** 응답 캐시 디렉터리 (없으면 캐시하지 않음) */
+  cacheDir?: string | null;
+  /** HTTP 요청 함수 (기본: 전역 fetch) */
+  fetcher?: Fetcher;
+}
+
+/** KASI 음양력·절기 API 클라이언트 */
+export class KasiClient {
+  private readonly serviceKey: string;
+  private readonly cacheDir: string | null;
+  private readonly fetcher: Fetcher;
+
+  constructor(options: KasiClientOptions) {
+    if (options.serviceKey.trim() === '') throw new Error(`KASI service key is empty (set ${KASI_KEY_ENV})`);
+    this.serviceKey = options.serviceKey;
+    this.cacheDir = options.cacheDir ?? null;
+    this.fetcher = options.fetcher ?? ((url) => fetch(url));
+  }
+
+  /**
+   * 양력 날짜의 음력 날짜를 조회한다 (LrsrCldInfoService/getLunCalInfo).
+   */
+  async lunarDate(year: number, month: number, day: number): Promise<ReferenceLunarDate> {
+    const [item] = await this.request('LrsrCldInfoService/getLunCalInfo', {
+      solYear: pad(year, 4), solMonth: pad(month, 2), solDay: pad(day, 2),
+    });
+    if (!item) throw new Error(`KASI returned no lunar date for ${pad(year, 4)}-${pad(month, 2)}-${pad(day, 2)}`);
+    return {
+      solar: `${pad(year, 4)}-${pad(month, 2)}-${pad(day, 2)}`,
+      lunar: {
+        year: Number(item.lunYear),
+        month: Number(item.lunMonth),
+        day: Number(item.lunDay),
+        leap: item.lunLeapmonth === '윤',
+      },
+    };
+  }
+
+  /**
+   * 한 달의 절기 시각을 조회한다 (SpcdeInfoService/get24DivisionsInfo).
+   * 절기 이름(한글)을 TERM_DEFS의 키로 바꿔 돌려준다.
+   */
+  async solarTerms(year: number, month: number): Promise<ReferenceTerm[]> {
+    const items = await this.request('SpcdeInfoService/get24DivisionsInfo', {
+      solYear: pad(year, 4), solMonth: pad(month, 2),
+    });
+    return items.map((item) => {
+      const def = astro.TERM_DEFS.find((d) => d.nameKo === String(item.dateName).trim());
+      if (!def) throw new Error(`unknown solar term name from KASI: ${item.dateName}`);
+      const date = String(item.locdate);
+      const time = String(item.kst).trim().padStart(4, '0');
+      return { key: def.key, kst: `${date.slice(0, 4)}-${date.slice(4, 6)}-${date.slice(6, 8)}T${time.slice(0, 2)}:${time.slice(2, 4)}` };
+    });
+  }
+
+  /**
+   * 연도 범위의 기준 자료를 모은다: 모든 절기와 매월 1일·15일의 음력 날짜.
+   * @param from 첫 연도
+   * @param to 마지막 연도 (포함)
+   * @param onMonth 한 달 치를 받을 때마다 호출 (진행 표시용)
+   */
+  async referenceData(from: number, to: number, onMonth?: () => void): Promise<ReferenceData> {
+    const data: ReferenceData = { terms: [], lunar: [] };
+    for (let year = from; year <= to; year++) {
+      for (let month = 1; month <= 12; month++) {
+        data.terms.push(...await this.solarTerms(year, month));
+        for (const day of [1, 15]) data.lunar.push(await this.lunarDate(year, month, day));
+        onMonth?.();
+      }
+    }
+    return data;
+  }
+
+  /** API를 호출해 item 목록을 돌려준다 (캐시 우선) */
+  private async request(operation: string, params: Record<string, string>): Promise<KasiItem[]> {
+    const cacheFile = this.cacheDir
+      ? join(this.cacheDir, `${operation.replace('/', '_')}_${Object.values(params).join('')}.json`)
+      : null;
+    if (cacheFile) {
+      try {
+        return JSON.parse(readFileSync(cacheFile, 'utf8')) as KasiItem[];
+      } catch {
+        // 캐시가 없거나 깨졌으면 새로 받는다
+      }
+    }
+    const query = new URLSearchParams({ ...params, ServiceKey: this.serviceKey, _type: 'json', numOfRows: '100' });
+    const res = await this.fetcher(`${KASI_API_BASE}/${operation}?${query}`);
+    if (!res.ok) throw new Error(`KASI request ${operation} failed: HTTP ${res.status}`);
+    const items = parseItems(await res.json(), operation);
+    if (cacheFile && this.cacheDir) {
+      mkdirSync(this.cacheDir, { recursive: true });
+      writeFileSync(cacheFile, JSON.stringify(items));
+    }
+    return items;
+  }
+}
+
+/**
+ * KASI JSON 응답에서 item 목록을 꺼낸다.
+ * 결과가 하나면 배열이 아닌 객체, 없으면 빈 문자열로 오는 경우를 함께 처리한다.
+ */
+export function parseItems(body: unknown, operation: string): KasiItem[] {
+  const response = (body as { response?: Record<string, unknown> } | null)?.response;
+  const header = response?.header as { resultCode?: string; resultMsg?: string } | undefined;
+  if (!header || header.resultCode !== '00') {
+    throw new Error(`KASI request ${operation} failed: ${header?.resultMsg ?? 'malformed response'}`);
+  }
+  const items = (response?.body as { items?: unknown } | undefined)?.items;
+  const item = (items as { item?: unknown } | undefined)?.item;
+  if (item == null) return [];
+  return (Array.isArray(item) ? item : [item]) as KasiItem[];
+}
+
+function pad(n: number, width: number): string {
+  return String(n).padStart(width, '0');
+}
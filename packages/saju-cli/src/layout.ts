@@ -0,0 +1,94 @@
+/**
+ * @fileoverview 명식(命式) 레이아웃
+ *
+ * - 세로: 인쇄된 명식처럼 오른쪽에서 왼쪽으로 연주·월주·일주·시주를 배치하고,
+ *   천간 위에 십성, 지지 아래에 지장간·12운성·12신살을 놓는다.
+ * - 카드: 기둥마다 한 블록으로 천간·지지·지장간·12운성·신살·공망을 모은다.
+ */
+
+import {
+  type SajuResult,
+  bazi,
+  shinsal,
+  type Localizer,
+  type PillarKind,
+  type ShinsalKind,
+} from 'saju-lib';
+import { abbreviateLabels, alignColumns } from './textWidth.js';
+
+/** 세로 명식의 열 순서 (왼쪽→오른쪽: 시·일·월·연) */
+export const VERTICAL_COLUMN_ORDER: readonly PillarKind[] = ['Hour', 'Day', 'Month', 'Year'];
+
+/**
+ * 세로 명식 표를 렌더링한다.
+ * @param out 출력 줄 버퍼
+ * @param result 사주 계산 결과
+ * @param i18n 레이블 제공자
+ * @param compact true면 셀의 한자 병기를 생략해 폭을 줄인다
+ */
+export function renderVerticalChart(out: string[], result: SajuResult, i18n: Localizer, compact = false): void {
+  const byKind = {
+    Year: result.yearPillar,
+    Month: result.monthPillar,
+    Day: result.dayPillar,
+    Hour: result.hourPillar,
+  };
+  const dayStem = result.dayPillar.stem;
+  const shinsalBranch = result.shinsalBase === 'Day' ? result.dayPillar.branch : result.yearPillar.branch;
+  const cols = VERTICAL_COLUMN_ORDER.map((kind) => ({ kind, pillar: byKind[kind] }));
+
+  const rows: string[][] = [
+    ['', ...cols.map((c) => i18n.pillarKindLabel(c.kind))],
+    [i18n.tenGodsLabel(), ...cols.map((c) => (c.kind === 'Day'
+      ? i18n.dayStemWord()
+      : i18n.tenGodLabel(bazi.tenGod(dayStem, c.pillar.stem))))],
+    [i18n.stemsLabel(), ...cols.map((c) => i18n.stemLabel(c.pillar.stem))],
+    [i18n.branchesLabel(), ...cols.map((c) => i18n.branchLabel(c.pillar.branch))],
+    [i18n.tenGodsLabel(), ...cols.map((c) => i18n.tenGodLabel(bazi.tenGodBranch(dayStem, c.pillar.branch)))],
+    [i18n.hiddenStemsHeading(), ...cols.map((c) => bazi.hiddenStems(c.pillar.branch).map((s) => i18n.stemLabel(s)).join(' '))],
+    [i18n.twelveStagesLabel(), ...cols.map((c) => i18n.stageLabel(bazi.twelveStageIndex(dayStem, c.pillar.branch)))],
+    [i18n.twelveShinsalLabel(), ...cols.map((c) => i18n.shinsalLabel(shinsal.twelveShinsalIndex(shinsalBranch, c.pillar.branch)))],
+  ];
+
+  out.push(i18n.chartHeading());
+  out.push(...alignColumns(compact ? rows.map((row) => row.map(abbreviateLabels)) : rows));
+  out.push('');
+}
+
+/**
+ * 기둥별 카드를 렌더링한다 (연주→시주).
+ * 여러 섹션에 흩어진 기둥 하나의 정보를 한 블록에 모은다.
+ * @param out 출력 줄 버퍼
+ * @param result 사주 계산 결과
+ * @param i18n 레이블 제공자
+ */
+export function renderPillarCards(out: string[], result: SajuResult, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  const dayStem = result.dayPillar.stem;
+  const shinsalBranch = result.shinsalBase === 'Day' ? result.dayPillar.branch : result.yearPillar.branch;
+  const voids = bazi.gongmang(dayStem, result.dayPillar.branch);
+  const entries = bazi.tenGodsFull(dayStem, pillars);
+
+  out.push(i18n.chartHeading());
+  kinds.forEach((kind, i) => {
+    const p = pillars[i];
+    const stemGod = kind === 'Day' ? i18n.dayStemWord() : i18n.tenGodLabel(bazi.tenGod(dayStem, p.stem));
+    const hidden = entries
+      .filter((e) => e.layer === 'Hidden' && e.position === kind)
+      .map((e) => `${i18n.stemLabel(e.stem)} ${i18n.tenGodLabel(e.tenGod)}${e.rank ? ` (${i18n.hiddenRankLabel(e.rank)})` : ''}`);
+    const kindsHere: ShinsalKind[] = [];
+    for (const entry of result.shinsalEntries) {
+      if (entry.kind !== 'GongMang' && entry.foundAt.includes(kind) && !kindsHere.includes(entry.kind)) kindsHere.push(entry.kind);
+    }
+
+    out.push(`[${i18n.pillarKindLabel(kind)}] ${i18n.pillarLabel(p)}`);
+    out.push(`- ${i18n.stemWord()} ${i18n.stemLabel(p.stem)}: ${i18n.elementLabel(bazi.stemElement(p.stem))} ${i18n.polarityLabel(bazi.stemPolarity(p.stem))} | ${stemGod}`);
+    out.push(`- ${i18n.branchWord()} ${i18n.branchLabel(p.branch)}: ${i18n.elementLabel(bazi.branchElement(p.branch))} ${i18n.polarityLabel(bazi.branchPolarity(p.branch))} | ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, p.branch))}`);
+    out.push(`- ${i18n.hiddenStemsHeading()}: ${hidden.join(', ')}`);
+    out.push(`- ${i18n.twelveStagesLabel()} ${i18n.stageLabel(bazi.twelveStageIndex(dayStem, p.branch))} | ${i18n.twelveShinsalLabel()} ${i18n.shinsalLabel(shinsal.twelveShinsalIndex(shinsalBranch, p.branch))}`);
+    out.push(`- ${i18n.keyShinsalLabel()}: ${kindsHere.length > 0 ? kindsHere.map((k) => i18n.shinsalKindLabel(k)).join(', ') : i18n.noneLabel()}`);
+    if (voids.includes(p.branch)) out.push(`- ${i18n.shinsalKindLabel('GongMang')}`);
+    out.push('');
+  });
+}
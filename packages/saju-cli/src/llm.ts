@@ -0,0 +1,271 @@
+/**
+ * @fileoverview LLM 프롬프트용 구조화 내보내기
+ *
+ * 명식을 'key: JSON 값' 줄로 출력한다. 키는 고정된 영문 snake_case,
+ * 값은 한 줄 JSON이며 용어마다 식별자(id)와 한국어(ko)·영어(en) 레이블을 함께 싣는다.
+ * 장식·정렬 없이 그대로 프롬프트에 붙이거나 해석 모델에 넘길 수 있다.
+ *
+ * 필드 이름 규칙 (CLI의 모든 JSON 출력 공통):
+ * - 필드 이름은 영문 snake_case이며 --lang과 무관하다. 표시 문자열은 ko·en 필드에만 싣는다.
+ * - 열거형 값(오행 'Wood', 십성 'BiGyeon' 등)과 그 값을 키로 쓰는 맵은 라이브러리 식별자를 그대로 쓴다.
+ * - 라이브러리 객체를 그대로 내보내지 않고 이 파일의 변환 함수를 거쳐 이름을 고정한다.
+ * 이미 낸 필드의 이름·의미를 바꾸면 SCHEMA_VERSION(schema.ts)을 올린다. 형식 식별자도 함께 바뀐다.
+ */
+
+import {
+  type SajuResult,
+  type SajuWarning,
+  type Pillar,
+  type PillarPosition,
+  astro,
+  bazi,
+  gyeokguk,
+  ilju,
+  luck,
+  tenGodStats,
+  timezone,
+  I18n,
+} from 'saju-lib';
+import { SCHEMA_VERSION } from './schema.js';
+
+/** 내보내기 형식 식별자 (스키마 버전을 따른다) */
+export const LLM_FORMAT_VERSION = `saju-llm/${SCHEMA_VERSION}`;
+
+const KO = new I18n('Ko');
+const EN = new I18n('En');
+
+/** 식별자와 한·영 레이블 */
+export interface Term {
+  id: string
+  ko: string
+  en: string
+}
+
+export function term(id: string, label: (i18n: I18n) => string): Term {
+  return { id, ko: label(KO), en: label(EN) };
+}
+
+/** 기둥 하나의 구조화 데이터 */
+export function pillarData(pillar: Pillar, position: PillarPosition, result: SajuResult) {
+  const dayStem = result.dayPillar.stem;
+  const entries = bazi.tenGodsFull(dayStem, [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar]);
+  const stemElement = bazi.stemElement(pillar.stem);
+  const branchElement = bazi.branchElement(pillar.branch);
+  const stage = bazi.twelveStageIndex(dayStem, pillar.branch);
+  return {
+    ganji: ilju.iljuKey(pillar),
+    stem: {
+      ...term(String(pillar.stem), (i) => i.stemLabel(pillar.stem)),
+      element: stemElement,
+      yang: bazi.stemPolarity(pillar.stem),
+      ten_god: position === 'Day' ? null : term(bazi.tenGod(dayStem, pillar.stem), (i) => i.tenGodLabel(bazi.tenGod(dayStem, pillar.stem))),
+    },
+    branch: {
+      ...term(String(pillar.branch), (i) => i.branchLabel(pillar.branch)),
+      element: branchElement,
+      yang: bazi.branchPolarity(pillar.branch),
+      ten_god: term(bazi.tenGodBranch(dayStem, pillar.branch), (i) => i.tenGodLabel(bazi.tenGodBranch(dayStem, pillar.branch))),
+    },
+    hidden_stems: entries
+      .filter((e) => e.layer === 'Hidden' && e.position === position)
+      .map((e) => ({ stem: term(String(e.stem), (i) => i.stemLabel(e.stem)), rank: e.rank, ten_god: term(e.tenGod, (i) => i.tenGodLabel(e.tenGod)) })),
+    twelve_stage: term(String(stage), (i) => i.stageLabel(stage)),
+  };
+}
+
+/** 계산 신뢰도 경고 (--pillars-only의 warnings 줄도 같은 모양) */
+export function warningData(warning: SajuWarning) {
+  return {
+    code: warning.code,
+    year: warning.year,
+    message: warning.message,
+    resolved_time: warning.resolvedTime ?? null,
+  };
+}
+
+/** JD를 출생지 현지 시각 'YYYY-MM-DD HH:mm'으로 표기한다 */
+export function localTime(result: SajuResult, jd: number): string {
+  return timezone.toLocal(result.tzSpec, astro.datetimeFromJd(jd)).format('YYYY-MM-DD HH:mm');
+}
+
+/** 입력과 그 해석 (입력 날짜·시각, 역법, 시간대, 보정 시각 등) */
+export function inputData(result: SajuResult) {
+  return {
+    date: result.inputDate,
+    time: result.inputTime,
+    raw_input: result.rawInput,
+    calendar: result.calendarIsLunar ? 'lunar' : 'solar',
+    leap_month: result.leapMonth,
+    time_zone: result.tzName,
+    corrected_local_time: result.lmtInfo?.correctedLocal ?? null,
+    gender: result.gender,
+    approximate: result.approximate,
+    hemisphere: result.hemisphere === 'South' ? 'south' : 'north',
+    historical_date: result.historicalDate && {
+      calendar: 'julian',
+      julian_date: result.historicalDate.julianDate,
+      gregorian_date: result.historicalDate.gregorianDate,
+      region: result.historicalDate.region,
+      gregorian_from: result.historicalDate.gregorianFrom,
+    },
+  };
+}
+
+/** 출생 시점이 속한 절월 구간 (시각은 출생지 현지 시각, 일수는 소수 첫째 자리) */
+export function birthTermData(result: SajuResult) {
+  const ctx = result.birthTerm;
+  const at = (jd: number) => localTime(result, jd);
+  const days = (d: number) => Math.round(d * 10) / 10;
+  return {
+    ...term(ctx.term.key, (i) => i.termName(ctx.term)),
+    start: at(ctx.startJd),
+    days_since: days(ctx.daysSinceStart),
+    next: term(ctx.nextTerm.key, (i) => i.termName(ctx.nextTerm)),
+    next_start: at(ctx.endJd),
+    days_until: days(ctx.daysUntilNext),
+  };
+}
+
+/** 출생 시각 가까이의 기둥 경계와 그 너머의 4기둥 */
+export function boundaryData(result: SajuResult) {
+  return {
+    window_minutes: result.boundaryWindow,
+    alternatives: result.boundaryAlternatives.map((alt) => ({
+      side: alt.side === 'Earlier' ? 'earlier' : 'later',
+      minutes: alt.minutes,
+      boundary: alt.boundary,
+      changed: alt.changed,
+      pillars: {
+        year: ilju.iljuKey(alt.yearPillar),
+        month: ilju.iljuKey(alt.monthPillar),
+        day: ilju.iljuKey(alt.dayPillar),
+        hour: ilju.iljuKey(alt.hourPillar),
+      },
+    })),
+  };
+}
+
+/** 출생·절입·운 경계 시점의 율리우스일 (UT 기준, 반올림하지 않음) */
+export function julianDayData(result: SajuResult) {
+  return {
+    birth: result.birthJd,
+    birth_term: { start: result.birthTerm.startJd, next_start: result.birthTerm.endJd },
+    daewon: result.daewonItems.map((item) => ({
+      start_months: item.startMonths,
+      start: luck.daewonStartJd(result.birthJd, luck.exactStartMonths(item)),
+    })),
+    yearly: result.yearlyLuck.map((yl) => ({ year: yl.year, start: yl.startJd, end: yl.endJd })),
+    monthly: result.monthlyLuckYears.map((m) => ({
+      year: m.year,
+      months: m.months.map((ml) => ({ branch: ml.branch, start: ml.startJd, end: ml.endJd })),
+    })),
+    terms: result.solarTerms.map((t) => ({ key: t.def.key, jd: t.jd })),
+  };
+}
+
+/** 일간과 그 강약 판정 */
+export function dayMasterData(result: SajuResult) {
+  const dayStem = result.dayPillar.stem;
+  return {
+    ...term(String(dayStem), (i) => i.stemLabel(dayStem)),
+    element: bazi.stemElement(dayStem),
+    strength: term(result.strength.level, (i) => i.strengthLevelLabel(result.strength.level)),
+    strength_score: result.strength.total,
+  };
+}
+
+/** 원국의 오행 개수 (합화 반영) */
+export function elementsData(result: SajuResult) {
+  const counts = bazi.elementsCount(natalPillars(result), result.elementOverrides);
+  return { Wood: counts[0], Fire: counts[1], Earth: counts[2], Metal: counts[3], Water: counts[4] };
+}
+
+/** 격국 */
+export function gyeokgukData(result: SajuResult): Term {
+  const kind = gyeokguk.determineGyeokguk(natalPillars(result), result.elementOverrides ? result.stemCombinations : []).kind;
+  return term(kind, (i) => i.gyeokgukLabel(kind));
+}
+
+/** 용신·희신·기신·구신 */
+export function yongshinData(result: SajuResult) {
+  const y = result.yongshin;
+  return {
+    method: y.method,
+    yongshin: term(y.yongshin, (i) => i.elementLabel(y.yongshin)),
+    heeshin: term(y.heeshin, (i) => i.elementLabel(y.heeshin)),
+    gishin: term(y.gishin, (i) => i.elementLabel(y.gishin)),
+    gushin: term(y.gushin, (i) => i.elementLabel(y.gushin)),
+  };
+}
+
+/** 천간·지지 합충 관계와 신살 */
+export function interactionsData(result: SajuResult) {
+  return {
+    stem_interactions: result.stemInteractions.map((r) => ({
+      ...term(r.relation, (i) => i.stemRelationLabel(r.relation)),
+      positions: r.positions,
+    })),
+    branch_interactions: result.branchInteractions.map((r) => ({
+      ...term(r.relation, (i) => i.branchRelationLabel(r.relation)),
+      positions: r.positions,
+    })),
+    shinsal: result.shinsalEntries.map((e) => ({
+      ...term(e.kind, (i) => i.shinsalKindLabel(e.kind)),
+      found_at: e.foundAt,
+    })),
+  };
+}
+
+/** 운 기둥 하나의 간지·십성·길흉 (대운·세운·월운 공통) */
+export function luckPillarData(pillar: Pillar, result: SajuResult) {
+  const dayStem = result.dayPillar.stem;
+  return {
+    ganji: ilju.iljuKey(pillar),
+    stem_ten_god: bazi.tenGod(dayStem, pillar.stem),
+    branch_ten_god: bazi.tenGodBranch(dayStem, pillar.branch),
+    favorability: luck.pillarFavorability(pillar, result.yongshin),
+  };
+}
+
+function natalPillars(result: SajuResult): Pillar[] {
+  return [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+}
+
+/**
+ * 계산 결과를 LLM 친화적인 'key: JSON' 줄 목록으로 변환한다.
+ * @param result 사주 계산 결과
+ * @param showJd 율리우스일(julian_day) 줄을 덧붙인다
+ */
+export function renderLlmExport(result: SajuResult, showJd = false): string[] {
+  const natal = natalPillars(result);
+  const positions: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const dist = tenGodStats.tenGodDistribution(natal, result.dayPillar.stem);
+  const { stem_interactions, branch_interactions, shinsal } = interactionsData(result);
+
+  const lines: [string, unknown][] = [
+    ['format', LLM_FORMAT_VERSION],
+    ['schema_version', SCHEMA_VERSION],
+    ['fingerprint', result.fingerprint],
+    ['input', inputData(result)],
+    ['warnings', result.warnings.map(warningData)],
+    ['birth_term', birthTermData(result)],
+    ...positions.map((pos, i): [string, unknown] => [`pillar.${pos.toLowerCase()}`, pillarData(natal[i], pos, result)]),
+    ['day_master', dayMasterData(result)],
+    ['elements', elementsData(result)],
+    ['ten_gods', dist.counts],
+    ['gyeokguk', gyeokgukData(result)],
+    ['yongshin', yongshinData(result)],
+    ['stem_interactions', stem_interactions],
+    ['branch_interactions', branch_interactions],
+    ['shinsal', shinsal],
+    ['daewon', result.daewonItems.map((item) => ({
+      start_age: Math.floor(item.startMonths / 12),
+      start_months: item.startMonths,
+      ...luckPillarData(item.pillar, result),
+    }))],
+    ['yearly', result.yearlyLuck.map((yl) => ({ year: yl.year, ...luckPillarData(yl.pillar, result) }))],
+  ];
+  if (result.boundaryWindow !== null) lines.push(['boundary_sensitivity', boundaryData(result)]);
+  if (showJd) lines.push(['julian_day', julianDayData(result)]);
+  return lines.map(([key, value]) => `${key}: ${JSON.stringify(value)}`);
+}
@@ -7,35 +7,108 @@
  * 사용 예:
  *   saju --date 2000-01-15 --time 12:00 --gender male
  *   saju --date 1990-05-20 --time 08:30 --gender female --calendar lunar
+ *   saju convert --to lunar 1990-09-15
+ *   saju ganji 2025-10-03
+ *   saju selfcheck --years 1900..2100
+ *   SAJU_DEV=1 saju crosscheck --years 1900..2100
+ *   saju gen --count 10000 --from 1950 --to 2010 --seed 42 > charts.ndjson
+ *   saju verify --data kasi.json
+ *   saju --format llm daemon --socket /tmp/saju.sock
+ *   saju locations 서울
+ *   saju schema > saju-json.schema.json
  */
 
+import { readFileSync, writeFileSync } from 'node:fs';
+import { homedir } from 'node:os';
+import { join } from 'node:path';
+import { isMainThread, parentPort, Worker, workerData } from 'node:worker_threads';
 import { Command } from 'commander';
 import {
+  bazi,
   calculate,
+  calculateCached,
+  calculatePillars,
   type CalendarType,
   type SajuRequest,
   type SajuResult,
-  type StrengthResult,
-  type TimeZoneSpec,
-  astro,
-  bazi,
+  gaeun,
+  glossary,
+  health,
+  historical,
+  ilju,
+  input,
+  location,
   luck,
-  strength as str,
+  lunar,
+  naming,
+  render,
+  school,
+  shinsalNotes,
+  SUPPORTED_YEAR_RANGES,
+  strength,
+  timings,
+  timezone,
   I18n,
+  type AgeConvention,
+  type Romanization,
   type Lang,
-  type PillarKind,
-  timezone,
-  type Pillar,
-  type SolarTerm,
-  type Element,
-  type Direction,
   type Gender,
+  type DaewonAgeRange,
+  type DaewonRounding,
+  type DaewonDirectionMode,
+  type DaewonYearBasis,
+  type DayBoundary,
+  type Hemisphere,
+  type LmtBoundary,
+  type ShinsalBase,
 } from 'saju-lib';
 import {
   parseIntegerOption,
   parseOptionalIntegerOption,
+  parseNumberOption,
   parseOptionalNumberOption,
+  parseChoiceOption,
+  parseRangeOption,
 } from './cliParsing.js';
+import { renderHanjaLine, renderOneLine, renderPillarsReport, renderReport, type Layout } from './report.js';
+import { convertDate, formatConversion, type ConvertTarget } from './convert.js';
+import {
+  pillarsOfDate,
+  formatDatePillars,
+  datePillarsJson,
+  findOccurrences,
+  type GanjiKind,
+} from './ganji.js';
+import { alignColumns, fitToWidth } from './textWidth.js';
+import { CHART_CSV_HEADER, csvField, isChartSection, renderChartCsv, renderLuckCsv, parseLuckSections } from './csv.js';
+import { renderLlmExport, warningData } from './llm.js';
+import { chartJson, pillarsJson } from './json.js';
+import { chartReportSchema, pillarsReportSchema } from './jsonSchema.js';
+import { openOutput, STDOUT, type OutputSink } from './output.js';
+import { renderMarkdown, renderPillarsMarkdown } from './markdown.js';
+import { Progress, PROGRESS_MODES, type ProgressMode } from './progress.js';
+import { COLOR_MODES, type ColorMode, colorizeLines, shouldColor } from './color.js';
+import { batchRecordKey, parseBatchFile, splitBatch, type BatchOutcome, type BatchRecord } from './batch.js';
+import { aggregateStats, chartSample, formatStats, statsJson, type ChartSample } from './stats.js';
+import { runSelfCheck, formatSelfCheck } from './selfcheck.js';
+import { SCHEMA_VERSION, versioned } from './schema.js';
+import { generateBirths, generatedLine, seededRandom } from './gen.js';
+import { runCrossCheck, formatCrossCheck } from './crosscheck.js';
+import { KASI_SAMPLE, parseReferenceData, verifyReference, formatVerify, verifyJson } from './verify.js';
+import { KasiClient, KASI_KEY_ENV } from './kasi.js';
+import { defaultSocketPath, startDaemon } from './daemon.js';
+
+/** 줄바꿈 최소 폭 (이보다 좁으면 레이블조차 들어가지 않는다) */
+const MIN_WIDTH = 20;
+
+/** --batch에서 같은 입력이라 계산을 건너뛴(hits) 레코드와 계산한(misses) 레코드 수 */
+const batchReuse = timings.registerCache('batch', { hits: 0, misses: 0 });
+
+/** 결과 캐시(calculateCached) 사용 여부: 같은 질의가 되풀이되는 daemon에서만 켠다 */
+let useResultCache = false;
+
+/** 개발용 명령(crosscheck) 등록 여부 */
+const DEV_COMMANDS = process.env.SAJU_DEV === '1';
 
 const program = new Command();
 
@@ -43,24 +116,240 @@ program
   .name('saju')
   .version('0.1.0')
   .description('Saju palja calculator using solar terms (입춘 기준)')
-  .requiredOption('--date <YYYY-MM-DD>', 'Birth date')
-  .requiredOption('--time <HH:MM>', 'Birth time')
-  .requiredOption('--gender <male|female|m|f|남|여>', 'Gender')
+  .enablePositionalOptions()
+  .option('--date <YYYY-MM-DD>', 'Birth date (required)')
+  .option('--time <HH:MM>', 'Birth time (required)')
+  .option('--datetime <ISO-8601>', 'Birth date and time in one (e.g. 1990-03-05T06:30:00+09:00); the offset replaces --tz')
+  .option('--now <ISO-8601>', 'Reference time for --date today|yesterday|tomorrow|+3d|-2w (default: the current time in --tz)')
+  .option('--gender <male|female|unknown|m|f|u|남|여|미상>', 'Gender (required unless --event)')
+  .option('--event', 'Chart a moment instead of a person (no gender, no luck pillars)', false)
   .option('--calendar <solar|lunar>', 'Calendar type', 'solar')
-  .option('--leap-month', 'Lunar leap month', false)
+  .option('--leap-month', 'Lunar leap month')
+  .option('--no-leap-month', 'Lunar regular (non-leap) month')
+  .option('--try-both', 'Print both charts when a lunar month is ambiguous (regular/leap)', false)
+  .option('--force', 'Compute a solar year outside the supported range as an approximate chart', false)
+  .option('--pillars-only', 'Print only the four pillars (skips luck periods and analysis; fast for batch jobs)', false)
   .option('--tz <timezone>', 'Timezone (IANA or offset)', 'Asia/Seoul')
   .option('--lang <ko|en>', 'Language', 'ko')
+  .option('--age-convention <man|korean>', 'Daewon ages as 만 나이 (man) or 세는나이 (korean)', 'man')
+  .option('--romanization <korean|pinyin>', 'Stem/branch names in English output: Korean romanization (Gap, Ja) or pinyin (Jia, Zi)', 'korean')
   .option('--daewon-count <n>', 'Daewon count', '10')
+  .option('--daewon-rounding <round|floor|ceil|traditional>', 'Rounding rule for the daewon start month count (default: round)')
+  .option('--daewon-direction <auto|forward|backward>', 'Daewon direction: inferred from gender and year stem yin/yang, or fixed', 'auto')
+  .option('--daewon-year <lichun|lunar-new-year>', 'Year boundary of the year stem whose yin/yang sets the auto daewon direction', 'lichun')
+  .option('--daewon-until-age <n>', 'List every daewon up to this age (overrides --daewon-count)')
+  .option('--daewon-ages <a..b>', 'List daewon overlapping this age range (overrides --daewon-count)')
   .option('--month-year <YYYY>', 'Monthly luck year')
+  .option('--month-years <a..b>', 'Monthly luck for consecutive years (e.g. 2025..2027)')
   .option('--year-start <YYYY>', 'Yearly luck start')
   .option('--year-count <n>', 'Yearly luck count', '10')
+  .option('--yearly-until-age <n>', 'List yearly luck from the birth year to this age, grouped by daewon')
   .option('--local-mean-time', 'Use local mean time correction', false)
   .option('--longitude <DEG>', 'Longitude for LMT')
   .option('--location <NAME>', 'Location name for LMT')
+  .option('--lmt-boundary <corrected|clock>', 'Compare hour/day boundaries against the LMT-corrected or the clock time', 'corrected')
+  .option('--boundary-window <minutes>', 'Warn when an hour, day, solar-term or Ipchun boundary lies within this many minutes of the birth (e.g. 30)')
+  .option('--historical-calendar <gregorian|julian|auto>', 'Calendar of a solar input date (auto: the one in force in the --tz country before its Gregorian reform)', 'gregorian')
+  .option('--hemisphere <north|south>', 'Birth hemisphere; south shifts the month branch by six (non-default school option)', 'north')
+  .option('--day-boundary <jasi|midnight>', 'When the day pillar changes: 23:00 (jasi) or 00:00 with 23:00-24:00 as late 子 hour (midnight) (default: jasi)')
+  .option('--shinsal-base <year|day>', 'Branch the 12 shinsal are counted from (default: year)')
+  .option('--school <yeonhae|japyeong-modern>', 'Preset bundling day boundary, shinsal base, daewon rounding, month scoring and --transform (explicit options still win; year boundary, hidden stems and 12 stages are fixed)')
   .option('--show-terms', 'Show solar terms', false)
-  .action((opts) => {
+  .option('--era', 'Show Dangun/Buddhist era years and 세차 for the birth and target years', false)
+  .option('--sun-sign', 'Show the Western sun sign (and the ingress time when the birth is within a day of a cusp)', false)
+  .option('--show-strength', 'Append the day master strength to --format hanja', false)
+  .option('--show-jd', 'Add raw Julian Day values to the birth time, solar terms and luck boundaries (text and --format llm)', false)
+  .option('--width <n>', 'Wrap output lines to this many columns')
+  .option('--compact', 'Abbreviate labels (drop hanja) and fit the terminal width', false)
+  .option('--color <auto|always|never>', 'Color stems and branches by element in the text report (auto: when stdout is a terminal, without --output and NO_COLOR)', 'auto')
+  .option('--format <text|csv|llm|json|markdown|oneline|hanja>', 'Output format (csv: luck timeline rows, llm: key/JSON lines for prompts, json: the full chart as one JSON document, markdown: headings, tables and lists for notes and issues, oneline: one summary line, hanja: just the eight characters)', 'text')
+  .option('--sections <list>', 'Sections for --format csv (daewon,yearly,monthly), or chart for one summary row per chart', 'daewon,yearly,monthly')
+  .option('--layout <list|vertical|card>', 'Chart layout (vertical: traditional right-to-left columns, card: one block per pillar)', 'list')
+  .option('--batch <FILE>', 'JSON array or JSON lines file; each record overrides options (date, time, gender, tz, calendar, location, lmtBoundary, sections, …)')
+  .option('--jobs <n>', 'Worker threads for --batch', '1')
+  .option('--stats', 'After --batch, print day master, element count, strength and daewon direction (by gender) distributions', false)
+  .option('--progress <auto|bar|json|off>', 'Progress on stderr for --batch (auto: bar when stderr is a terminal)', 'auto')
+  .option('--timings', 'Print time spent in term computation, lunar conversion, pillars, luck, analysis and rendering (and cache hits) to stderr', false)
+  .option('--svg-timeline <FILE>', 'Write an SVG timeline of daewon and yearly luck to FILE')
+  .option('--output <FILE>', 'Write the report (any --format, including --batch and --stats output) to FILE as UTF-8 instead of stdout')
+  .option('--svg-out <FILE>', 'Write the four-pillar chart (stems, branches, hidden stems, ten gods) as SVG to FILE')
+  .option('--ilju-data <FILE>', 'JSON file overriding the built-in day pillar notes')
+  .option('--gaeun-data <FILE>', 'JSON file overriding the built-in element colors/directions/numbers/seasons')
+  .option('--glossary', 'Append definitions of the technical terms used in the output', false)
+  .option('--shinsal-notes', 'Append one-line meanings of detected shinsal', false)
+  .option('--shinsal-data <FILE>', 'JSON file overriding the built-in shinsal meanings (implies --shinsal-notes)')
+  .option('--health', 'Show the element–organ health section for absent/excess elements', false)
+  .option('--health-data <FILE>', 'JSON file overriding the built-in organ notes (implies --health)')
+  .option('--name <HANGUL>', 'Append a 성명학 section: 수리 grids and sound elements of this name, checked against absent elements and 용신')
+  .option('--name-hanja <HANJA>', 'Hanja spelling of --name (stroke counts from the built-in table)')
+  .option('--name-strokes <list>', 'Stroke count of every character of --name (e.g. 8,5,9; overrides the table)')
+  .option('--month-scoring <stage|matrix>', 'Month branch contribution to strength: 12-stage bonus or element-vs-month score table (default: stage)')
+  .option('--strength-thresholds <list>', 'Score thresholds for 극신약,신약,신강,극신강 (e.g. -10,-3,3,10)')
+  .option('--transform', 'Count complete 삼합/방합 branches and stems of a transformed 천간합 as their combined element (element counts, strength and 격국)')
+  .option('--no-transform', 'Do not transform 삼합/방합 branches or 천간합 stems (overrides --school)')
+  .action(async (opts) => {
+    try {
+      await run(opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+program
+  .command('convert')
+  .description('Convert a date between solar and lunar calendars')
+  .argument('<date>', 'Date to convert (YYYY-MM-DD, or today|yesterday|tomorrow|+3d|-2w with --to lunar)')
+  .requiredOption('--to <lunar|solar>', 'Target calendar')
+  .option('--tz <timezone>', 'Timezone that decides today', 'Asia/Seoul')
+  .option('--now <ISO-8601>', 'Reference time for relative dates (default: now)')
+  .option('--leap', 'Input lunar date is in the leap month (with --to solar)', false)
+  .option('--json', 'Print JSON', false)
+  .option('--lang <ko|en>', 'Language', 'ko')
+  .action((date: string, opts: ConvertOptions) => {
+    try {
+      runConvert(date, opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+program
+  .command('ganji')
+  .description('Show the sexagenary pillars of a date, or list when a pillar occurs (--find)')
+  .argument('[date]', 'Solar date (YYYY-MM-DD, or today|yesterday|tomorrow|+3d|-2w)')
+  .option('--time <HH:MM>', 'Time (adds the hour pillar)')
+  .option('--tz <timezone>', 'Timezone (IANA or offset)', 'Asia/Seoul')
+  .option('--now <ISO-8601>', 'Reference time for relative dates (default: now)')
+  .option('--find <name>', 'Pillar to search for (e.g. 을사, 乙巳, eulsa)')
+  .option('--kind <year|month|day>', 'Pillar kind for --find', 'year')
+  .option('--from <YYYY>', 'First year for --find', '1900')
+  .option('--to <YYYY>', 'Last year for --find', '2100')
+  .option('--json', 'Print JSON', false)
+  .option('--lang <ko|en>', 'Language', 'ko')
+  .action((date: string | undefined, opts: GanjiOptions) => {
+    try {
+      runGanji(date, opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+program
+  .command('locations')
+  .description('List the built-in locations accepted by --location')
+  .argument('[query]', 'Only list locations whose key, name or alias contains this text')
+  .option('--json', 'Print JSON', false)
+  .action((query: string | undefined, opts: { json: boolean }) => {
+    const found = location.searchLocations(query ?? '');
+    if (opts.json) {
+      console.log(JSON.stringify(found, null, 2));
+      return;
+    }
+    const rows = found.map((loc) => [
+      loc.key,
+      loc.display,
+      `${loc.longitude.toFixed(4)}°E`,
+      `${loc.latitude.toFixed(4)}°N`,
+      loc.tz,
+      loc.aliases.join(', '),
+    ]);
+    for (const line of alignColumns(rows)) console.log(line);
+  });
+
+program
+  .command('selfcheck')
+  .description('Check lunar round-trips, solar term order, day pillar continuity and month boundaries')
+  .option('--years <a..b>', 'Year range to check', '1900..2100')
+  .option('--json', 'Print JSON', false)
+  .option('--progress <auto|bar|json|off>', 'Progress on stderr (auto: bar when stderr is a terminal)', 'auto')
+  .action((opts: SelfCheckOptions) => {
+    try {
+      runSelfCheckCommand(opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+if (DEV_COMMANDS) {
+  program
+    .command('crosscheck')
+    .description('[dev] Compare the lunar table and day pillars against independent astronomical/epoch implementations')
+    .option('--years <a..b>', 'Year range to check', '1900..2100')
+    .option('--json', 'Print JSON', false)
+    .option('--progress <auto|bar|json|off>', 'Progress on stderr (auto: bar when stderr is a terminal)', 'auto')
+    .action((opts: SelfCheckOptions) => {
+      try {
+        runCrossCheckCommand(opts);
+      } catch (err: unknown) {
+        const message = err instanceof Error ? err.message : String(err);
+        console.error(`error: ${message}`);
+        process.exit(1);
+      }
+    });
+}
+
+program
+  .command('gen')
+  .description('Generate random charts as NDJSON (one JSON object per line) for datasets and load tests')
+  .option('--count <N>', 'Number of charts', '100')
+  .option('--from <YEAR>', 'First birth year', '1950')
+  .option('--to <YEAR>', 'Last birth year', '2010')
+  .option('--seed <N>', 'Random seed for a reproducible dataset (default: random, printed to stderr)')
+  .option('--tz <TZ>', 'Time zone of the generated local birth times', 'Asia/Seoul')
+  .option('--progress <auto|bar|json|off>', 'Progress on stderr (auto: bar when stderr is a terminal)', 'auto')
+  .action((opts: GenOptions) => {
+    try {
+      runGenCommand(opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+program
+  .command('verify')
+  .description('Compare solar term times and lunar dates against KASI reference data')
+  .option('--data <FILE>', 'Reference data JSON (default: bundled KASI sample)')
+  .option('--kasi <a..b>', `Fetch reference data for these years from the KASI open API (needs ${KASI_KEY_ENV} or --kasi-key)`)
+  .option('--kasi-key <KEY>', 'data.go.kr service key for the KASI open API')
+  .option('--kasi-cache <DIR>', 'Cache directory for KASI API responses', join(homedir(), '.cache', 'saju', 'kasi'))
+  .option('--tolerance <MIN>', 'Fail if a solar term deviates by more than this many minutes')
+  .option('--json', 'Print JSON', false)
+  .option('--progress <auto|bar|json|off>', 'Progress on stderr while fetching --kasi data (auto: bar when stderr is a terminal)', 'auto')
+  .action(async (opts: VerifyOptions) => {
+    try {
+      await runVerify(opts);
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : String(err);
+      console.error(`error: ${message}`);
+      process.exit(1);
+    }
+  });
+
+program
+  .command('schema')
+  .description('Print the JSON Schema of the --format json chart document')
+  .option('--pillars-only', 'Print the schema of the --pillars-only --format json document instead', false)
+  .action((opts: { pillarsOnly: boolean }) => {
+    console.log(JSON.stringify(opts.pillarsOnly ? pillarsReportSchema() : chartReportSchema(), null, 2));
+  });
+
+program
+  .command('daemon')
+  .description('Stay resident and answer newline-delimited JSON queries (--batch record keys) over a Unix socket or Windows named pipe')
+  .option('--socket <PATH>', 'Socket path (Windows: a named pipe like \\\\.\\pipe\\saju)', defaultSocketPath())
+  .action(async (opts: DaemonOptions) => {
     try {
-      run(opts);
+      await runDaemon(opts);
     } catch (err: unknown) {
       const message = err instanceof Error ? err.message : String(err);
       console.error(`error: ${message}`);
@@ -68,7 +357,11 @@ program
     }
   });
 
-program.parse();
+if (isMainThread) {
+  program.parse();
+} else {
+  runBatchWorker();
+}
 
 /**
  * CLI 메인 실행 함수.
@@ -76,242 +369,972 @@ program.parse();
  */
 /** CLI 옵션 타입 */
 interface CliOptions {
-  date: string
-  time: string
-  gender: string
+  date?: string
+  time?: string
+  datetime?: string
+  now?: string
+  gender?: string
+  event: boolean
   calendar: string
-  leapMonth: boolean
+  leapMonth?: boolean
+  tryBoth: boolean
+  force: boolean
+  pillarsOnly: boolean
   tz: string
   lang: string
+  ageConvention: string
+  romanization: string
   daewonCount: string
+  daewonRounding?: string
+  daewonDirection: string
+  daewonYear: string
+  daewonUntilAge?: string
+  daewonAges?: string
   monthYear?: string
+  monthYears?: string
   yearStart?: string
   yearCount: string
+  yearlyUntilAge?: string
   localMeanTime: boolean
   longitude?: string
+  lmtBoundary: string
+  dayBoundary?: string
+  hemisphere: string
+  historicalCalendar: string
+  boundaryWindow?: string
+  shinsalBase?: string
+  school?: string
   location?: string
   showTerms: boolean
+  showStrength: boolean
+  era: boolean
+  sunSign: boolean
+  showJd: boolean
+  format: string
+  sections: string
+  layout: string
+  width?: string
+  compact: boolean
+  color: string
+  iljuData?: string
+  gaeunData?: string
+  glossary: boolean
+  shinsalNotes: boolean
+  shinsalData?: string
+  health: boolean
+  healthData?: string
+  name?: string
+  nameHanja?: string
+  nameStrokes?: string
+  transform?: boolean
+  strengthThresholds?: string
+  monthScoring?: string
+  svgTimeline?: string
+  svgOut?: string
+  output?: string
+  batch?: string
+  jobs: string
+  stats: boolean
+  progress: string
+  timings: boolean
+}
+
+/** 필수 옵션(--date/--time) 확인을 마친 CLI 옵션 */
+type ChartInput = CliOptions & { date: string; time: string };
+
+async function run(rawOpts: CliOptions): Promise<void> {
+  // 배치 워커의 표준 출력은 터미널이 아니므로 auto를 먼저 정해 넘긴다
+  const opts: CliOptions = { ...rawOpts, color: resolveColor(rawOpts) ? 'always' : 'never' };
+  if (opts.timings) timings.enableTimings();
+  const started = performance.now();
+  let report: timings.TimingReport | null = null;
+  if (opts.batch != null) {
+    // 긴 배치는 쓸 수 없는 --output을 계산 전에 알린다
+    const out = opts.output != null ? openOutput(opts.output) : STDOUT;
+    try {
+      report = await runBatch(opts, opts.batch, out);
+    } finally {
+      out.close();
+    }
+  } else {
+    if (opts.stats) throw new Error('--stats requires --batch');
+    // 입력 오류로 기존 --output 파일을 비우지 않도록 다 계산한 뒤 연다
+    const lines = chartLines(opts);
+    const out = opts.output != null ? openOutput(opts.output) : STDOUT;
+    try {
+      for (const line of lines) {
+        out.line(line);
+      }
+    } finally {
+      out.close();
+    }
+  }
+  if (opts.timings) {
+    for (const line of formatTimings(report ?? timings.timingReport(), performance.now() - started)) console.error(line);
+  }
+}
+
+/** --timings 결과를 단계별 시간(호출 수)과 캐시 적중 줄로 표시한다 */
+function formatTimings(report: timings.TimingReport, totalMs: number): string[] {
+  const phases = timings.TIMING_PHASES.map((p) => {
+    const t = report.phases[p];
+    return `${p.toLowerCase()} ${t.ms.toFixed(1)} (${t.calls})`;
+  });
+  const caches = Object.entries(report.caches).map(([name, c]) => {
+    const total = c.hits + c.misses;
+    return `${name} ${c.hits} hit / ${c.misses} miss${total > 0 ? ` (${Math.round((c.hits / total) * 100)}%)` : ''}`;
+  });
+  return [
+    `timings (ms): ${phases.join(' | ')} | total ${totalMs.toFixed(1)}`,
+    `cache: ${caches.join(' | ')}`,
+  ];
+}
+
+/**
+ * --batch 파일의 레코드마다 옵션을 덮어써 명식을 출력한다.
+ * --jobs가 2 이상이면 작업자 스레드에 레코드를 나눠 맡기고, 결과는 레코드 순서대로 출력한다.
+ * 절기 캐시는 스레드마다 따로 두므로 잠금 없이 공유 문제가 생기지 않는다.
+ * 실패한 레코드는 오류를 알리고 건너뛰며, 하나라도 실패하면 종료 코드 1로 끝낸다.
+ * 옵션까지 같은 레코드(쌍둥이, 중복 레코드)는 처음 것만 계산하고 그 결과를 다시 출력한다.
+ *
+ * @returns 작업자 스레드에서 측정한 --timings 합계 (스레드를 쓰지 않았거나 측정하지 않으면 null)
+ */
+async function runBatch(opts: CliOptions, path: string, out: OutputSink): Promise<timings.TimingReport | null> {
+  if (opts.svgTimeline != null) throw new Error('--svg-timeline cannot be used with --batch');
+  if (opts.svgOut != null) throw new Error('--svg-out cannot be used with --batch');
+  const jobs = parseIntegerOption(opts.jobs, '--jobs');
+  if (jobs < 1) throw new Error('--jobs must be >= 1');
+  // 표 뒤에 stats 줄이 붙으면 CSV로 읽을 수 없다
+  if (opts.stats && parseOutputFormat(opts) === 'csv' && isChartSection(opts.sections)) {
+    throw new Error('--stats cannot be used with --sections chart');
+  }
+  let text: string;
+  try {
+    text = readFileSync(path, 'utf8');
+  } catch (err: unknown) {
+    const message = err instanceof Error ? err.message : String(err);
+    throw new Error(`failed to read --batch ${path}: ${message}`);
+  }
+  const records = parseBatchFile(text);
+  const isText = parseOutputFormat(opts) === 'text';
+  // --format csv --sections chart: 머리글을 한 번만 쓰고 레코드마다 한 행 (한 사람 한 행의 표)
+  const chartCsv = parseOutputFormat(opts) === 'csv' && isChartSection(opts.sections);
+  if (chartCsv) out.line(['record', 'id', ...CHART_CSV_HEADER].join(','));
+  const samples: ChartSample[] = [];
+  const progress = new Progress('batch', records.length, parseProgressMode(opts.progress));
+  // 레코드마다 같은 입력의 첫 레코드 번호 (그 결과를 재사용한다)
+  const firstOf = new Map<string, number>();
+  const sources = records.map((record, i) => {
+    const key = batchRecordKey({ ...opts, ...record.overrides });
+    if (!firstOf.has(key)) firstOf.set(key, i);
+    return firstOf.get(key)!;
+  });
+  const unique = sources.filter((source, i) => source === i);
+  batchReuse.misses += unique.length;
+  batchReuse.hits += records.length - unique.length;
+
+  let failed = 0;
+  const print = (index: number, outcome: BatchOutcome) => {
+    const id = records[index].id;
+    const label = `record ${index + 1}${id != null ? ` (${id})` : ''}`;
+    progress.clear();
+    if ('error' in outcome) {
+      failed++;
+      console.error(`error: ${label}: ${outcome.error}`);
+    } else if (chartCsv) {
+      const prefix = [index + 1, id ?? ''].map(csvField).join(',');
+      for (const row of outcome.lines.slice(1)) out.line(`${prefix},${row}`);
+      if (outcome.sample) samples.push(outcome.sample);
+    } else {
+      out.line(isText ? `── ${label} ──` : `# ${label}`);
+      for (const line of outcome.lines) out.line(line);
+      if (isText) out.line('');
+      if (outcome.sample) samples.push(outcome.sample);
+    }
+    progress.tick();
+  };
+
+  let report: timings.TimingReport | null = null;
+  // 뒤에 같은 입력 레코드가 있는 결과, 또는 앞 레코드보다 먼저 끝나 출력을 기다리는 결과
+  const reused = new Set(sources.filter((source, i) => source !== i));
+  const outcomes = new Map<number, BatchOutcome>();
+  if (jobs === 1 || unique.length <= 1) {
+    records.forEach((record, i) => {
+      const outcome = sources[i] === i ? renderBatchRecord(opts, record, i) : outcomes.get(sources[i])!;
+      if (reused.has(i)) outcomes.set(i, outcome);
+      print(i, outcome);
+    });
+  } else {
+    let next = 0;
+    report = await runBatchWorkers(opts, records, unique, jobs, (outcome) => {
+      outcomes.set(outcome.index, outcome);
+      for (let ready = outcomes.get(sources[next]); ready; ready = outcomes.get(sources[next])) {
+        if (!reused.has(next)) outcomes.delete(next);
+        print(next++, ready);
+      }
+    });
+    // 중복 건너뛰기 통계는 이 스레드에서 센다
+    if (report) report = timings.mergeTimingReports(report, timings.timingReport());
+  }
+  progress.finish();
+  if (opts.stats) {
+    const stats = aggregateStats(samples);
+    if (isText) {
+      const i18n = new I18n(opts.lang === 'en' ? 'En' : 'Ko');
+      for (const line of formatStats(stats, i18n)) out.line(line);
+    } else {
+      out.line(`stats: ${JSON.stringify(versioned(statsJson(stats)))}`);
+    }
+  }
+  if (failed > 0) process.exitCode = 1;
+  return report;
+}
+
+function parseProgressMode(raw: string): ProgressMode {
+  return parseChoiceOption(raw, '--progress', PROGRESS_MODES);
+}
+
+function parseColorMode(raw: string): ColorMode {
+  return parseChoiceOption(raw, '--color', COLOR_MODES);
+}
+
+/** --color를 출력 환경에 맞춰 정한다 (auto: 표준 출력이 터미널이고 --output·NO_COLOR가 없을 때) */
+function resolveColor(opts: CliOptions): boolean {
+  return shouldColor(parseColorMode(opts.color), {
+    isTTY: process.stdout.isTTY === true,
+    toFile: opts.output != null,
+    noColor: process.env.NO_COLOR,
+  });
+}
+
+/** 레코드 하나를 계산해 출력할 줄 또는 오류 메시지를 만든다 */
+function renderBatchRecord(opts: CliOptions, record: BatchRecord, index: number): BatchOutcome {
+  try {
+    // 윤달 후보를 모두 출력하면 첫 명식만 집계한다
+    let sample: ChartSample | null = null;
+    const lines = chartLines({ ...opts, ...record.overrides }, (result) => {
+      if (opts.stats) sample ??= chartSample(result);
+    });
+    return { index, lines, sample };
+  } catch (err: unknown) {
+    return { index, error: err instanceof Error ? err.message : String(err) };
+  }
+}
+
+/** 작업자 스레드에 넘기는 데이터 */
+interface BatchWorkerData {
+  opts: CliOptions;
+  records: { index: number; record: BatchRecord }[];
+}
+
+/** 작업자 스레드가 보내는 메시지: 레코드 결과, 또는 마지막에 --timings 측정값 */
+type BatchWorkerMessage = BatchOutcome | { timings: timings.TimingReport };
+
+/**
+ * 레코드를 작업자 스레드 jobs개에 나눠 계산하고, 결과가 올 때마다 onOutcome을 부른다.
+ * @param indices 계산할 레코드 번호 (중복을 뺀 것)
+ * @returns 작업자별 --timings 측정값의 합 (측정하지 않으면 null)
+ */
+function runBatchWorkers(
+  opts: CliOptions,
+  records: BatchRecord[],
+  indices: number[],
+  jobs: number,
+  onOutcome: (outcome: BatchOutcome) => void,
+): Promise<timings.TimingReport | null> {
+  const workers = splitBatch(indices.length, jobs).map((chunk) => new Promise<timings.TimingReport | null>((resolve, reject) => {
+    const data: BatchWorkerData = { opts, records: chunk.map((k) => ({ index: indices[k], record: records[indices[k]] })) };
+    const worker = new Worker(new URL(import.meta.url), { workerData: data });
+    let report: timings.TimingReport | null = null;
+    worker.on('message', (message: BatchWorkerMessage) => {
+      if ('timings' in message) report = message.timings;
+      else onOutcome(message);
+    });
+    worker.on('error', reject);
+    worker.on('exit', (code) => (code === 0 ? resolve(report) : reject(new Error(`batch worker exited with code ${code}`))));
+  }));
+  return Promise.all(workers).then((reports) => reports.reduce<timings.TimingReport | null>(
+    (sum, r) => (sum && r ? timings.mergeTimingReports(sum, r) : sum ?? r),
+    null,
+  ));
 }
 
-function run(opts: CliOptions): void {
+/** --jobs 작업자 스레드 진입점: 맡은 레코드를 차례로 계산해 부모 스레드로 보낸다 */
+function runBatchWorker(): void {
+  const { opts, records } = workerData as BatchWorkerData;
+  if (opts.timings) timings.enableTimings();
+  for (const { index, record } of records) {
+    parentPort?.postMessage(renderBatchRecord(opts, record, index) satisfies BatchWorkerMessage);
+  }
+  if (opts.timings) parentPort?.postMessage({ timings: timings.timingReport() } satisfies BatchWorkerMessage);
+}
+
+/**
+ * 옵션 하나로 명식을 계산해 출력할 줄을 만든다 (윤달 모호성·용어 풀이·줄바꿈 포함)
+ * @param onResult 계산한 결과마다 부른다 (윤달 후보를 모두 출력하면 여러 번, --pillars-only면 부르지 않음)
+ */
+function chartLines(rawOpts: CliOptions, onResult?: (result: SajuResult) => void): string[] {
+  const datedOpts = applyDatetime(rawOpts);
+  if (datedOpts.date == null) throw new Error("required option '--date <YYYY-MM-DD>' not specified");
+  const cliOpts = { ...datedOpts, date: resolveRelativeDate(datedOpts.date, datedOpts.now, datedOpts.tz) };
+  if (cliOpts.time == null) throw new Error("required option '--time <HH:MM>' not specified");
+  const opts = applyDateTextFlags(cliOpts, cliOpts.date);
+  checkSupportedYear(cliOpts.date, opts.calendar, opts.force);
+  const chart: ChartInput = { ...opts, date: cliOpts.date, time: cliOpts.time };
   const lang: Lang = opts.lang === 'en' ? 'En' : 'Ko';
-  const i18n = new I18n(lang);
+  const i18n = new I18n(lang, parseAgeConvention(opts.ageConvention), parseRomanization(opts.romanization));
+  const data: ReferenceData = {
+    ilju: opts.iljuData ? ilju.mergeIljuData(readJsonFile(opts.iljuData, '--ilju-data')) : ilju.ILJU_DATA,
+    gaeun: opts.gaeunData ? gaeun.mergeGaeunData(readJsonFile(opts.gaeunData, '--gaeun-data')) : gaeun.GAEUN_DATA,
+    health: opts.healthData
+      ? health.mergeHealthData(readJsonFile(opts.healthData, '--health-data'))
+      : opts.health ? health.HEALTH_DATA : null,
+    shinsal: opts.shinsalData
+      ? shinsalNotes.mergeShinsalCatalog(readJsonFile(opts.shinsalData, '--shinsal-data'))
+      : opts.shinsalNotes ? shinsalNotes.SHINSAL_CATALOG : null,
+  };
+
+  let lines: string[];
+  const candidates = opts.leapMonth == null ? detectLeapAmbiguity(chart) : null;
+  if (candidates) {
+    if (opts.tryBoth && opts.svgTimeline != null) {
+      throw new Error('--svg-timeline cannot be used with --try-both');
+    }
+    if (opts.tryBoth && opts.svgOut != null) {
+      throw new Error('--svg-out cannot be used with --try-both');
+    }
+    if (opts.tryBoth && parseOutputFormat(opts) === 'csv' && isChartSection(opts.sections)) {
+      throw new Error('--sections chart cannot be used with --try-both');
+    }
+    if (!opts.tryBoth) {
+      throw new Error(
+        `lunar ${chart.date} is ambiguous: regular month = solar ${formatYmd(candidates.regular)}, `
+        + `leap month = solar ${formatYmd(candidates.leap)}; `
+        + 'pass --leap-month or --no-leap-month (or --try-both to print both)',
+      );
+    }
+    lines = [];
+    for (const leapMonth of [false, true]) {
+      if (leapMonth) lines.push('');
+      const solar = leapMonth ? candidates.leap : candidates.regular;
+      lines.push(i18n.leapCandidateHeading(leapMonth, formatYmd(solar)));
+      lines.push(...renderChart(chart, leapMonth, i18n, data, onResult));
+    }
+  } else {
+    lines = renderChart(chart, opts.leapMonth ?? false, i18n, data, onResult);
+  }
 
-  const gender = parseGender(opts.gender);
+  const isText = parseOutputFormat(opts) === 'text';
+  if (isText && opts.glossary) {
+    const terms = glossary.usedGlossaryTerms(lines, i18n);
+    if (terms.length > 0) {
+      lines.push(i18n.glossaryHeading());
+      for (const t of terms) lines.push(`- ${t.term}: ${t.definition}`);
+    }
+  }
+  if (!isText) return lines;
+  const colored = parseColorMode(opts.color) === 'always' ? colorizeLines(lines, i18n) : lines;
+  return fitToWidth(colored, { width: resolveWidth(opts), compact: opts.compact });
+}
+
+/** --pillars-only: 운·분석 없이 4기둥만 계산해 출력한다 */
+function renderPillarsOnly(opts: ChartInput, calendar: CalendarType, leapMonth: boolean, useLmt: boolean, i18n: I18n): string[] {
+  if (opts.svgTimeline != null) throw new Error('--svg-timeline cannot be used with --pillars-only');
+  const result = calculatePillars({
+    date: opts.date,
+    time: opts.time,
+    calendar,
+    leapMonth,
+    tz: opts.tz,
+    useLmt,
+    longitude: parseOptionalNumberOption(opts.longitude, '--longitude'),
+    location: opts.location ?? null,
+    lmtBoundary: parseLmtBoundary(opts.lmtBoundary),
+    dayBoundary: resolveDayBoundary(opts, resolveSchool(opts)),
+    hemisphere: parseHemisphere(opts.hemisphere),
+    historicalCalendar: parseHistoricalCalendar(opts.historicalCalendar),
+    force: opts.force,
+  });
+  if (opts.svgOut != null) {
+    writeFileSync(opts.svgOut, render.renderChartSvg(result, { i18n }));
+  }
+  const pillars = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar].map(ilju.iljuKey);
+  const format = parseOutputFormat(opts);
+  if (format === 'csv') {
+    if (isChartSection(opts.sections)) throw new Error('--sections chart cannot be used with --pillars-only');
+    return ['year,month,day,hour', pillars.join(',')];
+  }
+  if (format === 'llm') {
+    const [year, month, day, hour] = pillars;
+    return [
+      `schema_version: ${SCHEMA_VERSION}`,
+      `pillars: ${JSON.stringify({
+        year, month, day, hour, approximate: result.approximate, hemisphere: result.hemisphere === 'South' ? 'south' : 'north',
+      })}`,
+      `warnings: ${JSON.stringify(result.warnings.map(warningData))}`,
+    ];
+  }
+  if (format === 'json') {
+    return [JSON.stringify(pillarsJson(result))];
+  }
+  if (format === 'markdown') {
+    return timings.timed('Render', () => renderPillarsMarkdown(result, i18n));
+  }
+  if (format === 'hanja') {
+    if (opts.showStrength) throw new Error('--show-strength cannot be used with --pillars-only');
+    return [renderHanjaLine(result, null, i18n)];
+  }
+  if (format === 'oneline') {
+    return [i18n.onelinePillars([result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar])];
+  }
+  return timings.timed('Render', () => renderPillarsReport(result, i18n));
+}
+
+/**
+ * --datetime을 --date/--time/--tz로 풀어 옵션에 반영한다.
+ * 오프셋이 붙어 있으면 그 오프셋을 시간대로 쓰고, 없으면 --tz를 그대로 쓴다.
+ */
+function applyDatetime(opts: CliOptions): CliOptions {
+  if (opts.datetime == null) return opts;
+  if (opts.date != null || opts.time != null) throw new Error('--datetime cannot be used with --date/--time');
+  const parsed = input.parseIsoDateTime(opts.datetime);
+  if (!parsed) throw new Error('--datetime must be ISO-8601 like 1990-03-05T06:30:00+09:00');
+  return { ...opts, date: parsed.date, time: parsed.time, tz: parsed.tz ?? opts.tz };
+}
+
+/**
+ * 상대 날짜('today', 'yesterday', '+3d', '-2w')를 양력 YYYY-MM-DD로 바꾼다.
+ * 기준일은 --now(없으면 지금)를 tz 시간대로 본 날짜이며, --now에 오프셋이 없으면 그 날짜를 그대로 쓴다.
+ * 상대 날짜가 아니면 입력을 그대로 돌려준다.
+ */
+function resolveRelativeDate(raw: string, now: string | undefined, tz: string): string {
+  let today: string;
+  if (now == null) {
+    today = timezone.toLocal(timezone.parseTimezone(tz), new Date()).format('YYYY-MM-DD');
+  } else if (/^\d{4}-\d{2}-\d{2}$/.test(now.trim())) {
+    today = now.trim();
+  } else {
+    const parsed = input.parseIsoDateTime(now);
+    if (!parsed) throw new Error('--now must be ISO-8601 like 2025-10-03T09:00:00+09:00 or a date');
+    const instant = parsed.tz == null ? null : timezone.localize(timezone.parseTimezone(parsed.tz), parsed.date, parsed.time);
+    today = instant == null ? parsed.date : timezone.toLocal(timezone.parseTimezone(tz), instant).format('YYYY-MM-DD');
+  }
+  return input.resolveRelativeDate(raw, today) ?? raw;
+}
+
+/**
+ * --date 문구에 밝힌 역법·윤달('음력 1990년 윤5월 3일')을 옵션에 반영한다.
+ * --no-leap-month나 --calendar solar와 어긋나면 오류로 알린다.
+ */
+function applyDateTextFlags(opts: CliOptions, date: string): CliOptions {
+  const parsed = input.parseDateInput(date);
+  let result = opts;
+  if (parsed.calendar === 'Lunar') {
+    result = { ...result, calendar: 'lunar' };
+  } else if (parsed.calendar === 'Solar' && opts.calendar === 'lunar') {
+    throw new Error(`--date ${date} says 양력 but --calendar is lunar`);
+  }
+  if (parsed.leapMonth) {
+    if (opts.leapMonth === false) throw new Error(`--date ${date} names a leap month but --no-leap-month was given`);
+    result = { ...result, leapMonth: true };
+  }
+  return result;
+}
+
+/**
+ * 입력 연도가 지원 범위 안인지 계산 전에 확인한다.
+ * 양력은 --force로 건너뛰어 근사 결과를 낼 수 있고, 음력은 음력 표 밖이면 항상 거부한다.
+ * 날짜 형식 오류는 calculate()의 검증에 맡긴다.
+ */
+function checkSupportedYear(raw: string, calendarRaw: string, force: boolean): void {
+  const date = input.normalizeDate(raw);
+  if (!/^\d{4}-/.test(date)) return;
+  const year = Number(date.slice(0, 4));
+  const calendar: CalendarType = calendarRaw === 'lunar' ? 'Lunar' : 'Solar';
+  const [min, max] = SUPPORTED_YEAR_RANGES[calendar];
+  if ((year >= min && year <= max) || (calendar === 'Solar' && force)) return;
+  const { Solar: solar, Lunar: lunarRange } = SUPPORTED_YEAR_RANGES;
+  const ranges = `solar ${solar[0]}-${solar[1]} (solar terms), lunar ${lunarRange[0]}-${lunarRange[1]} (lunar table)`;
+  throw new Error(calendar === 'Solar'
+    ? `year ${year} is outside the supported ranges: ${ranges}; pass --force for an approximate chart`
+    : `lunar year ${year} is outside the supported ranges: ${ranges}; --force cannot extend the lunar table`);
+}
+
+/** 출력 형식 */
+type OutputFormat = 'text' | 'csv' | 'llm' | 'json' | 'markdown' | 'oneline' | 'hanja';
+
+function parseOutputFormat(opts: CliOptions): OutputFormat {
+  return parseChoiceOption<OutputFormat>(opts.format, '--format', ['text', 'csv', 'llm', 'json', 'markdown', 'oneline', 'hanja']);
+}
+
+/** --name / --name-hanja / --name-strokes로 이름을 분석한다 (--name이 없으면 null) */
+function resolveName(opts: CliOptions): naming.NameAnalysis | null {
+  if (opts.name == null) {
+    if (opts.nameHanja != null || opts.nameStrokes != null) throw new Error('--name-hanja/--name-strokes require --name');
+    return null;
+  }
+  const strokes = opts.nameStrokes?.split(',').map((p) => parseIntegerOption(p.trim(), '--name-strokes')) ?? null;
+  return naming.analyzeName({ hangul: opts.name, hanja: opts.nameHanja ?? null, strokes });
+}
+
+/** --school 값을 학파 설정으로 변환한다 (없으면 null) */
+function resolveSchool(opts: CliOptions): Readonly<school.SchoolSettings> | null {
+  if (opts.school == null) return null;
+  return school.SCHOOL_PRESETS[parseChoiceOption(opts.school, '--school', school.SCHOOL_NAMES)];
+}
+
+/** --day-boundary, 없으면 학파 설정, 그것도 없으면 23시 자시 경계 */
+function resolveDayBoundary(opts: CliOptions, preset: Readonly<school.SchoolSettings> | null): DayBoundary {
+  if (opts.dayBoundary == null) return preset?.dayBoundary ?? 'Jasi';
+  return parseChoiceOption(opts.dayBoundary, '--day-boundary', ['jasi', 'midnight']) === 'midnight' ? 'Midnight' : 'Jasi';
+}
+
+/** --shinsal-base, 없으면 학파 설정, 그것도 없으면 연지 기준 */
+function resolveShinsalBase(opts: CliOptions, preset: Readonly<school.SchoolSettings> | null): ShinsalBase {
+  if (opts.shinsalBase == null) return preset?.shinsalBase ?? 'Year';
+  return parseChoiceOption(opts.shinsalBase, '--shinsal-base', ['year', 'day']) === 'day' ? 'Day' : 'Year';
+}
+
+/** --month-scoring / --strength-thresholds로 강약 판정 설정을 만든다 (--month-scoring이 없으면 학파 설정) */
+function resolveStrengthProfile(opts: CliOptions, preset: Readonly<school.SchoolSettings> | null): strength.StrengthProfile {
+  const scoring = opts.monthScoring == null
+    ? preset?.monthScoring ?? strength.DEFAULT_STRENGTH_PROFILE.monthScoring
+    : parseChoiceOption(opts.monthScoring, '--month-scoring', ['stage', 'matrix']) === 'matrix' ? 'Matrix' : 'Stage';
+  return {
+    ...strength.DEFAULT_STRENGTH_PROFILE,
+    monthScoring: scoring,
+    thresholds: opts.strengthThresholds == null
+      ? strength.DEFAULT_STRENGTH_PROFILE.thresholds
+      : parseStrengthThresholds(opts.strengthThresholds),
+  };
+}
+
+/** --strength-thresholds 'veryWeak,weak,strong,veryStrong' 값을 임계값으로 변환한다 */
+function parseStrengthThresholds(raw: string): strength.StrengthThresholds {
+  const parts = raw.split(',');
+  if (parts.length !== 4) throw new Error('--strength-thresholds must be four numbers: veryWeak,weak,strong,veryStrong');
+  const [veryWeak, weak, strong, veryStrong] = parts.map((p) => parseNumberOption(p.trim(), '--strength-thresholds'));
+  if (!(veryWeak < weak && weak < strong && strong < veryStrong)) {
+    throw new Error('--strength-thresholds must be in increasing order');
+  }
+  return { veryStrong, strong, weak, veryWeak };
+}
+
+/** --daewon-rounding 값을 반올림 규칙으로 변환한다 */
+function parseDaewonRounding(raw: string): DaewonRounding {
+  const map: Record<string, DaewonRounding> = { round: 'Round', floor: 'Floor', ceil: 'Ceil', traditional: 'Traditional' };
+  return map[parseChoiceOption(raw, '--daewon-rounding', Object.keys(map))];
+}
+
+/** --daewon-direction 값을 대운 방향 결정 방식으로 변환한다 */
+function parseDaewonDirection(raw: string): DaewonDirectionMode {
+  const map: Record<string, DaewonDirectionMode> = { auto: 'Auto', forward: 'Forward', backward: 'Backward' };
+  return map[parseChoiceOption(raw, '--daewon-direction', Object.keys(map))];
+}
+
+/** --daewon-year 값을 방향 판정 연간의 연 경계로 변환한다 */
+function parseDaewonYearBasis(raw: string): DaewonYearBasis {
+  return parseChoiceOption(raw, '--daewon-year', ['lichun', 'lunar-new-year']) === 'lichun' ? 'Lichun' : 'LunarNewYear';
+}
+
+/** --hemisphere 값을 출생 반구로 변환한다 */
+function parseHemisphere(raw: string): Hemisphere {
+  return parseChoiceOption(raw, '--hemisphere', ['north', 'south']) === 'south' ? 'South' : 'North';
+}
+
+/** --historical-calendar 값을 양력 입력의 역법 해석으로 변환한다 */
+function parseHistoricalCalendar(raw: string): historical.HistoricalCalendar {
+  const map: Record<string, historical.HistoricalCalendar> = { gregorian: 'Gregorian', julian: 'Julian', auto: 'Auto' };
+  return map[parseChoiceOption(raw, '--historical-calendar', Object.keys(map))];
+}
+
+/** --lmt-boundary 값을 경계 비교 기준으로 변환한다 */
+function parseLmtBoundary(raw: string): LmtBoundary {
+  return parseChoiceOption(raw, '--lmt-boundary', ['corrected', 'clock']) === 'clock' ? 'Clock' : 'Corrected';
+}
+
+/** --age-convention 값을 나이 표기 관례로 변환한다 */
+function parseAgeConvention(raw: string): AgeConvention {
+  return parseChoiceOption(raw, '--age-convention', ['man', 'korean']) === 'korean' ? 'Korean' : 'Man';
+}
+
+/** --romanization 값을 영문 천간·지지 표기로 변환한다 */
+function parseRomanization(raw: string): Romanization {
+  return parseChoiceOption(raw, '--romanization', ['korean', 'pinyin']) === 'pinyin' ? 'Pinyin' : 'Korean';
+}
+
+/** 해설용 참고 데이터 (내장 데이터 또는 외부 파일로 교체한 것) */
+interface ReferenceData {
+  ilju: readonly ilju.IljuEntry[]
+  gaeun: Readonly<gaeun.GaeunTable>
+  /** 건강 섹션을 출력하지 않으면 null */
+  health: Readonly<health.HealthTable> | null
+  /** 신살 풀이를 출력하지 않으면 null */
+  shinsal: Readonly<shinsalNotes.ShinsalCatalog> | null
+}
+
+/** 옵션과 윤달 여부로 요청을 만들어 계산하고 출력 줄을 반환한다 */
+function renderChart(
+  opts: ChartInput,
+  leapMonth: boolean,
+  i18n: I18n,
+  data: ReferenceData,
+  onResult?: (result: SajuResult) => void,
+): string[] {
   const calendar: CalendarType = opts.calendar === 'lunar' ? 'Lunar' : 'Solar';
   const useLmt = opts.localMeanTime || opts.longitude != null || opts.location != null;
+  if (opts.pillarsOnly) {
+    return renderPillarsOnly(opts, calendar, leapMonth, useLmt, i18n);
+  }
+  const gender = resolveGender(opts);
 
+  const yearlyUntilAge = parseOptionalIntegerOption(opts.yearlyUntilAge, '--yearly-until-age');
+  const preset = resolveSchool(opts);
   const req: SajuRequest = {
     date: opts.date,
     time: opts.time,
     calendar,
-    leapMonth: opts.leapMonth,
+    leapMonth,
     gender,
     tz: opts.tz,
     useLmt,
     longitude: parseOptionalNumberOption(opts.longitude, '--longitude'),
     location: opts.location ?? null,
+    lmtBoundary: parseLmtBoundary(opts.lmtBoundary),
+    dayBoundary: resolveDayBoundary(opts, preset),
+    hemisphere: parseHemisphere(opts.hemisphere),
+    historicalCalendar: parseHistoricalCalendar(opts.historicalCalendar),
+    boundaryWindow: parseOptionalIntegerOption(opts.boundaryWindow, '--boundary-window'),
+    shinsalBase: resolveShinsalBase(opts, preset),
     daewonCount: parseIntegerOption(opts.daewonCount, '--daewon-count'),
-    monthYear: parseOptionalIntegerOption(opts.monthYear, '--month-year'),
+    daewonAges: resolveDaewonAges(opts, yearlyUntilAge),
+    daewonRounding: opts.daewonRounding == null ? preset?.daewonRounding ?? 'Round' : parseDaewonRounding(opts.daewonRounding),
+    daewonDirection: parseDaewonDirection(opts.daewonDirection),
+    daewonYearBasis: parseDaewonYearBasis(opts.daewonYear),
+    ...resolveMonthYears(opts),
     yearStart: parseOptionalIntegerOption(opts.yearStart, '--year-start'),
     yearCount: parseIntegerOption(opts.yearCount, '--year-count'),
+    yearlyUntilAge,
+    transform: opts.transform ?? preset?.transform ?? false,
+    strengthProfile: resolveStrengthProfile(opts, preset),
+    force: opts.force,
   };
 
-  const result = calculate(req);
+  const result = useResultCache ? calculateCached(req) : calculate(req);
+  onResult?.(result);
+  if (opts.svgTimeline != null) {
+    writeFileSync(opts.svgTimeline, render.renderLuckTimelineSvg(result, { lang: i18n.lang }));
+  }
+  if (opts.svgOut != null) {
+    writeFileSync(opts.svgOut, render.renderChartSvg(result, { i18n }));
+  }
+  const format = parseOutputFormat(opts);
+  if (format === 'csv') {
+    if (isChartSection(opts.sections)) return timings.timed('Render', () => renderChartCsv(result));
+    return timings.timed('Render', () => renderLuckCsv(result, parseLuckSections(opts.sections)));
+  }
+  if (format === 'llm') {
+    return timings.timed('Render', () => renderLlmExport(result, opts.showJd));
+  }
+  if (format === 'json') {
+    return [timings.timed('Render', () => JSON.stringify(chartJson(result, opts.showJd)))];
+  }
+  if (format === 'markdown') {
+    return timings.timed('Render', () => renderMarkdown(result, i18n, { event: opts.event, asOf: new Date() }));
+  }
+  if (format === 'hanja') {
+    return [renderHanjaLine(result, opts.showStrength ? result.strength : null, i18n)];
+  }
+  if (format === 'oneline') {
+    return [timings.timed('Render', () => renderOneLine(result, i18n))];
+  }
+  return timings.timed('Render', () => renderReport(result, i18n, {
+    layout: parseChoiceOption<Layout>(opts.layout, '--layout', ['list', 'vertical', 'card']),
+    compact: opts.compact,
+    event: opts.event,
+    showTerms: opts.showTerms,
+    groupYearly: yearlyUntilAge != null,
+    era: opts.era,
+    sunSign: opts.sunSign,
+    showJd: opts.showJd,
+    asOf: new Date(),
+    iljuDataset: data.ilju,
+    gaeunTable: data.gaeun,
+    healthTable: data.health,
+    shinsalCatalog: data.shinsal,
+    name: resolveName(opts),
+    school: opts.school == null ? null : parseChoiceOption(opts.school, '--school', school.SCHOOL_NAMES),
+  }));
+}
+
+/**
+ * 윤달 여부 없이 입력된 음력 날짜가 평달/윤달 양쪽에 해당하는지 검사한다.
+ * 날짜 형식·범위 오류는 여기서 판단하지 않고 계산 단계의 검증에 맡긴다.
+ */
+function detectLeapAmbiguity(opts: ChartInput): lunar.LeapCandidates | null {
+  if (opts.calendar !== 'lunar') return null;
+  const m = /^(\d{4})-(\d{2})-(\d{2})$/.exec(input.normalizeDate(opts.date));
+  if (!m) return null;
+  try {
+    return lunar.leapCandidates(Number(m[1]), Number(m[2]), Number(m[3]));
+  } catch {
+    return null;
+  }
+}
 
-  printHeader(result, i18n);
-  printPillars(result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printHiddenStems(result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printTenGods(result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printTwelveStages(result.dayPillar.stem, result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printTwelveShinsal(result.yearPillar.branch, result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printStrength(result.strength, i18n);
-  printElements(result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
-  printDaewon(result.daewonDirection, result.daewonStartMonths, result.daewonItems, result.dayPillar.stem, i18n);
-  printYearlyLuck(result.yearlyLuck, result.dayPillar.stem, result.tzSpec, i18n);
-  printMonthlyLuck(result.monthlyLuck, result.dayPillar.stem, result.tzSpec, i18n);
+/** UTC Date를 YYYY-MM-DD 문자열로 변환한다 */
+function formatYmd(d: Date): string {
+  return d.toISOString().slice(0, 10);
+}
 
-  if (opts.showTerms) {
-    printTerms(result.tzSpec, result.solarTerms, i18n);
+/** --gender/--event 조합을 검증하고 성별을 결정한다 (이벤트 차트는 null) */
+function resolveGender(opts: CliOptions): Gender | null {
+  if (opts.event) {
+    if (opts.gender != null) throw new Error('--gender cannot be used with --event');
+    return null;
   }
+  if (opts.gender == null) throw new Error('--gender is required (or use --event for a moment chart)');
+  return parseGender(opts.gender);
 }
 
-/** 성별 문자열을 Gender 타입으로 파싱한다 */
-function parseGender(input: string): Gender {
+/** 성별 문자열을 Gender 타입으로 파싱한다 (미상이면 null) */
+function parseGender(input: string): Gender | null {
   switch (input.toLowerCase()) {
     case 'male': case 'm': case '남': return 'Male';
     case 'female': case 'f': case '여': return 'Female';
-    default: throw new Error('gender must be male|female|m|f|남|여');
+    case 'unknown': case 'u': case '미상': return null;
+    default: throw new Error('gender must be male|female|unknown|m|f|u|남|여|미상');
   }
 }
 
-/** 보정 초를 '±00m00s' 형식으로 포맷한다 */
-function formatCorrection(seconds: number): string {
-  const sign = seconds >= 0 ? '+' : '-';
-  const abs = Math.abs(seconds);
-  const mins = Math.floor(abs / 60);
-  const secs = abs % 60;
-  return `${sign}${String(mins).padStart(2, '0')}m${String(secs).padStart(2, '0')}s`;
+/** --month-year / --month-years를 월운 시작 연도와 연도 수로 변환한다 */
+function resolveMonthYears(opts: CliOptions): { monthYear: number | null; monthYearCount: number } {
+  if (opts.monthYears == null) {
+    return { monthYear: parseOptionalIntegerOption(opts.monthYear, '--month-year'), monthYearCount: 1 };
+  }
+  if (opts.monthYear != null) throw new Error('--month-year cannot be used with --month-years');
+  const [from, to] = parseRangeOption(opts.monthYears, '--month-years');
+  return { monthYear: from, monthYearCount: to - from + 1 };
 }
 
-/** 지장간을 '갑(甲), 병(丙), 무(戊)' 형식으로 포맷한다 */
-function formatHiddenStems(i18n: I18n, branch: number): string {
-  return bazi.hiddenStems(branch).map((stem) => i18n.stemLabel(stem)).join(', ');
+/**
+ * --daewon-until-age / --daewon-ages를 대운 나이 범위로 변환한다.
+ * 평생 세운(--yearly-until-age)만 지정하면 같은 나이까지의 대운을 함께 산출한다.
+ */
+function resolveDaewonAges(opts: CliOptions, yearlyUntilAge: number | null): DaewonAgeRange | null {
+  if (opts.daewonUntilAge != null && opts.daewonAges != null) {
+    throw new Error('--daewon-until-age cannot be used with --daewon-ages');
+  }
+  if (opts.daewonUntilAge != null) {
+    return { fromAge: 0, toAge: parseIntegerOption(opts.daewonUntilAge, '--daewon-until-age') };
+  }
+  if (opts.daewonAges != null) {
+    const [fromAge, toAge] = parseRangeOption(opts.daewonAges, '--daewon-ages');
+    return { fromAge, toAge };
+  }
+  return yearlyUntilAge != null ? { fromAge: 0, toAge: yearlyUntilAge } : null;
 }
 
-/** 지장간을 십성과 함께 포맷한다 */
-function formatHiddenStemsWithTengod(i18n: I18n, dayStem: number, branch: number): string {
-  return bazi.hiddenStems(branch)
-    .map((stem) => `${i18n.stemLabel(stem)} ${i18n.tenGodLabel(bazi.tenGod(dayStem, stem))}`)
-    .join(', ');
+/**
+ * 출력 폭을 결정한다.
+ * --width가 우선이며, --compact만 지정하면 터미널 폭(없거나 --output 파일이면 80)을 사용한다.
+ */
+function resolveWidth(opts: CliOptions): number | null {
+  const width = parseOptionalIntegerOption(opts.width, '--width');
+  if (width != null) {
+    if (width < MIN_WIDTH) throw new Error(`--width must be >= ${MIN_WIDTH}`);
+    return width;
+  }
+  if (!opts.compact) return null;
+  return opts.output == null ? (process.stdout.columns || 80) : 80;
 }
 
-/** 입력 정보 및 보정 내역을 출력한다 */
-function printHeader(result: SajuResult, i18n: I18n): void {
-  console.log(i18n.title());
-  console.log(`- ${i18n.inputLabel()}(${i18n.calendarLabel(result.calendarIsLunar, result.leapMonth)}): ${result.inputDate} ${result.inputTime} ${result.tzName}`);
-  if (result.convertedSolar) {
-    console.log(`- ${i18n.convertedSolarLabel()}: ${result.convertedSolar} ${result.inputTime} ${result.tzName}`);
+/** 참고 데이터 파일(JSON)을 읽어 파싱한다 */
+function readJsonFile(path: string, optionName: string): unknown {
+  try {
+    return JSON.parse(readFileSync(path, 'utf8'));
+  } catch (err: unknown) {
+    const message = err instanceof Error ? err.message : String(err);
+    throw new Error(`failed to read ${optionName} ${path}: ${message}`);
   }
-  if (result.convertedLunar) {
-    const l = result.convertedLunar;
-    const suffix = l.isLeap ? i18n.leapSuffix() : '';
-    console.log(`- ${i18n.convertedLunarLabel()}: ${String(l.year).padStart(4, '0')}-${String(l.month).padStart(2, '0')}-${String(l.day).padStart(2, '0')}${suffix}`);
+}
+
+/** convert 서브커맨드 옵션 타입 */
+interface ConvertOptions {
+  to: string
+  tz: string
+  now?: string
+  leap: boolean
+  json: boolean
+  lang: string
+}
+
+/** 양력 ↔ 음력 변환 결과를 출력한다 */
+function runConvert(date: string, opts: ConvertOptions): void {
+  const to = parseChoiceOption<ConvertTarget>(opts.to, '--to', ['lunar', 'solar']);
+  const resolved = resolveRelativeDate(date, opts.now, opts.tz);
+  if (resolved !== date && to === 'solar') throw new Error(`relative date ${date} is a solar date; use --to lunar`);
+  const result = convertDate(resolved, to, opts.leap);
+  if (opts.json) {
+    console.log(JSON.stringify(versioned(result), null, 2));
+    return;
   }
-  if (result.lmtInfo) {
-    const info = result.lmtInfo;
-    if (info.locationLabel) {
-      console.log(`- ${i18n.localMeanTimeLabel()}: ${i18n.locationLabel()} ${info.locationLabel} | ${i18n.longitudeLabel()} ${info.longitude.toFixed(4)}deg | ${i18n.stdMeridianLabel()} ${info.stdMeridian.toFixed(1)}deg | ${i18n.correctionLabel()} ${formatCorrection(info.correctionSeconds)}`);
-    } else {
-      console.log(`- ${i18n.localMeanTimeLabel()}: ${i18n.longitudeLabel()} ${info.longitude.toFixed(4)}deg | ${i18n.stdMeridianLabel()} ${info.stdMeridian.toFixed(1)}deg | ${i18n.correctionLabel()} ${formatCorrection(info.correctionSeconds)}`);
-    }
-    console.log(`- ${i18n.correctedTimeLabel()}: ${info.correctedLocal} ${result.tzName}`);
-  }
-  console.log(`- ${i18n.genderLabel()}: ${i18n.genderValue(result.gender)}`);
-  console.log(`- ${i18n.dayBoundaryLabel()}: 23:00`);
-  console.log();
-}
+  console.log(formatConversion(result, to, new I18n(opts.lang === 'en' ? 'En' : 'Ko')));
+}
 
-/** 사주 네 기둥을 출력한다 */
-function printPillars(year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
-  const pillars = [year, month, day, hour];
-  console.log(i18n.pillarsHeading());
-  for (let idx = 0; idx < 4; idx++) {
-    const k = kinds[idx];
-    const p = pillars[idx];
-    const stemW = idx === 2 ? i18n.dayStemWord() : i18n.stemWord();
-    console.log(`- ${i18n.pillarKindLabel(k)}: ${i18n.pillarLabel(p)} | ${stemW}: ${i18n.elementLabel(bazi.stemElement(p.stem))} ${i18n.polarityLabel(bazi.stemPolarity(p.stem))} | ${i18n.branchWord()}: ${i18n.elementLabel(bazi.branchElement(p.branch))} ${i18n.polarityLabel(bazi.branchPolarity(p.branch))}`);
-  }
-  console.log();
+/** ganji 서브커맨드 옵션 타입 */
+interface GanjiOptions {
+  time?: string
+  tz: string
+  now?: string
+  find?: string
+  kind: string
+  from: string
+  to: string
+  json: boolean
+  lang: string
 }
 
-/** 지장간을 출력한다 */
-function printHiddenStems(year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
-  const pillars = [year, month, day, hour];
-  console.log(i18n.hiddenStemsHeading());
-  for (let idx = 0; idx < 4; idx++) {
-    console.log(`- ${i18n.branchKindLabel(kinds[idx])}: ${formatHiddenStems(i18n, pillars[idx].branch)}`);
-  }
-  console.log();
-}
-
-/** 십성(천간·지지·지장간)을 출력한다 */
-function printTenGods(year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
-  const pillars = [year, month, day, hour];
-  const ds = day.stem;
-  console.log(i18n.tenGodsHeading());
-  console.log(`- ${i18n.stemsLabel()}: ${kinds.map((k, i) => `${i18n.stemKindLabel(k)} ${i18n.tenGodLabel(bazi.tenGod(ds, pillars[i].stem))}`).join(' / ')}`);
-  console.log(`- ${i18n.branchesMainLabel()}: ${kinds.map((k, i) => `${i18n.branchKindLabel(k)} ${i18n.tenGodLabel(bazi.tenGodBranch(ds, pillars[i].branch))}`).join(' / ')}`);
-  for (let idx = 0; idx < 4; idx++) {
-    console.log(`- ${i18n.branchesHiddenLabel(kinds[idx])}: ${formatHiddenStemsWithTengod(i18n, ds, pillars[idx].branch)}`);
-  }
-  console.log();
-}
-
-/** 12운성을 출력한다 */
-function printTwelveStages(dayStem: number, year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
-  const pillars = [year, month, day, hour];
-  console.log(i18n.twelveStagesHeading());
-  console.log(`- ${kinds.map((k, i) => `${i18n.branchKindLabel(k)}: ${i18n.stageLabel(bazi.twelveStageIndex(dayStem, pillars[i].branch))}`).join(' / ')}`);
-  console.log();
-}
-
-/** 12신살을 출력한다 */
-function printTwelveShinsal(yearBranch: number, year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
-  const pillars = [year, month, day, hour];
-  console.log(i18n.twelveShinsalHeading());
-  console.log(`- ${kinds.map((k, i) => `${i18n.branchKindLabel(k)}: ${i18n.shinsalLabel(bazi.twelveShinsalIndex(yearBranch, pillars[i].branch))}`).join(' / ')}`);
-  console.log();
-}
-
-/** 신강/신약 판정 결과를 출력한다 */
-function printStrength(strength: StrengthResult, i18n: I18n): void {
-  const { STAGE_BONUS, STEM_WEIGHT, HIDDEN_WEIGHT } = str.STRENGTH_WEIGHTS;
-  const stageBonus = strength.stageClass === 'Strong' ? STAGE_BONUS : strength.stageClass === 'Weak' ? -STAGE_BONUS : 0;
-  const supportTotal = strength.supportStems * STEM_WEIGHT + strength.supportHidden * HIDDEN_WEIGHT;
-  const drainTotal = strength.drainStems * STEM_WEIGHT + strength.drainHidden * HIDDEN_WEIGHT;
-
-  console.log(i18n.strengthHeading());
-  console.log(`- ${i18n.monthStageLabel()}: ${i18n.stageLabel(strength.stageIndex)} (${i18n.strengthClassLabel(strength.stageClass)})`);
-  console.log(`- ${i18n.rootLabel()}: ${strength.rootCount} / ${i18n.supportLabel()}(${i18n.stemsLabel()} ${strength.supportStems}·${i18n.hiddenStemsHeading()} ${strength.supportHidden}) / ${i18n.drainLabel()}(${i18n.stemsLabel()} ${strength.drainStems}·${i18n.hiddenStemsHeading()} ${strength.drainHidden})`);
-  console.log(`- ${i18n.scoreLabel()}: ${strength.total} (${i18n.basisLabel()} ${i18n.monthStageLabel()} ${stageBonus} + ${i18n.rootLabel()} ${strength.rootCount} + ${i18n.supportLabel()} ${supportTotal} - ${i18n.drainLabel()} ${drainTotal})`);
-  console.log(`- ${i18n.verdictLabel()}: ${i18n.strengthVerdictLabel(strength.verdict)}`);
-  console.log();
-}
-
-/** 오행 분포를 출력한다 */
-function printElements(year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: I18n): void {
-  const counts = bazi.elementsCount([year, month, day, hour]);
-  const elements: Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
-  console.log(i18n.elementsHeading());
-  console.log(`- ${elements.map((el, i) => `${i18n.elementShortLabel(el)} ${counts[i]}`).join(' / ')}`);
-  console.log();
-}
-
-/** 대운을 출력한다 */
-function printDaewon(direction: Direction, startMonths: number, items: luck.DaewonItem[], dayStem: number, i18n: I18n): void {
-  console.log(`${i18n.daewonHeading()} (${i18n.directionLabel(direction)} , ${i18n.startLabel()} ${i18n.formatAge(startMonths, false)})`);
-  for (const item of items) {
-    console.log(`- ${i18n.formatAge(item.startMonths, true)}: ${i18n.pillarLabel(item.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, item.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, item.pillar.branch))}`);
-  }
-  console.log();
-}
-
-/** 세운(연운)을 출력한다 */
-function printYearlyLuck(years: luck.YearLuck[], dayStem: number, tzSpec: TimeZoneSpec, i18n: I18n): void {
-  console.log(i18n.yearlyLuckHeading());
-  for (const y of years) {
-    const startLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(y.startJd));
-    const endLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(y.endJd));
-    console.log(`- ${i18n.formatYearLabel(y.year)}: ${startLocal.format('YYYY-MM-DD HH:mm')} ~ ${endLocal.format('YYYY-MM-DD HH:mm')} | ${i18n.pillarLabel(y.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, y.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, y.pillar.branch))}`);
-  }
-  console.log();
-}
-
-/** 월운을 출력한다 */
-function printMonthlyLuck(monthly: luck.MonthlyLuck, dayStem: number, tzSpec: TimeZoneSpec, i18n: I18n): void {
-  console.log(i18n.monthlyLuckHeading(monthly.year));
-  console.log(`- ${i18n.yearLuckLabel()}: ${i18n.pillarLabel(monthly.yearPillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, monthly.yearPillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, monthly.yearPillar.branch))}`);
-  for (const m of monthly.months) {
-    const startLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(m.startJd));
-    const endLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(m.endJd));
-    console.log(`- ${i18n.monthLabel(m.branch)}: ${startLocal.format('YYYY-MM-DD HH:mm')} ~ ${endLocal.format('YYYY-MM-DD HH:mm')} | ${i18n.pillarLabel(m.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, m.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, m.pillar.branch))}`);
+/** 날짜의 간지를 출력하거나 (--find) 간지가 나타나는 시기를 나열한다 */
+function runGanji(date: string | undefined, opts: GanjiOptions): void {
+  if (opts.find != null) {
+    if (date != null) throw new Error('a date cannot be combined with --find');
+    const pillar = bazi.parsePillarName(opts.find);
+    const kind = parseChoiceOption<GanjiKind>(opts.kind, '--kind', ['year', 'month', 'day']);
+    const from = parseIntegerOption(opts.from, '--from');
+    const to = parseIntegerOption(opts.to, '--to');
+    if (from > to) throw new Error('--from must not be after --to');
+    const found = findOccurrences(pillar, kind, from, to);
+    console.log(opts.json ? JSON.stringify(found) : found.join('\n'));
+    return;
+  }
+  if (date == null) throw new Error('a date or --find is required');
+  const pillars = pillarsOfDate(resolveRelativeDate(date, opts.now, opts.tz), opts.time, opts.tz);
+  if (opts.json) {
+    console.log(JSON.stringify(versioned(datePillarsJson(pillars)), null, 2));
+    return;
   }
-  console.log();
+  const i18n = new I18n(opts.lang === 'en' ? 'En' : 'Ko');
+  for (const line of formatDatePillars(pillars, i18n)) console.log(line);
+}
+
+/** selfcheck 서브커맨드 옵션 타입 */
+interface SelfCheckOptions {
+  years: string
+  json: boolean
+  progress: string
+}
+
+/** 자체 검증을 실행하고 불일치가 있으면 종료 코드 1로 끝낸다 */
+function runSelfCheckCommand(opts: SelfCheckOptions): void {
+  const [from, to] = parseRangeOption(opts.years, '--years');
+  const progress = new Progress('selfcheck', to - from + 1, parseProgressMode(opts.progress));
+  const report = runSelfCheck(from, to, () => progress.tick());
+  progress.finish();
+  console.log(opts.json ? JSON.stringify(versioned(report), null, 2) : formatSelfCheck(report).join('\n'));
+  if (report.issues.length > 0) process.exit(1);
+}
+
+/** 독립 구현과 교차 검증하고 어긋난 날이 있으면 종료 코드 1로 끝낸다 (옵션은 selfcheck와 같다) */
+function runCrossCheckCommand(opts: SelfCheckOptions): void {
+  const [from, to] = parseRangeOption(opts.years, '--years');
+  const progress = new Progress('crosscheck', to - from + 1, parseProgressMode(opts.progress));
+  const report = runCrossCheck(from, to, () => progress.tick());
+  progress.finish();
+  console.log(opts.json ? JSON.stringify(versioned(report), null, 2) : formatCrossCheck(report).join('\n'));
+  if (report.issues.length > 0) process.exit(1);
 }
 
-/** 24절기를 출력한다 */
-function printTerms(tzSpec: TimeZoneSpec, terms: SolarTerm[], i18n: I18n): void {
-  console.log(`${i18n.termsHeading()} (${timezone.tzName(tzSpec)} ${i18n.tzLabel()})`);
-  for (const term of terms) {
-    const utcDate = astro.datetimeFromJd(term.jd);
-    const local = timezone.toLocal(tzSpec, utcDate);
-    console.log(`- ${i18n.termName(term.def)}: ${local.format('YYYY-MM-DD HH:mm:ss')}`);
+/** gen 서브커맨드 옵션 타입 */
+interface GenOptions {
+  count: string
+  from: string
+  to: string
+  seed?: string
+  tz: string
+  progress: string
+}
+
+/** 무작위 명식을 NDJSON으로 출력한다 (시드를 주지 않으면 고른 시드를 stderr에 알린다) */
+function runGenCommand(opts: GenOptions): void {
+  const count = parseIntegerOption(opts.count, '--count');
+  if (count < 1) throw new Error('--count must be at least 1');
+  const from = parseIntegerOption(opts.from, '--from');
+  const to = parseIntegerOption(opts.to, '--to');
+  if (from > to) throw new Error('--from must not be after --to');
+  const [min, max] = SUPPORTED_YEAR_RANGES.Solar;
+  if (from < min || to > max) throw new Error(`--from/--to must be within ${min}..${max}`);
+  let seed: number;
+  if (opts.seed != null) {
+    seed = parseIntegerOption(opts.seed, '--seed');
+  } else {
+    seed = Date.now() >>> 0;
+    console.error(`seed: ${seed}`);
+  }
+  const progress = new Progress('gen', count, parseProgressMode(opts.progress));
+  for (const birth of generateBirths(count, from, to, seededRandom(seed))) {
+    console.log(generatedLine(birth, opts.tz));
+    progress.tick();
+  }
+  progress.finish();
+}
+
+/** verify 서브커맨드 옵션 타입 */
+interface VerifyOptions {
+  data?: string
+  kasi?: string
+  kasiKey?: string
+  kasiCache: string
+  tolerance?: string
+  json: boolean
+  progress: string
+}
+
+/** 기준 자료와 대조하고, 음력 불일치나 허용 오차 초과가 있으면 종료 코드 1로 끝낸다 */
+async function runVerify(opts: VerifyOptions): Promise<void> {
+  const tolerance = parseOptionalNumberOption(opts.tolerance, '--tolerance');
+  if (opts.data != null && opts.kasi != null) throw new Error('--data cannot be combined with --kasi');
+  let data = KASI_SAMPLE;
+  if (opts.data != null) {
+    data = parseReferenceData(readJsonFile(opts.data, '--data'));
+  } else if (opts.kasi != null) {
+    const [from, to] = parseRangeOption(opts.kasi, '--kasi');
+    const serviceKey = opts.kasiKey ?? process.env[KASI_KEY_ENV];
+    if (serviceKey == null) throw new Error(`--kasi needs --kasi-key or the ${KASI_KEY_ENV} environment variable`);
+    const progress = new Progress('kasi', (to - from + 1) * 12, parseProgressMode(opts.progress));
+    data = await new KasiClient({ serviceKey, cacheDir: opts.kasiCache }).referenceData(from, to, () => progress.tick());
+    progress.finish();
   }
-  console.log();
+  const report = verifyReference(data);
+  console.log(opts.json ? JSON.stringify(versioned(verifyJson(report)), null, 2) : formatVerify(report).join('\n'));
+  if (report.lunarMismatches.length > 0 || (tolerance !== null && report.maxTermMinutes > tolerance)) process.exit(1);
+}
+
+/** daemon 서브커맨드 옵션 타입 */
+interface DaemonOptions {
+  socket: string
+}
+
+/**
+ * 소켓을 열고 종료 신호가 올 때까지 질의에 답한다.
+ * 질의마다 `daemon` 앞에 준 옵션에 레코드 값을 덮어써 --batch 레코드처럼 계산한다.
+ */
+async function runDaemon(opts: DaemonOptions): Promise<void> {
+  const base = program.opts() as CliOptions;
+  if (base.batch != null) throw new Error('--batch cannot be used with daemon');
+  if (base.svgTimeline != null) throw new Error('--svg-timeline cannot be used with daemon');
+  if (base.svgOut != null) throw new Error('--svg-out cannot be used with daemon');
+  if (base.output != null) throw new Error('--output cannot be used with daemon');
+  if (base.stats) throw new Error('--stats cannot be used with daemon');
+  // 첫 질의도 빠르도록 올해 앞뒤의 절기를 미리 계산해 둔다
+  const year = new Date().getFullYear();
+  for (let y = year - 1; y <= year + 1; y++) luck.getCachedTerms(y);
+  useResultCache = true;
+  const daemon = await startDaemon(opts.socket, (record, index) => renderBatchRecord(base, record, index - 1));
+  console.error(`saju daemon listening on ${daemon.path}`);
+  const stop = () => {
+    void daemon.close().then(() => process.exit(0));
+  };
+  process.once('SIGINT', stop);
+  process.once('SIGTERM', stop);
 }
@@ -0,0 +1,186 @@
+/**
+ * @fileoverview --format markdown: 명식을 마크다운 문서로 렌더링한다
+ *
+ * 제목·섹션은 제목(#, ##), 네 기둥·십성·지장간은 표, 대운·세운·월운은 목록으로 써서
+ * Notion·Obsidian·GitHub 이슈에 그대로 붙여 넣을 수 있게 한다.
+ * 레이블과 요약 줄은 텍스트 리포트와 같은 것을 쓴다. 줄바꿈 폭 맞춤(--width)은 하지 않는다.
+ */
+
+import {
+  type HiddenRank,
+  type PillarKind,
+  type PillarsResult,
+  type SajuResult,
+  type Element,
+  type Pillar,
+  type TimeZoneSpec,
+  astro,
+  bazi,
+  type Localizer,
+  timezone,
+} from 'saju-lib';
+import { luckAnnotation, renderSummary } from './report.js';
+
+const KINDS: readonly PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+const RANKS: readonly HiddenRank[] = ['JeongGi', 'YeoGi', 'JungGi'];
+const ELEMENTS: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+
+/** 마크다운 렌더링 옵션 */
+export interface MarkdownOptions {
+  /** 이벤트(시점) 차트: 성별과 대운·세운·월운 섹션을 생략한다 */
+  event: boolean
+  /** 요약 줄의 '현재 대운' 기준 시각 */
+  asOf: Date
+}
+
+/** 표 칸 안의 '|'와 줄바꿈을 이스케이프한다 */
+export function markdownCell(text: string): string {
+  return text.replace(/\\/g, '\\\\').replace(/\|/g, '\\|').replace(/\r?\n/g, ' ');
+}
+
+/** 머리 행과 본문 행으로 파이프 표를 만든다 */
+function table(head: string[], rows: string[][]): string[] {
+  const line = (cells: string[]) => `| ${cells.map(markdownCell).join(' | ')} |`;
+  return [line(head), `|${head.map(() => ' --- |').join('')}`, ...rows.map(line)];
+}
+
+/**
+ * 계산 결과 전체를 마크다운 줄 목록으로 렌더링한다.
+ * @param result 사주 계산 결과
+ * @param i18n 레이블 제공자
+ * @param options 렌더링 옵션
+ */
+export function renderMarkdown(result: SajuResult, i18n: Localizer, options: MarkdownOptions): string[] {
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  const out = [`# ${title(result.approximate, i18n)}`, '', renderSummary(result, i18n, options.event ? null : options.asOf), ''];
+
+  out.push(`- ${i18n.inputLabel()}(${i18n.calendarLabel(result.calendarIsLunar, result.leapMonth)}): ${result.inputDate} ${result.inputTime} ${result.tzName}`);
+  if (result.convertedSolar) {
+    out.push(`- ${i18n.convertedSolarLabel()}: ${result.convertedSolar} ${result.inputTime} ${result.tzName}`);
+  }
+  if (result.lmtInfo) {
+    out.push(`- ${i18n.correctedTimeLabel()}: ${result.lmtInfo.correctedLocal} ${result.tzName}`);
+  }
+  if (!options.event) {
+    out.push(`- ${i18n.genderLabel()}: ${result.gender ? i18n.genderValue(result.gender) : i18n.genderUnknownValue()}`);
+  }
+  for (const warning of result.warnings) {
+    out.push(`- ${i18n.warningLabel()}: ${i18n.warningText(warning)}`);
+  }
+  out.push('');
+
+  renderPillarTable(out, natal, i18n);
+  renderTenGodTable(out, natal, i18n);
+  renderHiddenStemTable(out, natal, i18n);
+
+  const s = result.strength;
+  out.push(`## ${i18n.strengthHeading()}`, '');
+  out.push(`- ${i18n.verdictLabel()}: ${i18n.strengthLevelLabel(s.level)} (${i18n.scoreLabel()} ${s.total})`);
+  const counts = bazi.elementsCount(natal, result.elementOverrides);
+  out.push(`- ${i18n.elementsHeading()}: ${ELEMENTS.map((el, i) => `${i18n.elementShortLabel(el)} ${counts[i]}`).join(' / ')}`);
+  out.push(`- ${i18n.yongshinLabel()}: ${i18n.elementLabel(result.yongshin.yongshin)}`);
+  out.push('');
+
+  if (!options.event) {
+    renderLuckLists(out, result, natal, i18n);
+  }
+  // 마지막 빈 줄은 남기지 않는다
+  while (out[out.length - 1] === '') out.pop();
+  return out;
+}
+
+/** --pillars-only 결과를 마크다운으로: 제목, 네 기둥 표, 경고 */
+export function renderPillarsMarkdown(result: PillarsResult, i18n: Localizer): string[] {
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  const out = [`# ${title(result.approximate, i18n)}`, ''];
+  renderPillarTable(out, natal, i18n);
+  for (const warning of result.warnings) {
+    out.push(`- ${i18n.warningLabel()}: ${i18n.warningText(warning)}`);
+  }
+  while (out[out.length - 1] === '') out.pop();
+  return out;
+}
+
+function title(approximate: boolean, i18n: Localizer): string {
+  return approximate ? `${i18n.title()} ${i18n.approximateTag()}` : i18n.title();
+}
+
+function kindHead(i18n: Localizer): string[] {
+  return ['', ...KINDS.map((k) => i18n.pillarKindLabel(k))];
+}
+
+/** 네 기둥 표: 천간·지지(오행·음양)와 12운성 */
+function renderPillarTable(out: string[], natal: Pillar[], i18n: Localizer): void {
+  const dayStem = natal[2].stem;
+  out.push(`## ${i18n.pillarsHeading()}`, '');
+  out.push(...table(kindHead(i18n), [
+    [i18n.stemWord(), ...natal.map((p) => `${i18n.stemLabel(p.stem)} ${i18n.elementShortLabel(bazi.stemElement(p.stem))} ${i18n.polarityLabel(bazi.stemPolarity(p.stem))}`)],
+    [i18n.branchWord(), ...natal.map((p) => `${i18n.branchLabel(p.branch)} ${i18n.elementShortLabel(bazi.branchElement(p.branch))} ${i18n.polarityLabel(bazi.branchPolarity(p.branch))}`)],
+    [i18n.twelveStagesLabel(), ...natal.map((p) => i18n.stageLabel(bazi.twelveStageIndex(dayStem, p.branch)))],
+  ]));
+  out.push('');
+}
+
+/** 십성 표: 천간과 지지 본기 (일간 칸은 '일간') */
+function renderTenGodTable(out: string[], natal: Pillar[], i18n: Localizer): void {
+  const dayStem = natal[2].stem;
+  out.push(`## ${i18n.tenGodsHeading()}`, '');
+  out.push(...table(kindHead(i18n), [
+    [i18n.stemsLabel(), ...natal.map((p, i) => (i === 2 ? i18n.dayStemWord() : i18n.tenGodLabel(bazi.tenGod(dayStem, p.stem))))],
+    [i18n.branchesMainLabel(), ...natal.map((p) => i18n.tenGodLabel(bazi.tenGodBranch(dayStem, p.branch)))],
+  ]));
+  out.push('');
+}
+
+/** 지장간 표: 정기·여기·중기 행, 칸마다 지장간과 그 십성 (없으면 빈 칸) */
+function renderHiddenStemTable(out: string[], natal: Pillar[], i18n: Localizer): void {
+  const dayStem = natal[2].stem;
+  out.push(`## ${i18n.hiddenStemsHeading()}`, '');
+  out.push(...table(kindHead(i18n), RANKS.map((rank) => [
+    i18n.hiddenRankLabel(rank),
+    ...natal.map((p) => {
+      const stem = bazi.hiddenStems(p.branch)[bazi.hiddenStemRanks(p.branch).indexOf(rank)];
+      return stem === undefined ? '' : `${i18n.stemLabel(stem)} ${i18n.tenGodLabel(bazi.tenGod(dayStem, stem))}`;
+    }),
+  ])));
+  out.push('');
+}
+
+/** 운 기둥 한 항목: '**label** 기둥 (기간) — 십성: 천간 …, 지지 …' */
+function luckItem(label: string, pillar: Pillar, natal: Pillar[], i18n: Localizer, range = ''): string {
+  const dayStem = natal[2].stem;
+  const period = range ? ` (${range})` : '';
+  return `- **${label}** ${i18n.pillarLabel(pillar)}${period} — ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, pillar.branch))}${luckAnnotation(pillar, natal, i18n)}`;
+}
+
+/** 'YYYY-MM-DD ~ YYYY-MM-DD' (현지 날짜) */
+function dateRange(tzSpec: TimeZoneSpec, startJd: number, endJd: number): string {
+  const date = (jd: number) => timezone.toLocal(tzSpec, astro.datetimeFromJd(jd)).format('YYYY-MM-DD');
+  return `${date(startJd)} ~ ${date(endJd)}`;
+}
+
+/** 대운·세운·월운 목록 */
+function renderLuckLists(out: string[], result: SajuResult, natal: Pillar[], i18n: Localizer): void {
+  if (result.daewonDirection !== null && result.daewonStartSpan !== null) {
+    out.push(`## ${i18n.daewonHeading()}`, '');
+    out.push(`${i18n.directionLabel(result.daewonDirection)}, ${i18n.startLabel()} ${i18n.formatSpan(result.daewonStartSpan)}`, '');
+    for (const item of result.daewonItems) {
+      out.push(luckItem(i18n.formatAge(item.startMonths, false), item.pillar, natal, i18n));
+    }
+    out.push('');
+  }
+  if (result.yearlyLuck.length > 0) {
+    out.push(`## ${i18n.yearlyLuckHeading()}`, '');
+    for (const y of result.yearlyLuck) {
+      out.push(luckItem(i18n.formatYearLabel(y.year), y.pillar, natal, i18n, dateRange(result.tzSpec, y.startJd, y.endJd)));
+    }
+    out.push('');
+  }
+  for (const monthly of result.monthlyLuckYears) {
+    out.push(`## ${i18n.monthlyLuckHeading(monthly.year)}`, '');
+    for (const m of monthly.months) {
+      out.push(luckItem(i18n.monthLabel(m.branch), m.pillar, natal, i18n, dateRange(result.tzSpec, m.startJd, m.endJd)));
+    }
+    out.push('');
+  }
+}
@@ -0,0 +1,43 @@
+/**
+ * @fileoverview 리포트 출력 대상 (표준 출력 또는 --output 파일)
+ *
+ * 명식·배치 출력은 어느 형식이든 줄 단위로 이 대상에 쓴다. --output 파일은 시작할 때
+ * 한 번 열어 쓸 수 없는 경로를 계산 전에 알리고, 줄마다 UTF-8(BOM 없음)로 바로 쓴다.
+ * 긴 배치도 메모리에 모으지 않고 레코드 순서대로 흘려 쓴다.
+ */
+
+import { closeSync, openSync, writeSync } from 'node:fs';
+
+/** 출력 줄을 받는 곳 */
+export interface OutputSink {
+  /** 한 줄을 쓴다 (줄바꿈은 붙여 준다) */
+  line(text: string): void;
+  /** 파일이면 닫는다 (표준 출력은 그대로 둔다) */
+  close(): void;
+}
+
+/** 표준 출력 */
+export const STDOUT: OutputSink = {
+  line: (text) => console.log(text),
+  close: () => {},
+};
+
+/**
+ * --output 파일을 열어 출력 대상을 만든다 (있으면 덮어쓴다).
+ * @throws Error 파일을 만들거나 쓸 수 없을 때 (디렉터리 없음, 권한 없음 등)
+ */
+export function openOutput(path: string): OutputSink {
+  let fd: number;
+  try {
+    fd = openSync(path, 'w');
+  } catch (err: unknown) {
+    const message = err instanceof Error ? err.message : String(err);
+    throw new Error(`cannot write --output ${path}: ${message}`);
+  }
+  return {
+    line: (text) => {
+      writeSync(fd, `${text}\n`, null, 'utf8');
+    },
+    close: () => closeSync(fd),
+  };
+}
@@ -0,0 +1,113 @@
+/**
+ * @fileoverview 긴 작업의 진행 상황 표시 (stderr)
+ *
+ * --batch, selfcheck, verify --kasi처럼 오래 걸리는 작업이 조용히 멈춘 것처럼
+ * 보이지 않도록 진행률과 남은 시간(ETA)을 표준 오류로 내보낸다.
+ * 표준 출력의 결과와 섞이지 않으므로 파이프로 넘겨도 안전하다.
+ *
+ * - bar: 터미널 한 줄을 덮어쓰는 진행 막대
+ * - json: 기계가 읽는 JSON 줄 ({"progress","done","total","percent","eta_seconds"})
+ * - auto: 표준 오류가 터미널이면 bar, 아니면 표시하지 않음
+ */
+
+/** 진행 표시 방식 */
+export type ProgressMode = 'auto' | 'bar' | 'json' | 'off';
+
+export const PROGRESS_MODES: readonly ProgressMode[] = ['auto', 'bar', 'json', 'off'];
+
+/** 진행 상황을 쓰는 스트림 (process.stderr 호환) */
+export interface ProgressStream {
+  write(text: string): unknown;
+  isTTY?: boolean;
+}
+
+/** 진행 막대 폭 (문자) */
+const BAR_WIDTH = 24;
+
+/** 다시 그리는 최소 간격 (밀리초): 막대는 자주, JSON 줄은 드물게 */
+const BAR_INTERVAL_MS = 100;
+const JSON_INTERVAL_MS = 1000;
+
+/** 작업 하나의 진행 상황 */
+export class Progress {
+  private done = 0;
+  private readonly startedAt: number;
+  private lastDrawnAt = -Infinity;
+  /** 막대가 지금 터미널에 그려져 있는지 */
+  private visible = false;
+  private readonly mode: 'bar' | 'json' | 'off';
+
+  /**
+   * @param label 작업 이름 (예: 'batch', 'selfcheck')
+   * @param total 전체 단위 수
+   * @param mode 표시 방식
+   * @param stream 출력 스트림 (기본 process.stderr)
+   * @param now 현재 시각 (밀리초, 테스트에서 대체 가능)
+   */
+  constructor(
+    private readonly label: string,
+    private readonly total: number,
+    mode: ProgressMode,
+    private readonly stream: ProgressStream = process.stderr,
+    private readonly now: () => number = Date.now,
+  ) {
+    this.mode = mode === 'auto' ? (stream.isTTY ? 'bar' : 'off') : mode;
+    this.startedAt = now();
+  }
+
+  /** count 단위를 마쳤음을 알린다 */
+  tick(count = 1): void {
+    this.done = Math.min(this.total, this.done + count);
+    if (this.mode === 'off') return;
+    const t = this.now();
+    const interval = this.mode === 'bar' ? BAR_INTERVAL_MS : JSON_INTERVAL_MS;
+    // clear()로 지운 막대는 바로 다시 그린다
+    const throttled = this.mode === 'json' || this.visible;
+    if (throttled && this.done < this.total && t - this.lastDrawnAt < interval) return;
+    this.lastDrawnAt = t;
+    this.draw(t);
+  }
+
+  /**
+   * 막대 줄을 지운다. 같은 터미널에 결과를 쓰기 전에 부르면 막대와 결과가 섞이지 않는다
+   * (다음 tick에서 다시 그린다).
+   */
+  clear(): void {
+    if (!this.visible) return;
+    this.stream.write('\r\x1b[2K');
+    this.visible = false;
+  }
+
+  /** 작업을 마친다 */
+  finish(): void {
+    this.clear();
+  }
+
+  private draw(t: number): void {
+    const percent = this.total === 0 ? 100 : (this.done / this.total) * 100;
+    const elapsed = (t - this.startedAt) / 1000;
+    const eta = this.done === 0 ? null : (elapsed / this.done) * (this.total - this.done);
+    if (this.mode === 'json') {
+      this.stream.write(`${JSON.stringify({
+        progress: this.label,
+        done: this.done,
+        total: this.total,
+        percent: Math.round(percent * 10) / 10,
+        eta_seconds: eta === null ? null : Math.round(eta * 10) / 10,
+      })}\n`);
+      return;
+    }
+    const filled = Math.round((percent / 100) * BAR_WIDTH);
+    const bar = `${'#'.repeat(filled)}${'.'.repeat(BAR_WIDTH - filled)}`;
+    this.visible = true;
+    this.stream.write(`\r${this.label} [${bar}] ${percent.toFixed(0).padStart(3)}% ${this.done}/${this.total} ETA ${eta === null ? '--' : formatEta(eta)}`);
+  }
+}
+
+/** 남은 초를 '1h02m', '3m05s', '42s' 형식으로 표시한다 */
+export function formatEta(seconds: number): string {
+  const s = Math.max(0, Math.round(seconds));
+  if (s >= 3600) return `${Math.floor(s / 3600)}h${String(Math.floor((s % 3600) / 60)).padStart(2, '0')}m`;
+  if (s >= 60) return `${Math.floor(s / 60)}m${String(s % 60).padStart(2, '0')}s`;
+  return `${s}s`;
+}
@@ -0,0 +1,768 @@
+/**
+ * @fileoverview CLI 텍스트 리포트 렌더러
+ *
+ * 계산 결과(SajuResult)를 사람이 읽는 텍스트 줄 목록으로 변환한다.
+ * 출력(stdout)과 분리되어 있어 레이아웃 전환·테스트가 쉽다.
+ */
+
+import {
+  type PillarsResult,
+  type SajuResult,
+  aptitude,
+  type StrengthResult,
+  type TimeZoneSpec,
+  astro,
+  balance,
+  bazi,
+  dayBranch,
+  era,
+  formation,
+  gaeun,
+  gyeokguk,
+  health,
+  ilgan,
+  ilju,
+  luck,
+  naming,
+  shinsal,
+  shinsalNotes,
+  strength as str,
+  tenGodStats,
+  type Localizer,
+  type PillarKind,
+  type BoundaryAlternative,
+  timezone,
+  type Pillar,
+  type SolarTerm,
+  type Element,
+  type Direction,
+  type YongshinResult,
+  type Relation,
+  type StrengthClass,
+  type PillarPosition,
+  type BranchCombination,
+  type StemCombination,
+  type ElementOverrides,
+  type DaewonStartSpan,
+  type TenGodEntry,
+  type ShinsalBase,
+  school,
+} from 'saju-lib';
+import { renderPillarCards, renderVerticalChart } from './layout.js';
+import { alignColumns } from './textWidth.js';
+
+/** 명식 레이아웃: 항목별 목록(list) 또는 전통 세로 명식(vertical) */
+export type Layout = 'list' | 'vertical' | 'card';
+
+/** 리포트 렌더링 옵션 */
+export interface ReportOptions {
+  layout: Layout
+  compact: boolean
+  /** 이벤트(시점) 차트: 성별·대운·세운·월운 섹션을 생략한다 */
+  event: boolean
+  showTerms: boolean
+  /** 세운을 소속 대운별로 묶어 출력한다 (평생 세운) */
+  groupYearly: boolean
+  /** 출생·대상 연도의 단기/불기/세차 표기 */
+  era: boolean
+  /** 서양 태양 별자리 대조 줄 */
+  sunSign: boolean
+  /** 출생·절입·운 경계 시점에 율리우스일(JD)을 덧붙인다 */
+  showJd: boolean
+  /** 요약 줄의 '현재 대운' 기준 시각 */
+  asOf: Date
+  iljuDataset: readonly ilju.IljuEntry[]
+  gaeunTable: Readonly<gaeun.GaeunTable>
+  /** 오행–장부 건강 섹션 (null이면 생략) */
+  healthTable: Readonly<health.HealthTable> | null
+  /** 검출된 신살의 풀이 (null이면 생략) */
+  shinsalCatalog: Readonly<shinsalNotes.ShinsalCatalog> | null
+  /** 원국과 대조할 이름 분석 (null이면 생략) */
+  name: naming.NameAnalysis | null
+  /** --school로 고른 학파 (헤더에 실제 설정과 함께 밝힌다, 없으면 null) */
+  school: school.SchoolName | null
+}
+
+/**
+ * 계산 결과 전체를 텍스트 줄 목록으로 렌더링한다.
+ * @param result 사주 계산 결과
+ * @param i18n 레이블 제공자
+ * @param options 렌더링 옵션
+ * @returns 출력할 줄 목록 (섹션 사이 빈 줄 포함)
+ */
+export function renderReport(result: SajuResult, i18n: Localizer, options: ReportOptions): string[] {
+  const out: string[] = [];
+  const { yearPillar: year, monthPillar: month, dayPillar: day, hourPillar: hour } = result;
+  const natal = [year, month, day, hour];
+  const jdNote = julianDayNote(options.showJd);
+
+  renderHeader(out, result, i18n, options, renderSummary(result, i18n, options.event ? null : options.asOf));
+  if (options.layout === 'vertical') {
+    renderVerticalChart(out, result, i18n, options.compact);
+  } else if (options.layout === 'card') {
+    renderPillarCards(out, result, i18n);
+  } else {
+    renderPillars(out, year, month, day, hour, i18n);
+    renderHiddenStems(out, year, month, day, hour, i18n);
+    renderTenGods(out, year, month, day, hour, i18n);
+    renderTwelveStages(out, day.stem, year, month, day, hour, i18n);
+    renderTwelveShinsal(out, result.shinsalBase, year, month, day, hour, i18n);
+  }
+  if (result.boundaryWindow !== null) {
+    renderBoundarySensitivity(out, result.boundaryWindow, result.boundaryAlternatives, i18n);
+  }
+  if (options.shinsalCatalog) {
+    renderShinsalNotes(out, result, options.shinsalCatalog, i18n);
+  }
+  renderBranchPairs(out, natal, i18n);
+  renderDayBranch(out, dayBranch.dayBranchSummary(natal, result.shinsalBase), natal, i18n);
+  renderStemCombinations(out, result.stemCombinations, i18n);
+  renderCombinations(out, result.branchCombinations, i18n);
+  renderStrength(out, result.strength, i18n);
+  renderFormations(out, formation.findFormations(natal, result.strength.verdict), i18n);
+  renderElements(out, year, month, day, hour, result.elementOverrides, i18n);
+  const advice = balance.elementAdvisory(natal, day.stem);
+  renderBalance(out, advice, i18n);
+  if (options.healthTable) {
+    renderHealth(out, health.healthNotes(advice, options.healthTable), i18n);
+  }
+  renderTenGodDistribution(out, tenGodStats.tenGodDistribution(natal, day.stem), tenGodStats.weightedTenGodDistribution(natal, day.stem), i18n);
+  renderAptitude(out, natal, day.stem, i18n);
+  renderIlgan(out, day.stem, result.strength.verdict, i18n);
+  renderIlju(out, day, options.iljuDataset, i18n);
+  renderGaeun(out, result.yongshin, options.gaeunTable, i18n);
+  if (options.name) {
+    renderNaming(out, options.name, naming.checkNameAgainstChart(options.name, advice, result.yongshin), i18n);
+  }
+  if (!options.event) {
+    if (result.daewonDirection !== null && result.daewonStartSpan !== null) {
+      const directionNote = i18n.directionModeNote(result.daewonDirectionMode, result.daewonYearBasis);
+      renderDaewon(out, result.daewonDirection, directionNote, result.daewonStartSpan, result.daewonRounding, result.daewonItems, natal, i18n,
+        (item) => jdNote(luck.daewonStartJd(result.birthJd, luck.exactStartMonths(item))));
+    } else if (result.daewonCandidates.length > 0) {
+      renderDaewonCandidates(out, result.daewonCandidates, day.stem, i18n);
+    }
+    if (options.groupYearly && result.daewonItems.length > 0) {
+      const groups = luck.groupYearlyByDaewon(result.yearlyLuck, result.daewonItems, result.birthJd);
+      renderGroupedYearlyLuck(out, groups, natal, result.tzSpec, i18n, jdNote);
+    } else {
+      renderYearlyLuck(out, result.yearlyLuck, natal, result.tzSpec, i18n, jdNote);
+    }
+    for (const monthly of result.monthlyLuckYears) {
+      renderMonthlyLuck(out, monthly, natal, result.tzSpec, i18n, jdNote);
+    }
+  }
+
+  if (options.showTerms) {
+    renderTerms(out, result.tzSpec, result.solarTerms, i18n, jdNote);
+  }
+  return out;
+}
+
+/**
+ * --pillars-only 결과를 텍스트 줄로 출력한다: 제목, 네 기둥, 경고.
+ */
+export function renderPillarsReport(result: PillarsResult, i18n: Localizer): string[] {
+  const out = [result.approximate ? `${i18n.title()} ${i18n.approximateTag()}` : i18n.title()];
+  renderPillars(out, result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar, i18n);
+  if (result.historicalDate) {
+    out.push(`- ${i18n.historicalDateLabel()}: ${i18n.historicalDateValue(result.historicalDate)}`);
+  }
+  if (result.hemisphere === 'South') {
+    out.push(`- ${i18n.hemisphereLabel()}: ${i18n.hemisphereValue(result.hemisphere)}`);
+  }
+  for (const warning of result.warnings) {
+    out.push(`- ${i18n.warningLabel()}: ${i18n.warningText(warning)}`);
+  }
+  return out;
+}
+
+const POSITION_INDEX: Record<PillarPosition, number> = { Year: 0, Month: 1, Day: 2, Hour: 3 };
+const RELATIONS: readonly Relation[] = ['Same', 'Output', 'Wealth', 'Officer', 'Resource'];
+
+/** 보정 초를 '±00m00s' 형식으로 포맷한다 */
+function formatCorrection(seconds: number): string {
+  const sign = seconds >= 0 ? '+' : '-';
+  const abs = Math.abs(seconds);
+  const mins = Math.floor(abs / 60);
+  const secs = abs % 60;
+  return `${sign}${String(mins).padStart(2, '0')}m${String(secs).padStart(2, '0')}s`;
+}
+
+/** 지장간을 '갑(甲), 병(丙), 무(戊)' 형식으로 포맷한다 */
+function formatHiddenStems(i18n: Localizer, branch: number): string {
+  return bazi.hiddenStems(branch).map((stem) => i18n.stemLabel(stem)).join(', ');
+}
+
+/** 십성 항목을 '계(癸) 겁재 (정기)' 형식으로 포맷한다 */
+function formatTenGodEntry(i18n: Localizer, entry: TenGodEntry): string {
+  const rank = entry.rank ? ` (${i18n.hiddenRankLabel(entry.rank)})` : '';
+  return `${i18n.stemLabel(entry.stem)} ${i18n.tenGodLabel(entry.tenGod)}${rank}`;
+}
+
+/** 헤더 요약 줄과 한 줄 출력(--format oneline)이 함께 쓰는 판정 */
+interface ChartSummary {
+  verdict: StrengthClass
+  gyeokguk: gyeokguk.GyeokgukKind
+  yongshin: Element
+  /** asOf 시점의 대운 (asOf가 null이거나 그 시점의 대운이 없으면 null) */
+  currentDaewon: luck.DaewonItem | null
+}
+
+function chartSummary(result: SajuResult, asOf: Date | null): ChartSummary {
+  const pillars = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  return {
+    verdict: result.strength.verdict,
+    gyeokguk: gyeokguk.determineGyeokguk(pillars, result.elementOverrides ? result.stemCombinations : []).kind,
+    yongshin: result.yongshin.yongshin,
+    currentDaewon: asOf ? luck.daewonAt(result.daewonItems, result.birthJd, astro.jdFromDatetime(asOf)) : null,
+  };
+}
+
+/**
+ * 한 줄 요약을 만든다: 일간과 신강/신약, 격국, 용신, 현재 대운.
+ * asOf가 null이거나 그 시점의 대운이 없으면 대운은 생략한다.
+ */
+export function renderSummary(result: SajuResult, i18n: Localizer, asOf: Date | null): string {
+  const summary = chartSummary(result, asOf);
+  const parts = [
+    i18n.summaryDayMaster(summary.verdict, ilgan.ilganEntry(result.dayPillar.stem).name[i18n.lang]),
+    i18n.gyeokgukLabel(summary.gyeokguk),
+    `${i18n.yongshinLabel()} ${i18n.elementLabel(summary.yongshin)}`,
+  ];
+  const current = summary.currentDaewon;
+  if (current) {
+    parts.push(i18n.summaryDaewon(i18n.pillarLabel(current.pillar), i18n.displayAge(Math.floor(current.startMonths / 12))));
+  }
+  return parts.join(' | ');
+}
+
+/**
+ * 명식을 한 줄로 만든다 (--format oneline, 챗봇·로그·터미널 확인용).
+ * 예: '갑오년 병인월 갑자일 을축시 | 신약 | 용신:수 | 대운:역행 4세'. 대운 방향이 없으면 대운은 생략한다.
+ */
+export function renderOneLine(result: SajuResult, i18n: Localizer): string {
+  const summary = chartSummary(result, null);
+  const parts = [
+    i18n.onelinePillars([result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar]),
+    i18n.strengthVerdictLabel(summary.verdict),
+    i18n.onelineYongshin(summary.yongshin),
+  ];
+  if (result.daewonDirection !== null && result.daewonStartMonths !== null) {
+    parts.push(i18n.onelineDaewon(result.daewonDirection, i18n.displayAge(Math.floor(result.daewonStartMonths / 12))));
+  }
+  const line = parts.join(' | ');
+  return result.approximate ? `${line} ${i18n.approximateTag()}` : line;
+}
+
+/**
+ * 여덟 글자를 한자로만 한 줄에 쓴다 (--format hanja, 스크립트·다른 도구에 붙여 넣기용).
+ * 예: '庚午 戊寅 甲子 丙寅'. strength를 주면 일간 강약을 덧붙인다 ('庚午 戊寅 甲子 丙寅 | 신약').
+ */
+export function renderHanjaLine(pillars: Pick<PillarsResult, 'yearPillar' | 'monthPillar' | 'dayPillar' | 'hourPillar'>, strength: StrengthResult | null, i18n: Localizer): string {
+  const line = [pillars.yearPillar, pillars.monthPillar, pillars.dayPillar, pillars.hourPillar].map(ilju.iljuKey).join(' ');
+  return strength ? `${line} | ${i18n.strengthLevelLabel(strength.level)}` : line;
+}
+
+/** --show-jd일 때 시점 뒤에 붙일 ' [JD …]' (아니면 빈 문자열) */
+function julianDayNote(show: boolean): (jd: number) => string {
+  return show ? (jd) => ` [JD ${jd.toFixed(6)}]` : () => '';
+}
+
+/** 출생 시점이 속한 절월 구간: 절입 시각, 경과 일수, 다음 절입까지 남은 일수 */
+function renderBirthTerm(out: string[], result: SajuResult, i18n: Localizer, jdNote: (jd: number) => string): void {
+  const ctx = result.birthTerm;
+  const at = (jd: number) => `${timezone.toLocal(result.tzSpec, astro.datetimeFromJd(jd)).format('YYYY-MM-DD HH:mm')}${jdNote(jd)}`;
+  out.push(`- ${i18n.birthTermLabel()}: ${i18n.termName(ctx.term)} ${at(ctx.startJd)} (${i18n.daysSinceTerm(ctx.daysSinceStart)}) | ${i18n.nextTermNote(i18n.termName(ctx.nextTerm), at(ctx.endJd), ctx.daysUntilNext)}`);
+}
+
+/** 입력 정보 및 보정 내역을 출력한다 */
+function renderHeader(out: string[], result: SajuResult, i18n: Localizer, options: ReportOptions, summary: string): void {
+  const { event, era: showEra, sunSign: showSunSign } = options;
+  out.push(result.approximate ? `${i18n.title()} ${i18n.approximateTag()}` : i18n.title());
+  out.push(summary);
+  out.push(`- ${i18n.inputLabel()}(${i18n.calendarLabel(result.calendarIsLunar, result.leapMonth)}): ${result.inputDate} ${result.inputTime} ${result.tzName}`);
+  if (result.rawInput) {
+    out.push(`- ${i18n.rawInputLabel()}: ${result.rawInput.date} ${result.rawInput.time} → ${result.inputDate} ${result.inputTime}`);
+  }
+  if (result.historicalDate) {
+    out.push(`- ${i18n.historicalDateLabel()}: ${i18n.historicalDateValue(result.historicalDate)}`);
+  }
+  if (result.convertedSolar) {
+    out.push(`- ${i18n.convertedSolarLabel()}: ${result.convertedSolar} ${result.inputTime} ${result.tzName}`);
+  }
+  if (result.convertedLunar) {
+    const l = result.convertedLunar;
+    const suffix = l.isLeap ? i18n.leapSuffix() : '';
+    out.push(`- ${i18n.convertedLunarLabel()}: ${String(l.year).padStart(4, '0')}-${String(l.month).padStart(2, '0')}-${String(l.day).padStart(2, '0')}${suffix}`);
+  }
+  if (result.lmtInfo) {
+    const info = result.lmtInfo;
+    if (info.locationLabel) {
+      out.push(`- ${i18n.localMeanTimeLabel()}: ${i18n.locationLabel()} ${info.locationLabel} | ${i18n.longitudeLabel()} ${info.longitude.toFixed(4)}deg | ${i18n.stdMeridianLabel()} ${info.stdMeridian.toFixed(1)}deg | ${i18n.correctionLabel()} ${formatCorrection(info.correctionSeconds)}`);
+    } else {
+      out.push(`- ${i18n.localMeanTimeLabel()}: ${i18n.longitudeLabel()} ${info.longitude.toFixed(4)}deg | ${i18n.stdMeridianLabel()} ${info.stdMeridian.toFixed(1)}deg | ${i18n.correctionLabel()} ${formatCorrection(info.correctionSeconds)}`);
+    }
+    out.push(`- ${i18n.correctedTimeLabel()}: ${info.correctedLocal} ${result.tzName}`);
+    const alt = result.lmtAlternative;
+    const note = alt ? ` (${i18n.lmtAlternativeNote(alt.boundary, i18n.pillarLabel(alt.dayPillar), i18n.pillarLabel(alt.hourPillar))})` : '';
+    out.push(`- ${i18n.lmtBoundaryHeading()}: ${i18n.lmtBoundaryLabel(result.lmtBoundary)}${note}`);
+  }
+  if (event) {
+    out.push(`- ${i18n.chartTypeLabel()}: ${i18n.eventChartValue()}`);
+  } else {
+    out.push(`- ${i18n.genderLabel()}: ${result.gender ? i18n.genderValue(result.gender) : i18n.genderUnknownValue()}`);
+  }
+  out.push(`- ${i18n.dayBoundaryLabel()}: ${i18n.dayBoundaryValue(result.dayBoundary)}`);
+  if (result.hemisphere === 'South') {
+    out.push(`- ${i18n.hemisphereLabel()}: ${i18n.hemisphereValue(result.hemisphere)}`);
+  }
+  if (options.school) {
+    const settings = {
+      dayBoundary: result.dayBoundary,
+      shinsalBase: result.shinsalBase,
+      daewonRounding: result.daewonRounding,
+      monthScoring: result.strength.monthScoring,
+      transform: result.elementOverrides !== null,
+    };
+    out.push(`- ${i18n.schoolLabel()}: ${options.school} (${i18n.schoolSettingsText(settings)})`);
+  }
+  renderSijin(out, result, i18n);
+  renderBirthTerm(out, result, i18n, julianDayNote(options.showJd));
+  if (options.showJd) {
+    out.push(`- ${i18n.julianDayLabel()}: ${result.birthJd.toFixed(6)} (UT)`);
+  }
+  if (showEra) {
+    const birthYear = Number((result.convertedSolar ?? result.inputDate).slice(0, 4));
+    out.push(`- ${i18n.eraLabel()} ${i18n.eraBirthLabel()}: ${i18n.eraValue(era.eraYears(birthYear))}`);
+    if (!event) {
+      out.push(`- ${i18n.eraLabel()} ${i18n.eraTargetLabel()}: ${i18n.eraValue(era.eraYears(result.monthlyLuck.year))}`);
+    }
+  }
+  if (showSunSign) {
+    const sun = astro.sunSign(result.birthJd);
+    const cusp = sun.cusp
+      ? ` | ${i18n.cuspNote(sun.cusp.sign, timezone.toLocal(result.tzSpec, astro.datetimeFromJd(sun.cusp.jd)).format('YYYY-MM-DD HH:mm'))}`
+      : '';
+    out.push(`- ${i18n.sunSignLabel()}: ${i18n.zodiacSignLabel(sun.sign)} (${i18n.eclipticLongitudeLabel()} ${sun.longitude.toFixed(2)}°)${cusp}`);
+  }
+  for (const warning of result.warnings) {
+    out.push(`- ${i18n.warningLabel()}: ${i18n.warningText(warning)}`);
+  }
+  out.push('');
+}
+
+/** 시주의 시진 구간(시계 시각)과 띠 동물을 출력한다 */
+function renderSijin(out: string[], result: SajuResult, i18n: Localizer): void {
+  const branch = result.hourPillar.branch;
+  // 보정 시각으로 경계를 비교할 때만 시계 시각 구간이 밀린다
+  const correction = result.lmtInfo && result.lmtBoundary === 'Corrected' ? result.lmtInfo.correctionSeconds : 0;
+  const [start, end] = bazi.sijinWindow(branch, correction);
+  const shift = -Math.round(correction / 60);
+  const note = shift !== 0 ? ` (${i18n.sijinShiftNote(shift)})` : '';
+  out.push(`- ${i18n.sijinHeading()}: ${i18n.sijinLabel(branch)} ${formatClock(start)}–${formatClock(end)}, ${i18n.branchAnimalLabel(branch)}${note}`);
+}
+
+/** 자정 기준 분을 'HH:MM'으로 포맷한다 */
+function formatClock(minutes: number): string {
+  return `${String(Math.floor(minutes / 60)).padStart(2, '0')}:${String(minutes % 60).padStart(2, '0')}`;
+}
+
+/** 사주 네 기둥을 출력한다 */
+function renderPillars(out: string[], year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [year, month, day, hour];
+  out.push(i18n.pillarsHeading());
+  for (let idx = 0; idx < 4; idx++) {
+    const k = kinds[idx];
+    const p = pillars[idx];
+    const stemW = idx === 2 ? i18n.dayStemWord() : i18n.stemWord();
+    out.push(`- ${i18n.pillarKindLabel(k)}: ${i18n.pillarLabel(p)} | ${stemW}: ${i18n.elementLabel(bazi.stemElement(p.stem))} ${i18n.polarityLabel(bazi.stemPolarity(p.stem))} | ${i18n.branchWord()}: ${i18n.elementLabel(bazi.branchElement(p.branch))} ${i18n.polarityLabel(bazi.branchPolarity(p.branch))}`);
+  }
+  out.push('');
+}
+
+/** 출생 시각 가까이의 기둥 경계와 그 너머의 4기둥을 출력한다 (창 안에 경계가 없으면 생략) */
+function renderBoundarySensitivity(out: string[], window: number, alternatives: BoundaryAlternative[], i18n: Localizer): void {
+  if (alternatives.length === 0) return;
+  out.push(i18n.boundarySensitivityHeading(window));
+  for (const alt of alternatives) {
+    const pillars = [alt.yearPillar, alt.monthPillar, alt.dayPillar, alt.hourPillar].map((p) => i18n.pillarLabel(p)).join(' ');
+    const changed = alt.changed.map((kind) => i18n.pillarKindLabel(kind)).join(', ');
+    out.push(`- ${i18n.boundaryAlternativeLead(alt)}: ${pillars} (${i18n.changedPillarsLabel()}: ${changed})`);
+  }
+  out.push('');
+}
+
+/** 지장간을 출력한다 */
+function renderHiddenStems(out: string[], year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [year, month, day, hour];
+  out.push(i18n.hiddenStemsHeading());
+  for (let idx = 0; idx < 4; idx++) {
+    out.push(`- ${i18n.branchKindLabel(kinds[idx])}: ${formatHiddenStems(i18n, pillars[idx].branch)}`);
+  }
+  out.push('');
+}
+
+/** 십성(천간·지지·지장간)을 출력한다 */
+function renderTenGods(out: string[], year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [year, month, day, hour];
+  const ds = day.stem;
+  const entries = bazi.tenGodsFull(ds, pillars);
+  out.push(i18n.tenGodsHeading());
+  out.push(`- ${i18n.stemsLabel()}: ${entries.filter((e) => e.layer === 'Stem').map((e) => `${i18n.stemKindLabel(e.position)} ${i18n.tenGodLabel(e.tenGod)}`).join(' / ')}`);
+  out.push(`- ${i18n.branchesMainLabel()}: ${entries.filter((e) => e.rank === 'JeongGi').map((e) => `${i18n.branchKindLabel(e.position)} ${i18n.tenGodLabel(e.tenGod)}`).join(' / ')}`);
+  for (const kind of kinds) {
+    const hidden = entries.filter((e) => e.layer === 'Hidden' && e.position === kind);
+    out.push(`- ${i18n.branchesHiddenLabel(kind)}: ${hidden.map((e) => formatTenGodEntry(i18n, e)).join(', ')}`);
+  }
+  out.push('');
+}
+
+/** 12운성을 출력한다 */
+function renderTwelveStages(out: string[], dayStem: number, year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [year, month, day, hour];
+  out.push(i18n.twelveStagesHeading());
+  out.push(`- ${kinds.map((k, i) => `${i18n.branchKindLabel(k)}: ${i18n.stageLabel(bazi.twelveStageIndex(dayStem, pillars[i].branch))}`).join(' / ')}`);
+  out.push('');
+}
+
+/** 12신살을 출력한다 */
+function renderTwelveShinsal(out: string[], base: ShinsalBase, year: Pillar, month: Pillar, day: Pillar, hour: Pillar, i18n: Localizer): void {
+  const kinds: PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [year, month, day, hour];
+  const baseBranch = base === 'Day' ? day.branch : year.branch;
+  out.push(i18n.twelveShinsalHeading(base));
+  out.push(`- ${kinds.map((k, i) => `${i18n.branchKindLabel(k)}: ${i18n.shinsalLabel(bazi.twelveShinsalIndex(baseBranch, pillars[i].branch))}`).join(' / ')}`);
+  out.push('');
+}
+
+/** 검출된 주요 신살과 12신살의 한 줄 풀이를 출력한다 */
+function renderShinsalNotes(out: string[], result: SajuResult, catalog: Readonly<shinsalNotes.ShinsalCatalog>, i18n: Localizer): void {
+  const positions: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pillars = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  out.push(i18n.shinsalNotesHeading());
+  for (const entry of result.shinsalEntries) {
+    const at = entry.foundAt.map((p) => i18n.positionLabel(p)).join(', ');
+    out.push(`- ${i18n.shinsalKindLabel(entry.kind)} (${at}): ${catalog.kinds[entry.kind][i18n.lang]}`);
+  }
+  const twelve = new Map<number, PillarPosition[]>();
+  const baseBranch = result.shinsalBase === 'Day' ? result.dayPillar.branch : result.yearPillar.branch;
+  pillars.forEach((pillar, i) => {
+    const idx = bazi.twelveShinsalIndex(baseBranch, pillar.branch);
+    twelve.set(idx, [...(twelve.get(idx) ?? []), positions[i]]);
+  });
+  for (const [idx, at] of twelve) {
+    out.push(`- ${i18n.shinsalLabel(idx)} (${at.map((p) => i18n.positionLabel(p)).join(', ')}): ${catalog.twelve[idx][i18n.lang]}`);
+  }
+  out.push('');
+}
+
+/** 신강/신약 판정 결과를 출력한다 */
+function renderStrength(out: string[], strength: StrengthResult, i18n: Localizer): void {
+  const { STEM_WEIGHT, HIDDEN_WEIGHT } = str.STRENGTH_WEIGHTS;
+  const monthLabel = strength.monthScoring === 'Matrix' ? i18n.monthMatrixLabel() : i18n.monthStageLabel();
+  const supportTotal = strength.supportStems * STEM_WEIGHT + strength.supportHidden * HIDDEN_WEIGHT;
+  const drainTotal = strength.drainStems * STEM_WEIGHT + strength.drainHidden * HIDDEN_WEIGHT;
+
+  out.push(i18n.strengthHeading());
+  out.push(`- ${i18n.monthStageLabel()}: ${i18n.stageLabel(strength.stageIndex)} (${i18n.strengthClassLabel(strength.stageClass)})`);
+  out.push(`- ${i18n.rootLabel()}: ${strength.rootCount} / ${i18n.supportLabel()}(${i18n.stemsLabel()} ${strength.supportStems}·${i18n.hiddenStemsHeading()} ${strength.supportHidden}) / ${i18n.drainLabel()}(${i18n.stemsLabel()} ${strength.drainStems}·${i18n.hiddenStemsHeading()} ${strength.drainHidden})`);
+  out.push(`- ${i18n.scoreLabel()}: ${strength.total} (${i18n.basisLabel()} ${monthLabel} ${strength.monthScore} + ${i18n.rootLabel()} ${strength.rootCount} + ${i18n.supportLabel()} ${supportTotal} - ${i18n.drainLabel()} ${drainTotal})`);
+  const signed = (n: number) => (n > 0 ? `+${n}` : `${n}`);
+  const perPillar = strength.pillars.map((p) => `${i18n.positionLabel(p.position)} ${signed(p.total)}`
+    + ` (${i18n.stemsLabel()} ${signed(p.stem)}, ${i18n.hiddenStemsHeading()} ${signed(p.hidden)}${p.root ? `, ${i18n.rootLabel()}` : ''})`);
+  out.push(`- ${i18n.perPillarLabel()}: ${perPillar.join(' / ')}`);
+  out.push(`- ${i18n.verdictLabel()}: ${i18n.strengthLevelLabel(strength.level)}`);
+  out.push('');
+}
+
+/** 성립한 특수 구조와 그 구조를 이루는 기둥을 출력한다 */
+function renderFormations(out: string[], formations: formation.Formation[], i18n: Localizer): void {
+  if (formations.length === 0) return;
+  out.push(i18n.formationsHeading());
+  for (const f of formations) {
+    const positions = f.positions.map((pos) => i18n.positionLabel(pos)).join('·');
+    out.push(`- ${f.rule.name[i18n.lang]}${positions ? ` (${positions})` : ''}: ${f.rule.note[i18n.lang]}`);
+  }
+  out.push('');
+}
+
+/** 오행 분포를 출력한다 (합화 반영 시 바뀐 오행으로 센다) */
+function renderElements(
+  out: string[], year: Pillar, month: Pillar, day: Pillar, hour: Pillar,
+  overrides: ElementOverrides | null, i18n: Localizer,
+): void {
+  const counts = bazi.elementsCount([year, month, day, hour], overrides);
+  const elements: Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+  out.push(overrides ? `${i18n.elementsHeading()} (${i18n.transformedNote()})` : i18n.elementsHeading());
+  out.push(`- ${elements.map((el, i) => `${i18n.elementShortLabel(el)} ${counts[i]}`).join(' / ')}`);
+  const flow = balance.dayElementFlow([year, month, day, hour], day.stem, overrides);
+  const groups = flow.elements.map((e) => `${i18n.relationGroupLabel(e.relation)} ${e.count}`).join(' / ');
+  out.push(`- ${i18n.dayFlowLabel(flow.dayElement)}: ${i18n.generatesLabel()} ${flow.generating} · ${i18n.controlsLabel()} ${flow.controlling} | ${groups}`);
+  out.push('');
+}
+
+/** 없는 오행·과다한 오행을 십성 역할·공급 글자와 함께 출력한다 */
+function renderBalance(out: string[], advice: balance.ElementAdvice[], i18n: Localizer): void {
+  if (advice.length === 0) return;
+  out.push(i18n.balanceHeading());
+  for (const a of advice) {
+    const status = a.status === 'Lacking' ? i18n.lackingLabel() : i18n.excessLabel(a.count);
+    const roles = `${i18n.relationGroupLabel(a.relation)}: ${a.tenGods.map((g) => i18n.tenGodLabel(g)).join(', ')}`;
+    const letters = [...a.stems.map((s) => i18n.stemLabel(s)), ...a.branches.map((b) => i18n.branchLabel(b))].join(', ');
+    const lettersLabel = a.status === 'Lacking' ? i18n.supplyLabel() : i18n.excessSourceLabel();
+    out.push(`- ${i18n.elementLabel(a.element)} ${status} | ${roles} | ${lettersLabel}: ${letters}`);
+  }
+  out.push('');
+}
+
+/** 과부족 오행의 장부 대응과 주의 문구를 출력한다 */
+function renderHealth(out: string[], notes: health.HealthNote[], i18n: Localizer): void {
+  if (notes.length === 0) return;
+  out.push(i18n.healthHeading());
+  for (const n of notes) {
+    const status = n.status === 'Lacking' ? i18n.lackingLabel() : i18n.excessLabel(n.count);
+    out.push(`- ${i18n.elementLabel(n.element)} ${status} (${n.organs[i18n.lang].join(', ')}): ${n.note[i18n.lang]}`);
+  }
+  out.push(i18n.healthDisclaimer());
+  out.push('');
+}
+
+/** 십성 그룹 가중치와 적성 경향을 출력한다 */
+function renderAptitude(out: string[], pillars: Pillar[], dayStem: number, i18n: Localizer): void {
+  const weights = aptitude.relationWeights(pillars, dayStem);
+  out.push(i18n.aptitudeHeading());
+  out.push(`- ${RELATIONS.map((rel) => `${i18n.relationGroupLabel(rel)} ${weights[rel].toFixed(1)}`).join(', ')}`);
+  for (const rule of aptitude.aptitudeSummary(pillars, dayStem)) {
+    const fields = rule.fields[i18n.lang];
+    out.push(`- ${rule.tendency[i18n.lang]}${fields.length > 0 ? ` | ${i18n.fieldsLabel()}: ${fields.join(', ')}` : ''}`);
+  }
+  out.push('');
+}
+
+/** 십성별 개수, 그룹 합계와 우세 그룹, 없는 십성, 지장간 포함 가중 분포를 출력한다 */
+function renderTenGodDistribution(
+  out: string[], dist: tenGodStats.TenGodDistribution, weighted: tenGodStats.TenGodDistribution, i18n: Localizer,
+): void {
+  out.push(i18n.tenGodDistributionHeading());
+  out.push(`- ${tenGodStats.TEN_GOD_ORDER.map((god) => `${i18n.tenGodLabel(god)} ${dist.counts[god]}`).join(' / ')}`);
+  const dominant = dist.dominant.length > 0 ? ` (${i18n.dominantLabel()}: ${dist.dominant.map((rel) => i18n.relationGroupLabel(rel)).join(', ')})` : '';
+  out.push(`- ${RELATIONS.map((rel) => `${i18n.relationGroupLabel(rel)} ${dist.groups[rel]}`).join(', ')}${dominant}`);
+  if (dist.absent.length > 0) {
+    out.push(`- ${i18n.absentTenGodsLabel()}: ${dist.absent.map((god) => i18n.tenGodLabel(god)).join(', ')}`);
+  }
+  out.push(`- ${i18n.weightedLabel()}: ${RELATIONS.map((rel) => `${i18n.relationGroupLabel(rel)} ${weighted.groups[rel].toFixed(1)}`).join(', ')}`);
+  out.push('');
+}
+
+/** 일간론(일간별 물상·기질과 신강/신약별 해설)을 출력한다 */
+function renderIlgan(out: string[], dayStem: number, verdict: StrengthClass, i18n: Localizer): void {
+  const entry = ilgan.ilganEntry(dayStem);
+  out.push(i18n.ilganHeading(entry.name[i18n.lang], verdict));
+  out.push(`- ${i18n.imageLabel()}: ${entry.image[i18n.lang]}`);
+  out.push(`- ${i18n.temperamentLabel()}: ${entry.note[i18n.lang]}`);
+  out.push(`- ${entry.byVerdict[verdict][i18n.lang]}`);
+  out.push('');
+}
+
+/** 일주론(일주별 키워드·성향)을 출력한다 */
+function renderIlju(out: string[], day: Pillar, dataset: readonly ilju.IljuEntry[], i18n: Localizer): void {
+  const entry = ilju.iljuEntry(day, dataset);
+  out.push(i18n.iljuHeading(i18n.pillarLabel(day)));
+  out.push(`- ${i18n.keywordsLabel()}: ${entry.keywords[i18n.lang].join(', ')}`);
+  out.push(`- ${i18n.temperamentLabel()}: ${entry.note[i18n.lang]}`);
+  out.push('');
+}
+
+/** 이름의 수리 4격·발음오행과 원국 대조를 출력한다 */
+function renderNaming(out: string[], name: naming.NameAnalysis, check: naming.NameChartCheck, i18n: Localizer): void {
+  const written = name.hanja ? `${name.hangul} (${name.hanja})` : name.hangul;
+  out.push(`${i18n.namingHeading()}: ${written} | ${i18n.strokeBasisLabel(name.strokeBasis)} ${name.strokes.join('·')}`);
+  for (const g of name.grids) {
+    out.push(`- ${i18n.nameGridLabel(g.kind)} ${g.strokes}: ${i18n.luckyNumberLabel(g.lucky)}, ${i18n.elementLabel(g.element)}`);
+  }
+  out.push(`- ${i18n.soundElementsLabel()}: ${name.sounds.map((s) => `${s.syllable} ${i18n.elementLabel(s.element)}`).join(', ')}`);
+  const fills = check.supplied.length > 0 ? check.supplied.map((e) => i18n.elementLabel(e)).join(', ') : i18n.noneLabel();
+  const still = check.unsupplied.length > 0 ? ` (${i18n.nameUnsuppliedLabel()}: ${check.unsupplied.map((e) => i18n.elementLabel(e)).join(', ')})` : '';
+  out.push(`- ${i18n.nameChartLabel()}: ${i18n.nameSuppliesLabel()} ${fills}${still} | `
+    + `${i18n.yongshinLabel()} ${i18n.presenceLabel(check.hasYongshin)} | ${i18n.gishinLabel()} ${i18n.presenceLabel(check.hasGishin)}`);
+  out.push('');
+}
+
+/** 용신·희신 오행의 생활 개운 대응(색상·방위·숫자·계절)을 출력한다 */
+function renderGaeun(out: string[], yongshin: YongshinResult, table: Readonly<gaeun.GaeunTable>, i18n: Localizer): void {
+  out.push(i18n.gaeunHeading());
+  const roles: [string, Element][] = [
+    [i18n.yongshinLabel(), yongshin.yongshin],
+    [i18n.heeshinLabel(), yongshin.heeshin],
+  ];
+  for (const [role, element] of roles) {
+    const entry = table[element];
+    out.push(`- ${role} ${i18n.elementLabel(element)}: `
+      + `${i18n.colorsLabel()} ${entry.colors[i18n.lang].join(', ')} | `
+      + `${i18n.directionsLabel()} ${entry.directions[i18n.lang].join(', ')} | `
+      + `${i18n.numbersLabel()} ${entry.numbers.join(', ')} | `
+      + `${i18n.seasonsLabel()} ${entry.seasons[i18n.lang].join(', ')}`);
+  }
+  out.push('');
+}
+
+/** 운 기둥 줄 끝에 붙이는 원국 대비 주석 (원진·귀문 쌍, 공망) */
+export function luckAnnotation(pillar: Pillar, natal: Pillar[], i18n: Localizer): string {
+  const notes = shinsal.luckBranchPairs(natal, pillar.branch)
+    .map((p) => `${i18n.shinsalKindLabel(p.kind)} ${p.positions.map((pos) => i18n.positionLabel(pos)).join('·')}`);
+  const gm = shinsal.luckGongmang(natal, pillar);
+  if (gm.luckVoided) notes.push(i18n.shinsalKindLabel('GongMang'));
+  if (gm.natalVoided.length > 0) {
+    notes.push(i18n.natalVoidedLabel(gm.natalVoided.map((pos) => i18n.positionLabel(pos)).join('·')));
+  }
+  return notes.length > 0 ? ` | ${notes.join(', ')}` : '';
+}
+
+/** 원국 지지 사이의 원진·귀문 쌍을 출력한다 */
+function renderBranchPairs(out: string[], natal: Pillar[], i18n: Localizer): void {
+  const pairs = shinsal.natalBranchPairs(natal);
+  out.push(i18n.branchPairsHeading());
+  if (pairs.length === 0) out.push(`- ${i18n.noneLabel()}`);
+  for (const pair of pairs) {
+    const [a, b] = pair.positions;
+    const label = (pos: PillarPosition) => `${i18n.positionLabel(pos)} ${i18n.branchLabel(natal[POSITION_INDEX[pos]].branch)}`;
+    out.push(`- ${i18n.shinsalKindLabel(pair.kind)}: ${label(a)} – ${label(b)}`);
+  }
+  out.push('');
+}
+
+/** 일지(배우자궁)의 십성·운성·신살과 다른 지지와의 관계를 출력한다 */
+function renderDayBranch(out: string[], summary: dayBranch.DayBranchSummary, natal: Pillar[], i18n: Localizer): void {
+  const other = (positions: PillarPosition[]) => positions
+    .filter((pos) => pos !== 'Day')
+    .map((pos) => `${i18n.positionLabel(pos)} ${i18n.branchLabel(natal[POSITION_INDEX[pos]].branch)}`)
+    .join('·');
+  const relations = [
+    ...summary.interactions.map((r) => `${i18n.branchRelationLabel(r.relation)} ${other(r.positions)}`),
+    ...summary.pairs.map((pair) => `${i18n.shinsalKindLabel(pair.kind)} ${other(pair.positions)}`),
+  ];
+  out.push(i18n.dayBranchHeading(i18n.branchLabel(summary.branch)));
+  out.push(`- ${i18n.tenGodsLabel()} ${i18n.tenGodLabel(summary.tenGod)} / ${i18n.twelveStagesLabel()} ${i18n.stageLabel(summary.stage)} / ${i18n.twelveShinsalLabel()} ${i18n.shinsalLabel(summary.twelveShinsal)}`);
+  out.push(`- ${i18n.dayBranchRelationsLabel()}: ${relations.length > 0 ? relations.join(' / ') : i18n.noneLabel()}`);
+  out.push(`- ${i18n.keyShinsalLabel()}: ${summary.shinsal.length > 0 ? summary.shinsal.map((k) => i18n.shinsalKindLabel(k)).join(', ') : i18n.noneLabel()}`);
+  out.push('');
+}
+
+/** 이웃한 천간합과 합화 성립 여부(월령·쟁합·극신)를 출력한다 */
+function renderStemCombinations(out: string[], combos: StemCombination[], i18n: Localizer): void {
+  if (combos.length === 0) return;
+  out.push(i18n.stemCombinationsHeading());
+  for (const c of combos) {
+    const members = c.positions.map((pos, i) => `${i18n.positionLabel(pos)} ${i18n.stemLabel(c.stems[i])}`).join(' – ');
+    const reasons = [i18n.monthSupportLabel(c.monthSupport)];
+    if (c.contested) reasons.push(i18n.contestedLabel());
+    if (c.obstructed) reasons.push(i18n.obstructedLabel());
+    const hwaGi = c.transformed && c.positions.includes('Day') ? ` (${i18n.gyeokgukLabel('HwaGi')})` : '';
+    out.push(`- ${members} → ${i18n.elementLabel(c.element)}: ${i18n.transformedLabel(c.transformed)}${hwaGi} [${reasons.join(', ')}]`);
+  }
+  out.push('');
+}
+
+/** 삼합·방합 국(완전·반합)과 국의 오행을 출력한다 */
+function renderCombinations(out: string[], combos: BranchCombination[], i18n: Localizer): void {
+  if (combos.length === 0) return;
+  out.push(i18n.combinationsHeading());
+  for (const c of combos) {
+    const members = c.positions.map((pos, i) => `${i18n.positionLabel(pos)} ${i18n.branchLabel(c.branches[i])}`).join(' – ');
+    out.push(`- ${i18n.branchRelationLabel(c.relation)} ${i18n.completeLabel(c.complete)}: ${members} → ${i18n.elementLabel(c.element)}`);
+  }
+  out.push('');
+}
+
+/** 대운을 출력한다 */
+function renderDaewon(
+  out: string[], direction: Direction, directionNote: string, startSpan: DaewonStartSpan, rounding: luck.DaewonRounding,
+  items: luck.DaewonItem[], natal: Pillar[], i18n: Localizer, startNote: (item: luck.DaewonItem) => string,
+): void {
+  const dayStem = natal[2].stem;
+  out.push(`${i18n.daewonHeading()} (${i18n.directionLabel(direction)}${directionNote} , ${i18n.startLabel()} ${i18n.formatSpan(startSpan)}, ${i18n.roundingLabel(rounding)})`);
+  for (const item of items) {
+    out.push(`- ${i18n.formatAge(item.startMonths, true)}${startNote(item)}: ${i18n.pillarLabel(item.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, item.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, item.pillar.branch))}${luckAnnotation(item.pillar, natal, i18n)}`);
+  }
+  out.push('');
+}
+
+/** 성별 미상일 때 순행·역행 대운을 나란히 출력한다 */
+function renderDaewonCandidates(out: string[], candidates: luck.DaewonSequence[], dayStem: number, i18n: Localizer): void {
+  const cell = (item: luck.DaewonItem | undefined): string => (item
+    ? `${i18n.formatAge(item.startMonths, true)} ${i18n.pillarLabel(item.pillar)} ${i18n.tenGodLabel(bazi.tenGod(dayStem, item.pillar.stem))}`
+    : '');
+  const rows: string[][] = [
+    candidates.map((c) => `${i18n.directionLabel(c.direction)} (${i18n.startLabel()} ${i18n.formatSpan(c.startSpan)})`),
+  ];
+  const length = Math.max(...candidates.map((c) => c.items.length));
+  for (let i = 0; i < length; i++) {
+    rows.push(candidates.map((c) => cell(c.items[i])));
+  }
+  out.push(i18n.daewonHeading());
+  out.push(`- ${i18n.daewonBothDirectionsNote()}`);
+  out.push(...alignColumns(rows, 4).map((line) => `  ${line}`));
+  out.push('');
+}
+
+/** 세운(연운)을 출력한다 */
+function renderYearlyLuck(
+  out: string[], years: luck.YearLuck[], natal: Pillar[], tzSpec: TimeZoneSpec, i18n: Localizer, jdNote: (jd: number) => string,
+): void {
+  out.push(i18n.yearlyLuckHeading());
+  for (const y of years) out.push(formatYearLuck(y, natal, tzSpec, i18n, jdNote));
+  out.push('');
+}
+
+/** 세운 한 줄 */
+function formatYearLuck(y: luck.YearLuck, natal: Pillar[], tzSpec: TimeZoneSpec, i18n: Localizer, jdNote: (jd: number) => string): string {
+  const dayStem = natal[2].stem;
+  const startLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(y.startJd));
+  const endLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(y.endJd));
+  return `- ${i18n.formatYearLabel(y.year)}: ${startLocal.format('YYYY-MM-DD HH:mm')}${jdNote(y.startJd)} ~ ${endLocal.format('YYYY-MM-DD HH:mm')}${jdNote(y.endJd)} | ${i18n.pillarLabel(y.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, y.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, y.pillar.branch))}${luckAnnotation(y.pillar, natal, i18n)}`;
+}
+
+/** 세운을 소속 대운별로 묶어 출력한다 */
+function renderGroupedYearlyLuck(
+  out: string[], groups: luck.YearLuckGroup[], natal: Pillar[], tzSpec: TimeZoneSpec, i18n: Localizer, jdNote: (jd: number) => string,
+): void {
+  out.push(i18n.yearlyLuckHeading());
+  for (const group of groups) {
+    out.push(group.daewon
+      ? `[${i18n.daewonHeading()} ${i18n.formatAge(group.daewon.startMonths, false)} ${i18n.pillarLabel(group.daewon.pillar)}]`
+      : `[${i18n.beforeDaewonLabel()}]`);
+    for (const y of group.years) out.push(formatYearLuck(y, natal, tzSpec, i18n, jdNote));
+  }
+  out.push('');
+}
+
+/** 월운을 출력한다 */
+function renderMonthlyLuck(
+  out: string[], monthly: luck.MonthlyLuck, natal: Pillar[], tzSpec: TimeZoneSpec, i18n: Localizer, jdNote: (jd: number) => string,
+): void {
+  const dayStem = natal[2].stem;
+  out.push(i18n.monthlyLuckHeading(monthly.year));
+  out.push(`- ${i18n.yearLuckLabel()}: ${i18n.pillarLabel(monthly.yearPillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, monthly.yearPillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, monthly.yearPillar.branch))}`);
+  for (const m of monthly.months) {
+    const startLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(m.startJd));
+    const endLocal = timezone.toLocal(tzSpec, astro.datetimeFromJd(m.endJd));
+    out.push(`- ${i18n.monthLabel(m.branch)}: ${startLocal.format('YYYY-MM-DD HH:mm')}${jdNote(m.startJd)} ~ ${endLocal.format('YYYY-MM-DD HH:mm')}${jdNote(m.endJd)} | ${i18n.pillarLabel(m.pillar)} | ${i18n.tenGodsLabel()}: ${i18n.stemsLabel()} ${i18n.tenGodLabel(bazi.tenGod(dayStem, m.pillar.stem))}, ${i18n.branchesLabel()} ${i18n.tenGodLabel(bazi.tenGodBranch(dayStem, m.pillar.branch))}${luckAnnotation(m.pillar, natal, i18n)}`);
+  }
+  out.push('');
+}
+
+/** 24절기를 출력한다 */
+function renderTerms(out: string[], tzSpec: TimeZoneSpec, terms: SolarTerm[], i18n: Localizer, jdNote: (jd: number) => string): void {
+  out.push(`${i18n.termsHeading()} (${timezone.tzName(tzSpec)} ${i18n.tzLabel()})`);
+  for (const term of terms) {
+    const utcDate = astro.datetimeFromJd(term.jd);
+    const local = timezone.toLocal(tzSpec, utcDate);
+    out.push(`- ${i18n.termName(term.def)}: ${local.format('YYYY-MM-DD HH:mm:ss')}${jdNote(term.jd)}`);
+  }
+  out.push('');
+}
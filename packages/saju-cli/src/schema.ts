@@ -0,0 +1,21 @@
+/**
+ * @fileoverview 구조화 출력의 스키마 버전
+ *
+ * JSON 출력(각 서브커맨드의 --json, stats 줄, --format llm, daemon 응답)은 schema_version을 함께 싣는다.
+ * 같은 버전 안에서는 필드를 추가만 한다. 이미 낸 필드의 이름·의미·타입을 바꾸거나 지우면
+ * SCHEMA_VERSION을 올린다. __tests__/schema.test.ts가 버전마다 고정한 필드 목록이
+ * 출력에 그대로 남아 있는지 검사하므로, 추가는 통과하고 변경·삭제는 실패한다.
+ *
+ * --format json 문서의 모양은 `saju schema`가 JSON Schema로 출력한다 (jsonSchema.ts).
+ *
+ * 최상위가 배열인 출력(locations --json, ganji --find --json)은 모양을 바꾸지 않으려고
+ * 버전을 싣지 않는다.
+ */
+
+/** 구조화 출력의 스키마 버전 (호환되지 않는 변경마다 1씩 올린다) */
+export const SCHEMA_VERSION = 2;
+
+/** JSON 객체 출력 앞에 schema_version을 붙인다 */
+export function versioned<T extends object>(body: T): { schema_version: number } & T {
+  return { schema_version: SCHEMA_VERSION, ...body };
+}
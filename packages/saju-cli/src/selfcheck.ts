@@ -0,0 +1,156 @@
+/**
+ * @fileoverview `saju selfcheck` 서브커맨드 — 내장 표·천문 계산 자체 검증
+ *
+ * 외부 기준 자료 없이 계산 결과끼리의 일관성을 확인한다:
+ * - 양력 → 음력 → 양력 왕복 변환이 원래 날짜로 돌아오는지
+ * - 24절기가 정의 순서대로 단조 증가하고 간격이 정상인지
+ * - 날짜가 하루 늘 때마다 일주가 60갑자에서 한 칸씩 나아가는지
+ * - 절입 직전·직후의 월지가 절기와 맞는지
+ *
+ * 표를 확장하거나 계산식을 바꾼 뒤 회귀 확인용으로 돌린다.
+ *
+ * 사용 예:
+ *   saju selfcheck --years 1900..2100
+ */
+
+import { astro, bazi, lunar, type LunarDate } from 'saju-lib';
+
+/** 검증 항목 */
+export type SelfCheckKind = 'LunarRoundTrip' | 'TermOrder' | 'DayCycle' | 'MonthTerm';
+
+export const SELF_CHECK_KINDS: readonly SelfCheckKind[] = ['LunarRoundTrip', 'TermOrder', 'DayCycle', 'MonthTerm'];
+
+/** 불일치 하나 */
+export interface SelfCheckIssue {
+  kind: SelfCheckKind;
+  message: string;
+}
+
+/** 검증 결과 */
+export interface SelfCheckReport {
+  from: number;
+  to: number;
+  /** 항목별 검사 건수 */
+  checked: Record<SelfCheckKind, number>;
+  issues: SelfCheckIssue[];
+}
+
+/** 절기 간격 허용 범위 (일). 근일점·원일점 부근에서 14.7~15.7일 */
+const TERM_GAP_DAYS: readonly [number, number] = [14, 17];
+
+/** 음력 표 범위 밖을 뜻하는 변환 오류 */
+const OUT_OF_RANGE: readonly lunar.LunarErrorCode[] = ['LUNAR_YEAR_RANGE', 'SOLAR_BEFORE_RANGE', 'SOLAR_AFTER_RANGE'];
+
+/** 절입 전후 월지 확인에 쓰는 간격 (1분) */
+const BOUNDARY_EPSILON = 1 / 1440;
+
+/**
+ * 지정 연도 범위의 자체 검증을 실행한다.
+ *
+ * @param from 첫 연도
+ * @param to 마지막 연도 (포함)
+ * @param onYear 한 해의 검사를 마칠 때마다 호출 (진행 표시용)
+ */
+export function runSelfCheck(from: number, to: number, onYear?: (year: number) => void): SelfCheckReport {
+  const report: SelfCheckReport = {
+    from,
+    to,
+    checked: { LunarRoundTrip: 0, TermOrder: 0, DayCycle: 0, MonthTerm: 0 },
+    issues: [],
+  };
+  for (let year = from; year <= to; year++) {
+    checkLunarRoundTrip(year, report);
+    checkTermOrder(year, report);
+    checkDayCycle(year, report);
+    checkMonthTerms(year, report);
+    onYear?.(year);
+  }
+  return report;
+}
+
+/** 검증 결과를 텍스트 줄로 표시한다 */
+export function formatSelfCheck(report: SelfCheckReport): string[] {
+  const lines = [`selfcheck ${report.from}..${report.to}`];
+  for (const kind of SELF_CHECK_KINDS) {
+    const count = report.issues.filter((issue) => issue.kind === kind).length;
+    lines.push(`${kind}: ${report.checked[kind]} checked, ${count} issue${count === 1 ? '' : 's'}`);
+  }
+  for (const issue of report.issues) lines.push(`- [${issue.kind}] ${issue.message}`);
+  lines.push(report.issues.length === 0 ? 'OK' : `FAILED (${report.issues.length})`);
+  return lines;
+}
+
+function checkLunarRoundTrip(year: number, report: SelfCheckReport): void {
+  for (const date of daysOfYear(year)) {
+    let l: LunarDate;
+    try {
+      l = lunar.solarToLunar(date);
+    } catch (err: unknown) {
+      // 음력 표 범위 밖은 검사 대상이 아니다
+      if (lunar.isLunarConversionError(err) && OUT_OF_RANGE.includes(err.code)) continue;
+      throw err;
+    }
+    report.checked.LunarRoundTrip++;
+    const back = lunar.lunarToSolar(l.year, l.month, l.day, l.isLeap);
+    if (back.getTime() !== date.getTime()) {
+      const label = `${l.year}-${l.month}-${l.day}${l.isLeap ? ' (leap)' : ''}`;
+      report.issues.push({ kind: 'LunarRoundTrip', message: `${formatDate(date)} → lunar ${label} → ${formatDate(back)}` });
+    }
+  }
+}
+
+function checkTermOrder(year: number, report: SelfCheckReport): void {
+  const terms = [...astro.computeSolarTerms(year), astro.computeSolarTerms(year + 1)[0]];
+  for (let i = 0; i < 24; i++) {
+    report.checked.TermOrder++;
+    const [curr, next] = [terms[i], terms[i + 1]];
+    if (curr.def.key !== astro.TERM_DEFS[i].key) {
+      report.issues.push({ kind: 'TermOrder', message: `${year}: term #${i} is ${curr.def.key}, expected ${astro.TERM_DEFS[i].key}` });
+    }
+    const gap = next.jd - curr.jd;
+    if (gap < TERM_GAP_DAYS[0] || gap > TERM_GAP_DAYS[1]) {
+      report.issues.push({ kind: 'TermOrder', message: `${year}: ${curr.def.key} → ${next.def.key} gap ${gap.toFixed(3)} days` });
+    }
+  }
+}
+
+function checkDayCycle(year: number, report: SelfCheckReport): void {
+  let prev: { jdn: number; index: number } | null = null;
+  for (const date of daysOfYear(year)) {
+    report.checked.DayCycle++;
+    const jdn = bazi.jdnFromDate(date.getUTCFullYear(), date.getUTCMonth() + 1, date.getUTCDate());
+    const [stem, branch] = bazi.dayPillarFromJdn(jdn);
+    const index = bazi.sexagenaryIndex({ stem, branch });
+    if (prev && (jdn !== prev.jdn + 1 || index !== (prev.index + 1) % 60)) {
+      report.issues.push({ kind: 'DayCycle', message: `${formatDate(date)}: jdn ${jdn}, cycle ${index} after jdn ${prev.jdn}, cycle ${prev.index}` });
+    }
+    prev = { jdn, index };
+  }
+}
+
+function checkMonthTerms(year: number, report: SelfCheckReport): void {
+  for (const term of astro.computeSolarTerms(year)) {
+    const branch = bazi.monthBranchFromTermKey(term.def.key);
+    if (branch === null) continue;
+    report.checked.MonthTerm++;
+    const before = bazi.monthBranchForBirth(term.jd - BOUNDARY_EPSILON);
+    const after = bazi.monthBranchForBirth(term.jd + BOUNDARY_EPSILON);
+    if (after !== branch || before !== (branch + 11) % 12) {
+      report.issues.push({ kind: 'MonthTerm', message: `${year} ${term.def.key}: month branch ${before} → ${after}, expected ${(branch + 11) % 12} → ${branch}` });
+    }
+  }
+}
+
+/** 한 해의 양력 날짜 (UTC 자정) */
+function* daysOfYear(year: number): Generator<Date> {
+  const date = new Date(Date.UTC(year, 0, 1));
+  date.setUTCFullYear(year);
+  while (date.getUTCFullYear() === year) {
+    yield new Date(date.getTime());
+    date.setUTCDate(date.getUTCDate() + 1);
+  }
+}
+
+function formatDate(date: Date): string {
+  return date.toISOString().slice(0, 10);
+}
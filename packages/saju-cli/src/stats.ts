@@ -0,0 +1,120 @@
+/**
+ * @fileoverview 일괄 처리(--batch --stats) 집계
+ *
+ * 레코드마다 명식의 요약(일간, 오행 개수, 강약, 성별, 대운 방향)을 모아
+ * 데이터셋 전체의 분포를 낸다. 연구용 통계와, 알려진 기저 비율(예: 일간이 고르게 나오는지,
+ * 양남음녀 순행 비율)로 엔진을 점검하는 데 쓴다.
+ *
+ * 요약은 작업자 스레드에서 부모 스레드로 보내므로 직렬화할 수 있는 값만 담는다.
+ */
+
+import {
+  bazi,
+  type Direction,
+  type Element,
+  type Gender,
+  I18n,
+  type SajuResult,
+  type StrengthLevel,
+} from 'saju-lib';
+
+/** 명식 하나의 집계용 요약 */
+export interface ChartSample {
+  dayStem: number;
+  /** 오행별 개수 (목화토금수 순, 합화 반영) */
+  elements: number[];
+  level: StrengthLevel;
+  gender: Gender | null;
+  direction: Direction | null;
+}
+
+/** 데이터셋 분포 */
+export interface BatchStats {
+  /** 집계한 명식 수 */
+  total: number;
+  /** 일간(천간 인덱스)별 명식 수 */
+  dayMasters: number[];
+  /** 오행별로, 개수 k(0~8)인 명식 수 */
+  elementHistogram: Record<Element, number[]>;
+  /** 강약 판정별 명식 수 */
+  strength: Record<StrengthLevel, number>;
+  /** 성별별 대운 방향 수 (성별이 없으면 빠진다) */
+  daewonDirection: Record<Gender, Record<Direction, number>>;
+}
+
+const ELEMENTS: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+const LEVELS: readonly StrengthLevel[] = ['VeryStrong', 'Strong', 'Neutral', 'Weak', 'VeryWeak'];
+const GENDERS: readonly Gender[] = ['Male', 'Female'];
+
+/** 계산 결과에서 집계용 요약을 뽑는다 */
+export function chartSample(result: SajuResult): ChartSample {
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar];
+  return {
+    dayStem: result.dayPillar.stem,
+    elements: [...bazi.elementsCount(natal, result.elementOverrides)],
+    level: result.strength.level,
+    gender: result.gender,
+    direction: result.daewonDirection,
+  };
+}
+
+/** 요약 목록을 분포로 모은다 */
+export function aggregateStats(samples: readonly ChartSample[]): BatchStats {
+  const stats: BatchStats = {
+    total: samples.length,
+    dayMasters: Array(10).fill(0),
+    elementHistogram: Object.fromEntries(ELEMENTS.map((e) => [e, Array(9).fill(0)])) as Record<Element, number[]>,
+    strength: Object.fromEntries(LEVELS.map((l) => [l, 0])) as Record<StrengthLevel, number>,
+    daewonDirection: {
+      Male: { Forward: 0, Backward: 0 },
+      Female: { Forward: 0, Backward: 0 },
+    },
+  };
+  for (const s of samples) {
+    stats.dayMasters[s.dayStem]++;
+    ELEMENTS.forEach((e, i) => stats.elementHistogram[e][Math.min(s.elements[i], 8)]++);
+    stats.strength[s.level]++;
+    if (s.gender !== null && s.direction !== null) stats.daewonDirection[s.gender][s.direction]++;
+  }
+  return stats;
+}
+
+function percent(count: number, total: number): string {
+  return total === 0 ? '0%' : `${Math.round((count / total) * 1000) / 10}%`;
+}
+
+/** 분포의 JSON 출력 (필드 이름은 snake_case로 고정, 일간 배열은 천간 인덱스 순) */
+export function statsJson(stats: BatchStats) {
+  return {
+    total: stats.total,
+    day_masters: stats.dayMasters,
+    element_histogram: stats.elementHistogram,
+    strength: stats.strength,
+    daewon_direction: stats.daewonDirection,
+  };
+}
+
+/** 분포를 텍스트 줄로 표시한다 (개수와 비율) */
+export function formatStats(stats: BatchStats, i18n: I18n): string[] {
+  const { total } = stats;
+  const out = [`${i18n.batchStatsHeading()} (n=${total})`];
+  out.push(`- ${i18n.dayStemWord()}: ${stats.dayMasters
+    .map((n, stem) => `${i18n.stemLabel(stem)} ${n} (${percent(n, total)})`)
+    .join(', ')}`);
+  for (const e of ELEMENTS) {
+    const bins = stats.elementHistogram[e];
+    const last = bins.reduce((max, n, k) => (n > 0 ? k : max), 0);
+    out.push(`- ${i18n.elementLabel(e)}: ${bins.slice(0, last + 1).map((n, k) => `${k}:${n}`).join(' ')}`);
+  }
+  out.push(`- ${i18n.strengthHeading()}: ${LEVELS
+    .map((l) => `${i18n.strengthLevelLabel(l)} ${stats.strength[l]} (${percent(stats.strength[l], total)})`)
+    .join(', ')}`);
+  for (const g of GENDERS) {
+    const d = stats.daewonDirection[g];
+    const sum = d.Forward + d.Backward;
+    out.push(`- ${i18n.daewonHeading()} ${i18n.genderValue(g)}: `
+      + `${i18n.directionLabel('Forward')} ${d.Forward} (${percent(d.Forward, sum)}), `
+      + `${i18n.directionLabel('Backward')} ${d.Backward} (${percent(d.Backward, sum)})`);
+  }
+  return out;
+}
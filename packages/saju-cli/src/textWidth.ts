@@ -0,0 +1,148 @@
+/**
+ * @fileoverview 터미널 표시 폭 계산
+ *
+ * 한글·한자 등 동아시아 전각 문자는 터미널에서 2칸을 차지하므로
+ * `String.length` 대신 표시 폭 기준으로 정렬해야 열이 맞는다.
+ */
+
+/** ANSI SGR 이스케이프 시퀀스 (색상 코드) */
+const ANSI_PATTERN = /\x1b\[[0-9;]*m/g
+
+/** 전각(2칸) 문자 여부 */
+function isWide(code: number): boolean {
+  return (
+    (code >= 0x1100 && code <= 0x115f) ||   // 한글 자모
+    (code >= 0x2e80 && code <= 0x303e) ||   // CJK 부수·기호
+    (code >= 0x3041 && code <= 0x33ff) ||   // 가나·CJK 호환
+    (code >= 0x3400 && code <= 0x4dbf) ||   // CJK 확장 A
+    (code >= 0x4e00 && code <= 0x9fff) ||   // CJK 통합 한자
+    (code >= 0xa960 && code <= 0xa97f) ||   // 한글 자모 확장 A
+    (code >= 0xac00 && code <= 0xd7a3) ||   // 한글 음절
+    (code >= 0xf900 && code <= 0xfaff) ||   // CJK 호환 한자
+    (code >= 0xfe30 && code <= 0xfe4f) ||   // CJK 호환 형태
+    (code >= 0xff00 && code <= 0xff60) ||   // 전각 ASCII
+    (code >= 0xffe0 && code <= 0xffe6) ||
+    (code >= 0x20000 && code <= 0x3fffd)    // CJK 확장 B 이후
+  )
+}
+
+/**
+ * 문자열의 터미널 표시 폭을 계산한다 (ANSI 색상 코드는 0칸).
+ * @param text 대상 문자열
+ */
+export function displayWidth(text: string): number {
+  let width = 0
+  for (const ch of text.replace(ANSI_PATTERN, '')) {
+    width += isWide(ch.codePointAt(0) ?? 0) ? 2 : 1
+  }
+  return width
+}
+
+/**
+ * 표시 폭 기준으로 오른쪽을 공백으로 채운다.
+ * @param text 대상 문자열
+ * @param width 목표 표시 폭
+ */
+export function padEndDisplay(text: string, width: number): string {
+  return text + ' '.repeat(Math.max(0, width - displayWidth(text)))
+}
+
+/**
+ * 2차원 셀 배열을 열 폭에 맞춰 정렬된 줄 목록으로 만든다.
+ * 각 열의 폭은 해당 열에서 가장 넓은 셀 기준이며, 줄 끝 공백은 제거한다.
+ * @param rows 행 목록 (각 행은 셀 문자열 배열)
+ * @param gap 열 사이 공백 수
+ */
+export function alignColumns(rows: string[][], gap = 2): string[] {
+  const widths: number[] = []
+  for (const row of rows) {
+    row.forEach((cell, i) => {
+      widths[i] = Math.max(widths[i] ?? 0, displayWidth(cell))
+    })
+  }
+  return rows.map((row) =>
+    row.map((cell, i) => padEndDisplay(cell, widths[i] + gap)).join('').trimEnd(),
+  )
+}
+
+/** 줄바꿈된 이어지는 줄의 들여쓰기 */
+const CONTINUATION_INDENT = '  '
+
+/** 한자가 들어 있는 괄호 표기 (예: '(比肩)', ' (甲子)') */
+const HANJA_PAREN_PATTERN = /\s?\([^()]*[一-鿿][^()]*\)/g
+
+/**
+ * 괄호 안의 한자 병기를 제거하여 레이블을 줄인다.
+ * 예: '비견(比肩)' → '비견', 'Companion (比肩)' → 'Companion'
+ * 여러 번 적용해도 결과가 같다.
+ * @param text 대상 문자열
+ */
+export function abbreviateLabels(text: string): string {
+  return text.replace(HANJA_PAREN_PATTERN, '')
+}
+
+/** ANSI 색상 코드 하나 또는 문자 하나 (색상 코드 중간에서 자르지 않도록) */
+const BREAK_UNIT_PATTERN = /\x1b\[[0-9;]*m|[\s\S]/gu
+
+/** 표시 폭 기준으로 문자열을 강제로 자른다 (구분자가 없는 긴 조각용) */
+function breakByWidth(text: string, width: number): string[] {
+  const parts: string[] = []
+  let current = ''
+  for (const ch of text.match(BREAK_UNIT_PATTERN) ?? []) {
+    if (current && displayWidth(current + ch) > width) {
+      parts.push(current)
+      current = ''
+    }
+    current += ch
+  }
+  if (current) parts.push(current)
+  return parts
+}
+
+/**
+ * 한 줄을 표시 폭 안에 들어오도록 나눈다.
+ * ' / ', ' | ', ', ' 구분자 뒤에서 우선 끊고, 이어지는 줄은 들여쓴다.
+ * @param line 대상 줄
+ * @param width 최대 표시 폭
+ */
+export function wrapLine(line: string, width: number): string[] {
+  if (displayWidth(line) <= width) return [line]
+  const segments = line.split(/(?<= \/ | \| |, )/)
+  const lines: string[] = []
+  let current = ''
+  for (const seg of segments) {
+    if (current.trim() && displayWidth(current + seg.trimEnd()) > width) {
+      lines.push(current.trimEnd())
+      current = CONTINUATION_INDENT + seg.trimStart()
+    } else {
+      current += seg
+    }
+    while (displayWidth(current.trimEnd()) > width) {
+      const [head, ...rest] = breakByWidth(current, width)
+      lines.push(head.trimEnd())
+      current = CONTINUATION_INDENT + rest.join('')
+    }
+  }
+  if (current.trim()) lines.push(current.trimEnd())
+  return lines
+}
+
+/** 터미널 출력 맞춤 옵션 */
+export interface FitOptions {
+  /** 최대 표시 폭 (null이면 줄바꿈하지 않음) */
+  width: number | null
+  /** 한자 병기 등을 생략하는 축약 모드 */
+  compact: boolean
+}
+
+/**
+ * 리포트 줄 목록을 축약·줄바꿈하여 터미널 폭에 맞춘다.
+ * @param lines 원본 줄 목록
+ * @param options 맞춤 옵션
+ */
+export function fitToWidth(lines: string[], options: FitOptions): string[] {
+  const abbreviated = options.compact ? lines.map(abbreviateLabels) : lines
+  if (options.width == null) return abbreviated
+  const width = options.width
+  return abbreviated.flatMap((line) => wrapLine(line, width))
+}
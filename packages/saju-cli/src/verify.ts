@@ -0,0 +1,175 @@
+/**
+ * @fileoverview `saju verify` 서브커맨드 — 한국천문연구원(KASI) 기준 자료와 대조
+ *
+ * 절기 시각과 음력 날짜를 기준 자료와 비교해 최대 오차를 보고한다.
+ * 기본으로 KASI 월력요항에서 발췌한 소량의 표본을 내장하며,
+ * 같은 형식의 JSON 파일(--data)로 더 많은 자료를 대조할 수 있다.
+ *
+ * 기준 자료 형식:
+ *   {
+ *     "terms": [{ "key": "lichun", "kst": "2024-02-04T17:27" }],
+ *     "lunar": [{ "solar": "2024-02-10", "lunar": { "year": 2024, "month": 1, "day": 1, "leap": false } }]
+ *   }
+ *
+ * 사용 예:
+ *   saju verify
+ *   saju verify --data kasi-1900-2100.json --json
+ */
+
+import { astro, lunar } from 'saju-lib';
+
+/** 절기 기준값 (한국 표준시, 분 단위) */
+export interface ReferenceTerm {
+  /** 절기 키 (astro.TERM_DEFS의 key) */
+  key: string;
+  /** 절입 시각 YYYY-MM-DDTHH:MM (KST) */
+  kst: string;
+}
+
+/** 음력 날짜 기준값 */
+export interface ReferenceLunarDate {
+  /** 양력 날짜 YYYY-MM-DD */
+  solar: string;
+  lunar: { year: number; month: number; day: number; leap: boolean };
+}
+
+/** 대조할 기준 자료 */
+export interface ReferenceData {
+  terms: ReferenceTerm[];
+  lunar: ReferenceLunarDate[];
+}
+
+/**
+ * 내장 표본 (KASI 월력요항 발췌).
+ * 2024년 이분이지, 2023~2025년 입춘, 설날·추석과 윤달 첫날.
+ */
+export const KASI_SAMPLE: ReferenceData = {
+  terms: [
+    { key: 'lichun', kst: '2023-02-04T11:42' },
+    { key: 'lichun', kst: '2024-02-04T17:27' },
+    { key: 'chunfen', kst: '2024-03-20T12:06' },
+    { key: 'xiazhi', kst: '2024-06-21T05:51' },
+    { key: 'qiufen', kst: '2024-09-22T21:44' },
+    { key: 'dongzhi', kst: '2024-12-21T18:21' },
+    { key: 'lichun', kst: '2025-02-03T23:10' },
+  ],
+  lunar: [
+    { solar: '2000-02-05', lunar: { year: 2000, month: 1, day: 1, leap: false } },
+    { solar: '2020-01-25', lunar: { year: 2020, month: 1, day: 1, leap: false } },
+    { solar: '2020-05-23', lunar: { year: 2020, month: 4, day: 1, leap: true } },
+    { solar: '2023-01-22', lunar: { year: 2023, month: 1, day: 1, leap: false } },
+    { solar: '2023-03-22', lunar: { year: 2023, month: 2, day: 1, leap: true } },
+    { solar: '2023-09-29', lunar: { year: 2023, month: 8, day: 15, leap: false } },
+    { solar: '2024-02-10', lunar: { year: 2024, month: 1, day: 1, leap: false } },
+    { solar: '2024-09-17', lunar: { year: 2024, month: 8, day: 15, leap: false } },
+    { solar: '2025-01-29', lunar: { year: 2025, month: 1, day: 1, leap: false } },
+    { solar: '2025-10-06', lunar: { year: 2025, month: 8, day: 15, leap: false } },
+  ],
+};
+
+/** 절기 하나의 대조 결과 */
+export interface TermDeviation {
+  key: string;
+  kst: string;
+  /** 계산값 − 기준값 (분) */
+  minutes: number;
+}
+
+/** 음력 날짜 하나의 불일치 */
+export interface LunarMismatch {
+  solar: string;
+  expected: string;
+  actual: string;
+}
+
+/** 대조 결과 */
+export interface VerifyReport {
+  terms: TermDeviation[];
+  /** 절기 최대 절대 오차 (분, 절기가 없으면 0) */
+  maxTermMinutes: number;
+  lunarChecked: number;
+  lunarMismatches: LunarMismatch[];
+}
+
+/** KST = UTC+9 */
+const KST_OFFSET_HOURS = 9;
+
+/**
+ * JSON 값을 기준 자료로 검증한다.
+ * @throws TypeError 형식이 맞지 않는 경우
+ */
+export function parseReferenceData(raw: unknown): ReferenceData {
+  if (typeof raw !== 'object' || raw === null || Array.isArray(raw)) {
+    throw new TypeError('reference data must be an object with "terms" and "lunar" arrays');
+  }
+  const { terms = [], lunar: dates = [] } = raw as Record<string, unknown>;
+  if (!Array.isArray(terms) || !Array.isArray(dates)) {
+    throw new TypeError('"terms" and "lunar" must be arrays');
+  }
+  for (const t of terms) {
+    if (typeof t?.key !== 'string' || typeof t?.kst !== 'string' || !/^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}$/.test(t.kst)) {
+      throw new TypeError(`invalid term entry: ${JSON.stringify(t)} (expected { key, kst: "YYYY-MM-DDTHH:MM" })`);
+    }
+  }
+  for (const d of dates) {
+    const l = d?.lunar;
+    if (typeof d?.solar !== 'string' || !/^\d{4}-\d{2}-\d{2}$/.test(d.solar)
+      || !Number.isInteger(l?.year) || !Number.isInteger(l?.month) || !Number.isInteger(l?.day) || typeof l?.leap !== 'boolean') {
+      throw new TypeError(`invalid lunar entry: ${JSON.stringify(d)} (expected { solar, lunar: { year, month, day, leap } })`);
+    }
+  }
+  return { terms: terms as ReferenceTerm[], lunar: dates as ReferenceLunarDate[] };
+}
+
+/** 기준 자료와 계산값을 대조한다 */
+export function verifyReference(data: ReferenceData): VerifyReport {
+  const terms = data.terms.map((ref): TermDeviation => {
+    const [y, mo, d, h, mi] = ref.kst.split(/[-T:]/).map(Number);
+    const refJd = astro.jdFromUtcDate(y, mo, d, h - KST_OFFSET_HOURS, mi, 0);
+    return { key: ref.key, kst: ref.kst, minutes: (astro.termJd(y, ref.key) - refJd) * 1440 };
+  });
+  const lunarMismatches: LunarMismatch[] = [];
+  for (const ref of data.lunar) {
+    const [y, mo, d] = ref.solar.split('-').map(Number);
+    const l = lunar.solarToLunar(new Date(Date.UTC(y, mo - 1, d)));
+    const actual = { year: l.year, month: l.month, day: l.day, leap: l.isLeap };
+    const [expectedLabel, actualLabel] = [formatLunar(ref.lunar), formatLunar(actual)];
+    if (expectedLabel !== actualLabel) {
+      lunarMismatches.push({ solar: ref.solar, expected: expectedLabel, actual: actualLabel });
+    }
+  }
+  return {
+    terms,
+    maxTermMinutes: Math.max(0, ...terms.map((t) => Math.abs(t.minutes))),
+    lunarChecked: data.lunar.length,
+    lunarMismatches,
+  };
+}
+
+/** 대조 결과의 JSON 출력 (필드 이름은 snake_case로 고정) */
+export function verifyJson(report: VerifyReport) {
+  return {
+    terms: report.terms.map((t) => ({ key: t.key, kst: t.kst, minutes: t.minutes })),
+    max_term_minutes: report.maxTermMinutes,
+    lunar_checked: report.lunarChecked,
+    lunar_mismatches: report.lunarMismatches.map((m) => ({ solar: m.solar, expected: m.expected, actual: m.actual })),
+  };
+}
+
+/** 대조 결과를 텍스트 줄로 표시한다 */
+export function formatVerify(report: VerifyReport): string[] {
+  const lines: string[] = [];
+  for (const t of report.terms) {
+    lines.push(`${t.kst} ${t.key}: ${t.minutes >= 0 ? '+' : ''}${t.minutes.toFixed(1)} min`);
+  }
+  lines.push(`terms: ${report.terms.length} checked, max deviation ${report.maxTermMinutes.toFixed(1)} min`);
+  for (const m of report.lunarMismatches) {
+    lines.push(`- ${m.solar}: expected lunar ${m.expected}, got ${m.actual}`);
+  }
+  lines.push(`lunar dates: ${report.lunarChecked} checked, ${report.lunarMismatches.length} mismatched`);
+  return lines;
+}
+
+function formatLunar(l: { year: number; month: number; day: number; leap: boolean }): string {
+  return `${l.year}-${String(l.month).padStart(2, '0')}-${String(l.day).padStart(2, '0')}${l.leap ? ' (leap)' : ''}`;
+}
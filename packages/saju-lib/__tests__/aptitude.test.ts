@@ -0,0 +1,40 @@
+/**
+ * @fileoverview aptitude 모듈 테스트 — 십성 그룹 가중치와 적성 규칙
+ */
+import { describe, it, expect } from 'vitest'
+import { aptitude, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+// 丙午 丙午 甲子 丁卯 — 甲木 일간, 화(식상) 편중
+const FIRE_HEAVY = [p(2, 6), p(2, 6), p(0, 0), p(3, 3)]
+
+describe('relationWeights', () => {
+  it('천간·지장간을 가중 집계하고 월주는 1.5배로 본다', () => {
+    expect(aptitude.relationWeights(FIRE_HEAVY, 0)).toEqual({
+      Same: 1.5,
+      Output: 7.25,
+      Wealth: 1.25,
+      Officer: 0,
+      Resource: 1.5,
+    })
+  })
+})
+
+describe('aptitudeSummary', () => {
+  it('만족한 규칙을 규칙표 순서대로 반환한다', () => {
+    expect(aptitude.aptitudeSummary(FIRE_HEAVY, 0).map((r) => r.id)).toEqual(['output-developed', 'officer-absent'])
+  })
+
+  it('사용자 규칙표를 받을 수 있다', () => {
+    const rules: aptitude.AptitudeRule[] = [{
+      id: 'custom',
+      when: [{ relation: 'Same', min: 1, max: 2 }],
+      tendency: { Ko: '사용자', En: 'custom' },
+      fields: { Ko: [], En: [] },
+    }]
+    expect(aptitude.aptitudeSummary(FIRE_HEAVY, 0, rules).map((r) => r.id)).toEqual(['custom'])
+  })
+})
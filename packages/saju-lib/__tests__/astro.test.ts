@@ -55,3 +55,156 @@ describe('computeSolarTerms', () => {
     }
   })
 })
+
+describe('termJd', () => {
+  it('24절기 전체 계산과 같은 시점을 구한다', () => {
+    for (const year of [1900, 2024, 2100]) {
+      const terms = astro.computeSolarTerms(year)
+      for (const term of terms) {
+        expect(Math.abs(astro.termJd(year, term.def.key) - term.jd)).toBeLessThan(1e-8)
+      }
+    }
+  })
+
+  it('알 수 없는 절기 키는 RangeError', () => {
+    expect(() => astro.termJd(2024, 'nope')).toThrow(RangeError)
+  })
+})
+
+describe('monthBoundaryJd', () => {
+  it('입춘부터 다음 해 입춘까지 절(節) 13개를 차례로 돌려준다', () => {
+    const jie = [...astro.computeSolarTerms(2024), ...astro.computeSolarTerms(2025)]
+      .filter((t) => astro.isMonthBoundaryTerm(t.def))
+    const start = jie.findIndex((t) => t.def.key === 'lichun')
+    for (let month = 0; month <= 12; month++) {
+      expect(Math.abs(astro.monthBoundaryJd(2024, month) - jie[start + month].jd)).toBeLessThan(1e-8)
+    }
+  })
+})
+
+describe('monthIndexAt', () => {
+  it('절월 구간 한가운데서는 termContaining과 같은 달을 돌려준다', () => {
+    for (let month = 0; month < 12; month++) {
+      const mid = (astro.monthBoundaryJd(2024, month) + astro.monthBoundaryJd(2024, month + 1)) / 2
+      expect(astro.monthIndexAt(mid)).toBe(month)
+      expect(astro.termContaining(mid, true).def.key).toBe(astro.TERM_DEFS[(2 + month * 2) % 24].key)
+    }
+  })
+
+  it('절입 시각 바로 앞뒤를 구분한다', () => {
+    const lichun = astro.termJd(2024, 'lichun')
+    expect(astro.monthIndexAt(lichun - 1e-5)).toBe(11)
+    expect(astro.monthIndexAt(lichun + 1e-5)).toBe(0)
+  })
+})
+
+describe('termContaining', () => {
+  it('24절기 구간의 시작·끝이 computeSolarTerms와 같다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    for (let i = 0; i < terms.length - 1; i++) {
+      const mid = (terms[i].jd + terms[i + 1].jd) / 2
+      const period = astro.termContaining(mid)
+      expect(period.def.key).toBe(terms[i].def.key)
+      expect(Math.abs(period.startJd - terms[i].jd)).toBeLessThan(1e-8)
+      expect(Math.abs(period.endJd - terms[i + 1].jd)).toBeLessThan(1e-8)
+    }
+  })
+
+  it('절(節)만 경계로 삼으면 입춘~경칩 구간을 돌려준다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const lichun = terms.find((t) => t.def.key === 'lichun')!
+    const jingzhe = terms.find((t) => t.def.key === 'jingzhe')!
+    const yushui = terms.find((t) => t.def.key === 'yushui')!
+    const period = astro.termContaining(yushui.jd + 1, true)
+    expect(period.def.key).toBe('lichun')
+    expect(Math.abs(period.startJd - lichun.jd)).toBeLessThan(1e-8)
+    expect(Math.abs(period.endJd - jingzhe.jd)).toBeLessThan(1e-8)
+  })
+
+  it('춘분점(0°)을 넘는 구간도 찾는다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const chunfen = terms.find((t) => t.def.key === 'chunfen')!
+    expect(astro.termContaining(chunfen.jd - 1).def.key).toBe('jingzhe')
+    expect(astro.termContaining(chunfen.jd + 1, true).def.key).toBe('jingzhe')
+  })
+
+  it('절(節) 판정', () => {
+    const keys = astro.TERM_DEFS.filter(astro.isMonthBoundaryTerm).map((d) => d.key)
+    expect(keys).toHaveLength(12)
+    expect(keys).toContain('lichun')
+    expect(keys).not.toContain('yushui')
+  })
+})
+
+describe('nextTerm / prevTerm', () => {
+  it('임의 시점의 앞뒤 절기를 찾는다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const qingming = terms.find((t) => t.def.key === 'qingming')!
+    const guyu = terms.find((t) => t.def.key === 'guyu')!
+    const jd = qingming.jd + 3
+    expect(astro.nextTerm(jd).def.key).toBe('guyu')
+    expect(Math.abs(astro.nextTerm(jd).jd - guyu.jd)).toBeLessThan(1e-8)
+    expect(astro.prevTerm(jd).def.key).toBe('qingming')
+    expect(Math.abs(astro.prevTerm(jd).jd - qingming.jd)).toBeLessThan(1e-8)
+  })
+
+  it('절(節)만 대상으로 하면 중기를 건너뛴다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const guyu = terms.find((t) => t.def.key === 'guyu')!
+    expect(astro.nextTerm(guyu.jd + 1, true).def.key).toBe('lixia')
+    expect(astro.prevTerm(guyu.jd + 1, true).def.key).toBe('qingming')
+  })
+
+  it('연말을 넘어 다음 해 소한을 찾는다', () => {
+    const dongzhi = astro.computeSolarTerms(2024).find((t) => t.def.key === 'dongzhi')!
+    const xiaohan = astro.computeSolarTerms(2025).find((t) => t.def.key === 'xiaohan')!
+    const next = astro.nextTerm(dongzhi.jd + 1)
+    expect(next.def.key).toBe('xiaohan')
+    expect(Math.abs(next.jd - xiaohan.jd)).toBeLessThan(1e-8)
+  })
+})
+
+describe('sunApparentLongitude / moonApparentLongitude', () => {
+  /** 0° 부근 각도를 -180~180으로 본 차이 */
+  const angleDiff = (a: number, b: number) => ((a - b + 540) % 360) - 180
+
+  it('2024년 춘분(03-20 03:06 UTC)에 태양 황경이 0°', () => {
+    const jd = astro.jdFromUtcDate(2024, 3, 20, 3, 6, 0)
+    expect(Math.abs(angleDiff(astro.sunApparentLongitude(jd), 0))).toBeLessThan(0.01)
+  })
+
+  it('Meeus 예제 47.a의 달 황경과 일치한다', () => {
+    // 1992-04-12 0h TD: λ = 133.162655°
+    expect(Math.abs(astro.moonApparentLongitude(2448724.5) - 133.162655)).toBeLessThan(0.01)
+  })
+
+  it('삭·망 시각에 이각이 0°·180°', () => {
+    // 2024-01-11 11:57 UTC 삭, 2024-01-25 17:54 UTC 망
+    const newMoon = astro.jdFromUtcDate(2024, 1, 11, 11, 57, 0)
+    const fullMoon = astro.jdFromUtcDate(2024, 1, 25, 17, 54, 0)
+    expect(Math.abs(angleDiff(astro.moonElongation(newMoon), 0))).toBeLessThan(0.1)
+    expect(Math.abs(angleDiff(astro.moonElongation(fullMoon), 180))).toBeLessThan(0.1)
+  })
+})
+
+describe('sunSign', () => {
+  it('황경 30°마다 별자리가 바뀐다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const xiazhi = terms.find((t) => t.def.key === 'xiazhi')!
+    expect(astro.sunSign(xiazhi.jd + 5).sign).toBe('Cancer')
+    expect(astro.sunSign(xiazhi.jd + 5).cusp).toBeNull()
+    expect(astro.sunSign(xiazhi.jd - 5).sign).toBe('Gemini')
+  })
+
+  it('경계 근처면 진입 시점(= 중기)을 함께 준다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const chunfen = terms.find((t) => t.def.key === 'chunfen')!
+    const before = astro.sunSign(chunfen.jd - 0.5)
+    expect(before.sign).toBe('Pisces')
+    expect(before.cusp?.sign).toBe('Aries')
+    expect(Math.abs(before.cusp!.jd - chunfen.jd)).toBeLessThan(1e-8)
+    const after = astro.sunSign(chunfen.jd + 0.5)
+    expect(after.sign).toBe('Aries')
+    expect(Math.abs(after.cusp!.jd - chunfen.jd)).toBeLessThan(1e-8)
+  })
+})
@@ -0,0 +1,66 @@
+/**
+ * @fileoverview balance 모듈 테스트 — 오행 과부족
+ */
+import { describe, it, expect } from 'vitest'
+import { balance, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+describe('elementAdvisory', () => {
+  // 戊辰 / 己丑 / 戊戌(일주) / 甲寅: 토 6, 목 2, 화·금·수 0
+  const pillars = [p(4, 4), p(5, 1), p(4, 10), p(0, 2)]
+  const advice = balance.elementAdvisory(pillars, 4)
+
+  it('없는 오행과 4개 이상인 오행만 오행 순서로 나열한다', () => {
+    expect(advice.map((a) => [a.element, a.status, a.count])).toEqual([
+      ['Fire', 'Lacking', 0],
+      ['Earth', 'Excess', 6],
+      ['Metal', 'Lacking', 0],
+      ['Water', 'Lacking', 0],
+    ])
+  })
+
+  it('일간 기준 십성 역할과 공급 천간·지지를 제시한다', () => {
+    const fire = advice[0]
+    expect(fire.relation).toBe('Resource')
+    expect(fire.tenGods).toEqual(['PyeonIn', 'JeongIn'])
+    expect(fire.stems).toEqual([2, 3])
+    expect(fire.branches).toEqual([5, 6])
+    expect(advice[1].relation).toBe('Same')
+    expect(advice[1].branches).toEqual([1, 4, 7, 10])
+  })
+
+  it('고르게 분포하면 빈 배열', () => {
+    // 甲子 / 丙寅 / 戊午 / 庚申: 목2 화2 토1 금2 수1
+    expect(balance.elementAdvisory([p(0, 0), p(2, 2), p(4, 6), p(6, 8)], 4)).toEqual([])
+  })
+})
+
+describe('relationMatrix', () => {
+  it('행렬 원소는 relation(a, b)와 같다', () => {
+    const m = balance.relationMatrix()
+    expect(m).toHaveLength(5)
+    expect(m[0]).toEqual(['Same', 'Output', 'Wealth', 'Officer', 'Resource'])
+    // 화(1) 기준 수(4) → 관성
+    expect(m[1][4]).toBe('Officer')
+  })
+})
+
+describe('dayElementFlow', () => {
+  // 戊辰 / 己丑 / 戊戌(일주) / 甲寅: 토 6, 목 2
+  const flow = balance.dayElementFlow([p(4, 4), p(5, 1), p(4, 10), p(0, 2)], 4)
+
+  it('일간 오행을 생하고 극하는 글자 수를 센다', () => {
+    expect(flow.dayElement).toBe('Earth')
+    expect(flow.generating).toBe(0)
+    expect(flow.controlling).toBe(2)
+    expect(flow.same).toBe(6)
+    expect(flow.generated + flow.controlled).toBe(0)
+  })
+
+  it('오행별 관계를 목화토금수 순으로 담는다', () => {
+    expect(flow.elements.map((e) => e.relation)).toEqual(['Officer', 'Resource', 'Same', 'Output', 'Wealth'])
+  })
+})
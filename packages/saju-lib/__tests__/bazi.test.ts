@@ -1,7 +1,7 @@
 import { describe, it, expect } from 'vitest'
 import * as bazi from '../src/bazi.js'
 import { STRENGTH_WEIGHTS } from '../src/bazi.js'
-import type { Pillar } from '../src/types.js'
+import type { ElementOverrides, Pillar } from '../src/types.js'
 
 // ── 테스트 헬퍼 ──
 
@@ -97,6 +97,18 @@ describe('hourBranchIndex', () => {
   })
 })
 
+describe('sijinWindow', () => {
+  it('보정 없으면 묘시 05:00~07:00, 자시는 자정을 넘는다', () => {
+    expect(bazi.sijinWindow(3)).toEqual([300, 420])
+    expect(bazi.sijinWindow(0)).toEqual([1380, 60])
+  })
+
+  it('서울 평태양시 보정(-1925초)이면 시계 시각으로 32분 늦게 시작한다', () => {
+    expect(bazi.sijinWindow(3, -1925)).toEqual([332, 452])
+    expect(bazi.sijinWindow(11, -1925)).toEqual([1292, 1412])
+  })
+})
+
 // ── 월간(月干) ──
 
 describe('monthStemFromYear', () => {
@@ -146,6 +158,51 @@ describe('hourStemFromDay', () => {
   })
 })
 
+// ── 60갑자 순번 ──
+
+describe('sexagenaryIndex', () => {
+  it('甲子=0, 乙丑=1, 甲戌=10, 癸亥=59', () => {
+    expect(bazi.sexagenaryIndex(p(0, 0))).toBe(0)
+    expect(bazi.sexagenaryIndex(p(1, 1))).toBe(1)
+    expect(bazi.sexagenaryIndex(p(0, 10))).toBe(10)
+    expect(bazi.sexagenaryIndex(p(9, 11))).toBe(59)
+  })
+
+  it('음양이 맞지 않는 조합은 예외', () => {
+    expect(() => bazi.sexagenaryIndex(p(0, 1))).toThrow(RangeError)
+  })
+
+  it('pillarFromSexagenary와 왕복 일치', () => {
+    for (let i = 0; i < 60; i++) {
+      expect(bazi.sexagenaryIndex(bazi.pillarFromSexagenary(i))).toBe(i)
+    }
+  })
+})
+
+describe('parsePillarName', () => {
+  it('한자·한글·로마자 표기를 모두 받는다', () => {
+    expect(bazi.parsePillarName('乙巳')).toEqual(p(1, 5))
+    expect(bazi.parsePillarName('을사')).toEqual(p(1, 5))
+    expect(bazi.parsePillarName('Eul-Sa')).toEqual(p(1, 5))
+    expect(bazi.parsePillarName('gyeongin')).toEqual(p(6, 2))
+    expect(bazi.parsePillarName('sinsa')).toEqual(p(7, 5))
+  })
+
+  it('알 수 없는 이름이나 음양이 맞지 않는 조합은 예외', () => {
+    expect(() => bazi.parsePillarName('갑축')).toThrow(RangeError)
+    expect(() => bazi.parsePillarName('foo')).toThrow(RangeError)
+  })
+})
+
+describe('parseElementName', () => {
+  it('오행 ID와 한자를 받고 그 외에는 null', () => {
+    expect(bazi.parseElementName('Metal')).toBe('Metal')
+    expect(bazi.parseElementName('水')).toBe('Water')
+    expect(bazi.parseElementName('metal')).toBeNull()
+    expect(bazi.parseElementName('toString')).toBeNull()
+  })
+})
+
 // ── 천간 오행(天干 五行) ──
 
 describe('stemElement', () => {
@@ -498,6 +555,60 @@ describe('mainHiddenStem', () => {
   })
 })
 
+describe('tenGodsFull', () => {
+  // 甲子 丙寅 甲午 乙亥, 일간 甲(0)
+  const pillars = [p(0, 0), p(2, 2), p(0, 6), p(1, 11)]
+
+  it('기둥마다 천간 다음 지장간(정기 먼저)이 온다', () => {
+    const entries = bazi.tenGodsFull(0, pillars)
+    const year = entries.filter((e) => e.position === 'Year')
+    expect(year.map((e) => [e.layer, e.stem, e.rank])).toEqual([
+      ['Stem', 0, undefined],
+      ['Hidden', 9, 'JeongGi'],
+      ['Hidden', 8, 'YeoGi'],
+    ])
+    expect(entries).toHaveLength(4 + 2 + 3 + 3 + 3)
+  })
+
+  it('지장간 정기의 십성은 tenGodBranch와 같다', () => {
+    const entries = bazi.tenGodsFull(0, pillars)
+    const main = entries.filter((e) => e.rank === 'JeongGi')
+    expect(main.map((e) => e.tenGod)).toEqual(pillars.map((pl) => bazi.tenGodBranch(0, pl.branch)))
+    const hour = entries.filter((e) => e.position === 'Hour' && e.layer === 'Hidden')
+    expect(hour.map((e) => [e.tenGod, e.rank])).toEqual([
+      ['PyeonIn', 'JeongGi'],
+      ['BiGyeon', 'JungGi'],
+      ['PyeonJae', 'YeoGi'],
+    ])
+  })
+})
+
+describe('hiddenStemRanks', () => {
+  it('寅·申·亥는 둘째가 중기, 셋째 戊가 여기다', () => {
+    for (const b of [2, 8, 11]) {
+      expect(bazi.hiddenStemRanks(b)).toEqual(['JeongGi', 'JungGi', 'YeoGi'])
+      expect(bazi.hiddenStems(b)[2]).toBe(4)
+    }
+  })
+
+  it('午는 丁 정기, 己 중기, 丙 여기다', () => {
+    expect(bazi.hiddenStems(6)).toEqual([3, 5, 2])
+    expect(bazi.hiddenStemRanks(6)).toEqual(['JeongGi', 'JungGi', 'YeoGi'])
+  })
+
+  it('巳·四庫는 위치 순서대로 정기·여기·중기다', () => {
+    for (const b of [1, 4, 5, 7, 10]) {
+      expect(bazi.hiddenStemRanks(b)).toEqual(['JeongGi', 'YeoGi', 'JungGi'])
+    }
+  })
+
+  it('순위 수는 지장간 수와 같다', () => {
+    for (let b = 0; b < 12; b++) {
+      expect(bazi.hiddenStemRanks(b)).toHaveLength(bazi.hiddenStems(b).length)
+    }
+  })
+})
+
 // ── 천간 합(天干 合) ──
 
 describe('stemHap', () => {
@@ -865,6 +976,14 @@ describe('elementsCount', () => {
     expect(total).toBe(8) // 4기둥 × 2(천간+지지)
   })
 
+  it('합화 치환이 있으면 해당 위치를 치환 오행으로 센다', () => {
+    // 申子辰 수국: 申金·辰土 → 水
+    const pillars = [p(0, 8), p(0, 0), p(0, 4), p(0, 2)]
+    const overrides: ElementOverrides = { stems: [null, null, null, null], branches: ['Water', 'Water', 'Water', null] }
+    const [wood, , earth, metal, water] = bazi.elementsCount(pillars, overrides)
+    expect([wood, earth, metal, water]).toEqual([5, 0, 0, 3])
+  })
+
   it('반환 배열 순서: [목, 화, 토, 금, 수]', () => {
     // 甲寅(0,2): 木木, 丙午(2,6): 火火
     const pillars = [p(0, 2), p(2, 6), p(0, 2), p(2, 6)]
@@ -0,0 +1,40 @@
+/**
+ * @fileoverview dayBranch 모듈 테스트 — 일지(배우자궁) 중심 요약
+ */
+import { describe, it, expect } from 'vitest'
+import { dayBranch, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+describe('dayBranchSummary', () => {
+  // 甲子 丙寅 甲午 乙丑 — 일지 午: 연지 子와 충, 시지 丑과 원진·해
+  const summary = dayBranch.dayBranchSummary([p(0, 0), p(2, 2), p(0, 6), p(1, 1)])
+
+  it('일지가 참여하는 관계만 모은다', () => {
+    expect(summary.branch).toBe(6)
+    expect(summary.interactions.every((r) => r.positions.includes('Day'))).toBe(true)
+    expect(summary.interactions.map((r) => [r.relation, r.positions])).toContainEqual(['Chung', ['Year', 'Day']])
+    expect(summary.pairs.map((pair) => [pair.kind, pair.positions])).toContainEqual(['WonJinSal', ['Day', 'Hour']])
+  })
+
+  it('일지의 십성·12운성을 일간 기준으로 담는다', () => {
+    // 甲 기준 午 정기 丁 → 상관, 午는 사(死)
+    expect(summary.tenGod).toBe('SangGwan')
+    expect(summary.stage).toBe(7)
+  })
+
+  it('주요 신살에서 원진·귀문은 제외한다', () => {
+    expect(summary.shinsal).not.toContain('WonJinSal')
+    expect(summary.shinsal).not.toContain('GwiMunGwanSal')
+  })
+
+  it('12신살은 shinsalBase 기준으로 센다', () => {
+    const pillars = [p(0, 0), p(2, 2), p(0, 6), p(1, 1)]
+    // 연지 子 기준 午는 재살, 일지 午 기준 午는 장성살
+    expect(summary.twelveShinsal).toBe(10)
+    expect(dayBranch.dayBranchSummary(pillars, 'Year').twelveShinsal).toBe(10)
+    expect(dayBranch.dayBranchSummary(pillars, 'Day').twelveShinsal).toBe(4)
+  })
+})
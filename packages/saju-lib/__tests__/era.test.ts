@@ -0,0 +1,18 @@
+/**
+ * @fileoverview era 모듈 테스트 — 단기/불기/세차
+ */
+import { describe, it, expect } from 'vitest'
+import { era } from '../src/index'
+
+describe('eraYears', () => {
+  it('2025년 → 단기 4358, 불기 2569, 세차 乙巳', () => {
+    const result = era.eraYears(2025)
+    expect(result.dangun).toBe(4358)
+    expect(result.buddhist).toBe(2569)
+    expect(result.secha).toEqual({ stem: 1, branch: 5 })
+  })
+
+  it('2000년 → 세차 庚辰', () => {
+    expect(era.eraYears(2000).secha).toEqual({ stem: 6, branch: 4 })
+  })
+})
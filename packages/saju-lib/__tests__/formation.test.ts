@@ -0,0 +1,45 @@
+/**
+ * @fileoverview formation 모듈 테스트 — 특수 구조 규칙 평가
+ */
+import { describe, it, expect } from 'vitest'
+import { formation, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+describe('findFormations', () => {
+  it('일주 천간·지지가 같은 오행이면 간여지동', () => {
+    // 丙午 丙午 甲寅 丁卯
+    const result = formation.findFormations([p(2, 6), p(2, 6), p(0, 2), p(3, 3)], 'Strong')
+    expect(result.map((f) => [f.rule.id, f.positions])).toEqual([['ganyeo-jidong', ['Day']]])
+  })
+
+  it('양인과 칠살이 함께 있으면 양인가살과 그 기둥을 보고한다', () => {
+    // 庚子 乙卯 甲子 丙午 — 卯는 甲의 제왕(양인), 연간 庚은 칠살
+    const result = formation.findFormations([p(6, 0), p(1, 3), p(0, 0), p(2, 6)], 'Neutral')
+    const yangin = result.find((f) => f.rule.id === 'yangin-gasal')
+    expect(yangin?.positions).toEqual(['Year', 'Month'])
+    expect(result.map((f) => f.rule.id)).toContain('sal-in-sangsaeng')
+    expect(result.map((f) => f.rule.id)).toContain('siksin-jesal')
+  })
+
+  it('재성이 많아도 신약이 아니면 재다신약이 아니다', () => {
+    // 戊辰 己丑 甲戌 戊未 — 재성 7개
+    const pillars = [p(4, 4), p(5, 1), p(0, 10), p(4, 7)]
+    const weak = formation.findFormations(pillars, 'Weak').find((f) => f.rule.id === 'jaeda-sinyak')
+    expect(weak?.positions).toEqual(['Year', 'Month', 'Day', 'Hour'])
+    expect(formation.findFormations(pillars, 'Strong').map((f) => f.rule.id)).not.toContain('jaeda-sinyak')
+  })
+
+  it('사용자 규칙표를 받을 수 있다', () => {
+    const rules: formation.FormationRule[] = [{
+      id: 'custom',
+      name: { Ko: '사용자', En: 'custom' },
+      when: [{ kind: 'TenGod', tenGods: ['SikShin'], layer: 'Stem', min: 2 }],
+      note: { Ko: '', En: '' },
+    }]
+    const result = formation.findFormations([p(2, 6), p(2, 6), p(0, 2), p(3, 3)], 'Strong', rules)
+    expect(result.map((f) => [f.rule.id, f.positions])).toEqual([['custom', ['Year', 'Month']]])
+  })
+})
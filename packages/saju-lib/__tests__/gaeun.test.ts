@@ -0,0 +1,40 @@
+/**
+ * @fileoverview gaeun 모듈 테스트 — 생활 개운 대응표 조회·병합
+ */
+import { describe, it, expect } from 'vitest'
+import { gaeun } from '../src/index'
+
+describe('GAEUN_DATA', () => {
+  it('오행별 하도 수가 전통 배속과 일치한다', () => {
+    expect(gaeun.GAEUN_DATA.Water.numbers).toEqual([1, 6])
+    expect(gaeun.GAEUN_DATA.Fire.numbers).toEqual([2, 7])
+    expect(gaeun.GAEUN_DATA.Wood.numbers).toEqual([3, 8])
+    expect(gaeun.GAEUN_DATA.Metal.numbers).toEqual([4, 9])
+    expect(gaeun.GAEUN_DATA.Earth.numbers).toEqual([5, 10])
+  })
+})
+
+describe('mergeGaeunData', () => {
+  it('오행 ID와 한자 키 모두 지정한 필드만 교체한다', () => {
+    const merged = gaeun.mergeGaeunData({
+      Wood: { colors: { Ko: ['초록색'] } },
+      水: { numbers: [6] },
+    })
+    expect(merged.Wood.colors.Ko).toEqual(['초록색'])
+    expect(merged.Wood.colors.En).toEqual(gaeun.GAEUN_DATA.Wood.colors.En)
+    expect(merged.Water.numbers).toEqual([6])
+    expect(merged.Fire).toEqual(gaeun.GAEUN_DATA.Fire)
+  })
+
+  it('내장 데이터를 변경하지 않는다', () => {
+    gaeun.mergeGaeunData({ Wood: { directions: { Ko: ['x'] } } })
+    expect(gaeun.GAEUN_DATA.Wood.directions.Ko).toEqual(['동쪽'])
+  })
+
+  it('알 수 없는 키나 잘못된 형식은 거부한다', () => {
+    expect(() => gaeun.mergeGaeunData({ Air: {} })).toThrow(TypeError)
+    expect(() => gaeun.mergeGaeunData({ Wood: { seasons: { Ko: '봄' } } })).toThrow(TypeError)
+    expect(() => gaeun.mergeGaeunData({ Wood: { numbers: ['3'] } })).toThrow(TypeError)
+    expect(() => gaeun.mergeGaeunData([])).toThrow(TypeError)
+  })
+})
@@ -0,0 +1,30 @@
+/**
+ * @fileoverview glossary 모듈 테스트 — 용어 풀이
+ */
+import { describe, it, expect } from 'vitest'
+import { glossary, I18n } from '../src/index'
+
+describe('glossaryTerms', () => {
+  it('십성·12운성·신살 용어를 중복 없이 모은다', () => {
+    const terms = glossary.glossaryTerms(new I18n('Ko')).map((t) => t.term)
+    expect(terms).toContain('정관(正官)')
+    expect(terms).toContain('장생(長生)')
+    expect(terms).toContain('화개살(華蓋殺)')
+    expect(terms).toContain('천을귀인(天乙貴人)')
+    expect(new Set(terms).size).toBe(terms.length)
+  })
+})
+
+describe('usedGlossaryTerms', () => {
+  it('출력에 등장한 용어만 등장 순서대로 반환한다', () => {
+    const lines = ['- 연간: 정관(正官)', '- 월지: 장생(長生) / 비견(比肩)']
+    const used = glossary.usedGlossaryTerms(lines, new I18n('Ko')).map((t) => t.term)
+    expect(used).toEqual(['정관(正官)', '장생(長生)', '비견(比肩)'])
+  })
+
+  it('출력 언어의 레이블로 찾는다', () => {
+    const used = glossary.usedGlossaryTerms(['Direct Officer (正官)'], new I18n('En'))
+    expect(used).toHaveLength(1)
+    expect(used[0].definition).toBe(glossary.TEN_GOD_DEFINITIONS.JeongGwan.En)
+  })
+})
@@ -0,0 +1,42 @@
+/**
+ * @fileoverview gyeokguk 모듈 테스트 — 월지 기준 격국 판정
+ */
+import { describe, it, expect } from 'vitest'
+import { gyeokguk, interactions, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+describe('determineGyeokguk', () => {
+  it('투출한 월지 지장간을 정기보다 우선한다', () => {
+    // 庚子 癸酉 甲子 甲子 — 酉(辛·庚) 중 庚이 연간에 투출 → 편관격
+    expect(gyeokguk.determineGyeokguk([p(6, 0), p(9, 9), p(0, 0), p(0, 0)]))
+      .toEqual({ kind: 'ChilSal', stem: 6, revealed: true })
+  })
+
+  it('투출이 없으면 월지 정기로 정한다', () => {
+    // 壬子 癸酉 甲子 甲子 → 辛 정관격
+    expect(gyeokguk.determineGyeokguk([p(8, 0), p(9, 9), p(0, 0), p(0, 0)]))
+      .toEqual({ kind: 'JeongGwan', stem: 7, revealed: false })
+  })
+
+  it('월지가 건록·제왕이면 건록격·양인격·월겁격', () => {
+    expect(gyeokguk.determineGyeokguk([p(8, 0), p(2, 2), p(0, 0), p(0, 0)]).kind).toBe('GeonRok')
+    expect(gyeokguk.determineGyeokguk([p(8, 0), p(1, 3), p(0, 0), p(0, 0)]).kind).toBe('YangIn')
+    expect(gyeokguk.determineGyeokguk([p(8, 0), p(2, 2), p(1, 1), p(0, 0)]).kind).toBe('WolGeop')
+  })
+
+  it('일간이 합화하면 화기격, 합으로 묶인 천간은 투출로 보지 않는다', () => {
+    // 丙子 丙未 甲子 己子 — 甲己合土 화(化) → 화기격
+    const hwa = [p(2, 0), p(2, 7), p(0, 0), p(5, 0)]
+    expect(gyeokguk.determineGyeokguk(hwa, interactions.evaluateStemCombinations(hwa)))
+      .toEqual({ kind: 'HwaGi', stem: 5, revealed: true })
+
+    // 丁子 壬亥 丙子 丙子 — 丁壬合木(亥月) 화 → 월간 壬은 투출로 보지 않는다
+    const bound = [p(3, 0), p(8, 11), p(2, 0), p(2, 0)]
+    expect(gyeokguk.determineGyeokguk(bound)).toEqual({ kind: 'ChilSal', stem: 8, revealed: true })
+    expect(gyeokguk.determineGyeokguk(bound, interactions.evaluateStemCombinations(bound)))
+      .toEqual({ kind: 'ChilSal', stem: 8, revealed: false })
+  })
+})
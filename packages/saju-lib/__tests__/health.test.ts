@@ -0,0 +1,43 @@
+/**
+ * @fileoverview health 모듈 테스트 — 오행–장부 대응
+ */
+import { describe, it, expect } from 'vitest'
+import { balance, health, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+describe('healthNotes', () => {
+  it('없는 오행은 부족 문구, 과다 오행은 과다 문구를 붙인다', () => {
+    // 壬子 壬子 壬子 甲寅: 水 6, 木 2 → 火·土·金 없음, 水 과다
+    const advice = balance.elementAdvisory([p(8, 0), p(8, 0), p(8, 0), p(0, 2)], 8)
+    const notes = health.healthNotes(advice)
+    expect(notes.map((n) => [n.element, n.status])).toEqual([
+      ['Fire', 'Lacking'],
+      ['Earth', 'Lacking'],
+      ['Metal', 'Lacking'],
+      ['Water', 'Excess'],
+    ])
+    expect(notes[0].organs.Ko).toEqual(['심장', '소장'])
+    expect(notes[0].note).toEqual(health.HEALTH_DATA.Fire.lacking)
+    expect(notes[3].count).toBe(6)
+    expect(notes[3].note).toEqual(health.HEALTH_DATA.Water.excess)
+  })
+})
+
+describe('mergeHealthData', () => {
+  it('지정한 필드만 교체하고 내장 데이터는 유지한다', () => {
+    const merged = health.mergeHealthData({ 木: { organs: { Ko: ['간', '담', '눈'] }, excess: { En: 'x' } } })
+    expect(merged.Wood.organs.Ko).toEqual(['간', '담', '눈'])
+    expect(merged.Wood.excess.En).toBe('x')
+    expect(merged.Wood.excess.Ko).toBe(health.HEALTH_DATA.Wood.excess.Ko)
+    expect(health.HEALTH_DATA.Wood.organs.Ko).toEqual(['간', '담'])
+  })
+
+  it('알 수 없는 키나 잘못된 형식은 거부한다', () => {
+    expect(() => health.mergeHealthData({ Air: {} })).toThrow(TypeError)
+    expect(() => health.mergeHealthData({ Wood: { lacking: { Ko: ['x'] } } })).toThrow(TypeError)
+    expect(() => health.mergeHealthData(null)).toThrow(TypeError)
+  })
+})
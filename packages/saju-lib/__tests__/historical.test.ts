@@ -0,0 +1,47 @@
+/**
+ * @fileoverview historical 모듈 테스트 — 개력 이전 날짜의 역법 해석
+ */
+import { describe, it, expect } from 'vitest'
+import { historical } from '../src/index'
+
+describe('julianToGregorian', () => {
+  it('러시아 개력: 율리우스력 1918-01-31 다음 날이 그레고리력 1918-02-14', () => {
+    expect(historical.julianToGregorian(1918, 1, 31)).toEqual([1918, 2, 13])
+    expect(historical.julianToGregorian(1918, 2, 1)).toEqual([1918, 2, 14])
+  })
+
+  it('1900년 율리우스력 윤일은 유효하다', () => {
+    expect(historical.isValidJulianDate(1900, 2, 29)).toBe(true)
+    expect(historical.julianToGregorian(1900, 2, 29)).toEqual([1900, 3, 13])
+  })
+})
+
+describe('resolveHistoricalDate', () => {
+  it('개력 전 율리우스력 지역은 율리우스력으로 읽는다', () => {
+    const res = historical.resolveHistoricalDate([1917, 10, 25], 'Auto', 'Europe/Moscow')
+    expect(res.kind).toBe('Julian')
+    if (res.kind === 'Julian') {
+      expect(res.gregorian).toEqual([1917, 11, 7])
+      expect(res.reform?.region).toBe('Russia')
+    }
+  })
+
+  it('개력으로 건너뛴 날짜는 Gap', () => {
+    expect(historical.resolveHistoricalDate([1918, 2, 5], 'Auto', 'Europe/Moscow').kind).toBe('Gap')
+  })
+
+  it('태음태양력을 쓰던 때의 날짜는 Lunisolar', () => {
+    expect(historical.resolveHistoricalDate([1890, 5, 1], 'Auto', 'Asia/Seoul').kind).toBe('Lunisolar')
+    expect(historical.resolveHistoricalDate([1896, 1, 1], 'Auto', 'Asia/Seoul').kind).toBe('Gregorian')
+  })
+
+  it('표에 없는 시간대와 개력 이후 날짜는 그레고리력 그대로', () => {
+    expect(historical.resolveHistoricalDate([1900, 1, 1], 'Auto', 'America/New_York').kind).toBe('Gregorian')
+    expect(historical.resolveHistoricalDate([1918, 2, 14], 'Auto', 'Europe/Moscow').kind).toBe('Gregorian')
+  })
+
+  it('Julian을 지정하면 지역과 관계없이 율리우스력으로 읽는다', () => {
+    const res = historical.resolveHistoricalDate([2000, 1, 1], 'Julian', 'Asia/Seoul')
+    expect(res).toEqual({ kind: 'Julian', gregorian: [2000, 1, 14], reform: null })
+  })
+})
@@ -0,0 +1,58 @@
+/**
+ * @fileoverview i18n 모듈 테스트 — 나이 표기 관례
+ */
+import { describe, it, expect } from 'vitest'
+import { glossary, I18n, withLabels } from '../src/index'
+
+describe('formatAge', () => {
+  it('기본은 만 나이로 표기한다', () => {
+    expect(new I18n('Ko').formatAge(40, false)).toBe('3년 4개월')
+    expect(new I18n('En').formatAge(36, true)).toBe(' 3y')
+  })
+
+  it('세는나이 관례면 1을 더하고 세 단위로 표기한다', () => {
+    expect(new I18n('Ko', 'Korean').formatAge(40, false)).toBe('4세 4개월')
+    expect(new I18n('En', 'Korean').formatAge(36, false)).toBe('4 sui')
+  })
+})
+
+describe('formatSpan', () => {
+  it('대운 시작 시기를 년·개월·일·시간으로 표기한다', () => {
+    const span = { years: 3, months: 4, days: 12, hours: 6, exactMonths: 40.4 }
+    expect(new I18n('Ko').formatSpan(span)).toBe('3년 4개월 12일 6시간')
+    expect(new I18n('Ko', 'Korean').formatSpan(span)).toBe('4세 4개월 12일 6시간')
+  })
+})
+
+describe('romanization', () => {
+  it('병음 표기는 영문 천간·지지·기둥 이름에만 적용된다', () => {
+    const pinyin = new I18n('En', 'Man', 'Pinyin')
+    expect(pinyin.stemLabel(0)).toBe('Jia(甲)')
+    expect(pinyin.branchLabel(2)).toBe('Yin(寅)')
+    expect(pinyin.pillarLabel({ stem: 0, branch: 0 })).toBe('Jia Zi(甲子)')
+    expect(new I18n('En').pillarLabel({ stem: 0, branch: 0 })).toBe('GapJa(甲子)')
+    expect(new I18n('Ko', 'Man', 'Pinyin').pillarLabel({ stem: 0, branch: 0 })).toBe('갑자(甲子)')
+  })
+})
+
+describe('withLabels', () => {
+  it('일부 레이블만 바꾸고 나머지는 바탕 제공자를 따른다', () => {
+    const base = new I18n('Ko')
+    const custom = withLabels(base, {
+      tenGodLabel(god) { return god === 'ChilSal' ? '칠살(七殺)' : base.tenGodLabel(god) },
+      pillarLabel(pillar) { return `[${base.pillarLabel(pillar)}]` },
+    })
+    expect(custom.tenGodLabel('ChilSal')).toBe('칠살(七殺)')
+    expect(custom.tenGodLabel('JeongGwan')).toBe(base.tenGodLabel('JeongGwan'))
+    expect(custom.stemLabel(0)).toBe('갑(甲)')
+    expect(custom.lang).toBe('Ko')
+    expect(custom).toBeInstanceOf(I18n)
+  })
+
+  it('바꾼 레이블이 조합 레이블과 렌더링 함수에도 반영된다', () => {
+    const custom = withLabels(new I18n('Ko'), { pillarLabel(pillar) { return `#${pillar.stem}-${pillar.branch}` } })
+    expect(custom.eraValue({ year: 2024, dangun: 4357, buddhist: 2568, secha: { stem: 0, branch: 4 } })).toContain('세차 #0-4년')
+    const terms = glossary.glossaryTerms(withLabels(new I18n('Ko'), { tenGodLabel() { return '별칭' } }))
+    expect(terms.filter((t) => t.term === '별칭')).toHaveLength(1)
+  })
+})
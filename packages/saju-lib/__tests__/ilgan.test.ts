@@ -0,0 +1,29 @@
+/**
+ * @fileoverview ilgan 모듈 테스트 — 일간론 데이터 조회
+ */
+import { describe, it, expect } from 'vitest'
+import { ilgan } from '../src/index'
+
+describe('ILGAN_DATA', () => {
+  it('10천간 전체 항목이 있고 판정별 해설이 Ko/En 모두 채워져 있다', () => {
+    expect(ilgan.ILGAN_DATA).toHaveLength(10)
+    for (const e of ilgan.ILGAN_DATA) {
+      for (const verdict of ['Strong', 'Weak', 'Neutral'] as const) {
+        expect(e.byVerdict[verdict].Ko.length).toBeGreaterThan(0)
+        expect(e.byVerdict[verdict].En.length).toBeGreaterThan(0)
+      }
+    }
+  })
+})
+
+describe('ilganEntry', () => {
+  it('일간 인덱스로 조회한다', () => {
+    expect(ilgan.ilganEntry(0).name.Ko).toBe('갑목(甲木)')
+    expect(ilgan.ilganEntry(9).name.Ko).toBe('계수(癸水)')
+  })
+
+  it('범위를 벗어난 인덱스는 예외', () => {
+    expect(() => ilgan.ilganEntry(10)).toThrow(RangeError)
+    expect(() => ilgan.ilganEntry(-1)).toThrow(RangeError)
+  })
+})
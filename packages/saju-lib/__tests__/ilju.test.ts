@@ -0,0 +1,54 @@
+/**
+ * @fileoverview ilju 모듈 테스트 — 일주론 데이터 조회·병합
+ */
+import { describe, it, expect } from 'vitest'
+import { ilju } from '../src/index'
+
+describe('ILJU_DATA', () => {
+  it('60갑자 전체 항목이 있고 Ko/En 모두 채워져 있다', () => {
+    expect(ilju.ILJU_DATA).toHaveLength(60)
+    for (const e of ilju.ILJU_DATA) {
+      expect(e.keywords.Ko.length).toBeGreaterThan(0)
+      expect(e.keywords.En.length).toBeGreaterThan(0)
+      expect(e.note.Ko).toBeTruthy()
+      expect(e.note.En).toBeTruthy()
+    }
+  })
+})
+
+describe('iljuEntry', () => {
+  it('庚辰 일주는 괴강 설명을 반환한다', () => {
+    const e = ilju.iljuEntry({ stem: 6, branch: 4 })
+    expect(e.note.Ko).toContain('괴강')
+  })
+
+  it('iljuKey는 한자 표기를 반환한다', () => {
+    expect(ilju.iljuKey({ stem: 9, branch: 11 })).toBe('癸亥')
+  })
+})
+
+describe('mergeIljuData', () => {
+  it('지정한 필드만 교체하고 나머지는 내장 값을 유지한다', () => {
+    const merged = ilju.mergeIljuData({ 甲子: { note: { Ko: '사용자 설명' } } })
+    expect(merged[0].note.Ko).toBe('사용자 설명')
+    expect(merged[0].note.En).toBe(ilju.ILJU_DATA[0].note.En)
+    expect(merged[0].keywords.Ko).toEqual(ilju.ILJU_DATA[0].keywords.Ko)
+    expect(merged[1]).toEqual(ilju.ILJU_DATA[1])
+  })
+
+  it('내장 데이터를 변경하지 않는다', () => {
+    const before = ilju.ILJU_DATA[0].note.Ko
+    ilju.mergeIljuData({ 甲子: { note: { Ko: 'x' } } })
+    expect(ilju.ILJU_DATA[0].note.Ko).toBe(before)
+  })
+
+  it('알 수 없는 일주 키는 거부한다', () => {
+    expect(() => ilju.mergeIljuData({ 甲丑: {} })).toThrow(TypeError)
+    expect(() => ilju.mergeIljuData({ foo: {} })).toThrow(TypeError)
+  })
+
+  it('필드 형식이 잘못되면 거부한다', () => {
+    expect(() => ilju.mergeIljuData({ 甲子: { keywords: { Ko: 'x' } } })).toThrow(TypeError)
+    expect(() => ilju.mergeIljuData([])).toThrow(TypeError)
+  })
+})
@@ -0,0 +1,139 @@
+/**
+ * @fileoverview input 모듈 테스트 — 날짜·시각 표기 정규화
+ */
+import { describe, it, expect } from 'vitest'
+import {
+  normalizeDate,
+  normalizeTime,
+  parseDateInput,
+  parseIsoDateTime,
+  parseSijin,
+  resolveRelativeDate,
+  sijinMinutes,
+} from '../src/input.js'
+
+describe('normalizeDate', () => {
+  it('점·빗금·하이픈 구분과 8자리 표기를 YYYY-MM-DD로 바꾼다', () => {
+    for (const raw of ['1990-03-05', '1990-3-5', '1990.3.5', '1990. 3. 5.', '1990/03/05', '19900305', ' 1990.03.05 ']) {
+      expect(normalizeDate(raw)).toBe('1990-03-05')
+    }
+  })
+
+  it('구분자가 섞이거나 알아볼 수 없으면 그대로 둔다', () => {
+    expect(normalizeDate('1990.3-5')).toBe('1990.3-5')
+    expect(normalizeDate('90.3.5')).toBe('90.3.5')
+    expect(normalizeDate('1990-3')).toBe('1990-3')
+  })
+})
+
+describe('parseDateInput', () => {
+  it('한국어 날짜를 읽는다', () => {
+    expect(parseDateInput('1990년 3월 5일')).toEqual({ date: '1990-03-05', calendar: null, leapMonth: null })
+    expect(parseDateInput('1990년3월5일')).toEqual({ date: '1990-03-05', calendar: null, leapMonth: null })
+  })
+
+  it('음력·양력과 윤달 표시를 역법·윤달로 돌려준다', () => {
+    expect(parseDateInput('음력 1990년 윤5월 3일')).toEqual({ date: '1990-05-03', calendar: 'Lunar', leapMonth: true })
+    expect(parseDateInput('음력 1990.5.3')).toEqual({ date: '1990-05-03', calendar: 'Lunar', leapMonth: null })
+    expect(parseDateInput('양력 1990년 3월 5일')).toEqual({ date: '1990-03-05', calendar: 'Solar', leapMonth: null })
+  })
+
+  it('알아볼 수 없으면 역법 표시도 버리지 않는다', () => {
+    expect(parseDateInput('음력 정월 초하루')).toEqual({ date: '음력 정월 초하루', calendar: null, leapMonth: null })
+  })
+})
+
+describe('normalizeTime', () => {
+  it('한 자리 시·분과 구분자 없는 표기를 HH:MM(:SS)로 바꾼다', () => {
+    expect(normalizeTime('6:5')).toBe('06:05')
+    expect(normalizeTime('06:05')).toBe('06:05')
+    expect(normalizeTime('6:05:7')).toBe('06:05:07')
+    expect(normalizeTime('0605')).toBe('06:05')
+    expect(normalizeTime('060530')).toBe('06:05:30')
+  })
+
+  it('한국어 시각과 오전·오후를 24시간제로 바꾼다', () => {
+    expect(normalizeTime('6시 30분')).toBe('06:30')
+    expect(normalizeTime('오전 6시 30분')).toBe('06:30')
+    expect(normalizeTime('오후 6시 30분')).toBe('18:30')
+    expect(normalizeTime('오후 6시 반')).toBe('18:30')
+    expect(normalizeTime('18시 5분 30초')).toBe('18:05:30')
+    expect(normalizeTime('오전 12시')).toBe('00:00')
+    expect(normalizeTime('오후 12시 10분')).toBe('12:10')
+  })
+
+  it('am·pm과 오전·오후 콜론 표기를 24시간제로 바꾼다', () => {
+    expect(normalizeTime('6:30pm')).toBe('18:30')
+    expect(normalizeTime('6:30 PM')).toBe('18:30')
+    expect(normalizeTime('6 p.m.')).toBe('18:00')
+    expect(normalizeTime('6am')).toBe('06:00')
+    expect(normalizeTime('12:15am')).toBe('00:15')
+    expect(normalizeTime('12:15pm')).toBe('12:15')
+    expect(normalizeTime('11:59:30 pm')).toBe('23:59:30')
+    expect(normalizeTime('오후 6:30')).toBe('18:30')
+  })
+
+  it('알아볼 수 없으면 그대로 둔다', () => {
+    expect(normalizeTime('18:30pm')).toBe('18:30pm')
+    expect(normalizeTime('0am')).toBe('0am')
+    expect(normalizeTime('오후 18시')).toBe('오후 18시')
+    expect(normalizeTime('6h')).toBe('6h')
+    expect(normalizeTime('605')).toBe('605')
+  })
+})
+
+describe('parseSijin', () => {
+  it('시진 이름과 초·말을 읽는다', () => {
+    expect(parseSijin('자시')).toEqual({ branch: 0, part: 'Middle' })
+    expect(parseSijin('축시초')).toEqual({ branch: 1, part: 'Early' })
+    expect(parseSijin('미시 말')).toEqual({ branch: 7, part: 'Late' })
+    expect(parseSijin('亥時初')).toEqual({ branch: 11, part: 'Early' })
+    expect(parseSijin('06:30')).toBeNull()
+    expect(parseSijin('자정')).toBeNull()
+  })
+})
+
+describe('sijinMinutes', () => {
+  it('시진의 한가운데와 첫·마지막 분을 돌려준다', () => {
+    expect(sijinMinutes({ branch: 3, part: 'Middle' })).toEqual({ minutes: 6 * 60, dayOffset: 0 })
+    expect(sijinMinutes({ branch: 3, part: 'Early' })).toEqual({ minutes: 5 * 60, dayOffset: 0 })
+    expect(sijinMinutes({ branch: 3, part: 'Late' })).toEqual({ minutes: 6 * 60 + 59, dayOffset: 0 })
+  })
+
+  it('자시초는 전날 23시다', () => {
+    expect(sijinMinutes({ branch: 0, part: 'Middle' })).toEqual({ minutes: 0, dayOffset: 0 })
+    expect(sijinMinutes({ branch: 0, part: 'Early' })).toEqual({ minutes: 23 * 60, dayOffset: -1 })
+    expect(sijinMinutes({ branch: 0, part: 'Late' })).toEqual({ minutes: 59, dayOffset: 0 })
+  })
+})
+
+describe('parseIsoDateTime', () => {
+  it('날짜·시각·오프셋으로 나눈다', () => {
+    expect(parseIsoDateTime('1990-03-05T06:30:00+09:00')).toEqual({ date: '1990-03-05', time: '06:30:00', tz: '+09:00' })
+    expect(parseIsoDateTime('1990-03-05T06:30-0530')).toEqual({ date: '1990-03-05', time: '06:30', tz: '-05:30' })
+    expect(parseIsoDateTime('1990-03-05T06:30:00.250Z')).toEqual({ date: '1990-03-05', time: '06:30:00', tz: '+00:00' })
+    expect(parseIsoDateTime('1990-03-05 06:30+09')).toEqual({ date: '1990-03-05', time: '06:30', tz: '+09:00' })
+  })
+
+  it('오프셋이 없으면 tz는 null, 형식이 다르면 null', () => {
+    expect(parseIsoDateTime('1990-03-05T06:30')).toEqual({ date: '1990-03-05', time: '06:30', tz: null })
+    expect(parseIsoDateTime('1990-03-05')).toBeNull()
+    expect(parseIsoDateTime('1990.3.5T6:30')).toBeNull()
+  })
+})
+
+describe('resolveRelativeDate', () => {
+  it('키워드와 일·주 오프셋을 기준일에서 센다', () => {
+    expect(resolveRelativeDate('today', '2024-03-01')).toBe('2024-03-01')
+    expect(resolveRelativeDate('Yesterday', '2024-03-01')).toBe('2024-02-29')
+    expect(resolveRelativeDate('내일', '2024-12-31')).toBe('2025-01-01')
+    expect(resolveRelativeDate('+3d', '2024-02-27')).toBe('2024-03-01')
+    expect(resolveRelativeDate('-2w', '2024-01-10')).toBe('2023-12-27')
+  })
+
+  it('상대 날짜가 아니면 null', () => {
+    expect(resolveRelativeDate('2024-03-01', '2024-03-01')).toBeNull()
+    expect(resolveRelativeDate('3d', '2024-03-01')).toBeNull()
+    expect(resolveRelativeDate('constructor', '2024-03-01')).toBeNull()
+  })
+})
@@ -164,3 +164,121 @@ describe('findStemInteractions', () => {
     expect(chung.length).toBeGreaterThanOrEqual(1)
   })
 })
+
+describe('findBranchCombinations', () => {
+  it('세 지지가 모두 있으면 완전한 국으로 보고한다', () => {
+    const pillars = [
+      { stem: 0, branch: 8 },  // 申
+      { stem: 1, branch: 0 },  // 子
+      { stem: 2, branch: 4 },  // 辰
+      { stem: 3, branch: 3 },
+    ]
+    const result = interactions.findBranchCombinations(pillars)
+    expect(result[0]).toEqual({
+      relation: 'SamHap', complete: true, positions: ['Year', 'Month', 'Day'], branches: [8, 0, 4], element: 'Water',
+    })
+  })
+
+  it('왕지를 포함한 두 지지는 반합으로 보고한다', () => {
+    const pillars = [
+      { stem: 0, branch: 2 },  // 寅
+      { stem: 1, branch: 6 },  // 午
+      { stem: 2, branch: 1 },
+      { stem: 3, branch: 1 },
+    ]
+    const result = interactions.findBranchCombinations(pillars)
+    expect(result).toContainEqual({
+      relation: 'SamHap', complete: false, positions: ['Year', 'Month'], branches: [2, 6], element: 'Fire',
+    })
+  })
+
+  it('왕지가 없는 두 지지(寅戌)는 반합이 아니다', () => {
+    const pillars = [
+      { stem: 0, branch: 2 },  // 寅
+      { stem: 1, branch: 10 }, // 戌
+      { stem: 2, branch: 1 },
+      { stem: 3, branch: 1 },
+    ]
+    const result = interactions.findBranchCombinations(pillars)
+    expect(result.filter((c) => c.relation === 'SamHap')).toEqual([])
+  })
+})
+
+describe('branchTransformations', () => {
+  it('완전한 국의 지지만 국의 오행으로 바꾼다', () => {
+    const pillars = [
+      { stem: 0, branch: 2 },  // 寅
+      { stem: 1, branch: 3 },  // 卯
+      { stem: 2, branch: 4 },  // 辰
+      { stem: 3, branch: 6 },  // 午 (寅午 반합은 반영하지 않음)
+    ]
+    const overrides = interactions.branchTransformations(interactions.findBranchCombinations(pillars))
+    expect(overrides.stems).toEqual([null, null, null, null])
+    expect(overrides.branches).toEqual(['Wood', 'Wood', 'Wood', null])
+  })
+})
+
+describe('evaluateStemCombinations', () => {
+  it('월령을 얻고 쟁합·극신이 없으면 화(化)한다', () => {
+    // 丙子 丙未 甲子 己子 — 甲己合土, 未月 득령
+    const pillars = [
+      { stem: 2, branch: 0 },
+      { stem: 2, branch: 7 },
+      { stem: 0, branch: 0 },
+      { stem: 5, branch: 0 },
+    ]
+    expect(interactions.evaluateStemCombinations(pillars)).toEqual([{
+      positions: ['Day', 'Hour'], stems: [0, 5], element: 'Earth',
+      monthSupport: true, contested: false, obstructed: false, transformed: true,
+    }])
+  })
+
+  it('월령을 얻지 못하면 합이불화', () => {
+    // 子月(水)은 土를 돕지 않는다
+    const pillars = [
+      { stem: 2, branch: 0 },
+      { stem: 2, branch: 0 },
+      { stem: 0, branch: 0 },
+      { stem: 5, branch: 0 },
+    ]
+    const [combo] = interactions.evaluateStemCombinations(pillars)
+    expect(combo.monthSupport).toBe(false)
+    expect(combo.transformed).toBe(false)
+  })
+
+  it('쟁합·극신이 있으면 합이불화', () => {
+    // 己 甲 己 — 두 己가 甲 하나를 다툰다
+    const contested = interactions.evaluateStemCombinations([
+      { stem: 5, branch: 0 },
+      { stem: 0, branch: 7 },
+      { stem: 5, branch: 0 },
+      { stem: 2, branch: 0 },
+    ])
+    expect(contested).toHaveLength(2)
+    expect(contested.every((c) => c.contested && !c.transformed)).toBe(true)
+
+    // 연간 乙木이 합화 오행 土를 극한다
+    const [obstructed] = interactions.evaluateStemCombinations([
+      { stem: 1, branch: 0 },
+      { stem: 2, branch: 7 },
+      { stem: 0, branch: 0 },
+      { stem: 5, branch: 0 },
+    ])
+    expect(obstructed.obstructed).toBe(true)
+    expect(obstructed.transformed).toBe(false)
+  })
+})
+
+describe('stemTransformations', () => {
+  it('화(化)한 천간만 합화 오행으로 바꾼다', () => {
+    const pillars = [
+      { stem: 2, branch: 0 },
+      { stem: 2, branch: 7 },
+      { stem: 0, branch: 0 },
+      { stem: 5, branch: 0 },
+    ]
+    const overrides = interactions.stemTransformations(interactions.evaluateStemCombinations(pillars))
+    expect(overrides.stems).toEqual([null, null, 'Earth', 'Earth'])
+    expect(overrides.branches).toEqual([null, null, null, null])
+  })
+})
@@ -0,0 +1,27 @@
+/**
+ * @fileoverview location 모듈 테스트 — 지역 검색·목록
+ */
+import { describe, it, expect } from 'vitest'
+import { location } from '../src/index'
+
+describe('locationList', () => {
+  it('모든 지역에 경도·위도·시간대·별칭이 있다', () => {
+    for (const loc of location.locationList()) {
+      expect(loc.longitude).toBeGreaterThan(124)
+      expect(loc.latitude).toBeGreaterThan(33)
+      expect(loc.tz).toBe('Asia/Seoul')
+      expect(loc.aliases.length).toBeGreaterThan(0)
+    }
+  })
+})
+
+describe('searchLocations', () => {
+  it('key·표시명·별칭의 부분 문자열로 찾는다', () => {
+    expect(location.searchLocations('서울').map((l) => l.key)).toEqual(['seoul'])
+    expect(location.searchLocations('JU').map((l) => l.key)).toEqual(['gwangju', 'cheongju', 'jeonju', 'jeju'])
+  })
+
+  it('빈 검색어는 전체 목록', () => {
+    expect(location.searchLocations('')).toHaveLength(location.locationList().length)
+  })
+})
@@ -2,7 +2,7 @@
  * @fileoverview luck 모듈 테스트 — 대운/세운/월운
  */
 import { describe, it, expect } from 'vitest'
-import { luck } from '../src/index'
+import { astro, luck } from '../src/index'
 
 describe('daewonDirection', () => {
   it('양남(陽男)은 순행', () => {
@@ -50,6 +50,115 @@ describe('buildDaewonItems', () => {
   })
 })
 
+describe('iterateDaewon', () => {
+  it('buildDaewonPillars/buildDaewonItems와 같은 순서로 지연 생성한다', () => {
+    const month = { stem: 0, branch: 2 }
+    const iter = luck.iterateDaewon(36, month, 'Forward')
+    const expected = luck.buildDaewonItems(36, luck.buildDaewonPillars(month, 'Forward', 13))
+    for (const item of expected) {
+      expect(iter.next().value).toEqual(item)
+    }
+  })
+})
+
+describe('daewonStartDays', () => {
+  it('중기를 건너뛰고 앞뒤 절(節)까지 센다', () => {
+    const terms = astro.computeSolarTerms(2024)
+    const [qingming, guyu, lixia] = ['qingming', 'guyu', 'lixia'].map((key) => terms.find((t) => t.def.key === key)!)
+    const birthJd = guyu.jd + 1
+    expect(luck.daewonStartDays(birthJd, 'Forward')).toBeCloseTo(lixia.jd - birthJd, 8)
+    expect(luck.daewonStartDays(birthJd, 'Backward')).toBeCloseTo(birthJd - qingming.jd, 8)
+  })
+})
+
+describe('daewonStartSpan', () => {
+  it('3일=1년, 1일=4개월, 1시진=10일로 환산한다', () => {
+    expect(luck.daewonStartSpan(3)).toEqual({ years: 1, months: 0, days: 0, hours: 0, exactMonths: 12 })
+    expect(luck.daewonStartSpan(1)).toEqual({ years: 0, months: 4, days: 0, hours: 0, exactMonths: 4 })
+    // 1시진(2시간) = 10일
+    expect(luck.daewonStartSpan(2 / 24)).toMatchObject({ years: 0, months: 0, days: 10, hours: 0 })
+  })
+
+  it('개월 미만의 나머지를 일·시간으로 보존한다', () => {
+    // 10.1일 = 40.4개월 = 3년 4개월 12일
+    expect(luck.daewonStartSpan(10.1)).toMatchObject({ years: 3, months: 4, days: 12, hours: 0 })
+    // 실제 1분 = 환산 2시간
+    expect(luck.daewonStartSpan(1 + 1 / 1440)).toMatchObject({ years: 0, months: 4, days: 0, hours: 2 })
+  })
+})
+
+describe('roundDaewonMonths', () => {
+  it('규칙별로 정수 개월을 만든다', () => {
+    expect(luck.roundDaewonMonths(40.4, 'Round')).toBe(40)
+    expect(luck.roundDaewonMonths(40.6, 'Floor')).toBe(40)
+    expect(luck.roundDaewonMonths(40.2, 'Ceil')).toBe(41)
+  })
+
+  it('전통 규칙은 나머지 1일은 버리고 2일은 1년으로 올린다', () => {
+    // 10일 = 3년 + 1일 → 3년, 11일 = 3년 + 2일 → 4년
+    expect(luck.roundDaewonMonths(10 * 4, 'Traditional')).toBe(36)
+    expect(luck.roundDaewonMonths(11 * 4, 'Traditional')).toBe(48)
+  })
+})
+
+describe('buildDaewonItems exactMonths', () => {
+  it('반올림하지 않은 시작 시기를 각 대운에 이어 붙인다', () => {
+    const items = luck.buildDaewonItems(40, [{ stem: 1, branch: 3 }, { stem: 2, branch: 4 }], 40.4)
+    expect(items.map((i) => luck.exactStartMonths(i))).toEqual([40.4, 160.4])
+    expect(luck.exactStartMonths({ startMonths: 36, pillar: { stem: 0, branch: 0 } })).toBe(36)
+  })
+})
+
+describe('daewonItemsForAges', () => {
+  it('나이 범위와 겹치는 대운만 반환한다', () => {
+    // 시작 3세: 3, 13, 23, 33, 43, 53, 63 ...
+    const items = luck.daewonItemsForAges(36, { stem: 0, branch: 2 }, 'Forward', { fromAge: 30, toAge: 60 })
+    expect(items.map((i) => i.startMonths)).toEqual([276, 396, 516, 636])
+  })
+
+  it('0세부터 지정 나이까지의 대운을 모두 반환한다', () => {
+    const items = luck.daewonItemsForAges(36, { stem: 0, branch: 2 }, 'Backward', { fromAge: 0, toAge: 100 })
+    expect(items).toHaveLength(10)
+    expect(items[9].startMonths).toBe(1116)
+  })
+})
+
+describe('groupYearlyByDaewon', () => {
+  it('세운 구간 중간 시점의 대운으로 묶고, 첫 대운 이전은 null 그룹', () => {
+    const YEAR = 365.2425
+    const years = [0, 1, 2, 3, 4, 5].map((i) => ({
+      year: 2000 + i,
+      startJd: i * YEAR - 30,
+      endJd: (i + 1) * YEAR - 30,
+      pillar: { stem: 0, branch: 0 },
+    }))
+    const items = [
+      { startMonths: 36, pillar: { stem: 1, branch: 1 } },
+      { startMonths: 156, pillar: { stem: 2, branch: 2 } },
+    ]
+    const groups = luck.groupYearlyByDaewon(years, items, 0)
+    expect(groups).toHaveLength(2)
+    expect(groups[0].daewon).toBeNull()
+    expect(groups[0].years.map((y) => y.year)).toEqual([2000, 2001, 2002])
+    expect(groups[1].daewon).toBe(items[0])
+    expect(groups[1].years.map((y) => y.year)).toEqual([2003, 2004, 2005])
+  })
+})
+
+describe('daewonAt', () => {
+  it('시점이 속한 대운을 찾고, 첫 대운 이전과 마지막 대운 이후는 null', () => {
+    const MONTH = 365.2425 / 12
+    const items = [
+      { startMonths: 36, pillar: { stem: 1, branch: 1 } },
+      { startMonths: 156, pillar: { stem: 2, branch: 2 } },
+    ]
+    expect(luck.daewonAt(items, 0, 35 * MONTH)).toBeNull()
+    expect(luck.daewonAt(items, 0, 36 * MONTH)).toBe(items[0])
+    expect(luck.daewonAt(items, 0, 200 * MONTH)).toBe(items[1])
+    expect(luck.daewonAt(items, 0, 276 * MONTH)).toBeNull()
+  })
+})
+
 describe('yearlyLuck', () => {
   it('지정 연도 수만큼 세운을 반환한다', () => {
     const result = luck.yearlyLuck(2020, 5)
@@ -89,3 +198,39 @@ describe('monthlyLuck', () => {
     expect(typeof result.yearPillar.branch).toBe('number')
   })
 })
+
+describe('monthlyLuckRange', () => {
+  it('연속한 연도의 월운이 monthlyLuck과 같고 서로 이어진다', () => {
+    const result = luck.monthlyLuckRange(2025, 3)
+    expect(result.map((m) => m.year)).toEqual([2025, 2026, 2027])
+    for (const m of result) {
+      expect(m).toEqual(luck.monthlyLuck(m.year))
+    }
+    // 한 해의 마지막 달 종료 = 다음 해 입춘
+    expect(result[0].months[11].endJd).toBe(result[1].months[0].startJd)
+  })
+})
+
+describe('pillarFavorability', () => {
+  // 신약 목(木) 일간: 용신 水, 희신 木, 기신 金, 구신 土
+  const yongshin = {
+    yongshin: 'Water', heeshin: 'Wood', gishin: 'Metal', gushin: 'Earth', method: 'support',
+  } as const
+
+  it('오행 점수: 용신 +2, 희신 +1, 기신 -2, 구신 -1, 한신 0', () => {
+    expect(luck.elementFavorability('Water', yongshin)).toBe(2)
+    expect(luck.elementFavorability('Wood', yongshin)).toBe(1)
+    expect(luck.elementFavorability('Metal', yongshin)).toBe(-2)
+    expect(luck.elementFavorability('Earth', yongshin)).toBe(-1)
+    expect(luck.elementFavorability('Fire', yongshin)).toBe(0)
+  })
+
+  it('기둥 점수는 천간·지지 점수의 합', () => {
+    // 壬子: 水 + 水 = +4
+    expect(luck.pillarFavorability({ stem: 8, branch: 0 }, yongshin)).toBe(4)
+    // 庚申: 金 + 金 = -4
+    expect(luck.pillarFavorability({ stem: 6, branch: 8 }, yongshin)).toBe(-4)
+    // 甲午: 木 + 火 = +1
+    expect(luck.pillarFavorability({ stem: 0, branch: 6 }, yongshin)).toBe(1)
+  })
+})
@@ -137,3 +137,29 @@ describe('lunarToSolar', () => {
     expect(() => lunar.lunarToSolar(2000, 1, 1, true)).toThrow()
   })
 })
+
+describe('leapCandidates', () => {
+  it('윤달이 있는 월이면 평달/윤달 양력 후보를 모두 반환한다', () => {
+    // 2023년 윤2월
+    const result = lunar.leapCandidates(2023, 2, 1)
+    expect(result).not.toBeNull()
+    expect(result!.regular.toISOString().slice(0, 10)).toBe('2023-02-20')
+    expect(result!.leap.toISOString().slice(0, 10)).toBe('2023-03-22')
+  })
+
+  it('윤달이 없는 월이면 null', () => {
+    expect(lunar.leapCandidates(2023, 3, 1)).toBeNull()
+    expect(lunar.leapCandidates(2000, 2, 1)).toBeNull()
+  })
+
+  it('윤달(소월)에 없는 30일이면 null', () => {
+    // 2023년 2월은 대월, 윤2월은 소월
+    expect(lunar.leapCandidates(2023, 2, 30)).toBeNull()
+  })
+
+  it('leapMonthOf는 해당 연도의 윤달 월을 반환한다', () => {
+    expect(lunar.leapMonthOf(2023)).toBe(2)
+    expect(lunar.leapMonthOf(2020)).toBe(4)
+    expect(() => lunar.leapMonthOf(2100)).toThrow()
+  })
+})
@@ -0,0 +1,54 @@
+/**
+ * @fileoverview 음력 표 생성 테스트 — data/lunar-months.csv와 src/lunarTable.ts의 일치, 데이터 검사
+ */
+import { describe, it, expect } from 'vitest'
+import { readFileSync } from 'node:fs'
+import { lunar } from '../src/index'
+import {
+  DATASET_PATH,
+  TABLE_PATH,
+  parseDataset,
+  renderTable,
+} from '../scripts/generate-lunar-table.mjs'
+
+const dataset = readFileSync(DATASET_PATH, 'utf8')
+
+/** 한 줄을 바꾼 데이터로 검사 오류 메시지를 얻는다 */
+function errorFor(from: string, to: string): string {
+  expect(dataset).toContain(from)
+  try {
+    parseDataset(dataset.replace(from, to))
+  } catch (err) {
+    return (err as Error).message
+  }
+  return ''
+}
+
+describe('lunar table generation', () => {
+  it('생성된 표가 데이터 파일과 같다 (generate:lunar를 다시 실행해야 하면 실패)', () => {
+    expect(readFileSync(TABLE_PATH, 'utf8')).toBe(renderTable(parseDataset(dataset)))
+  })
+
+  it('데이터의 설날이 lunarToSolar 결과와 같다', () => {
+    for (const row of parseDataset(dataset)) {
+      expect(lunar.lunarToSolar(row.year, 1, 1, false).toISOString().slice(0, 10)).toBe(row.newYear)
+    }
+  })
+
+  it('지원 범위는 데이터의 첫 해와 마지막 해다', () => {
+    expect(lunar.LUNAR_MIN_YEAR).toBe(1900)
+    expect(lunar.LUNAR_MAX_YEAR).toBe(2099)
+  })
+
+  it('설날이 앞 해의 길이와 맞지 않으면 거부한다', () => {
+    expect(errorFor('1950,1950-02-17,', '1950,1950-02-18,')).toMatch(/:\d+: new_year 1950-02-18, but 1949 ends on 1950-02-17/)
+  })
+
+  it('윤달 번호와 일수가 어긋나면 거부한다', () => {
+    expect(errorFor(',8,29\n1901', ',0,29\n1901')).toMatch(/leap_days/)
+  })
+
+  it('달 길이가 29·30일이 아니면 거부한다', () => {
+    expect(errorFor('1901,1901-02-19,29 30', '1901,1901-02-19,31 30')).toMatch(/29 or 30/)
+  })
+})
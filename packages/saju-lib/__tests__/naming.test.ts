@@ -0,0 +1,73 @@
+/**
+ * @fileoverview naming 모듈 테스트 — 수리 4격, 발음오행, 원국 대조
+ */
+import { describe, it, expect } from 'vitest'
+import { naming, type YongshinResult } from '../src/index'
+
+describe('analyzeName', () => {
+  it('한자 원획으로 원형이정 4격을 구한다', () => {
+    const name = naming.analyzeName({ hangul: '김민준', hanja: '金民俊' })
+    expect(name.strokeBasis).toBe('Hanja')
+    expect(name.strokes).toEqual([8, 5, 9])
+    expect(name.grids.map((g) => [g.kind, g.strokes, g.lucky])).toEqual([
+      ['Won', 14, false],
+      ['Hyeong', 13, true],
+      ['I', 17, true],
+      ['Jeong', 22, false],
+    ])
+    expect(name.grids.map((g) => g.element)).toEqual(['Fire', 'Fire', 'Metal', 'Wood'])
+  })
+
+  it('한자가 없으면 한글 필획을 쓴다', () => {
+    const name = naming.analyzeName({ hangul: '김민준' })
+    expect(name.strokeBasis).toBe('Hangul')
+    expect(name.strokes).toEqual([5, 5, 5])
+  })
+
+  it('외자 이름의 이격은 성 획수다', () => {
+    const name = naming.analyzeName({ hangul: '이훈', strokes: [7, 10] })
+    expect(name.grids.find((g) => g.kind === 'I')?.strokes).toBe(7)
+  })
+
+  it('복성은 두 글자를 성으로 본다', () => {
+    expect(naming.analyzeName({ hangul: '남궁민수' }).surnameLength).toBe(2)
+    expect(naming.analyzeName({ hangul: '남민수' }).surnameLength).toBe(1)
+  })
+
+  it('발음오행은 초성으로 정하고 해례본 관례는 토·수를 바꾼다', () => {
+    const modern = naming.analyzeName({ hangul: '김민준' })
+    expect(modern.sounds.map((s) => s.element)).toEqual(['Wood', 'Water', 'Metal'])
+    expect(naming.soundElement('하', 'Haerye')).toBe('Water')
+    expect(naming.soundElement('민', 'Haerye')).toBe('Earth')
+  })
+
+  it('표에 없는 한자나 길이가 맞지 않는 입력은 거부한다', () => {
+    expect(() => naming.analyzeName({ hangul: '김민준', hanja: '金民' })).toThrow(RangeError)
+    expect(() => naming.analyzeName({ hangul: '김갑돌', hanja: '金甲乭' })).toThrow(/乭/)
+    expect(() => naming.analyzeName({ hangul: '김' })).toThrow(RangeError)
+  })
+})
+
+describe('isLuckyNumber', () => {
+  it('81을 넘으면 80을 빼서 본다', () => {
+    expect(naming.isLuckyNumber(81)).toBe(true)
+    expect(naming.isLuckyNumber(83)).toBe(naming.isLuckyNumber(3))
+    expect(naming.isLuckyNumber(4)).toBe(false)
+  })
+})
+
+describe('checkNameAgainstChart', () => {
+  it('없는 오행 보충과 용신·기신 포함 여부를 알린다', () => {
+    const name = naming.analyzeName({ hangul: '김민준', hanja: '金民俊' })
+    const lacking = (element: 'Earth' | 'Water') => ({
+      element, count: 0, status: 'Lacking' as const, relation: 'Same' as const,
+      tenGods: ['BiGyeon', 'GeopJae'] as ['BiGyeon', 'GeopJae'], stems: [], branches: [],
+    })
+    const yongshin: YongshinResult = { yongshin: 'Earth', heeshin: 'Fire', gishin: 'Water', gushin: 'Metal', method: 'support' }
+    const check = naming.checkNameAgainstChart(name, [lacking('Earth'), lacking('Water')], yongshin)
+    expect(check.supplied).toEqual(['Water'])
+    expect(check.unsupplied).toEqual(['Earth'])
+    expect(check.hasYongshin).toBe(false)
+    expect(check.hasGishin).toBe(true)
+  })
+})
@@ -0,0 +1,36 @@
+/**
+ * @fileoverview override 모듈 테스트 — 참고 데이터 덮어쓰기 검증
+ */
+import { describe, it, expect } from 'vitest'
+import { applyOverride, type OverrideSchema } from '../src/override'
+
+const SCHEMA: OverrideSchema = {
+  names: { value: 'strings', localized: true },
+  label: { value: 'string', localized: false },
+  numbers: { value: 'integers', localized: false },
+}
+
+function entry() {
+  return { names: { Ko: ['가'], En: ['a'] }, label: 'x', numbers: [1] }
+}
+
+describe('applyOverride', () => {
+  it('언어별 필드는 준 언어만, 나머지 필드는 값 전체를 바꾼다', () => {
+    const target = entry()
+    applyOverride(target, { names: { En: ['b'] }, label: 'y', numbers: [2, 3] }, SCHEMA, 'x')
+    expect(target).toEqual({ names: { Ko: ['가'], En: ['b'] }, label: 'y', numbers: [2, 3] })
+  })
+
+  it('스키마에 없는 필드와 언어는 거부한다', () => {
+    expect(() => applyOverride(entry(), { colour: [] }, SCHEMA, 'x')).toThrow('unknown field colour for x')
+    expect(() => applyOverride(entry(), { names: { Jp: ['a'] } }, SCHEMA, 'x')).toThrow('unknown language Jp in names for x')
+  })
+
+  it('값 모양이 다르면 거부한다', () => {
+    expect(() => applyOverride(entry(), { names: ['a'] }, SCHEMA, 'x')).toThrow(TypeError)
+    expect(() => applyOverride(entry(), { names: { Ko: 'a' } }, SCHEMA, 'x')).toThrow('names.Ko for x must be a string array')
+    expect(() => applyOverride(entry(), { label: 1 }, SCHEMA, 'x')).toThrow('label for x must be a string')
+    expect(() => applyOverride(entry(), { numbers: [1.5] }, SCHEMA, 'x')).toThrow('numbers for x must be an integer array')
+    expect(() => applyOverride(entry(), null, SCHEMA, 'x')).toThrow('entry for x must be an object')
+  })
+})
@@ -0,0 +1,59 @@
+/**
+ * @fileoverview 핵심 타입이 JSON으로 그대로 오가는지 검사 (변환기 없이 저장·전송)
+ */
+import { describe, it, expect } from 'vitest'
+import { bazi, calculate, lunar, type SajuRequest } from '../src/index'
+
+const req: SajuRequest = {
+  date: '1990-05-20',
+  time: '08:30',
+  calendar: 'Solar',
+  leapMonth: false,
+  gender: 'Female',
+  tz: 'Asia/Seoul',
+  useLmt: false,
+  longitude: null,
+  location: null,
+  daewonCount: 10,
+  monthYear: 2024,
+  yearStart: 2024,
+  yearCount: 5,
+}
+
+/** JSON으로 직렬화했다가 다시 읽는다 */
+function roundTrip<T>(value: T): T {
+  return JSON.parse(JSON.stringify(value)) as T
+}
+
+describe('plain data', () => {
+  const result = calculate(req)
+  const natal = [result.yearPillar, result.monthPillar, result.dayPillar, result.hourPillar]
+  const values: Record<string, unknown> = {
+    Pillar: natal,
+    TenGodEntry: bazi.tenGodsFull(result.dayPillar.stem, natal),
+    StrengthResult: result.strength,
+    YongshinResult: result.yongshin,
+    ShinsalEntry: result.shinsalEntries,
+    StemInteraction: result.stemInteractions,
+    BranchInteraction: result.branchInteractions,
+    DaewonItem: result.daewonItems,
+    DaewonSequence: result.daewonCandidates,
+    YearLuck: result.yearlyLuck,
+    MonthlyLuck: result.monthlyLuckYears,
+    SolarTerm: result.solarTerms,
+    LunarDate: lunar.solarToLunar(new Date(Date.UTC(1990, 4, 20))),
+    BirthTermContext: result.birthTerm,
+  }
+
+  for (const [name, value] of Object.entries(values)) {
+    it(`${name}는 JSON 왕복 후에도 같다`, () => {
+      expect(roundTrip(value)).toEqual(value)
+    })
+  }
+
+  it('운 항목에 값이 들어 있다 (빈 배열 비교가 아님)', () => {
+    expect(result.daewonItems.length).toBeGreaterThan(0)
+    expect(result.yearlyLuck).toHaveLength(5)
+    expect(result.monthlyLuckYears[0].months).toHaveLength(12)
+  })
+})
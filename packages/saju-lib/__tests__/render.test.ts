@@ -0,0 +1,66 @@
+/**
+ * @fileoverview render 모듈 테스트 — SVG 명식, 운 타임라인
+ */
+import { describe, it, expect } from 'vitest'
+import { calculate, calculatePillars, render, type SajuRequest } from '../src/index'
+
+function request(gender: SajuRequest['gender']): SajuRequest {
+  return {
+    date: '2000-01-15',
+    time: '17:15',
+    calendar: 'Solar',
+    leapMonth: false,
+    gender,
+    tz: 'Asia/Seoul',
+    useLmt: false,
+    longitude: null,
+    location: null,
+    daewonCount: 8,
+    monthYear: 2024,
+    yearStart: 2020,
+    yearCount: 5,
+  }
+}
+
+describe('scoreColor', () => {
+  it('점수 범위 밖은 양 끝 색으로 고정된다', () => {
+    expect(render.scoreColor(-10)).toBe(render.scoreColor(-4))
+    expect(render.scoreColor(10)).toBe(render.scoreColor(4))
+    expect(render.scoreColor(-4)).not.toBe(render.scoreColor(4))
+  })
+})
+
+describe('renderLuckTimelineSvg', () => {
+  it('대운 막대와 세운 눈금을 하나씩 그린다', () => {
+    const svg = render.renderLuckTimelineSvg(calculate(request('Male')))
+    expect(svg.startsWith('<svg')).toBe(true)
+    expect(svg.trimEnd().endsWith('</svg>')).toBe(true)
+    expect(svg.match(/<rect /g)).toHaveLength(8 + 5)
+  })
+
+  it('성별이 없으면 세운 행만 그린다', () => {
+    const svg = render.renderLuckTimelineSvg(calculate(request(null)), { lang: 'En' })
+    expect(svg.match(/<rect /g)).toHaveLength(5)
+    expect(svg).not.toContain('Decennial Luck')
+  })
+})
+
+describe('renderChartSvg', () => {
+  it('시·일·월·연 순서로 천간·지지 칸을 네 개씩 그린다', () => {
+    const svg = render.renderChartSvg(calculate(request('Male')))
+    expect(svg.startsWith('<svg')).toBe(true)
+    expect(svg.trimEnd().endsWith('</svg>')).toBe(true)
+    expect(svg.match(/<rect /g)).toHaveLength(8)
+    const headings = [...svg.matchAll(/class="heading">([^<]+)</g)].map((m) => m[1])
+    expect(headings).toEqual(['사주팔자 (입춘 기준)', '시주', '일주', '월주', '연주'])
+    // 2000-01-15는 입춘 전이라 기묘년
+    expect(svg).toContain('>卯</text>')
+    expect(svg).toContain('>일간</text>')
+  })
+
+  it('4기둥 결과도 받고 레이블 언어를 따른다', () => {
+    const svg = render.renderChartSvg(calculatePillars(request(null)), { lang: 'En' })
+    expect(svg).toContain('>Day stem</text>')
+    expect(svg).not.toContain('일간')
+  })
+})
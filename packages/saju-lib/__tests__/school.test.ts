@@ -0,0 +1,38 @@
+/**
+ * @fileoverview school 모듈 테스트 — 학파 설정 묶음
+ */
+import { describe, it, expect } from 'vitest'
+import { school, I18n } from '../src/index'
+
+describe('schoolSettings', () => {
+  it('학파 이름으로 설정을 찾는다', () => {
+    expect(school.schoolSettings('yeonhae')).toEqual({
+      dayBoundary: 'Jasi',
+      shinsalBase: 'Year',
+      daewonRounding: 'Traditional',
+      monthScoring: 'Stage',
+      transform: false,
+    })
+    expect(school.schoolSettings('japyeong-modern')?.dayBoundary).toBe('Midnight')
+  })
+
+  it('모르는 이름은 null', () => {
+    expect(school.schoolSettings('unknown')).toBeNull()
+  })
+
+  it('모든 학파에 설정이 있다', () => {
+    for (const name of school.SCHOOL_NAMES) {
+      expect(school.SCHOOL_PRESETS[name]).toBeDefined()
+    }
+  })
+})
+
+describe('schoolSettingsText', () => {
+  it('고른 설정과 고정 관례를 함께 밝힌다', () => {
+    const text = new I18n('Ko').schoolSettingsText(school.SCHOOL_PRESETS['japyeong-modern'])
+    expect(text).toContain('00:00')
+    expect(text).toContain('일지 기준')
+    expect(text).toContain('월지 점수표')
+    expect(text).toContain('입춘')
+  })
+})
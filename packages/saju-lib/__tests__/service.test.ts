@@ -1,6 +1,9 @@
 import { describe, it, expect } from 'vitest';
 import {
   calculate,
+  calculateCached,
+  calculatePillars,
+  clearResultCache,
   validateRequest,
   SajuValidationError,
   type SajuRequest,
@@ -8,7 +11,7 @@ import {
 import * as bazi from '../src/bazi.js';
 import type { Gender } from '../src/types.js';
 
-function makeRequest(date: string, time: string, gender: Gender): SajuRequest {
+function makeRequest(date: string, time: string, gender: Gender | null): SajuRequest {
   return {
     date,
     time,
@@ -64,6 +67,56 @@ describe('service tests (ported from Rust)', () => {
     expect(resultFemale.daewonDirection).toBe('Forward');
   });
 
+  it('test_event_chart_without_gender_skips_daewon', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', null));
+    expect(result.gender).toBeNull();
+    expect(result.daewonDirection).toBeNull();
+    expect(result.daewonStartMonths).toBeNull();
+    expect(result.daewonItems).toHaveLength(0);
+    // 기둥·분석은 성별과 무관하게 동일하다
+    const withGender = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    expect(result.dayPillar).toEqual(withGender.dayPillar);
+    expect(result.strength).toEqual(withGender.strength);
+    expect(result.yearlyLuck).toHaveLength(3);
+  });
+
+  it('test_unknown_gender_provides_both_daewon_directions', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', null));
+    expect(result.daewonCandidates.map((c) => c.direction)).toEqual(['Forward', 'Backward']);
+    // 己(음간) 연주 → 남자=역행, 여자=순행: 각 후보가 해당 성별 결과와 일치한다
+    const male = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    const female = calculate(makeRequest('2000-01-15', '17:15', 'Female'));
+    expect(result.daewonCandidates[1].items).toEqual(male.daewonItems);
+    expect(result.daewonCandidates[0].items).toEqual(female.daewonItems);
+    expect(male.daewonCandidates).toHaveLength(0);
+  });
+
+  it('test_daewon_ages_override_count', () => {
+    const base = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    const result = calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), daewonAges: { fromAge: 0, toAge: 100 } });
+    const start = result.daewonStartMonths!;
+    expect(result.daewonItems[0]).toEqual(base.daewonItems[0]);
+    expect(result.daewonItems.at(-1)!.startMonths).toBeLessThan(101 * 12);
+    expect(result.daewonItems.at(-1)!.startMonths + 120).toBeGreaterThanOrEqual(101 * 12);
+    expect(result.daewonItems.length).toBe(Math.floor((101 * 12 - start - 1) / 120) + 1);
+  });
+
+  it('test_yearly_until_age_starts_at_birth_year', () => {
+    // 입춘 전 출생: 연주 기준 연도는 1999
+    const result = calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), yearlyUntilAge: 80 });
+    expect(result.yearlyLuck[0].year).toBe(1999);
+    expect(result.yearlyLuck).toHaveLength(81);
+    expect(result.yearlyLuck[0].pillar).toEqual(result.yearPillar);
+    expect(() => calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), yearlyUntilAge: 120 }))
+      .toThrow(SajuValidationError);
+  });
+
+  it('test_daewon_ages_validation', () => {
+    const req = makeRequest('2000-01-15', '17:15', 'Male');
+    expect(() => calculate({ ...req, daewonAges: { fromAge: 60, toAge: 30 } })).toThrow(SajuValidationError);
+    expect(() => calculate({ ...req, daewonAges: { fromAge: 0, toAge: 200 } })).toThrow(SajuValidationError);
+  });
+
   it('test_lunar_calendar_input', () => {
     const req: SajuRequest = {
       date: '2000-01-01',
@@ -391,6 +444,39 @@ describe('service tests (ported from Rust)', () => {
     expect(result.lmtInfo!.correctionSeconds).not.toBe(0);
   });
 
+  it('LMT 보정이 23시 경계를 넘기면 다른 경계 기준의 일주·시주를 함께 제공한다', () => {
+    // 서울 경도 보정 약 -32분: 시계 23:10 → 보정 22:38
+    const base: SajuRequest = { ...makeRequest('2000-01-15', '23:10', 'Male'), useLmt: true, location: 'seoul' };
+    const corrected = calculate(base);
+    expect(corrected.lmtBoundary).toBe('Corrected');
+    expect(corrected.hourPillar.branch).toBe(11);
+    expect(corrected.lmtAlternative).not.toBeNull();
+    expect(corrected.lmtAlternative!.boundary).toBe('Clock');
+    expect(corrected.lmtAlternative!.hourPillar.branch).toBe(0);
+    expect(corrected.lmtAlternative!.dayPillar).not.toEqual(corrected.dayPillar);
+
+    const clock = calculate({ ...base, lmtBoundary: 'Clock' });
+    expect(clock.hourPillar).toEqual(corrected.lmtAlternative!.hourPillar);
+    expect(clock.dayPillar).toEqual(corrected.lmtAlternative!.dayPillar);
+    expect(clock.lmtAlternative!.boundary).toBe('Corrected');
+    expect(clock.yearPillar).toEqual(corrected.yearPillar);
+  });
+
+  it('LMT를 쓰지 않으면 경계 대안이 없다', () => {
+    expect(calculate(makeRequest('2000-01-15', '23:10', 'Male')).lmtAlternative).toBeNull();
+  });
+
+  it('0시 일주 경계면 23시대는 당일 일주에 다음 날 일간의 자시를 쓴다', () => {
+    const jasi = calculate(makeRequest('2000-01-15', '23:30', 'Male'));
+    const midnight = calculate({ ...makeRequest('2000-01-15', '23:30', 'Male'), dayBoundary: 'Midnight' });
+    expect(jasi.dayBoundary).toBe('Jasi');
+    expect(midnight.dayBoundary).toBe('Midnight');
+    expect(midnight.dayPillar).toEqual(calculate(makeRequest('2000-01-15', '12:00', 'Male')).dayPillar);
+    expect(jasi.dayPillar).toEqual(calculate(makeRequest('2000-01-16', '12:00', 'Male')).dayPillar);
+    expect(midnight.hourPillar).toEqual(jasi.hourPillar);
+    expect(midnight.hourPillar).toEqual(calculate(makeRequest('2000-01-16', '00:30', 'Male')).hourPillar);
+  });
+
   it('test_stem_hap_detection', () => {
     expect(bazi.stemHap(0, 5)).toBe('Earth');
     expect(bazi.stemHap(1, 6)).toBe('Metal');
@@ -477,3 +563,310 @@ describe('service tests (ported from Rust)', () => {
     expect(result.yearPillar.branch).toBe(4);
   });
 });
+
+describe('calculateCached', () => {
+  it('같은 요청이면 캐시된 결과를 반환한다', () => {
+    clearResultCache();
+    const first = calculateCached(makeRequest('2000-01-15', '17:15', 'Male'));
+    const second = calculateCached(makeRequest('2000-01-15', '17:15', 'Male'));
+    expect(second).toBe(first);
+    expect(second).toEqual(calculate(makeRequest('2000-01-15', '17:15', 'Male')));
+  });
+
+  it('필드 값이 다르면 따로 계산한다', () => {
+    clearResultCache();
+    const male = calculateCached(makeRequest('2000-01-15', '17:15', 'Male'));
+    const female = calculateCached(makeRequest('2000-01-15', '17:15', 'Female'));
+    expect(female).not.toBe(male);
+    expect(female.daewonDirection).not.toBe(male.daewonDirection);
+  });
+
+  it('검증 오류는 캐시하지 않고 그대로 던진다', () => {
+    expect(() => calculateCached(makeRequest('2000-13-01', '17:15', 'Male'))).toThrow(SajuValidationError);
+    expect(() => calculateCached(makeRequest('2000-13-01', '17:15', 'Male'))).toThrow(SajuValidationError);
+  });
+});
+
+describe('warnings', () => {
+  it('정상 범위의 입력이면 경고가 없다', () => {
+    expect(calculate(makeRequest('2000-01-15', '17:15', 'Male')).warnings).toEqual([]);
+  });
+
+  it('음력 표 범위 밖의 양력 날짜는 음력 변환 생략을 알린다', () => {
+    const result = calculate(makeRequest('2100-06-01', '12:00', 'Male'));
+    expect(result.convertedLunar).toBeNull();
+    expect(result.warnings.map((w) => w.code)).toEqual(['LUNAR_TABLE_RANGE']);
+    expect(result.warnings[0].year).toBe(2100);
+  });
+
+  it('표준시 채택 이전의 IANA 시간대는 평균태양시 오프셋임을 알린다', () => {
+    // Asia/Seoul은 1908-04-01 이전 +08:27:52 (평균태양시)
+    const before = calculate(makeRequest('1905-06-01', '12:00', 'Male'));
+    expect(before.warnings.map((w) => w.code)).toEqual(['TIMEZONE_HISTORY']);
+    const fixed = calculate({ ...makeRequest('1905-06-01', '12:00', 'Male'), tz: '+09:00' });
+    expect(fixed.warnings).toEqual([]);
+  });
+});
+
+describe('force', () => {
+  it('지원 범위 밖 양력 연도는 force 없이 거부한다', () => {
+    expect(() => calculate(makeRequest('1850-06-01', '12:00', 'Male'))).toThrow(SajuValidationError);
+  });
+
+  it('force면 근사 결과로 계산하고 경고를 남긴다', () => {
+    const result = calculate({ ...makeRequest('1850-06-01', '12:00', 'Male'), tz: '+09:00', force: true });
+    expect(result.approximate).toBe(true);
+    expect(result.warnings.map((w) => w.code)).toEqual(['EPHEMERIS_RANGE', 'LUNAR_TABLE_RANGE']);
+    // 1850 庚戌년
+    expect(result.yearPillar).toEqual({ stem: 6, branch: 10 });
+  });
+
+  it('범위 안이면 force여도 근사값이 아니다', () => {
+    expect(calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), force: true }).approximate).toBe(false);
+  });
+});
+
+describe('flexible input', () => {
+  it('다른 날짜·시각 표기도 같은 명식을 내고 원문을 남긴다', () => {
+    const canonical = calculate(makeRequest('1990-03-05', '06:05', 'Male'));
+    const loose = calculate(makeRequest('1990.3.5', '6:5', 'Male'));
+    expect([loose.yearPillar, loose.monthPillar, loose.dayPillar, loose.hourPillar])
+      .toEqual([canonical.yearPillar, canonical.monthPillar, canonical.dayPillar, canonical.hourPillar]);
+    expect([loose.inputDate, loose.inputTime]).toEqual(['1990-03-05', '06:05']);
+    expect(loose.rawInput).toEqual({ date: '1990.3.5', time: '6:5' });
+    expect(canonical.rawInput).toBeNull();
+  });
+
+  it('날짜 문구의 음력·윤달 표시가 역법과 윤달 여부를 정한다', () => {
+    const text = calculate(makeRequest('음력 2020년 윤4월 1일', '오전 6시 30분', 'Male'));
+    const flags = calculate({ ...makeRequest('2020-04-01', '06:30', 'Male'), calendar: 'Lunar', leapMonth: true });
+    expect(text.calendarIsLunar).toBe(true);
+    expect(text.leapMonth).toBe(true);
+    expect(text.convertedSolar).toBe(flags.convertedSolar);
+    expect(text.inputTime).toBe('06:30');
+  });
+
+  it('12시간제 시각은 24시간제로 풀어 원문과 함께 남긴다', () => {
+    const result = calculate(makeRequest('2000-01-15', '5:15pm', 'Male'));
+    expect(result.inputTime).toBe('17:15');
+    expect(result.rawInput).toEqual({ date: '2000-01-15', time: '5:15pm' });
+    expect(result.hourPillar).toEqual(calculate(makeRequest('2000-01-15', '17:15', 'Male')).hourPillar);
+  });
+
+  it('시진 입력은 시진 안의 시점으로 계산하고 근사 시각 경고를 남긴다', () => {
+    const result = calculate(makeRequest('2000-01-15', '유시', 'Male'));
+    expect(result.inputTime).toBe('18:00');
+    expect(result.hourPillar.branch).toBe(9);
+    expect(result.warnings.find((w) => w.code === 'APPROXIMATE_TIME')?.resolvedTime).toBe('2000-01-15 18:00');
+  });
+
+  it('자시초는 전날 23시로 계산한다', () => {
+    const result = calculate(makeRequest('2000-01-15', '자시초', 'Male'));
+    expect(result.warnings.find((w) => w.code === 'APPROXIMATE_TIME')?.resolvedTime).toBe('2000-01-14 23:00');
+    expect(result.hourPillar.branch).toBe(0);
+    expect(result.dayPillar).toEqual(calculate(makeRequest('2000-01-15', '00:30', 'Male')).dayPillar);
+  });
+
+  it('평태양시 보정이면 보정 시각이 시진 안에 오도록 시계 시각을 맞춘다', () => {
+    const result = calculate({ ...makeRequest('2000-01-15', '묘시초', 'Male'), useLmt: true, location: 'seoul' });
+    expect(result.lmtInfo?.correctedLocal).toBe('2000-01-15 05:00:00');
+    expect(result.hourPillar.branch).toBe(3);
+  });
+
+  it('알아볼 수 없는 표기는 형식 오류로 거부한다', () => {
+    expect(() => calculate(makeRequest('1990.3-5', '06:05', 'Male'))).toThrow(SajuValidationError);
+  });
+});
+
+describe('birthTerm', () => {
+  it('출생 시점이 속한 절월과 절입 후 경과·다음 절입까지 일수를 준다', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    const ctx = result.birthTerm;
+    expect(ctx.term.key).toBe('xiaohan');
+    expect(ctx.nextTerm.key).toBe('lichun');
+    expect(ctx.daysSinceStart).toBeGreaterThan(8);
+    expect(ctx.daysSinceStart).toBeLessThan(10);
+    expect(ctx.daysSinceStart + ctx.daysUntilNext).toBeCloseTo(ctx.endJd - ctx.startJd, 9);
+    expect(result.birthJd - ctx.startJd).toBeCloseTo(ctx.daysSinceStart, 9);
+  });
+});
+
+describe('calculatePillars', () => {
+  it('calculate()와 같은 네 기둥을 낸다', () => {
+    for (const [date, time] of [['2000-01-15', '17:15'], ['2024-02-04', '17:30'], ['1990-05-20', '23:40']]) {
+      const req = makeRequest(date, time, 'Male');
+      const full = calculate(req);
+      const fast = calculatePillars(req);
+      expect([fast.yearPillar, fast.monthPillar, fast.dayPillar, fast.hourPillar])
+        .toEqual([full.yearPillar, full.monthPillar, full.dayPillar, full.hourPillar]);
+      expect(fast.birthJd).toBe(full.birthJd);
+    }
+  });
+
+  it('LMT 보정과 경고를 함께 돌려준다', () => {
+    const result = calculatePillars({ ...makeRequest('1905-06-01', '12:00', null), useLmt: true, location: 'seoul' });
+    expect(result.lmtInfo?.locationLabel).not.toBeNull();
+    expect(result.warnings.map((w) => w.code)).toEqual(['TIMEZONE_HISTORY']);
+  });
+});
+
+describe('pillar accessors', () => {
+  it('기둥을 역할로 가리킨다', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    expect(result.pillar('Year')).toBe(result.yearPillar);
+    expect(result.pillar('Hour')).toBe(result.hourPillar);
+    expect(result.stemOf('Day')).toBe(result.dayPillar.stem);
+    expect(result.branchOf('Month')).toBe(result.monthPillar.branch);
+    const fast = calculatePillars(makeRequest('2000-01-15', '17:15', 'Male'));
+    expect(fast.pillar('Day')).toEqual(result.pillar('Day'));
+  });
+});
+
+describe('fingerprint', () => {
+  it('입력 표기와 보여 줄 범위가 달라도 같은 명식이면 같다', () => {
+    const base = calculate(makeRequest('1990-03-05', '06:30', 'Male'));
+    expect(base.fingerprint).toMatch(/^[0-9a-f]{16}$/);
+    expect(calculate(makeRequest('1990.3.5', '6:30', 'Male')).fingerprint).toBe(base.fingerprint);
+    expect(calculate({ ...makeRequest('1990-03-05', '06:30', 'Male'), yearCount: 5 }).fingerprint).toBe(base.fingerprint);
+  });
+
+  it('입력이나 관례가 바뀌면 달라진다', () => {
+    const base = calculate(makeRequest('1990-03-05', '06:30', 'Male')).fingerprint;
+    expect(calculate(makeRequest('1990-03-05', '06:31', 'Male')).fingerprint).not.toBe(base);
+    expect(calculate(makeRequest('1990-03-05', '06:30', 'Female')).fingerprint).not.toBe(base);
+    expect(calculate({ ...makeRequest('1990-03-05', '06:30', 'Male'), dayBoundary: 'Midnight' }).fingerprint).not.toBe(base);
+    expect(calculate({ ...makeRequest('1990-03-05', '06:30', 'Male'), transform: true }).fingerprint).not.toBe(base);
+  });
+});
+
+describe('lazy sections', () => {
+  it('운·절기·신살은 읽을 때 계산되고 같은 객체를 돌려준다', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', 'Male'));
+    expect(result.yearlyLuck).toBe(result.yearlyLuck);
+    expect(result.monthlyLuck).toBe(result.monthlyLuckYears[0]);
+    expect(result.daewonItems).toHaveLength(10);
+    expect(result.solarTerms).toHaveLength(24);
+    expect(result.shinsalEntries).toBe(result.shinsalEntries);
+  });
+
+  it('JSON 직렬화에 지연 필드도 포함된다', () => {
+    const json = JSON.parse(JSON.stringify(calculate(makeRequest('2000-01-15', '17:15', 'Male'))));
+    expect(json.yearlyLuck).toHaveLength(3);
+    expect(json.daewonStartMonths).toBeGreaterThan(0);
+  });
+});
+
+describe('daewon direction override', () => {
+  // 2023-01-25: 설(1/22) 이후, 입춘(2/4) 이전 — 입춘 기준 임인(양), 설 기준 계묘(음)
+  const edge = makeRequest('2023-01-25', '12:00', 'Male')
+
+  it('기본은 입춘 기준 연간으로 판정한다', () => {
+    const result = calculate(edge)
+    expect(result.daewonDirection).toBe('Forward')
+    expect(result.daewonDirectionMode).toBe('Auto')
+    expect(result.daewonYearBasis).toBe('Lichun')
+    expect(calculate({ ...edge, daewonYearBasis: 'Lichun' }).fingerprint).toBe(result.fingerprint)
+  })
+
+  it('설 기준이면 설과 입춘 사이 출생의 방향이 바뀐다', () => {
+    const result = calculate({ ...edge, daewonYearBasis: 'LunarNewYear' })
+    expect(result.daewonDirection).toBe('Backward')
+    expect(result.fingerprint).not.toBe(calculate(edge).fingerprint)
+    const lunarInput = calculate({ ...edge, date: '2023-01-04', calendar: 'Lunar', daewonYearBasis: 'LunarNewYear' })
+    expect(lunarInput.daewonDirection).toBe('Backward')
+  })
+
+  it('방향을 고정하면 성별과 무관하게 그 방향으로 산출한다', () => {
+    const forced = calculate({ ...edge, daewonDirection: 'Backward' })
+    expect(forced.daewonDirection).toBe('Backward')
+    expect(forced.daewonItems[0].pillar).toEqual(calculate({ ...edge, gender: 'Female' }).daewonItems[0].pillar)
+    const unknown = calculate({ ...makeRequest('2023-01-25', '12:00', null), daewonDirection: 'Forward' })
+    expect(unknown.daewonDirection).toBe('Forward')
+    expect(unknown.daewonCandidates).toEqual([])
+  })
+})
+
+describe('hemisphere', () => {
+  const req = makeRequest('2000-01-15', '17:15', 'Male')
+
+  it('남반구면 월지를 6지 옮기고 월간을 다시 세운다', () => {
+    const north = calculate(req)
+    const south = calculate({ ...req, hemisphere: 'South' })
+    expect(north.hemisphere).toBe('North')
+    expect(south.hemisphere).toBe('South')
+    expect(north.monthPillar).toEqual({ stem: 3, branch: 1 })
+    expect(south.monthPillar).toEqual({ stem: 7, branch: 7 })
+    expect(south.yearPillar).toEqual(north.yearPillar)
+    expect(south.dayPillar).toEqual(north.dayPillar)
+    expect(south.hourPillar).toEqual(north.hourPillar)
+    expect(south.fingerprint).not.toBe(north.fingerprint)
+    expect(calculate({ ...req, hemisphere: 'North' }).fingerprint).toBe(north.fingerprint)
+  })
+
+  it('calculatePillars도 같은 월주를 낸다', () => {
+    const pillars = calculatePillars({ ...req, hemisphere: 'South' })
+    expect(pillars.hemisphere).toBe('South')
+    expect(pillars.monthPillar).toEqual(calculate({ ...req, hemisphere: 'South' }).monthPillar)
+  })
+})
+
+describe('boundary sensitivity', () => {
+  it('창을 지정하지 않으면 검사하지 않는다', () => {
+    const result = calculate(makeRequest('2000-01-15', '17:15', 'Male'))
+    expect(result.boundaryWindow).toBeNull()
+    expect(result.boundaryAlternatives).toEqual([])
+  })
+
+  it('창 안의 시 경계와 그 너머의 시주를 찾는다', () => {
+    const req = { ...makeRequest('2000-01-15', '17:15', 'Male'), boundaryWindow: 30 }
+    const result = calculate(req)
+    expect(result.boundaryAlternatives).toHaveLength(1)
+    const [alt] = result.boundaryAlternatives
+    expect(alt.side).toBe('Earlier')
+    expect(alt.minutes).toBe(16)
+    expect(alt.boundary).toBe('Hour')
+    expect(alt.changed).toEqual(['Hour'])
+    expect(alt.hourPillar).toEqual(calculate(makeRequest('2000-01-15', '16:59', 'Male')).hourPillar)
+    expect(calculate({ ...req, boundaryWindow: 10 }).boundaryAlternatives).toEqual([])
+  })
+
+  it('입춘 직후 출생이면 연주·월주가 바뀌는 경계를 보고한다', () => {
+    const result = calculate({ ...makeRequest('2024-02-04', '17:40', 'Female'), boundaryWindow: 30 })
+    const alt = result.boundaryAlternatives.find((a) => a.side === 'Earlier')
+    expect(alt?.boundary).toBe('Year')
+    expect(alt?.changed).toEqual(['Year', 'Month'])
+    expect(alt?.minutes).toBeGreaterThanOrEqual(12)
+    expect(alt?.minutes).toBeLessThanOrEqual(15)
+  })
+
+  it('창 범위를 검증한다', () => {
+    expect(() => calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), boundaryWindow: 0 })).toThrow(/boundary window/)
+    expect(() => calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), boundaryWindow: 120 })).toThrow(/boundary window/)
+    expect(calculate({ ...makeRequest('2000-01-15', '17:15', 'Male'), boundaryWindow: 119 }).boundaryWindow).toBe(119)
+  })
+})
+
+describe('historical calendar', () => {
+  const moscow = { ...makeRequest('1917-10-25', '12:00', 'Male'), tz: 'Europe/Moscow' }
+
+  it('기본값은 입력을 그레고리력으로 읽는다', () => {
+    expect(calculate(moscow).historicalDate).toBeNull()
+  })
+
+  it('auto면 개력 전 러시아 날짜를 율리우스력으로 읽는다', () => {
+    const result = calculate({ ...moscow, historicalCalendar: 'Auto' })
+    expect(result.historicalDate).toEqual({
+      julianDate: '1917-10-25', gregorianDate: '1917-11-07', region: 'Russia', gregorianFrom: '1918-02-14',
+    })
+    const gregorian = calculate({ ...moscow, date: '1917-11-07' })
+    expect(result.dayPillar).toEqual(gregorian.dayPillar)
+    expect(result.monthPillar).toEqual(gregorian.monthPillar)
+    expect(result.fingerprint).not.toBe(gregorian.fingerprint)
+  })
+
+  it('개력으로 건너뛴 날짜와 음력 시대 날짜를 거부한다', () => {
+    expect(() => calculate({ ...moscow, date: '1918-02-05', historicalCalendar: 'Auto' })).toThrow(SajuValidationError)
+    expect(() => calculate({ ...makeRequest('1890-05-01', '12:00', 'Male'), historicalCalendar: 'Auto', force: true }))
+      .toThrow(SajuValidationError)
+  })
+})
@@ -167,3 +167,80 @@ describe('findShinsal', () => {
     expect(hakdang.length).toBeGreaterThanOrEqual(1)
   })
 })
+
+describe('isBranchPair', () => {
+  it('원진은 子未·丑午·寅酉·卯申·辰亥·巳戌', () => {
+    const pairs = [[0, 7], [1, 6], [2, 9], [3, 8], [4, 11], [5, 10]]
+    for (const [a, b] of pairs) {
+      expect(shinsal.isBranchPair('WonJinSal', a, b)).toBe(true)
+      expect(shinsal.isBranchPair('WonJinSal', b, a)).toBe(true)
+    }
+    expect(shinsal.isBranchPair('WonJinSal', 2, 5)).toBe(false)
+  })
+
+  it('귀문은 子酉·丑午·寅未·卯申·辰亥·巳戌', () => {
+    const pairs = [[0, 9], [1, 6], [2, 7], [3, 8], [4, 11], [5, 10]]
+    for (const [a, b] of pairs) {
+      expect(shinsal.isBranchPair('GwiMunGwanSal', a, b)).toBe(true)
+      expect(shinsal.isBranchPair('GwiMunGwanSal', b, a)).toBe(true)
+    }
+    expect(shinsal.isBranchPair('GwiMunGwanSal', 1, 10)).toBe(false)
+  })
+})
+
+describe('natalBranchPairs', () => {
+  it('원국 지지 사이의 쌍과 위치를 모두 찾는다', () => {
+    // 寅 酉 午 丑 — 寅酉 원진, 午丑 원진·귀문
+    const pillars = [
+      { stem: 0, branch: 2 },
+      { stem: 1, branch: 9 },
+      { stem: 2, branch: 6 },
+      { stem: 3, branch: 1 },
+    ]
+    expect(shinsal.natalBranchPairs(pillars)).toEqual([
+      { kind: 'WonJinSal', positions: ['Year', 'Month'] },
+      { kind: 'WonJinSal', positions: ['Day', 'Hour'] },
+      { kind: 'GwiMunGwanSal', positions: ['Day', 'Hour'] },
+    ])
+  })
+})
+
+describe('luckBranchPairs', () => {
+  it('운의 지지와 쌍을 이루는 원국 위치를 반환한다', () => {
+    const pillars = [
+      { stem: 0, branch: 0 },
+      { stem: 1, branch: 3 },
+      { stem: 2, branch: 0 },
+      { stem: 3, branch: 5 },
+    ]
+    // 未: 子와 원진 (연·일)
+    expect(shinsal.luckBranchPairs(pillars, 7)).toEqual([{ kind: 'WonJinSal', positions: ['Year', 'Day'] }])
+    // 申: 卯와 원진·귀문
+    expect(shinsal.luckBranchPairs(pillars, 8)).toEqual([
+      { kind: 'WonJinSal', positions: ['Month'] },
+      { kind: 'GwiMunGwanSal', positions: ['Month'] },
+    ])
+  })
+})
+
+describe('luckGongmang', () => {
+  // 甲子 일주 → 戌亥 공망
+  const pillars = [
+    { stem: 0, branch: 10 }, // 戌
+    { stem: 1, branch: 3 },
+    { stem: 0, branch: 0 },  // 甲子
+    { stem: 3, branch: 5 },  // 巳
+  ]
+
+  it('운의 지지가 원국 공망이면 luckVoided', () => {
+    expect(shinsal.luckGongmang(pillars, { stem: 9, branch: 11 }).luckVoided).toBe(true)  // 癸亥
+    expect(shinsal.luckGongmang(pillars, { stem: 0, branch: 2 }).luckVoided).toBe(false)  // 甲寅
+  })
+
+  it('운 기둥 기준 공망에 드는 원국 위치를 반환한다', () => {
+    // 甲寅 → 子丑 공망: 일지 子
+    expect(shinsal.luckGongmang(pillars, { stem: 0, branch: 2 }).natalVoided).toEqual(['Day'])
+    // 甲午 → 辰巳 공망: 시지 巳
+    expect(shinsal.luckGongmang(pillars, { stem: 0, branch: 6 }).natalVoided).toEqual(['Hour'])
+  })
+})
@@ -0,0 +1,33 @@
+/**
+ * @fileoverview shinsalNotes 모듈 테스트 — 신살 풀이 카탈로그
+ */
+import { describe, it, expect } from 'vitest'
+import { shinsalNotes } from '../src/index'
+
+describe('SHINSAL_CATALOG', () => {
+  it('12신살 12개 항목이 있다', () => {
+    expect(shinsalNotes.SHINSAL_CATALOG.twelve).toHaveLength(12)
+    expect(shinsalNotes.TWELVE_SHINSAL_HANJA).toHaveLength(12)
+  })
+})
+
+describe('mergeShinsalCatalog', () => {
+  it('종류 ID와 12신살 한자 이름으로 문구를 교체한다', () => {
+    const merged = shinsalNotes.mergeShinsalCatalog({
+      kinds: { YeokMaSal: { Ko: '이동수' } },
+      twelve: { 華蓋殺: { En: 'canopy' } },
+    })
+    expect(merged.kinds.YeokMaSal.Ko).toBe('이동수')
+    expect(merged.kinds.YeokMaSal.En).toBe(shinsalNotes.SHINSAL_CATALOG.kinds.YeokMaSal.En)
+    expect(merged.twelve[8].En).toBe('canopy')
+    expect(merged.twelve[8].Ko).toBe(shinsalNotes.SHINSAL_CATALOG.twelve[8].Ko)
+    expect(shinsalNotes.SHINSAL_CATALOG.kinds.YeokMaSal.Ko).not.toBe('이동수')
+  })
+
+  it('알 수 없는 키나 잘못된 형식은 거부한다', () => {
+    expect(() => shinsalNotes.mergeShinsalCatalog({ kinds: { Foo: { Ko: 'x' } } })).toThrow(TypeError)
+    expect(() => shinsalNotes.mergeShinsalCatalog({ twelve: { 地煞: { Ko: 'x' } } })).toThrow(TypeError)
+    expect(() => shinsalNotes.mergeShinsalCatalog({ kinds: { GongMang: { Ko: 1 } } })).toThrow(TypeError)
+    expect(() => shinsalNotes.mergeShinsalCatalog('x')).toThrow(TypeError)
+  })
+})
@@ -75,6 +75,76 @@ describe('assessStrength', () => {
     const result = strength.assessStrength(0, weakPillars);
     expect(result.drainStems).toBeGreaterThanOrEqual(2);
   });
+
+  it('기둥별 기여의 합에 월지 점수를 더하면 종합 점수', () => {
+    for (const pillars of [strongPillars, weakPillars]) {
+      const result = strength.assessStrength(0, pillars);
+      expect(result.pillars.map((p) => p.position)).toEqual(['Year', 'Month', 'Day', 'Hour']);
+      const sum = result.pillars.reduce((acc, p) => acc + p.total, 0);
+      expect(sum + result.monthScore).toBe(result.total);
+      expect(result.pillars.filter((p) => p.root)).toHaveLength(result.rootCount);
+    }
+  });
+
+  it('합화 치환된 지지는 지장간 전체를 치환 오행으로 본다', () => {
+    const plain = strength.assessStrength(0, weakPillars);
+    const result = strength.assessStrength(0, weakPillars, { stems: [null, null, null, null], branches: ['Wood', 'Wood', null, null] });
+    expect(result.rootCount).toBe(2);
+    expect(result.total).toBeGreaterThan(plain.total);
+  });
+
+  it('합화 치환된 천간은 치환 오행으로 지원/소모를 판정한다', () => {
+    const result = strength.assessStrength(0, weakPillars, { stems: [null, 'Water', null, 'Water'], branches: [null, null, null, null] });
+    expect(result.supportStems).toBe(4);
+  });
+});
+
+describe('strengthLevel', () => {
+  const thresholds = strength.DEFAULT_STRENGTH_PROFILE.thresholds;
+
+  it('점수를 5단계로 나눈다', () => {
+    expect(strength.strengthLevel(12, thresholds)).toBe('VeryStrong');
+    expect(strength.strengthLevel(3, thresholds)).toBe('Strong');
+    expect(strength.strengthLevel(0, thresholds)).toBe('Neutral');
+    expect(strength.strengthLevel(-3, thresholds)).toBe('Weak');
+    expect(strength.strengthLevel(-10, thresholds)).toBe('VeryWeak');
+  });
+
+  it('5단계 판정을 3단계 verdict로 묶는다', () => {
+    expect(strength.levelClass('VeryStrong')).toBe('Strong');
+    expect(strength.levelClass('VeryWeak')).toBe('Weak');
+    expect(strength.levelClass('Neutral')).toBe('Neutral');
+  });
+
+  it('Matrix 방식이면 월지 점수표 값을 월지 기여로 쓴다', () => {
+    const pillars: Pillar[] = [
+      { stem: 0, branch: 0 },
+      { stem: 0, branch: 8 },  // 申月 — 甲木은 사(死)
+      { stem: 0, branch: 6 },
+      { stem: 0, branch: 0 },
+    ];
+    const stage = strength.assessStrength(0, pillars);
+    const matrix = strength.assessStrength(0, pillars, null, { ...strength.DEFAULT_STRENGTH_PROFILE, monthScoring: 'Matrix' });
+    expect(matrix.monthScoring).toBe('Matrix');
+    expect(matrix.monthScore).toBe(strength.MONTH_SCORE_MATRIX.Wood[8]);
+    expect(matrix.total - matrix.monthScore).toBe(stage.total - stage.monthScore);
+  });
+
+  it('profile로 임계값을 바꿀 수 있다', () => {
+    const pillars: Pillar[] = [
+      { stem: 0, branch: 0 },
+      { stem: 0, branch: 2 },
+      { stem: 0, branch: 6 },
+      { stem: 0, branch: 0 },
+    ];
+    const base = strength.assessStrength(0, pillars);
+    const strict = strength.assessStrength(0, pillars, null, {
+      monthScoring: 'Stage',
+      thresholds: { veryStrong: 100, strong: 50, weak: -50, veryWeak: -100 },
+    });
+    expect(strict.total).toBe(base.total);
+    expect(strict.level).toBe('Neutral');
+  });
 });
 
 describe('determineYongshin', () => {
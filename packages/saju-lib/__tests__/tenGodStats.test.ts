@@ -0,0 +1,61 @@
+/**
+ * @fileoverview tenGodStats 모듈 테스트 — 십성 분포
+ */
+import { describe, it, expect } from 'vitest'
+import { tenGodStats, type Pillar } from '../src/index'
+
+function p(stem: number, branch: number): Pillar {
+  return { stem, branch }
+}
+
+// 丙午 丙午 甲子 丁卯 — 甲木 일간, 화(식상) 편중
+const FIRE_HEAVY = [p(2, 6), p(2, 6), p(0, 0), p(3, 3)]
+
+describe('tenGodDistribution', () => {
+  const dist = tenGodStats.tenGodDistribution(FIRE_HEAVY, 0)
+
+  it('일간을 제외한 천간과 지지 정기 7자를 센다', () => {
+    // 丙丙 식신, 丁 상관, 午午 정기 丁 상관, 子 정기 癸 정인, 卯 정기 乙 겁재
+    expect(dist.counts.SikShin).toBe(2)
+    expect(dist.counts.SangGwan).toBe(3)
+    expect(dist.counts.JeongIn).toBe(1)
+    expect(dist.counts.GeopJae).toBe(1)
+    expect(Object.values(dist.counts).reduce((a, b) => a + b, 0)).toBe(7)
+  })
+
+  it('그룹 합계와 우세 그룹, 없는 십성을 찾는다', () => {
+    expect(dist.groups).toEqual({ Same: 1, Output: 5, Wealth: 0, Officer: 0, Resource: 1 })
+    expect(dist.dominant).toEqual(['Output'])
+    expect(dist.absent).toEqual(['BiGyeon', 'PyeonJae', 'JeongJae', 'ChilSal', 'JeongGwan', 'PyeonIn'])
+  })
+
+  it('십성을 그룹으로 묶는다', () => {
+    expect(tenGodStats.tenGodGroup('ChilSal')).toBe('Officer')
+    expect(tenGodStats.tenGodGroup('GeopJae')).toBe('Same')
+  })
+})
+
+describe('weightedTenGodDistribution', () => {
+  const weighted = tenGodStats.weightedTenGodDistribution(FIRE_HEAVY, 0)
+
+  it('지장간 전체를 순위별 가중치로 더한다', () => {
+    // 午 지장간 丁(정기 1)·己(중기 0.5)·丙(여기 0.5) ×2, 子 癸 1·壬 0.5, 卯 乙 1·甲 0.5
+    expect(weighted.counts.SangGwan).toBe(1 + 2)
+    expect(weighted.counts.SikShin).toBe(2 + 1)
+    expect(weighted.counts.JeongJae).toBe(1)
+    expect(weighted.counts.PyeonIn).toBe(0.5)
+    expect(weighted.counts.BiGyeon).toBe(0.5)
+  })
+
+  it('정기만 있는 분포보다 합이 크고 지장간이 없던 십성도 드러난다', () => {
+    const total = Object.values(weighted.counts).reduce((a, b) => a + b, 0)
+    expect(total).toBe(3 + 4 + 0.5 * 6)
+    expect(weighted.absent).not.toContain('JeongJae')
+    expect(weighted.dominant).toEqual(['Output'])
+  })
+
+  it('가중치를 바꿀 수 있다', () => {
+    const flat = tenGodStats.weightedTenGodDistribution(FIRE_HEAVY, 0, { JeongGi: 1, YeoGi: 1, JungGi: 1 })
+    expect(Object.values(flat.counts).reduce((a, b) => a + b, 0)).toBe(3 + 10)
+  })
+})
@@ -0,0 +1,30 @@
+/**
+ * @fileoverview timezone 모듈 테스트 — 시간대 문자열 파싱
+ */
+import { describe, it, expect } from 'vitest'
+import { timezone } from '../src/index'
+
+describe('parseTimezone', () => {
+  it('IANA 이름과 고정 오프셋을 받는다', () => {
+    expect(timezone.parseTimezone('Asia/Seoul')).toEqual({ type: 'named', name: 'Asia/Seoul' })
+    expect(timezone.parseTimezone('+09:00')).toEqual({ type: 'fixed', offsetSeconds: 32400 })
+    expect(timezone.parseTimezone('-0530')).toEqual({ type: 'fixed', offsetSeconds: -19800 })
+  })
+
+  it('흔한 약어를 고정 오프셋으로 바꾼다 (대소문자 무관)', () => {
+    expect(timezone.parseTimezone('KST')).toEqual({ type: 'fixed', offsetSeconds: 32400 })
+    expect(timezone.parseTimezone('jst')).toEqual({ type: 'fixed', offsetSeconds: 32400 })
+    expect(timezone.parseTimezone('PST')).toEqual({ type: 'fixed', offsetSeconds: -28800 })
+    expect(timezone.parseTimezone('PDT')).toEqual({ type: 'fixed', offsetSeconds: -25200 })
+    expect(timezone.parseTimezone('ACST')).toEqual({ type: 'fixed', offsetSeconds: 34200 })
+  })
+
+  it('뜻이 여럿인 약어는 후보를 밝혀 거부한다', () => {
+    expect(() => timezone.parseTimezone('CST')).toThrow(/ambiguous timezone abbreviation CST .*China \+08:00/)
+    expect(() => timezone.parseTimezone('ist')).toThrow(/ambiguous/)
+  })
+
+  it('모르는 이름은 거부한다', () => {
+    expect(() => timezone.parseTimezone('Nowhere/City')).toThrow()
+  })
+})
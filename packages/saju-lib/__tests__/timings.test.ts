@@ -0,0 +1,73 @@
+/**
+ * @fileoverview timings 모듈 테스트 — 단계별 시간 누적, 캐시 통계
+ */
+import { afterEach, describe, it, expect, vi } from 'vitest'
+import {
+  enableTimings,
+  mergeTimingReports,
+  registerCache,
+  timed,
+  timingReport,
+} from '../src/timings.js'
+
+/** performance.now()를 호출할 때마다 주어진 값을 차례로 돌려준다 */
+function fakeClock(...ticks: number[]) {
+  const spy = vi.spyOn(performance, 'now')
+  for (const t of ticks) spy.mockReturnValueOnce(t)
+  return spy
+}
+
+afterEach(() => {
+  enableTimings(false)
+  vi.restoreAllMocks()
+})
+
+describe('timed', () => {
+  it('꺼져 있으면 함수만 호출하고 기록하지 않는다', () => {
+    enableTimings(false)
+    expect(timed('Terms', () => 42)).toBe(42)
+    expect(timingReport().phases.Terms).toEqual({ ms: 0, calls: 0 })
+  })
+
+  it('중첩된 단계의 시간은 바깥 단계에서 뺀다', () => {
+    enableTimings()
+    // Pillars 시작 0, Terms 시작 2, Terms 끝 5, Pillars 끝 10
+    fakeClock(0, 2, 5, 10)
+    timed('Pillars', () => timed('Terms', () => undefined))
+    const { phases } = timingReport()
+    expect(phases.Terms).toEqual({ ms: 3, calls: 1 })
+    expect(phases.Pillars).toEqual({ ms: 7, calls: 1 })
+  })
+
+  it('예외가 나도 시간을 기록한다', () => {
+    enableTimings()
+    fakeClock(0, 4)
+    expect(() => timed('Luck', () => { throw new Error('boom') })).toThrow('boom')
+    expect(timingReport().phases.Luck).toEqual({ ms: 4, calls: 1 })
+  })
+})
+
+describe('timingReport', () => {
+  it('캐시 통계는 측정을 켠 뒤의 변화량만 보고한다', () => {
+    const cache = registerCache('test', { hits: 5, misses: 2 })
+    enableTimings()
+    cache.hits += 3
+    cache.misses += 1
+    expect(timingReport().caches.test).toEqual({ hits: 3, misses: 1 })
+  })
+})
+
+describe('mergeTimingReports', () => {
+  it('단계 시간과 캐시 통계를 더한다', () => {
+    enableTimings()
+    const a = timingReport()
+    a.phases.Terms = { ms: 1.5, calls: 2 }
+    a.caches = { terms: { hits: 1, misses: 1 } }
+    const b = timingReport()
+    b.phases.Terms = { ms: 2, calls: 1 }
+    b.caches = { terms: { hits: 2, misses: 0 }, result: { hits: 0, misses: 1 } }
+    const merged = mergeTimingReports(a, b)
+    expect(merged.phases.Terms).toEqual({ ms: 3.5, calls: 3 })
+    expect(merged.caches).toEqual({ terms: { hits: 3, misses: 1 }, result: { hits: 0, misses: 1 } })
+  })
+})
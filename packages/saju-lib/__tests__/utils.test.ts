@@ -0,0 +1,58 @@
+/**
+ * @fileoverview utils 모듈 테스트 — 유클리드 나머지, LRU 캐시, 해시
+ */
+import { describe, it, expect } from 'vitest'
+import { fnv1a64, LruCache, lazy, remEuclid } from '../src/utils.js'
+
+describe('remEuclid', () => {
+  it('음수 피제수도 0 이상으로 돌려준다', () => {
+    expect(remEuclid(-1, 12)).toBe(11)
+    expect(remEuclid(13, 12)).toBe(1)
+  })
+})
+
+describe('LruCache', () => {
+  it('용량을 넘으면 가장 오래 쓰지 않은 항목을 버린다', () => {
+    const cache = new LruCache<string, number>(2)
+    cache.set('a', 1)
+    cache.set('b', 2)
+    expect(cache.get('a')).toBe(1) // a가 최근 사용이 됨
+    cache.set('c', 3)
+    expect(cache.get('b')).toBeUndefined()
+    expect(cache.get('a')).toBe(1)
+    expect(cache.get('c')).toBe(3)
+    expect(cache.size).toBe(2)
+  })
+
+  it('getOrCompute는 없을 때만 계산한다', () => {
+    const cache = new LruCache<number, number>(4)
+    let calls = 0
+    const compute = () => ++calls
+    expect(cache.getOrCompute(1, compute)).toBe(1)
+    expect(cache.getOrCompute(1, compute)).toBe(1)
+    expect(calls).toBe(1)
+    expect([cache.hits, cache.misses]).toEqual([1, 1])
+  })
+
+  it('용량은 양의 정수여야 한다', () => {
+    expect(() => new LruCache(0)).toThrow(RangeError)
+  })
+})
+
+describe('lazy', () => {
+  it('처음 호출할 때 한 번만 계산한다', () => {
+    let calls = 0
+    const value = lazy(() => ++calls)
+    expect(calls).toBe(0)
+    expect(value()).toBe(1)
+    expect(value()).toBe(1)
+    expect(calls).toBe(1)
+  })
+})
+
+describe('fnv1a64', () => {
+  it('표준 FNV-1a 64비트 값을 16자리 16진수로 돌려준다', () => {
+    expect(fnv1a64('')).toBe('cbf29ce484222325')
+    expect(fnv1a64('a')).toBe('af63dc4c8601ec8c')
+  })
+})
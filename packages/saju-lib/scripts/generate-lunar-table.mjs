@@ -0,0 +1,192 @@
+#!/usr/bin/env node
+/**
+ * @fileoverview 음력 표 생성기 — data/lunar-months.csv → src/lunarTable.ts
+ *
+ * CSV의 연도별 달 길이를 검사한 뒤 lunar.ts가 쓰는 20비트 압축 표로 컴파일한다.
+ * 검사 항목:
+ * - 연도가 빠짐없이 이어지고 평달이 12개, 달마다 29일 또는 30일이다.
+ * - 윤달 번호와 윤달 일수가 서로 맞다 (윤달이 없으면 둘 다 0).
+ * - 해의 길이가 353~355일(평년) 또는 383~385일(윤년)이다.
+ * - 설날이 1월 21일~2월 20일 사이이고, 다음 해 설날이 그해 설날 + 그해 일수와 같다.
+ *
+ * 사용 예:
+ *   node scripts/generate-lunar-table.mjs          # src/lunarTable.ts를 다시 쓴다
+ *   node scripts/generate-lunar-table.mjs --check  # 생성 결과와 다르면 실패 (빌드 전 검사)
+ */
+
+import { readFileSync, writeFileSync } from 'node:fs';
+import { fileURLToPath } from 'node:url';
+
+const ROOT = new URL('../', import.meta.url);
+export const DATASET_PATH = fileURLToPath(new URL('data/lunar-months.csv', ROOT));
+export const TABLE_PATH = fileURLToPath(new URL('src/lunarTable.ts', ROOT));
+
+const HEADER = 'year,new_year,month_days,leap_month,leap_days';
+const DAY_MS = 86_400_000;
+
+/** 데이터 오류 (줄 번호를 붙인다) */
+export class LunarDatasetError extends Error {
+  constructor(line, message) {
+    super(`lunar-months.csv:${line}: ${message}`);
+    this.name = 'LunarDatasetError';
+  }
+}
+
+/**
+ * CSV 본문을 연도별 행으로 읽고 검사한다.
+ * @param {string} text CSV 본문
+ * @returns {{ year: number, newYear: string, monthDays: number[], leapMonth: number, leapDays: number }[]}
+ */
+export function parseDataset(text) {
+  const rows = [];
+  let headerSeen = false;
+  text.split('\n').forEach((raw, index) => {
+    const line = index + 1;
+    const content = raw.trim();
+    if (content === '' || content.startsWith('#')) return;
+    if (!headerSeen) {
+      if (content !== HEADER) throw new LunarDatasetError(line, `header must be "${HEADER}"`);
+      headerSeen = true;
+      return;
+    }
+    const fields = content.split(',');
+    if (fields.length !== 5) throw new LunarDatasetError(line, `expected 5 fields, got ${fields.length}`);
+    const [year, newYear, monthDays, leapMonth, leapDays] = fields;
+    rows.push(checkRow(line, {
+      year: Number(year),
+      newYear,
+      monthDays: monthDays.trim().split(/\s+/).map(Number),
+      leapMonth: Number(leapMonth),
+      leapDays: Number(leapDays),
+    }, rows[rows.length - 1]));
+  });
+  if (rows.length === 0) throw new LunarDatasetError(1, 'no rows');
+  return rows;
+}
+
+function checkRow(line, row, prev) {
+  if (!Number.isInteger(row.year)) throw new LunarDatasetError(line, 'year must be an integer');
+  if (prev && row.year !== prev.year + 1) throw new LunarDatasetError(line, `year ${row.year} does not follow ${prev.year}`);
+  const newYear = parseYmd(row.newYear);
+  if (newYear === null) throw new LunarDatasetError(line, `invalid new_year ${row.newYear}`);
+  if (newYear.getUTCFullYear() !== row.year) throw new LunarDatasetError(line, `new_year ${row.newYear} is not in ${row.year}`);
+  const mmdd = row.newYear.slice(5);
+  if (mmdd < '01-21' || mmdd > '02-20') throw new LunarDatasetError(line, `new_year ${row.newYear} outside 01-21..02-20`);
+  if (row.monthDays.length !== 12) throw new LunarDatasetError(line, `expected 12 month lengths, got ${row.monthDays.length}`);
+  if (!row.monthDays.every(isMonthLength)) throw new LunarDatasetError(line, 'month lengths must be 29 or 30');
+  if (!Number.isInteger(row.leapMonth) || row.leapMonth < 0 || row.leapMonth > 12) {
+    throw new LunarDatasetError(line, 'leap_month must be 0-12');
+  }
+  if (row.leapMonth === 0 ? row.leapDays !== 0 : !isMonthLength(row.leapDays)) {
+    throw new LunarDatasetError(line, 'leap_days must be 0 without a leap month, 29 or 30 with one');
+  }
+  const days = yearDays(row);
+  const valid = row.leapMonth === 0 ? days >= 353 && days <= 355 : days >= 383 && days <= 385;
+  if (!valid) throw new LunarDatasetError(line, `year length ${days} days is impossible`);
+  if (prev) {
+    const expected = formatYmd(new Date(parseYmd(prev.newYear).getTime() + yearDays(prev) * DAY_MS));
+    if (row.newYear !== expected) throw new LunarDatasetError(line, `new_year ${row.newYear}, but ${prev.year} ends on ${expected}`);
+  }
+  return row;
+}
+
+/**
+ * 행을 lunar.ts의 20비트 엔트리로 압축한다.
+ * bit 16 = 윤달 대소, bit 15~4 = 1~12월 대소, bit 3~0 = 윤달 번호.
+ */
+export function encodeRow(row) {
+  let info = row.leapMonth;
+  row.monthDays.forEach((days, i) => {
+    if (days === 30) info |= 0x8000 >> i;
+  });
+  if (row.leapDays === 30) info |= 0x10000;
+  return info;
+}
+
+/** 검사한 행으로 src/lunarTable.ts 본문을 만든다 */
+export function renderTable(rows) {
+  const first = rows[0];
+  const last = rows[rows.length - 1];
+  const end = formatYmd(new Date(parseYmd(last.newYear).getTime() + yearDays(last) * DAY_MS));
+  const out = [
+    '/**',
+    ' * @fileoverview 음력 데이터 테이블 — 자동 생성 파일, 직접 고치지 말 것',
+    ' *',
+    ' * data/lunar-months.csv에서 scripts/generate-lunar-table.mjs로 만든다.',
+    ' * 고칠 때는 CSV를 고치고 `pnpm --filter saju-lib generate:lunar`를 실행한다.',
+    ' */',
+    '',
+    '/** 지원 음력 최소 연도 */',
+    `export const LUNAR_MIN_YEAR = ${first.year};`,
+    '/** 지원 음력 최대 연도 */',
+    `export const LUNAR_MAX_YEAR = ${last.year};`,
+    '/** LUNAR_MIN_YEAR 음력 1월 1일의 양력 날짜 (연, 월, 일) */',
+    `export const LUNAR_FIRST_NEW_YEAR: readonly [number, number, number] = [${ymdTuple(first.newYear)}];`,
+    '/** LUNAR_MAX_YEAR 다음 해 음력 1월 1일의 양력 날짜 (지원 범위 끝, 포함하지 않음) */',
+    `export const LUNAR_END_NEW_YEAR: readonly [number, number, number] = [${ymdTuple(end)}];`,
+    '',
+    '/**',
+    ` * 음력 데이터 테이블 (${first.year}~${last.year}년, ${rows.length}개 항목).`,
+    ' *',
+    ' * 각 항목은 20비트 정수로, 해당 음력 연도의 월별 대/소월과 윤달 정보를 인코딩한다:',
+    ' *',
+    ' * 비트 구조 (상위 → 하위):',
+    ' * - bit 16    : 윤달의 대소(大小). 1=30일(대), 0=29일(소). 윤달이 없으면 무시.',
+    ' * - bit 15~4  : 1~12월 각 월의 대소. 1=30일(대), 0=29일(소). bit 15=1월, bit 4=12월.',
+    ' * - bit 3~0   : 윤달이 들어가는 월 번호 (1~12). 0이면 해당 연도에 윤달 없음.',
+    ' */',
+    'export const LUNAR_INFO: readonly number[] = [',
+  ];
+  for (let i = 0; i < rows.length; i += 10) {
+    const chunk = rows.slice(i, i + 10);
+    out.push(`  // ${chunk[0].year}-${chunk[chunk.length - 1].year}`);
+    out.push(`  ${chunk.map((row) => `0x${encodeRow(row).toString(16).padStart(5, '0')},`).join(' ')}`);
+  }
+  out.push('];', '');
+  return out.join('\n');
+}
+
+function yearDays(row) {
+  return row.monthDays.reduce((sum, days) => sum + days, 0) + row.leapDays;
+}
+
+function isMonthLength(days) {
+  return days === 29 || days === 30;
+}
+
+function parseYmd(text) {
+  const m = /^(\d{4})-(\d{2})-(\d{2})$/.exec(text);
+  if (!m) return null;
+  const date = new Date(Date.UTC(Number(m[1]), Number(m[2]) - 1, Number(m[3])));
+  return formatYmd(date) === text ? date : null;
+}
+
+function formatYmd(date) {
+  return date.toISOString().slice(0, 10);
+}
+
+function ymdTuple(text) {
+  return text.split('-').map(Number).join(', ');
+}
+
+function main(args) {
+  const table = renderTable(parseDataset(readFileSync(DATASET_PATH, 'utf8')));
+  if (args.includes('--check')) {
+    if (readFileSync(TABLE_PATH, 'utf8') !== table) {
+      console.error('src/lunarTable.ts is out of date with data/lunar-months.csv; run `pnpm --filter saju-lib generate:lunar`');
+      process.exit(1);
+    }
+    return;
+  }
+  writeFileSync(TABLE_PATH, table);
+}
+
+if (process.argv[1] === fileURLToPath(import.meta.url)) {
+  try {
+    main(process.argv.slice(2));
+  } catch (err) {
+    if (!(err instanceof LunarDatasetError)) throw err;
+    console.error(err.message);
+    process.exit(1);
+  }
+}
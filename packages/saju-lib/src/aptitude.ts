@@ -0,0 +1,76 @@
+/**
+ * @fileoverview 십성 기반 적성(適性) 해석 모듈
+ *
+ * 원국의 십성 분포를 그룹(비겁·식상·재성·관성·인성)별 가중치로 집계하고
+ * 규칙표(aptitudeRules)를 평가하여 직업·적성 경향을 요약한다.
+ */
+
+import { hiddenStems, relation, stemElement } from './bazi.js';
+import { APTITUDE_RULES, type AptitudeRule } from './aptitudeRules.js';
+import type { Pillar, Relation } from './types.js';
+
+export { APTITUDE_RULES, DEVELOPED_WEIGHT, type AptitudeRule, type RelationCondition } from './aptitudeRules.js';
+
+/** 십성 그룹별 가중치 */
+export type RelationWeights = Record<Relation, number>;
+
+/** 천간 가중치 */
+const STEM_WEIGHT = 1;
+/** 지장간 정기 가중치 */
+const MAIN_HIDDEN_WEIGHT = 1;
+/** 지장간 여기·중기 가중치 */
+const MINOR_HIDDEN_WEIGHT = 0.5;
+/** 월주(월령)에 곱하는 배율 */
+const MONTH_FACTOR = 1.5;
+
+/** 요약에 싣는 최대 규칙 수 */
+export const MAX_TENDENCIES = 3;
+
+/**
+ * 일간을 제외한 천간과 지장간의 십성 그룹 가중치를 집계한다.
+ *
+ * 천간·지장간 정기는 1, 여기·중기는 0.5이며 월주는 1.5배로 본다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ */
+export function relationWeights(pillars: Pillar[], dayStem: number): RelationWeights {
+  const weights: RelationWeights = { Same: 0, Output: 0, Wealth: 0, Officer: 0, Resource: 0 };
+  const dayElement = stemElement(dayStem);
+  pillars.forEach((pillar, idx) => {
+    const factor = idx === 1 ? MONTH_FACTOR : 1;
+    if (idx !== 2) {
+      weights[relation(dayElement, stemElement(pillar.stem))] += STEM_WEIGHT * factor;
+    }
+    hiddenStems(pillar.branch).forEach((stem, i) => {
+      const w = i === 0 ? MAIN_HIDDEN_WEIGHT : MINOR_HIDDEN_WEIGHT;
+      weights[relation(dayElement, stemElement(stem))] += w * factor;
+    });
+  });
+  return weights;
+}
+
+/** 규칙의 조건을 모두 만족하는지 판정한다 */
+function matches(rule: AptitudeRule, weights: RelationWeights): boolean {
+  return rule.when.every((c) => {
+    const w = weights[c.relation];
+    return (c.min === undefined || w >= c.min) && (c.max === undefined || w <= c.max);
+  });
+}
+
+/**
+ * 원국의 적성 경향을 요약한다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ * @param rules 평가할 규칙 (기본: 내장 규칙표)
+ * @returns 만족한 규칙을 규칙표 순서대로 최대 MAX_TENDENCIES개
+ */
+export function aptitudeSummary(
+  pillars: Pillar[],
+  dayStem: number,
+  rules: readonly AptitudeRule[] = APTITUDE_RULES,
+): AptitudeRule[] {
+  const weights = relationWeights(pillars, dayStem);
+  return rules.filter((r) => matches(r, weights)).slice(0, MAX_TENDENCIES);
+}
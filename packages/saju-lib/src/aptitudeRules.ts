@@ -0,0 +1,111 @@
+/**
+ * @fileoverview 십성 기반 적성 판단 규칙표
+ *
+ * aptitude 모듈이 평가하는 규칙 데이터. 규칙 하나는 십성 그룹별
+ * 가중치 조건(모두 만족)과 그때의 성향·추천 분야 문구로 이루어진다.
+ * 규칙 순서가 곧 출력 우선순위다 (조합 규칙 → 발달 → 부재).
+ */
+
+import type { Lang } from './i18n.js';
+import type { Relation } from './types.js';
+
+/** 십성 그룹 가중치 조건 (min 이상, max 이하) */
+export interface RelationCondition {
+  relation: Relation;
+  min?: number;
+  max?: number;
+}
+
+/** 적성 규칙 하나 */
+export interface AptitudeRule {
+  /** 규칙 식별자 */
+  id: string;
+  /** 모두 만족해야 하는 조건 */
+  when: RelationCondition[];
+  /** 성향 설명 */
+  tendency: Record<Lang, string>;
+  /** 추천 분야 */
+  fields: Record<Lang, string[]>;
+}
+
+/** 그룹이 '발달'했다고 보는 가중치 */
+export const DEVELOPED_WEIGHT = 3;
+
+/** 내장 적성 규칙 */
+export const APTITUDE_RULES: readonly AptitudeRule[] = [
+  // ── 조합 ──
+  {
+    id: 'output-wealth',
+    when: [{ relation: 'Output', min: 2 }, { relation: 'Wealth', min: 2 }],
+    tendency: { Ko: '식상생재 — 기술·아이디어를 수익으로 연결하는 사업 감각', En: 'Output feeds Wealth — turns skills and ideas into income' },
+    fields: { Ko: ['사업', '영업', '마케팅', '프리랜서'], En: ['business', 'sales', 'marketing', 'freelancing'] },
+  },
+  {
+    id: 'officer-resource',
+    when: [{ relation: 'Officer', min: 2 }, { relation: 'Resource', min: 2 }],
+    tendency: { Ko: '관인상생 — 조직 안에서 신뢰를 쌓아 승진하는 구조', En: 'Officer feeds Resource — rises through trust within organizations' },
+    fields: { Ko: ['공무원', '공기업', '대기업 관리직', '법조'], En: ['civil service', 'public corporations', 'corporate management', 'law'] },
+  },
+  {
+    id: 'wealth-officer',
+    when: [{ relation: 'Wealth', min: 2 }, { relation: 'Officer', min: 2 }],
+    tendency: { Ko: '재생관 — 재물 관리와 책임 있는 직위가 함께 가는 구조', En: 'Wealth feeds Officer — money management paired with responsibility' },
+    fields: { Ko: ['금융', '회계', '경영 관리'], En: ['finance', 'accounting', 'management'] },
+  },
+  // ── 발달 ──
+  {
+    id: 'same-developed',
+    when: [{ relation: 'Same', min: DEVELOPED_WEIGHT }],
+    tendency: { Ko: '비겁 발달 — 독립심·경쟁심이 강하고 자기 사업을 선호', En: 'Strong Peers — independent and competitive, prefers own ventures' },
+    fields: { Ko: ['자영업', '스포츠', '동업', '영업'], En: ['self-employment', 'sports', 'partnerships', 'sales'] },
+  },
+  {
+    id: 'output-developed',
+    when: [{ relation: 'Output', min: DEVELOPED_WEIGHT }],
+    tendency: { Ko: '식상 발달 — 표현력·창의력이 뛰어나 말과 작품으로 드러냄', En: 'Strong Output — expressive and creative in words and works' },
+    fields: { Ko: ['예술', '방송', '교육', '디자인', '요식업'], En: ['arts', 'media', 'teaching', 'design', 'food service'] },
+  },
+  {
+    id: 'wealth-developed',
+    when: [{ relation: 'Wealth', min: DEVELOPED_WEIGHT }],
+    tendency: { Ko: '재성 발달 — 현실 감각과 계산이 밝고 결과를 중시', En: 'Strong Wealth — practical, numerate and results-driven' },
+    fields: { Ko: ['금융', '유통', '무역', '부동산'], En: ['finance', 'distribution', 'trade', 'real estate'] },
+  },
+  {
+    id: 'officer-developed',
+    when: [{ relation: 'Officer', min: DEVELOPED_WEIGHT }],
+    tendency: { Ko: '관성 발달 — 규범·책임감이 강해 조직 생활에 적합', En: 'Strong Officer — principled and responsible, suited to organizations' },
+    fields: { Ko: ['공직', '군·경찰', '관리직', '법률'], En: ['public office', 'military/police', 'management', 'law'] },
+  },
+  {
+    id: 'resource-developed',
+    when: [{ relation: 'Resource', min: DEVELOPED_WEIGHT }],
+    tendency: { Ko: '인성 발달 — 학습·사고력이 깊고 자격·지식으로 인정받음', En: 'Strong Resource — studious and reflective, valued for knowledge' },
+    fields: { Ko: ['연구', '교육', '의료', '출판', '상담'], En: ['research', 'education', 'medicine', 'publishing', 'counseling'] },
+  },
+  // ── 부재 ──
+  {
+    id: 'output-absent',
+    when: [{ relation: 'Output', max: 0 }],
+    tendency: { Ko: '식상 부재 — 생각을 밖으로 드러내는 연습이 필요', En: 'No Output — practice expressing ideas outwardly' },
+    fields: { Ko: [], En: [] },
+  },
+  {
+    id: 'wealth-absent',
+    when: [{ relation: 'Wealth', max: 0 }],
+    tendency: { Ko: '재성 부재 — 재무 감각보다 명분·전문성을 좇는 편', En: 'No Wealth — drawn to principle and expertise over money' },
+    fields: { Ko: [], En: [] },
+  },
+  {
+    id: 'officer-absent',
+    when: [{ relation: 'Officer', max: 0 }],
+    tendency: { Ko: '관성 부재 — 틀에 얽매이지 않는 자유로운 업무 환경이 맞음', En: 'No Officer — fits free, loosely structured work' },
+    fields: { Ko: [], En: [] },
+  },
+  {
+    id: 'resource-absent',
+    when: [{ relation: 'Resource', max: 0 }],
+    tendency: { Ko: '인성 부재 — 이론보다 실전 경험으로 배우는 편', En: 'No Resource — learns by doing rather than theory' },
+    fields: { Ko: [], En: [] },
+  },
+];
@@ -6,9 +6,18 @@
  *
  * 태양 위치 계산에는 VSOP87 이론의 간략 공식(low-precision solar position)을
  * 사용한다. Jean Meeus, "Astronomical Algorithms" 참조.
+ *
+ * 입춘처럼 절기 하나만 필요하면 termJd()가 그 절기만 직접 풀어 준다.
+ * 어떤 시점이 속한 절기 구간은 termContaining(), 앞뒤 절기는 prevTerm()·nextTerm()으로 구한다.
+ *
+ * 태양·달 시황경(sunApparentLongitude·moonApparentLongitude)과 달의 이각(moonElongation)도
+ * 공개하여 달 위상·삭망 계산이나 외부 검증에 같은 엔진을 쓸 수 있게 한다.
+ * sunSign()은 같은 황경으로 서양 태양 별자리를 대조해 준다.
  */
 
 import type { SolarTerm, TermDef } from './types.js';
+import { registerCache, timed } from './timings.js';
+import { LruCache } from './utils.js';
 
 /**
  * 24절기 정의 배열.
@@ -144,6 +153,241 @@ export function computeSolarTerms(year: number): SolarTerm[] {
   return results;
 }
 
+/** 태양의 평균 황경 이동 속도 (도/일) */
+const SUN_DEGREES_PER_DAY = 360.0 / 365.2422;
+
+/** 절기 시점 캐시 (키: 연도 × 24 + TERM_DEFS 인덱스) */
+const termJdCache = registerCache('termJd', new LruCache<number, number>(256));
+
+/** 절기 키 → TERM_DEFS 인덱스 */
+const TERM_INDEX: ReadonlyMap<string, number> = new Map(TERM_DEFS.map((def, i) => [def.key, i]));
+
+/** TERM_DEFS[0](소한)의 황경 */
+const FIRST_TERM_ANGLE = 285.0;
+
+/** 입춘의 TERM_DEFS 인덱스 (월운·월주의 첫 달 경계) */
+const LICHUN_INDEX = 2;
+
+/** 황경(15°의 배수)에 해당하는 절기 정의 */
+function termDefAt(angle: number): TermDef {
+  return TERM_DEFS[Math.round(normDeg(angle - FIRST_TERM_ANGLE) / 15.0) % 24];
+}
+
+/**
+ * 특정 연도(UTC 기준 1월 1일~12월 31일)의 절기 하나의 시점을 계산한다.
+ *
+ * 평균 운동으로 추정한 날짜에서 뉴턴법으로 목표 황경에 다가간 뒤,
+ * computeSolarTerms와 같은 이분법으로 마무리해 두 결과가 일치하도록 한다.
+ * 24절기 전체를 구하지 않으므로 입춘 하나만 필요한 연주 판정·세운에 쓴다.
+ *
+ * @param year 연도
+ * @param key 절기 키 (예: 'lichun')
+ * @returns 절기 시점의 JD
+ * @throws RangeError 알 수 없는 절기 키
+ */
+export function termJd(year: number, key: string): number {
+  const index = TERM_INDEX.get(key);
+  if (index === undefined) throw new RangeError(`unknown solar term key: ${key}`);
+  return termJdAt(year, index);
+}
+
+/** termJd의 인덱스 버전 (index: TERM_DEFS 인덱스) */
+function termJdAt(year: number, index: number): number {
+  return termJdCache.getOrCompute(year * 24 + index, () => {
+    // 소한(1월 6일 무렵)부터 약 15.2일 간격
+    const estimate = jdFromUtcDate(year, 1, 6, 0, 0, 0) + (index * 365.2422) / 24;
+    return solveLongitude(TERM_DEFS[index].angle, estimate);
+  });
+}
+
+/**
+ * 입춘 기준 연도의 월 경계(절입) 시점.
+ *
+ * 0은 그해 입춘, 1은 경칩, …, 11은 다음 해 소한, 12는 다음 해 입춘이다.
+ * 절(節)은 TERM_DEFS에서 한 칸씩 건너 놓여 있으므로 인덱스 계산만으로 찾는다.
+ *
+ * @param year 입춘 기준 연도
+ * @param month 월 순번 (0~12, 0 = 인월)
+ */
+export function monthBoundaryJd(year: number, month: number): number {
+  const index = LICHUN_INDEX + month * 2;
+  return index < 24 ? termJdAt(year, index) : termJdAt(year + 1, index - 24);
+}
+
+/** 절입 시각과 이만큼(도) 가까우면 황경만으로 월을 정하지 않고 절입 시각과 비교한다 */
+const MONTH_BOUNDARY_MARGIN_DEG = 1e-4;
+
+/**
+ * 주어진 시점이 입춘부터 몇 번째 달(절월)에 속하는지 구한다.
+ *
+ * 태양 황경만으로 정하며, 절입 시각에 아주 가까울 때만 termContaining으로 경계를 푼다.
+ * 구간 객체를 만들지 않으므로 월주 판정처럼 자주 부르는 곳에 쓴다.
+ *
+ * @param jd 율리우스일
+ * @returns 월 순번 (0~11, 0 = 인월)
+ */
+export function monthIndexAt(jd: number): number {
+  const sinceLichun = normDeg(sunApparentLongitude(jd) - TERM_DEFS[LICHUN_INDEX].angle);
+  const inMonth = sinceLichun % 30.0;
+  if (inMonth < MONTH_BOUNDARY_MARGIN_DEG || inMonth > 30.0 - MONTH_BOUNDARY_MARGIN_DEG) {
+    const angle = termContaining(jd, true).def.angle;
+    return Math.round(normDeg(angle - TERM_DEFS[LICHUN_INDEX].angle) / 30.0) % 12;
+  }
+  return Math.floor(sinceLichun / 30.0);
+}
+
+/** 절기 구간: 한 절기 시작부터 다음 절기(또는 다음 절) 시작 직전까지 */
+export interface TermPeriod {
+  /** 구간을 여는 절기 */
+  def: TermDef;
+  /** 구간 시작 JD (포함) */
+  startJd: number;
+  /** 구간 끝 JD (다음 구간 시작, 미포함) */
+  endJd: number;
+}
+
+/**
+ * 월의 경계가 되는 절(節)인지 판정한다.
+ * 입춘(315°)·경칩(345°)·청명(15°)처럼 황경이 15° + 30°n인 12절기다.
+ */
+export function isMonthBoundaryTerm(def: TermDef): boolean {
+  return def.angle % 30 === 15;
+}
+
+/**
+ * 주어진 시점이 속한 절기 구간을 구한다.
+ *
+ * 태양 황경으로 구간을 정한 뒤 시작·끝 절기를 termJd와 같은 방식으로 푼다.
+ * monthBoundaries가 참이면 절(節)만 경계로 삼아 월주 한 달(약 30일) 구간을,
+ * 거짓이면 24절기 구간(약 15일)을 돌려준다.
+ *
+ * @param jd 율리우스일
+ * @param monthBoundaries 절(節)만 경계로 삼을지 여부
+ */
+export function termContaining(jd: number, monthBoundaries = false): TermPeriod {
+  const step = monthBoundaries ? 30.0 : 15.0;
+  const offset = monthBoundaries ? 15.0 : 0.0;
+  const lon = sunApparentLongitude(jd);
+  let startAngle = normDeg(Math.floor((lon - offset) / step) * step + offset);
+  let startJd = solveLongitude(startAngle, jd - normDeg(lon - startAngle) / SUN_DEGREES_PER_DAY);
+  // 경계 바로 앞뒤에서는 황경과 이분법 결과가 어긋날 수 있으므로 이웃 구간으로 옮긴다
+  if (jd < startJd) {
+    startAngle = normDeg(startAngle - step);
+    startJd = solveLongitude(startAngle, startJd - step / SUN_DEGREES_PER_DAY);
+  }
+  let endJd = solveLongitude(normDeg(startAngle + step), startJd + step / SUN_DEGREES_PER_DAY);
+  if (jd >= endJd) {
+    startAngle = normDeg(startAngle + step);
+    startJd = endJd;
+    endJd = solveLongitude(normDeg(startAngle + step), startJd + step / SUN_DEGREES_PER_DAY);
+  }
+  return { def: termDefAt(startAngle), startJd, endJd };
+}
+
+/**
+ * 주어진 시점 이후 처음 오는 절기를 구한다.
+ *
+ * @param jd 율리우스일
+ * @param monthBoundaries 절(節)만 대상으로 할지 여부
+ * @returns jd보다 뒤에 오는 가장 가까운 절기
+ */
+export function nextTerm(jd: number, monthBoundaries = false): SolarTerm {
+  const period = termContaining(jd, monthBoundaries);
+  return { def: termDefAt(period.def.angle + (monthBoundaries ? 30.0 : 15.0)), jd: period.endJd };
+}
+
+/**
+ * 주어진 시점 이전의 마지막 절기를 구한다.
+ *
+ * @param jd 율리우스일
+ * @param monthBoundaries 절(節)만 대상으로 할지 여부
+ * @returns jd보다 앞선 가장 가까운 절기
+ */
+export function prevTerm(jd: number, monthBoundaries = false): SolarTerm {
+  let period = termContaining(jd, monthBoundaries);
+  // 절입 시각과 정확히 같으면 그 앞 구간의 절기
+  if (period.startJd >= jd) period = termContaining(period.startJd - 1, monthBoundaries);
+  return { def: period.def, jd: period.startJd };
+}
+
+/** 서양 황도 12궁 (백양궁부터 황경 30°씩) */
+export type ZodiacSign =
+  | 'Aries' | 'Taurus' | 'Gemini' | 'Cancer' | 'Leo' | 'Virgo'
+  | 'Libra' | 'Scorpio' | 'Sagittarius' | 'Capricorn' | 'Aquarius' | 'Pisces';
+
+export const ZODIAC_SIGNS: readonly ZodiacSign[] = [
+  'Aries', 'Taurus', 'Gemini', 'Cancer', 'Leo', 'Virgo',
+  'Libra', 'Scorpio', 'Sagittarius', 'Capricorn', 'Aquarius', 'Pisces',
+];
+
+/** 태양이 새 별자리에 들어가는 시점 */
+export interface ZodiacIngress {
+  /** 들어가는 별자리 */
+  sign: ZodiacSign;
+  jd: number;
+}
+
+/** 태양 별자리 (서양 점성술) */
+export interface SunSign {
+  sign: ZodiacSign;
+  /** 태양 시황경 (0~360도) */
+  longitude: number;
+  /** 경계(cusp) 근처면 가장 가까운 진입 시점, 아니면 null */
+  cusp: ZodiacIngress | null;
+}
+
+/** 경계 근처로 보는 기본 범위 (일) */
+const SUN_SIGN_CUSP_DAYS = 1.0;
+
+/**
+ * 주어진 시점의 태양 별자리를 구한다.
+ *
+ * 별자리 경계는 황경 30°의 배수로 중기(춘분·곡우·소만 …)와 같다.
+ * 경계에서 cuspDays일 이내면 그 경계의 진입 시점도 함께 돌려준다.
+ *
+ * @param jd 율리우스일
+ * @param cuspDays 경계 근처로 볼 범위 (일)
+ */
+export function sunSign(jd: number, cuspDays = SUN_SIGN_CUSP_DAYS): SunSign {
+  const longitude = sunApparentLongitude(jd);
+  const index = Math.floor(longitude / 30.0) % 12;
+  const sign = ZODIAC_SIGNS[index];
+  const sinceStart = (longitude - index * 30.0) / SUN_DEGREES_PER_DAY;
+  const untilEnd = ((index + 1) * 30.0 - longitude) / SUN_DEGREES_PER_DAY;
+  let cusp: ZodiacIngress | null = null;
+  if (sinceStart <= untilEnd && sinceStart <= cuspDays) {
+    cusp = { sign, jd: solveLongitude(index * 30.0, jd - sinceStart) };
+  } else if (untilEnd < sinceStart && untilEnd <= cuspDays) {
+    cusp = { sign: ZODIAC_SIGNS[(index + 1) % 12], jd: solveLongitude(normDeg((index + 1) * 30.0), jd + untilEnd) };
+  }
+  return { sign, longitude, cusp };
+}
+
+/**
+ * 태양 황경이 목표값에 도달하는 시점을 근사 시점 부근에서 구한다.
+ *
+ * 뉴턴법으로 다가간 뒤 ±0.01일 구간을 refineTerm으로 마무리해
+ * computeSolarTerms와 같은 결과를 낸다.
+ *
+ * @param target 목표 황경 (0~360도)
+ * @param nearJd 근사 시점 (수 일 이내)
+ */
+function solveLongitude(target: number, nearJd: number): number {
+  return timed('Terms', () => {
+    let jd = nearJd;
+    for (let i = 0; i < 20; i++) {
+      const diff = normDeg(target - sunApparentLongitude(jd) + 180.0) - 180.0;
+      jd += diff / SUN_DEGREES_PER_DAY;
+      if (Math.abs(diff) < 1e-7) break;
+    }
+    const lo = jd - 0.01;
+    const lon0 = sunApparentLongitude(lo);
+    let unwrapped = target;
+    while (unwrapped < lon0 - 180.0) unwrapped += 360.0;
+    return refineTerm(lo, jd + 0.01, lon0, unwrapped);
+  });
+}
+
 /**
  * 태양 시황경(apparent longitude)을 계산한다.
  *
@@ -152,10 +396,13 @@ export function computeSolarTerms(year: number): SolarTerm[] {
  * 2. 중심차(equation of center, C)를 적용하여 진황경 산출
  * 3. 장동(nutation) 보정을 적용하여 시황경 산출
  *
- * @param jd 율리우스일
+ * 정확도는 1900~2100년에서 약 0.01°(절기 시각 기준 약 15분)이다.
+ * 절기 계산 전체가 이 함수를 쓰므로 외부 검증도 같은 값으로 할 수 있다.
+ *
+ * @param jd 율리우스일 (역학시 TT를 UT로 근사)
  * @returns 태양 시황경 (0~360도)
  */
-function sunApparentLongitude(jd: number): number {
+export function sunApparentLongitude(jd: number): number {
   // 율리우스 세기(T) — J2000.0 에포크 기준
   const t = (jd - 2451545.0) / 36525.0;
   // 태양 평균 황경(L0)
@@ -175,6 +422,70 @@ function sunApparentLongitude(jd: number): number {
   return normDeg(lambda);
 }
 
+/**
+ * 달 황경 주기항 [D, M, M', F, 계수(10⁻⁶도)].
+ * Meeus 47장 표 47.A에서 계수 2000 이상인 항만 취했다.
+ */
+const MOON_LONGITUDE_TERMS: readonly (readonly [number, number, number, number, number])[] = [
+  [0, 0, 1, 0, 6288774], [2, 0, -1, 0, 1274027], [2, 0, 0, 0, 658314],
+  [0, 0, 2, 0, 213618], [0, 1, 0, 0, -185116], [0, 0, 0, 2, -114332],
+  [2, 0, -2, 0, 58793], [2, -1, -1, 0, 57066], [2, 0, 1, 0, 53322],
+  [2, -1, 0, 0, 45758], [0, 1, -1, 0, -40923], [1, 0, 0, 0, -34720],
+  [0, 1, 1, 0, -30383], [2, 0, 0, -2, 15327], [0, 0, 1, 2, -12528],
+  [0, 0, 1, -2, 10980], [4, 0, -1, 0, 10675], [0, 0, 3, 0, 10034],
+  [4, 0, -2, 0, 8548], [2, 1, -1, 0, -7888], [2, 1, 0, 0, -6766],
+  [1, 0, -1, 0, -5163], [1, 1, 0, 0, 4987], [2, -1, 1, 0, 4036],
+  [2, 0, 2, 0, 3994], [4, 0, 0, 0, 3861], [2, 0, -3, 0, 3665],
+  [0, 1, -2, 0, -2689], [2, 0, -1, 2, -2602], [2, -1, -2, 0, 2390],
+  [1, 0, 1, 0, -2348], [2, -2, 0, 0, 2236], [0, 1, 2, 0, -2120],
+  [0, 2, 0, 0, -2069],
+];
+
+/**
+ * 달의 시황경(apparent longitude)을 계산한다.
+ *
+ * Meeus "Astronomical Algorithms" 47장의 주기항 중 큰 항만 더한 간략식으로,
+ * 정확도는 약 0.01°(달 위치 기준 약 1분)이다. 장동 보정은 태양과 같은 식을 쓴다.
+ *
+ * @param jd 율리우스일 (역학시 TT를 UT로 근사)
+ * @returns 달 시황경 (0~360도)
+ */
+export function moonApparentLongitude(jd: number): number {
+  const t = (jd - 2451545.0) / 36525.0;
+  // 달 평균 황경(L'), 평균 이각(D), 태양·달 평균 근점이각(M, M'), 승교점 이각(F)
+  const lp = 218.3164477 + 481267.88123421 * t - 0.0015786 * t * t + (t * t * t) / 538841.0;
+  const d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t * t + (t * t * t) / 545868.0;
+  const m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t * t;
+  const mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t * t + (t * t * t) / 69699.0;
+  const f = 93.272095 + 483202.0175233 * t - 0.0036539 * t * t;
+  // 지구 궤도 이심률 감소 보정
+  const e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+
+  let sum = 0.0;
+  for (const [cd, cm, cmp, cf, coef] of MOON_LONGITUDE_TERMS) {
+    const arg = degToRad(cd * d + cm * m + cmp * mp + cf * f);
+    sum += coef * e ** Math.abs(cm) * Math.sin(arg);
+  }
+  // 금성(A1)·목성(A2) 섭동과 지구 편평도 보정
+  const a1 = 119.75 + 131.849 * t;
+  const a2 = 53.09 + 479264.29 * t;
+  sum += 3958 * Math.sin(degToRad(a1)) + 1962 * Math.sin(degToRad(lp - f)) + 318 * Math.sin(degToRad(a2));
+
+  const omega = 125.04 - 1934.136 * t;
+  return normDeg(lp + sum / 1e6 - 0.00478 * Math.sin(degToRad(omega)));
+}
+
+/**
+ * 달과 태양의 황경 차(이각, elongation)를 계산한다.
+ * 0°는 삭(朔, 새달), 90°는 상현, 180°는 망(望, 보름), 270°는 하현이다.
+ *
+ * @param jd 율리우스일
+ * @returns 달 황경 − 태양 황경 (0~360도)
+ */
+export function moonElongation(jd: number): number {
+  return normDeg(moonApparentLongitude(jd) - sunApparentLongitude(jd));
+}
+
 /**
  * 이분법(bisection)으로 태양 황경이 목표값에 도달하는 JD를 정밀 산출한다.
  * 60회 반복으로 약 10⁻¹⁸일(≈ 0.1µs) 정밀도를 달성한다.
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview 오행 과부족(過不足) 분석 모듈
+ *
+ * 원국의 오행 분포에서 없는 오행(0개)과 과다한 오행(4개 이상)을 찾아,
+ * 일간 기준 십성 역할과 해당 오행을 공급하는 천간·지지를 함께 제시한다.
+ * 오행 간 관계 행렬과 일간 오행으로 들고 나는 기운의 요약도 제공한다.
+ */
+
+import {
+  branchElement,
+  elementIndex,
+  elementsCount,
+  relation,
+  stemElement,
+  tenGod,
+} from './bazi.js';
+import type { Element, ElementOverrides, Pillar, Relation, TenGod } from './types.js';
+
+/** 과부족 구분 */
+export type ElementStatus = 'Lacking' | 'Excess';
+
+/** 과다로 보는 최소 개수 (천간+지지 8자 중) */
+export const EXCESS_THRESHOLD = 4;
+
+/** 오행 하나의 과부족 분석 */
+export interface ElementAdvice {
+  element: Element;
+  /** 원국 내 개수 (천간+지지) */
+  count: number;
+  status: ElementStatus;
+  /** 일간 기준 관계 (비겁/식상/재성/관성/인성) */
+  relation: Relation;
+  /** 해당 오행이 맡는 십성 (양간, 음간 순) */
+  tenGods: [TenGod, TenGod];
+  /** 해당 오행의 천간 인덱스 */
+  stems: number[];
+  /** 해당 오행의 지지 인덱스 */
+  branches: number[];
+}
+
+const ELEMENTS: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+
+/**
+ * 원국의 오행 과부족을 분석한다.
+ *
+ * 없는 오행은 보충이 필요한 기운으로, 4개 이상인 오행은 치우친 기운으로 본다.
+ * stems/branches는 부족하면 보충원, 과다하면 더 들어오면 부담이 되는 글자다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ * @returns 오행 순(목화토금수)으로 정렬한 분석 결과 (해당 없으면 빈 배열)
+ */
+export function elementAdvisory(pillars: Pillar[], dayStem: number): ElementAdvice[] {
+  const counts = elementsCount(pillars);
+  const dayElement = stemElement(dayStem);
+  const advice: ElementAdvice[] = [];
+  for (const element of ELEMENTS) {
+    const count = counts[elementIndex(element)];
+    if (count !== 0 && count < EXCESS_THRESHOLD) continue;
+    const stems = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].filter((s) => stemElement(s) === element);
+    const branches = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11].filter((b) => branchElement(b) === element);
+    advice.push({
+      element,
+      count,
+      status: count === 0 ? 'Lacking' : 'Excess',
+      relation: relation(dayElement, element),
+      tenGods: [tenGod(dayStem, stems[0]), tenGod(dayStem, stems[1])],
+      stems,
+      branches,
+    });
+  }
+  return advice;
+}
+
+/**
+ * 오행 상호 관계 5×5 행렬 (목화토금수 순).
+ * matrix[a][b]는 a를 기준으로 본 b의 관계, 즉 relation(a, b)이다.
+ */
+export function relationMatrix(): Relation[][] {
+  return ELEMENTS.map((a) => ELEMENTS.map((b) => relation(a, b)));
+}
+
+/** 오행 하나가 일간 오행에 대해 갖는 관계와 개수 */
+export interface ElementFlow {
+  element: Element;
+  /** 원국 내 개수 (천간+지지) */
+  count: number;
+  /** 일간 기준 관계 */
+  relation: Relation;
+}
+
+/** 일간 오행을 중심으로 본 원국 오행의 흐름 */
+export interface DayElementFlow {
+  dayElement: Element;
+  /** 오행별 개수와 관계 (목화토금수 순) */
+  elements: ElementFlow[];
+  /** 일간 오행을 생하는 글자 수 (인성) */
+  generating: number;
+  /** 일간 오행을 극하는 글자 수 (관성) */
+  controlling: number;
+  /** 일간 오행과 같은 글자 수 (비겁) */
+  same: number;
+  /** 일간 오행이 생하는 글자 수 (식상) */
+  generated: number;
+  /** 일간 오행이 극하는 글자 수 (재성) */
+  controlled: number;
+}
+
+/**
+ * 원국 오행이 일간 오행을 얼마나 생하고 극하는지 요약한다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ * @param overrides 합화 치환 오행 (있으면 일간 오행과 분포에 반영)
+ */
+export function dayElementFlow(pillars: Pillar[], dayStem: number, overrides?: ElementOverrides | null): DayElementFlow {
+  const counts = elementsCount(pillars, overrides);
+  const dayElement = overrides?.stems[2] ?? stemElement(dayStem);
+  const elements = ELEMENTS.map((element) => ({
+    element,
+    count: counts[elementIndex(element)],
+    relation: relation(dayElement, element),
+  }));
+  const sum = (rel: Relation) => elements.filter((e) => e.relation === rel).reduce((acc, e) => acc + e.count, 0);
+  return {
+    dayElement,
+    elements,
+    generating: sum('Resource'),
+    controlling: sum('Officer'),
+    same: sum('Same'),
+    generated: sum('Output'),
+    controlled: sum('Wealth'),
+  };
+}
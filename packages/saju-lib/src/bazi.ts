@@ -17,12 +17,16 @@
 
 import type {
   Element,
+  ElementOverrides,
+  HiddenRank,
   Pillar,
+  PillarPosition,
   Relation,
-  SolarTerm,
   StrengthClass,
   TenGod,
+  TenGodEntry,
 } from './types.js';
+import { monthIndexAt } from './astro.js';
 import { remEuclid } from './utils.js';
 
 // 분리 모듈 re-export (하위 호환성 유지)
@@ -39,7 +43,7 @@ export type { StrengthResult } from './strength.js';
 
 /**
  * 지장간 테이블: 각 지지에 숨겨진 천간 인덱스.
- * 배열 순서: [정기(正氣), 여기(餘氣), 중기(中氣)]
+ * 배열 순서: 정기(正氣)가 먼저, 이어서 보조 천간 (여기·중기 구분은 HIDDEN_RANKS)
  * - 정기: 해당 지지의 대표 천간
  * - 여기/중기: 보조 천간 (四正은 2개, 나머지는 3개)
  */
@@ -131,35 +135,13 @@ export function monthBranchFromTermKey(key: string): number | null {
 /**
  * 생일(birthJd)이 속하는 절기 구간의 월지를 결정한다.
  *
- * 전년도와 당해의 절기 경계를 합친 뒤, 생일 직전의 절(節)을 찾아
- * 해당 절기의 월지를 반환한다.
+ * 입춘부터 몇 번째 절월인지(monthIndexAt) 구해 인(寅)월부터 센 월지를 반환한다.
  *
  * @param birthJd 출생 시점 (JD)
- * @param termsPrev 전년도 절기 배열
- * @param termsCurr 당해 절기 배열
  * @returns 월지 인덱스 (0~11)
  */
-export function monthBranchForBirth(
-  birthJd: number,
-  termsPrev: SolarTerm[],
-  termsCurr: SolarTerm[],
-): number {
-  const boundaries = [...termsPrev, ...termsCurr]
-    .filter((t) => monthBranchFromTermKey(t.def.key) !== null)
-    .sort((a, b) => a.jd - b.jd);
-
-  let last: SolarTerm | null = null;
-  for (const term of boundaries) {
-    if (term.jd <= birthJd) {
-      last = term;
-    } else {
-      break;
-    }
-  }
-  if (!last) throw new Error('failed to determine month boundary');
-  const branch = monthBranchFromTermKey(last.def.key);
-  if (branch === null) throw new Error('invalid month boundary term');
-  return branch;
+export function monthBranchForBirth(birthJd: number): number {
+  return (monthIndexAt(birthJd) + 2) % 12;
 }
 
 /**
@@ -214,6 +196,22 @@ export function hourBranchIndex(hour: number, minute: number): number {
   return ((totalMinutes + 60) / 120 | 0) % 12;
 }
 
+/**
+ * 시지(時支)의 시진(時辰) 구간을 시계 시각(자정 기준 분)으로 반환한다.
+ *
+ * 보정 시각 기준 구간은 자시 23:00~01:00, 축시 01:00~03:00 … 이고,
+ * 평태양시 보정이 있으면 그만큼 시계 시각 구간이 밀린다
+ * (예: 서울 −32분 보정이면 묘시는 시계로 05:32~07:32).
+ *
+ * @param branch 시지 인덱스 (0~11)
+ * @param correctionSeconds 평태양시 보정 초 (보정 시각 = 시계 시각 + 보정)
+ * @returns [시작, 끝] (0~1439분, 끝이 시작보다 작으면 자정을 넘는다)
+ */
+export function sijinWindow(branch: number, correctionSeconds = 0): [number, number] {
+  const start = remEuclid(branch * 120 - 60 - Math.round(correctionSeconds / 60), 1440);
+  return [start, (start + 120) % 1440];
+}
+
 /**
  * 일간(日干)으로부터 시간(時干)을 산출한다.
  * 공식: (일간 × 2 + 시지) mod 10
@@ -225,6 +223,58 @@ export function hourStemFromDay(dayStem: number, hourBranch: number): number {
   return (dayStem * 2 + hourBranch) % 10;
 }
 
+/**
+ * 기둥의 60갑자 순번을 반환한다 (甲子=0 … 癸亥=59).
+ * 천간과 지지의 음양이 다르면 성립하지 않는 조합이므로 예외를 던진다.
+ * @param pillar 기둥
+ * @returns 60갑자 인덱스 (0~59)
+ */
+export function sexagenaryIndex(pillar: Pillar): number {
+  if (pillar.stem % 2 !== pillar.branch % 2) {
+    throw new RangeError(`invalid sexagenary pair: stem ${pillar.stem}, branch ${pillar.branch}`);
+  }
+  return remEuclid(6 * pillar.stem - 5 * pillar.branch, 60);
+}
+
+/**
+ * 60갑자 순번으로부터 기둥을 복원한다.
+ * @param index 60갑자 인덱스 (음수·60 이상은 순환)
+ */
+export function pillarFromSexagenary(index: number): Pillar {
+  const idx = remEuclid(index, 60);
+  return { stem: idx % 10, branch: idx % 12 };
+}
+
+/** 간지 이름 파싱용 천간·지지 표기 (한자 / 한글 / 로마자) */
+const STEM_NAMES: readonly (readonly string[])[] = [
+  ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'],
+  ['갑', '을', '병', '정', '무', '기', '경', '신', '임', '계'],
+  ['gap', 'eul', 'byeong', 'jeong', 'mu', 'gi', 'gyeong', 'sin', 'im', 'gye'],
+];
+const BRANCH_NAMES: readonly (readonly string[])[] = [
+  ['子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥'],
+  ['자', '축', '인', '묘', '진', '사', '오', '미', '신', '유', '술', '해'],
+  ['ja', 'chuk', 'in', 'myo', 'jin', 'sa', 'o', 'mi', 'sin', 'yu', 'sul', 'hae'],
+];
+
+/**
+ * 간지 이름을 기둥으로 파싱한다.
+ * 한자('乙巳'), 한글('을사'), 로마자('Eul-Sa', 'eulsa')를 받는다.
+ * @throws 알 수 없는 이름이거나 음양이 맞지 않는 조합인 경우
+ */
+export function parsePillarName(name: string): Pillar {
+  const normalized = name.trim().toLowerCase().replace(/[\s-]/g, '');
+  for (let script = 0; script < STEM_NAMES.length; script++) {
+    for (let stem = 0; stem < 10; stem++) {
+      const stemName = STEM_NAMES[script][stem];
+      if (!normalized.startsWith(stemName)) continue;
+      const branch = BRANCH_NAMES[script].indexOf(normalized.slice(stemName.length));
+      if (branch >= 0 && stem % 2 === branch % 2) return { stem, branch };
+    }
+  }
+  throw new RangeError(`unknown sexagenary name: ${name}`);
+}
+
 // ── 오행(五行) 관계 ──
 
 /**
@@ -345,7 +395,7 @@ export function tenGod(dayStem: number, targetStem: number): TenGod {
 /**
  * 지지의 지장간(숨겨진 천간들)을 반환한다.
  * @param branch 지지 인덱스 (0~11)
- * @returns 천간 인덱스 배열 [정기, 여기, (중기)]
+ * @returns 천간 인덱스 배열 (정기가 먼저, 각 순위는 hiddenStemRanks)
  */
 export function hiddenStems(branch: number): readonly number[] {
   if (branch < 0 || branch > 11) throw new RangeError(`branch index must be 0-11, got ${branch}`);
@@ -382,6 +432,56 @@ export function tenGodBranch(dayStem: number, branch: number): TenGod {
   return tenGod(dayStem, mainHiddenStem(branch));
 }
 
+/**
+ * HIDDEN_STEMS 항목마다의 지장간 순위. 배열 위치가 순위와 늘 같지는 않아 지지별로 적는다
+ * (寅·申·亥는 둘째가 중기, 午는 셋째 丙이 여기).
+ */
+const HIDDEN_RANKS: readonly (readonly HiddenRank[])[] = [
+  ['JeongGi', 'YeoGi'],           // 자: 癸 정기, 壬 여기
+  ['JeongGi', 'YeoGi', 'JungGi'], // 축: 己 癸 辛
+  ['JeongGi', 'JungGi', 'YeoGi'], // 인: 甲 丙(중기) 戊(여기)
+  ['JeongGi', 'YeoGi'],           // 묘: 乙 甲
+  ['JeongGi', 'YeoGi', 'JungGi'], // 진: 戊 乙 癸
+  ['JeongGi', 'YeoGi', 'JungGi'], // 사: 丙 戊 庚
+  ['JeongGi', 'JungGi', 'YeoGi'], // 오: 丁 己(중기) 丙(여기)
+  ['JeongGi', 'YeoGi', 'JungGi'], // 미: 己 丁 乙
+  ['JeongGi', 'JungGi', 'YeoGi'], // 신: 庚 壬(중기) 戊(여기)
+  ['JeongGi', 'YeoGi'],           // 유: 辛 庚
+  ['JeongGi', 'YeoGi', 'JungGi'], // 술: 戊 辛 丁
+  ['JeongGi', 'JungGi', 'YeoGi'], // 해: 壬 甲(중기) 戊(여기)
+];
+
+/**
+ * 지지의 지장간 순위를 hiddenStems와 같은 순서로 반환한다.
+ * @param branch 지지 인덱스 (0~11)
+ */
+export function hiddenStemRanks(branch: number): readonly HiddenRank[] {
+  if (branch < 0 || branch > 11) throw new RangeError(`branch index must be 0-11, got ${branch}`);
+  return HIDDEN_RANKS[branch];
+}
+
+/**
+ * 원국의 모든 천간과 지장간에 대한 십성을 위치·순위와 함께 반환한다.
+ *
+ * 기둥 순서(연·월·일·시)로, 각 기둥마다 천간 다음에 지장간(hiddenStems 순서, 정기가 먼저)이 온다.
+ * 일간 자신도 포함한다 (비견).
+ *
+ * @param dayStem 일간 인덱스
+ * @param pillars 네 기둥 (연·월·일·시)
+ */
+export function tenGodsFull(dayStem: number, pillars: Pillar[]): TenGodEntry[] {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const entries: TenGodEntry[] = [];
+  pillars.forEach((pillar, i) => {
+    entries.push({ position: POS[i], layer: 'Stem', stem: pillar.stem, tenGod: tenGod(dayStem, pillar.stem) });
+    const ranks = hiddenStemRanks(pillar.branch);
+    hiddenStems(pillar.branch).forEach((stem, k) => {
+      entries.push({ position: POS[i], layer: 'Hidden', stem, tenGod: tenGod(dayStem, stem), rank: ranks[k] });
+    });
+  });
+  return entries;
+}
+
 // ── 12운성(十二運星) ──
 
 /**
@@ -429,17 +529,34 @@ export function elementIndex(element: Element): number {
   return map[element];
 }
 
+const ELEMENT_NAMES: Record<string, Element> = {
+  Wood: 'Wood', Fire: 'Fire', Earth: 'Earth', Metal: 'Metal', Water: 'Water',
+  木: 'Wood', 火: 'Fire', 土: 'Earth', 金: 'Metal', 水: 'Water',
+};
+
+/**
+ * 오행 이름('Wood' 또는 한자 '木')을 Element로 변환한다.
+ * @returns 알 수 없는 이름이면 null
+ */
+export function parseElementName(name: string): Element | null {
+  return Object.hasOwn(ELEMENT_NAMES, name) ? ELEMENT_NAMES[name] : null;
+}
+
 /**
  * 네 기둥의 오행 분포(천간+지지)를 카운트한다.
  * @param pillars 네 기둥 배열
+ * @param overrides 합화(合化)로 바뀐 위치별 오행 (생략 시 본래 오행)
  * @returns [목, 화, 토, 금, 수] 개수 튜플
  */
-export function elementsCount(pillars: Pillar[]): [number, number, number, number, number] {
+export function elementsCount(
+  pillars: Pillar[],
+  overrides?: ElementOverrides | null,
+): [number, number, number, number, number] {
   const counts: [number, number, number, number, number] = [0, 0, 0, 0, 0];
-  for (const pillar of pillars) {
-    counts[elementIndex(stemElement(pillar.stem))]++;
-    counts[elementIndex(branchElement(pillar.branch))]++;
-  }
+  pillars.forEach((pillar, i) => {
+    counts[elementIndex(overrides?.stems[i] ?? stemElement(pillar.stem))]++;
+    counts[elementIndex(overrides?.branches[i] ?? branchElement(pillar.branch))]++;
+  });
   return counts;
 }
 
@@ -0,0 +1,53 @@
+/**
+ * @fileoverview 일지(日支, 배우자궁) 중심 관계 요약 모듈
+ *
+ * 일지가 다른 원국 지지와 맺는 합·충·형·파·해와 원진·귀문,
+ * 그리고 일지에 앉은 십성·12운성·12신살·주요 신살을 한데 모은다.
+ * 일지는 배우자 자리로 가장 자주 살피는 단일 위치다.
+ */
+
+import { tenGodBranch, twelveStageIndex } from './bazi.js';
+import { findBranchInteractions } from './interactions.js';
+import { findShinsal, natalBranchPairs, twelveShinsalIndex, type BranchPair } from './shinsal.js';
+import type { BranchInteraction, Pillar, ShinsalBase, ShinsalKind, TenGod } from './types.js';
+
+/** 일지 중심 요약 */
+export interface DayBranchSummary {
+  /** 일지 인덱스 */
+  branch: number;
+  /** 일지 정기 기준 십성 */
+  tenGod: TenGod;
+  /** 일간 기준 12운성 인덱스 */
+  stage: number;
+  /** shinsalBase(연지 또는 일지) 기준 12신살 인덱스 */
+  twelveShinsal: number;
+  /** 일지가 참여하는 합·충·형·파·해·방합·삼합 (findBranchInteractions 순서) */
+  interactions: BranchInteraction[];
+  /** 일지가 참여하는 원진·귀문 쌍 */
+  pairs: BranchPair[];
+  /** 일지에서 검출된 주요 신살 (원진·귀문 제외, 중복 없음) */
+  shinsal: ShinsalKind[];
+}
+
+/**
+ * 일지를 중심으로 원국 관계를 요약한다.
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ * @param shinsalBase 12신살 기준 지지 (기본 연지)
+ */
+export function dayBranchSummary(pillars: Pillar[], shinsalBase: ShinsalBase = 'Year'): DayBranchSummary {
+  const day = pillars[2];
+  const shinsal: ShinsalKind[] = [];
+  for (const entry of findShinsal(pillars)) {
+    if (entry.kind === 'WonJinSal' || entry.kind === 'GwiMunGwanSal') continue;
+    if (entry.foundAt.includes('Day') && !shinsal.includes(entry.kind)) shinsal.push(entry.kind);
+  }
+  return {
+    branch: day.branch,
+    tenGod: tenGodBranch(day.stem, day.branch),
+    stage: twelveStageIndex(day.stem, day.branch),
+    twelveShinsal: twelveShinsalIndex(shinsalBase === 'Day' ? day.branch : pillars[0].branch, day.branch),
+    interactions: findBranchInteractions(pillars).filter((r) => r.positions.includes('Day')),
+    pairs: natalBranchPairs(pillars).filter((pair) => pair.positions.includes('Day')),
+    shinsal,
+  };
+}
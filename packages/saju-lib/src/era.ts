@@ -0,0 +1,42 @@
+/**
+ * @fileoverview 연기(年紀) 표기 모듈
+ *
+ * 서기 연도를 한국 만세력에서 함께 쓰는 연기로 환산한다:
+ * - 단기(檀紀): 단군 기원, 서기 + 2333
+ * - 불기(佛紀): 불멸 기원, 서기 + 544 (한국 불교 기준)
+ * - 세차(歲次): 해당 연도의 간지 (예: 乙巳年)
+ */
+
+import type { Pillar } from './types.js';
+import { yearPillar } from './bazi.js';
+
+/** 단기 = 서기 + 2333 */
+export const DANGUN_OFFSET = 2333;
+/** 불기 = 서기 + 544 */
+export const BUDDHIST_OFFSET = 544;
+
+/** 한 연도의 연기 표기 */
+export interface EraYears {
+  /** 서기 연도 */
+  year: number;
+  /** 단기 연도 */
+  dangun: number;
+  /** 불기 연도 */
+  buddhist: number;
+  /** 세차 (연도 간지) */
+  secha: Pillar;
+}
+
+/**
+ * 서기 연도의 단기·불기·세차를 반환한다.
+ * 세차는 달력 연도 기준 간지이며, 입춘 전 출생의 연주와는 다를 수 있다.
+ */
+export function eraYears(year: number): EraYears {
+  const [stem, branch] = yearPillar(year);
+  return {
+    year,
+    dangun: year + DANGUN_OFFSET,
+    buddhist: year + BUDDHIST_OFFSET,
+    secha: { stem, branch },
+  };
+}
@@ -0,0 +1,77 @@
+/**
+ * @fileoverview 특수 구조(간여지동·양인가살·살인상생 등) 탐지 모듈
+ *
+ * 원국에서 일간을 제외한 천간과 지지 정기의 십성, 지지의 12운성,
+ * 기둥별 오행을 뽑아 규칙표(formationRules)를 평가하고,
+ * 성립한 구조와 그 구조를 이루는 기둥을 보고한다.
+ */
+
+import { branchElement, stemElement, tenGod, tenGodBranch, twelveStageIndex } from './bazi.js';
+import { FORMATION_RULES, type FormationCondition, type FormationRule } from './formationRules.js';
+import type { Pillar, PillarPosition, StrengthClass } from './types.js';
+
+export { FORMATION_RULES, type FormationCondition, type FormationRule } from './formationRules.js';
+
+/** 성립한 특수 구조 */
+export interface Formation {
+  rule: FormationRule;
+  /** 구조를 이루는 기둥 (기둥 순서, 중복 없음) */
+  positions: PillarPosition[];
+}
+
+const POSITIONS: readonly PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+
+/**
+ * 조건 하나를 평가한다.
+ * @returns 조건을 이루는 기둥 목록, 불성립이면 null
+ */
+function evaluate(cond: FormationCondition, pillars: Pillar[], verdict: StrengthClass): PillarPosition[] | null {
+  const dayStem = pillars[2].stem;
+  switch (cond.kind) {
+    case 'TenGod': {
+      const hits: PillarPosition[] = [];
+      pillars.forEach((pillar, i) => {
+        if (cond.layer !== 'Branch' && i !== 2 && cond.tenGods.includes(tenGod(dayStem, pillar.stem))) hits.push(POSITIONS[i]);
+        if (cond.layer !== 'Stem' && cond.tenGods.includes(tenGodBranch(dayStem, pillar.branch))) hits.push(POSITIONS[i]);
+      });
+      return hits.length >= (cond.min ?? 1) ? hits : null;
+    }
+    case 'Stage': {
+      if (cond.yangDayOnly && dayStem % 2 !== 0) return null;
+      const hits = POSITIONS.filter((_, i) => cond.stages.includes(twelveStageIndex(dayStem, pillars[i].branch)));
+      return hits.length > 0 ? hits : null;
+    }
+    case 'SamePillar': {
+      const hits = cond.positions.filter((pos) => {
+        const pillar = pillars[POSITIONS.indexOf(pos)];
+        return stemElement(pillar.stem) === branchElement(pillar.branch);
+      });
+      return hits.length > 0 ? hits : null;
+    }
+    case 'Verdict':
+      return cond.verdicts.includes(verdict) ? [] : null;
+  }
+}
+
+/**
+ * 원국에서 성립한 특수 구조를 찾는다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param verdict 신강/신약 판정
+ * @param rules 평가할 규칙 (기본: 내장 규칙표)
+ * @returns 성립한 구조 (규칙표 순서)
+ */
+export function findFormations(
+  pillars: Pillar[],
+  verdict: StrengthClass,
+  rules: readonly FormationRule[] = FORMATION_RULES,
+): Formation[] {
+  const result: Formation[] = [];
+  for (const rule of rules) {
+    const hits = rule.when.map((cond) => evaluate(cond, pillars, verdict));
+    if (hits.some((h) => h === null)) continue;
+    const used = new Set(hits.flatMap((h) => h ?? []));
+    result.push({ rule, positions: POSITIONS.filter((pos) => used.has(pos)) });
+  }
+  return result;
+}
@@ -0,0 +1,93 @@
+/**
+ * @fileoverview 특수 구조(格局 조합) 판단 규칙표
+ *
+ * formation 모듈이 평가하는 규칙 데이터. 규칙 하나는 명식에서 뽑은
+ * 글자(일간을 제외한 천간, 지지 정기)에 대한 조건(모두 만족)과
+ * 구조 이름·풀이로 이루어진다. 새 구조는 규칙을 추가하는 것만으로 늘릴 수 있다.
+ */
+
+import type { Lang } from './i18n.js';
+import type { PillarPosition, StrengthClass, TenGod } from './types.js';
+
+/**
+ * 구조 조건.
+ * - TenGod: 해당 십성 글자가 min개(기본 1) 이상 (layer로 천간·지지 한정 가능)
+ * - Stage: 일간 기준 12운성이 stages에 드는 지지가 있음 (yangDayOnly면 양간 일간만)
+ * - SamePillar: 천간과 지지의 오행이 같은 기둥이 있음 (positions로 위치 한정)
+ * - Verdict: 신강/신약 판정이 verdicts 중 하나 (위치 없음)
+ */
+export type FormationCondition =
+  | { kind: 'TenGod'; tenGods: TenGod[]; layer?: 'Stem' | 'Branch'; min?: number }
+  | { kind: 'Stage'; stages: number[]; yangDayOnly?: boolean }
+  | { kind: 'SamePillar'; positions: PillarPosition[] }
+  | { kind: 'Verdict'; verdicts: StrengthClass[] };
+
+/** 특수 구조 규칙 하나 */
+export interface FormationRule {
+  /** 규칙 식별자 */
+  id: string;
+  /** 구조 이름 */
+  name: Record<Lang, string>;
+  /** 모두 만족해야 하는 조건 */
+  when: FormationCondition[];
+  /** 한 줄 풀이 */
+  note: Record<Lang, string>;
+}
+
+/** 12운성 인덱스: 제왕 (양간의 양인) */
+const STAGE_JEWANG = 4;
+
+/** 내장 특수 구조 규칙 */
+export const FORMATION_RULES: readonly FormationRule[] = [
+  {
+    id: 'ganyeo-jidong',
+    name: { Ko: '간여지동(干與支同)', En: 'Same Stem and Branch (干與支同)' },
+    when: [{ kind: 'SamePillar', positions: ['Day'] }],
+    note: { Ko: '일주 천간과 지지가 같은 오행 — 주관이 뚜렷하고 배우자 자리에 비겁이 앉음', En: 'Day stem and branch share an element — strong-willed, with a peer in the spouse palace' },
+  },
+  {
+    id: 'yangin-gasal',
+    name: { Ko: '양인가살(羊刃駕殺)', En: 'Blade Rides the Killing (羊刃駕殺)' },
+    when: [
+      { kind: 'Stage', stages: [STAGE_JEWANG], yangDayOnly: true },
+      { kind: 'TenGod', tenGods: ['ChilSal'] },
+    ],
+    note: { Ko: '양인과 칠살이 서로 제어 — 위기에서 힘을 발휘하는 무관·권력형 구조', En: 'Blade and Killing restrain each other — authority that shines in crises' },
+  },
+  {
+    id: 'sal-in-sangsaeng',
+    name: { Ko: '살인상생(殺印相生)', En: 'Killing Feeds Resource (殺印相生)' },
+    when: [
+      { kind: 'TenGod', tenGods: ['ChilSal'] },
+      { kind: 'TenGod', tenGods: ['PyeonIn', 'JeongIn'] },
+    ],
+    note: { Ko: '칠살의 압박을 인성이 흡수해 일간을 돕는 구조 — 역경을 학문·권위로 바꿈', En: 'Resource absorbs the Killing’s pressure — hardship turned into learning and authority' },
+  },
+  {
+    id: 'siksin-jesal',
+    name: { Ko: '식신제살(食神制殺)', En: 'Eating God Controls Killing (食神制殺)' },
+    when: [
+      { kind: 'TenGod', tenGods: ['SikShin'] },
+      { kind: 'TenGod', tenGods: ['ChilSal'] },
+    ],
+    note: { Ko: '식신이 칠살을 제어 — 재능으로 압박을 다스리는 구조', En: 'Eating God tames the Killing — talent that masters pressure' },
+  },
+  {
+    id: 'sanggwan-gyeongwan',
+    name: { Ko: '상관견관(傷官見官)', En: 'Hurting Officer Meets Officer (傷官見官)' },
+    when: [
+      { kind: 'TenGod', tenGods: ['SangGwan'] },
+      { kind: 'TenGod', tenGods: ['JeongGwan'] },
+    ],
+    note: { Ko: '상관이 정관을 상함 — 규범·윗사람과의 마찰, 구설에 유의', En: 'Hurting Officer harms the Officer — friction with rules and superiors' },
+  },
+  {
+    id: 'jaeda-sinyak',
+    name: { Ko: '재다신약(財多身弱)', En: 'Much Wealth, Weak Self (財多身弱)' },
+    when: [
+      { kind: 'TenGod', tenGods: ['PyeonJae', 'JeongJae'], min: 3 },
+      { kind: 'Verdict', verdicts: ['Weak'] },
+    ],
+    note: { Ko: '재성이 많고 일간이 약함 — 재물이 많아도 감당이 어려워 비겁·인성의 도움이 필요', En: 'Much wealth for a weak self — needs Peers or Resource to hold it' },
+  },
+];
@@ -0,0 +1,117 @@
+/**
+ * @fileoverview 생활 개운(開運) 대응표 모듈
+ *
+ * 오행별 전통 대응(색상·방위·수·계절)을 내장한다.
+ * 용신·희신 오행에 해당하는 항목을 찾아 생활 속 개운법으로 제시하며,
+ * 외부 JSON 데이터로 문구를 현지화하거나 교체할 수 있다.
+ */
+
+import type { Element } from './types.js';
+import { parseElementName } from './bazi.js';
+import type { Lang } from './i18n.js';
+import { applyOverride, expectObject, type OverrideSchema } from './override.js';
+
+/** 오행 하나의 개운 대응 */
+export interface GaeunEntry {
+  /** 색상 */
+  colors: Record<Lang, string[]>;
+  /** 방위 */
+  directions: Record<Lang, string[]>;
+  /** 하도(河圖) 수 */
+  numbers: number[];
+  /** 계절 */
+  seasons: Record<Lang, string[]>;
+}
+
+/** 오행별 개운 대응표 */
+export type GaeunTable = Record<Element, GaeunEntry>;
+
+/**
+ * --gaeun-data JSON. 오행 ID('Wood')나 한자('木') 아래에 바꿀 색·방위·숫자·계절만 둔다.
+ *
+ * ```json
+ * { "木": { "colors": { "Ko": ["초록색"] }, "numbers": [3, 8] } }
+ * ```
+ */
+export type GaeunOverrides = Record<string, {
+  colors?: Partial<Record<Lang, string[]>>;
+  directions?: Partial<Record<Lang, string[]>>;
+  numbers?: number[];
+  seasons?: Partial<Record<Lang, string[]>>;
+}>;
+
+/** 내장 개운 대응표 (오행 배속의 통설) */
+export const GAEUN_DATA: Readonly<GaeunTable> = {
+  Wood: {
+    colors: { Ko: ['청색', '녹색'], En: ['blue', 'green'] },
+    directions: { Ko: ['동쪽'], En: ['east'] },
+    numbers: [3, 8],
+    seasons: { Ko: ['봄'], En: ['spring'] },
+  },
+  Fire: {
+    colors: { Ko: ['적색', '주황색'], En: ['red', 'orange'] },
+    directions: { Ko: ['남쪽'], En: ['south'] },
+    numbers: [2, 7],
+    seasons: { Ko: ['여름'], En: ['summer'] },
+  },
+  Earth: {
+    colors: { Ko: ['황색', '갈색'], En: ['yellow', 'brown'] },
+    directions: { Ko: ['중앙'], En: ['center'] },
+    numbers: [5, 10],
+    seasons: { Ko: ['환절기'], En: ['seasonal transitions'] },
+  },
+  Metal: {
+    colors: { Ko: ['백색', '금색'], En: ['white', 'gold'] },
+    directions: { Ko: ['서쪽'], En: ['west'] },
+    numbers: [4, 9],
+    seasons: { Ko: ['가을'], En: ['autumn'] },
+  },
+  Water: {
+    colors: { Ko: ['흑색', '남색'], En: ['black', 'navy'] },
+    directions: { Ko: ['북쪽'], En: ['north'] },
+    numbers: [1, 6],
+    seasons: { Ko: ['겨울'], En: ['winter'] },
+  },
+};
+
+function cloneEntry(e: GaeunEntry): GaeunEntry {
+  return {
+    colors: { ...e.colors },
+    directions: { ...e.directions },
+    numbers: [...e.numbers],
+    seasons: { ...e.seasons },
+  };
+}
+
+/** 개운 항목의 덮어쓸 수 있는 필드 (숫자는 언어와 무관하다) */
+const GAEUN_SCHEMA: OverrideSchema = {
+  colors: { value: 'strings', localized: true },
+  directions: { value: 'strings', localized: true },
+  numbers: { value: 'integers', localized: false },
+  seasons: { value: 'strings', localized: true },
+};
+
+/**
+ * 개운 JSON을 검사해 오행별 대응표에 덮어쓴 복사본을 만든다 (검사 규칙은 override.ts).
+ *
+ * @param overrides JSON.parse 결과 (GaeunOverrides 형식)
+ * @param base 병합 기준 대응표 (기본: 내장 데이터)
+ * @returns 병합된 대응표
+ * @throws TypeError 형식이 잘못되었거나 알 수 없는 오행 키가 있는 경우
+ */
+export function mergeGaeunData(overrides: unknown, base: Readonly<GaeunTable> = GAEUN_DATA): GaeunTable {
+  const entries = expectObject(overrides, 'gaeun data must be an object keyed by element (e.g. "Wood" or "木")');
+  const merged: GaeunTable = {
+    Wood: cloneEntry(base.Wood),
+    Fire: cloneEntry(base.Fire),
+    Earth: cloneEntry(base.Earth),
+    Metal: cloneEntry(base.Metal),
+    Water: cloneEntry(base.Water),
+  };
+  for (const [key, raw] of Object.entries(entries)) {
+    const element = parseElementName(key);
+    if (!element) throw new TypeError(`unknown element key: ${key}`);
+    applyOverride(merged[element], raw, GAEUN_SCHEMA, key);
+  }
+  return merged;
+}
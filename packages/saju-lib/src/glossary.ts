@@ -0,0 +1,90 @@
+/**
+ * @fileoverview 용어 풀이(Glossary) 모듈
+ *
+ * 십성·12운성·신살 등 명식에 나오는 전문 용어의 정의를 내장하고,
+ * 출력 텍스트에 실제로 등장한 용어만 골라 각주로 제공한다.
+ */
+
+import type { Lang, Localizer } from './i18n.js';
+import { SHINSAL_CATALOG } from './shinsalNotes.js';
+import type { ShinsalKind, TenGod } from './types.js';
+
+/** 용어 하나 */
+export interface GlossaryTerm {
+  /** 출력에 쓰이는 레이블 (예: '정관(正官)') */
+  term: string;
+  /** 정의 */
+  definition: string;
+}
+
+function text(ko: string, en: string): Record<Lang, string> {
+  return { Ko: ko, En: en };
+}
+
+/** 십성 정의 */
+export const TEN_GOD_DEFINITIONS: Readonly<Record<TenGod, Record<Lang, string>>> = {
+  BiGyeon: text('일간과 같은 오행·같은 음양 — 자아, 동료, 독립심', 'same element and polarity as the day master — self, peers, independence'),
+  GeopJae: text('일간과 같은 오행·다른 음양 — 경쟁, 승부욕, 재물 분탈', 'same element, opposite polarity — rivalry, competitiveness, shared wealth'),
+  SikShin: text('일간이 생하는 오행·같은 음양 — 표현, 여유, 먹을 복', 'element the day master produces, same polarity — expression, ease, nourishment'),
+  SangGwan: text('일간이 생하는 오행·다른 음양 — 재능, 비판, 기존 질서에 대한 도전', 'element the day master produces, opposite polarity — talent, criticism, challenging rules'),
+  PyeonJae: text('일간이 극하는 오행·같은 음양 — 유동 재물, 사업, 활동 반경', 'element the day master controls, same polarity — fluid wealth, business, reach'),
+  JeongJae: text('일간이 극하는 오행·다른 음양 — 고정 재물, 성실, 관리', 'element the day master controls, opposite polarity — steady wealth, diligence, management'),
+  ChilSal: text('일간을 극하는 오행·같은 음양(칠살) — 압박, 권력, 결단', 'element controlling the day master, same polarity (Seven Killings) — pressure, power, resolve'),
+  JeongGwan: text('일간을 극하는 오행·다른 음양 — 명예, 규범, 직장', 'element controlling the day master, opposite polarity — honor, rules, employment'),
+  PyeonIn: text('일간을 생하는 오행·같은 음양 — 직관, 특수 학문, 편향된 보살핌', 'element producing the day master, same polarity — intuition, unusual learning, uneven support'),
+  JeongIn: text('일간을 생하는 오행·다른 음양 — 학문, 자격, 어머니의 보살핌', 'element producing the day master, opposite polarity — learning, credentials, nurturing'),
+};
+
+/** 12운성 정의 (인덱스 0=장생 … 11=양) */
+export const STAGE_DEFINITIONS: readonly Record<Lang, string>[] = [
+  text('태어남 — 새로운 시작과 성장', 'birth — new beginnings and growth'),
+  text('목욕 — 미숙함과 불안정, 감수성', 'bathing — immaturity, instability, sensitivity'),
+  text('관을 씀 — 사회에 나설 준비', 'capping — preparing to enter society'),
+  text('녹을 받음 — 자립과 안정된 힘', 'official salary — independence and steady strength'),
+  text('전성기 — 가장 왕성한 기운', 'peak — the most vigorous stage'),
+  text('기운이 꺾이기 시작함 — 노련함', 'decline begins — seasoned experience'),
+  text('병듦 — 약해진 기운, 섬세함', 'sickness — weakened energy, delicacy'),
+  text('죽음 — 멈춤과 정리', 'death — stillness and closure'),
+  text('무덤 — 저장과 보관', 'tomb — storage and keeping'),
+  text('끊어짐 — 단절과 전환', 'severance — break and transition'),
+  text('잉태 — 새 기운의 씨앗', 'conception — the seed of new energy'),
+  text('길러짐 — 보호 속의 성장', 'nurturing — growth under protection'),
+];
+
+const TEN_GODS: readonly TenGod[] = [
+  'BiGyeon', 'GeopJae', 'SikShin', 'SangGwan', 'PyeonJae',
+  'JeongJae', 'ChilSal', 'JeongGwan', 'PyeonIn', 'JeongIn',
+];
+
+/**
+ * 지정 언어의 전체 용어 목록을 만든다 (십성 → 12운성 → 12신살 → 주요 신살).
+ * 같은 레이블은 처음 것만 남긴다.
+ */
+export function glossaryTerms(i18n: Localizer): GlossaryTerm[] {
+  const lang = i18n.lang;
+  const terms: GlossaryTerm[] = [
+    ...TEN_GODS.map((g) => ({ term: i18n.tenGodLabel(g), definition: TEN_GOD_DEFINITIONS[g][lang] })),
+    ...STAGE_DEFINITIONS.map((d, i) => ({ term: i18n.stageLabel(i), definition: d[lang] })),
+    ...SHINSAL_CATALOG.twelve.map((d, i) => ({ term: i18n.shinsalLabel(i), definition: d[lang] })),
+    ...(Object.keys(SHINSAL_CATALOG.kinds) as ShinsalKind[]).map((k) => ({
+      term: i18n.shinsalKindLabel(k),
+      definition: SHINSAL_CATALOG.kinds[k][lang],
+    })),
+  ];
+  const seen = new Set<string>();
+  return terms.filter((t) => !seen.has(t.term) && seen.add(t.term));
+}
+
+/**
+ * 출력 줄에 등장한 용어만 골라 등장 순서대로 반환한다.
+ * @param lines 출력 텍스트 줄
+ * @param i18n 출력에 사용한 레이블 제공자
+ */
+export function usedGlossaryTerms(lines: readonly string[], i18n: Localizer): GlossaryTerm[] {
+  const body = lines.join('\n');
+  return glossaryTerms(i18n)
+    .map((t) => ({ t, at: body.indexOf(t.term) }))
+    .filter(({ at }) => at >= 0)
+    .sort((a, b) => a.at - b.at)
+    .map(({ t }) => t);
+}
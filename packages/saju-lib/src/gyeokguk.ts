@@ -0,0 +1,75 @@
+/**
+ * @fileoverview 격국(格局) 판정 모듈
+ *
+ * 월지(月支) 지장간을 기준으로 명식의 격을 정한다.
+ * - 월지가 일간의 건록(建祿)이면 건록격, 제왕(帝旺)이면 양간은 양인격·음간은 월겁격
+ * - 그 외에는 비겁이 아닌 월지 지장간 중 연·월·시간에 투출(透出)한 것을
+ *   지장간 순서(hiddenStems 순, 정기가 먼저)로 먼저 취하고, 투출이 없으면 그 순서의 첫 글자를 취한다
+ *
+ * 천간합 합화를 반영하면 일간이 화(化)한 명식은 화기격(化氣格)이 되고,
+ * 합화로 묶인 천간은 투출로 보지 않는다.
+ */
+
+import { hiddenStems, stemElement, tenGod, twelveStageIndex } from './bazi.js';
+import type { Pillar, PillarPosition, StemCombination, TenGod } from './types.js';
+
+/** 격국 종류: 십성격 8종 + 건록격·양인격·월겁격 + 화기격 */
+export type GyeokgukKind =
+  | Exclude<TenGod, 'BiGyeon' | 'GeopJae'>
+  | 'GeonRok'
+  | 'YangIn'
+  | 'WolGeop'
+  | 'HwaGi';
+
+/** 격국 판정 결과 */
+export interface GyeokgukResult {
+  kind: GyeokgukKind;
+  /** 격을 정한 월지 지장간 (건록·양인·월겁격은 월지 정기, 화기격은 일간과 합한 천간) */
+  stem: number;
+  /** 격을 정한 글자가 천간에 투출했는지 */
+  revealed: boolean;
+}
+
+/** 12운성 인덱스: 건록 */
+const STAGE_GEONROK = 3;
+/** 12운성 인덱스: 제왕 */
+const STAGE_JEWANG = 4;
+
+const POSITION_INDEX: Record<PillarPosition, number> = { Year: 0, Month: 1, Day: 2, Hour: 3 };
+
+/**
+ * 격국을 판정한다.
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param combos 합화를 반영할 천간합 평가 결과 (생략 시 반영하지 않음)
+ * @returns 격국 판정 결과
+ */
+export function determineGyeokguk(pillars: Pillar[], combos: readonly StemCombination[] = []): GyeokgukResult {
+  const dayStem = pillars[2].stem;
+  const monthBranch = pillars[1].branch;
+  const hidden = hiddenStems(monthBranch);
+  const transformed = combos.filter((c) => c.transformed);
+
+  const dayCombo = transformed.find((c) => c.positions.includes('Day'));
+  if (dayCombo) {
+    const partner = dayCombo.positions[0] === 'Day' ? dayCombo.stems[1] : dayCombo.stems[0];
+    return { kind: 'HwaGi', stem: partner, revealed: true };
+  }
+
+  const bound = new Set<PillarPosition>(transformed.flatMap((c) => c.positions));
+  const visible = (['Year', 'Month', 'Hour'] as const)
+    .filter((pos) => !bound.has(pos))
+    .map((pos) => pillars[POSITION_INDEX[pos]].stem);
+  const dayElement = stemElement(dayStem);
+
+  const stage = twelveStageIndex(dayStem, monthBranch);
+  if (stage === STAGE_GEONROK || stage === STAGE_JEWANG) {
+    const kind: GyeokgukKind = stage === STAGE_GEONROK ? 'GeonRok' : dayStem % 2 === 0 ? 'YangIn' : 'WolGeop';
+    return { kind, stem: hidden[0], revealed: visible.includes(hidden[0]) };
+  }
+
+  const candidates = hidden.filter((s) => stemElement(s) !== dayElement);
+  const revealed = candidates.find((s) => visible.includes(s));
+  // 월지 지장간이 모두 비겁이면 건록·양인에 해당하므로 위에서 처리된다
+  const stem = revealed ?? candidates[0] ?? hidden[0];
+  return { kind: tenGod(dayStem, stem) as GyeokgukKind, stem, revealed: revealed !== undefined };
+}
@@ -0,0 +1,166 @@
+/**
+ * @fileoverview 오행–장부(臟腑) 건강 대응 모듈
+ *
+ * 오행별 전통 장부 배속(목→간·담, 화→심·소장 …)과
+ * 기운이 없거나 과다할 때의 주의 문구를 내장한다.
+ * 외부 JSON 데이터로 문구를 교체할 수 있다.
+ *
+ * 참고용 전통 해석이며 의학적 진단이 아니다.
+ */
+
+import type { Element } from './types.js';
+import { parseElementName } from './bazi.js';
+import type { Lang } from './i18n.js';
+import { applyOverride, expectObject, type OverrideSchema } from './override.js';
+import type { ElementAdvice, ElementStatus } from './balance.js';
+
+/** 오행 하나의 장부 대응 */
+export interface HealthEntry {
+  /** 장부(臟腑) */
+  organs: Record<Lang, string[]>;
+  /** 해당 오행이 없을 때의 주의 문구 */
+  lacking: Record<Lang, string>;
+  /** 해당 오행이 과다할 때의 주의 문구 */
+  excess: Record<Lang, string>;
+}
+
+/** 오행별 장부 대응표 */
+export type HealthTable = Record<Element, HealthEntry>;
+
+/** 원국의 과부족 오행 하나에 대한 건강 주의 */
+export interface HealthNote {
+  element: Element;
+  /** 원국 내 개수 (천간+지지) */
+  count: number;
+  status: ElementStatus;
+  organs: Record<Lang, string[]>;
+  note: Record<Lang, string>;
+}
+
+/**
+ * --health-data JSON. 오행 ID('Wood')나 한자('木') 아래에 바꿀 장부·주의 문구만 둔다.
+ *
+ * ```json
+ * { "木": { "organs": { "Ko": ["간", "담", "눈"] }, "lacking": { "Ko": "..." } } }
+ * ```
+ */
+export type HealthOverrides = Record<string, {
+  organs?: Partial<Record<Lang, string[]>>;
+  lacking?: Partial<Record<Lang, string>>;
+  excess?: Partial<Record<Lang, string>>;
+}>;
+
+/** 내장 장부 대응표 */
+export const HEALTH_DATA: Readonly<HealthTable> = {
+  Wood: {
+    organs: { Ko: ['간', '담'], En: ['liver', 'gallbladder'] },
+    lacking: {
+      Ko: '간·담 기능과 근육·눈의 피로에 유의한다.',
+      En: 'Watch liver and gallbladder function, muscle and eye fatigue.',
+    },
+    excess: {
+      Ko: '분노·긴장이 쌓여 간에 부담이 가기 쉬우니 스트레스 관리에 힘쓴다.',
+      En: 'Pent-up anger and tension strain the liver; manage stress.',
+    },
+  },
+  Fire: {
+    organs: { Ko: ['심장', '소장'], En: ['heart', 'small intestine'] },
+    lacking: {
+      Ko: '혈액순환이 약하고 손발이 차기 쉬우니 심혈관 건강에 유의한다.',
+      En: 'Circulation tends to be weak with cold extremities; watch cardiovascular health.',
+    },
+    excess: {
+      Ko: '열이 위로 올라 불면·혈압 상승이 오기 쉬우니 과로를 피한다.',
+      En: 'Rising heat brings insomnia and high blood pressure; avoid overwork.',
+    },
+  },
+  Earth: {
+    organs: { Ko: ['비장', '위'], En: ['spleen', 'stomach'] },
+    lacking: {
+      Ko: '소화력이 약하기 쉬우니 규칙적인 식사에 유의한다.',
+      En: 'Digestion tends to be weak; keep regular meals.',
+    },
+    excess: {
+      Ko: '위장에 습(濕)이 쌓이기 쉬우니 과식과 기름진 음식을 피한다.',
+      En: 'Dampness builds up in the stomach; avoid overeating and greasy food.',
+    },
+  },
+  Metal: {
+    organs: { Ko: ['폐', '대장'], En: ['lungs', 'large intestine'] },
+    lacking: {
+      Ko: '호흡기와 피부가 약하기 쉬우니 환절기 건강에 유의한다.',
+      En: 'Respiratory system and skin tend to be weak; take care at season changes.',
+    },
+    excess: {
+      Ko: '폐와 대장에 건조함이 쌓이기 쉬우니 수분 섭취에 신경 쓴다.',
+      En: 'Dryness accumulates in the lungs and bowels; stay hydrated.',
+    },
+  },
+  Water: {
+    organs: { Ko: ['신장', '방광'], En: ['kidneys', 'bladder'] },
+    lacking: {
+      Ko: '신장·방광 기능과 허리·뼈 건강에 유의한다.',
+      En: 'Watch kidney and bladder function, lower back and bone health.',
+    },
+    excess: {
+      Ko: '몸이 차고 부종이 생기기 쉬우니 보온에 힘쓴다.',
+      En: 'The body runs cold and swells easily; keep warm.',
+    },
+  },
+};
+
+function cloneEntry(e: HealthEntry): HealthEntry {
+  return { organs: { ...e.organs }, lacking: { ...e.lacking }, excess: { ...e.excess } };
+}
+
+/** 장부 항목의 덮어쓸 수 있는 필드 */
+const HEALTH_SCHEMA: OverrideSchema = {
+  organs: { value: 'strings', localized: true },
+  lacking: { value: 'string', localized: true },
+  excess: { value: 'string', localized: true },
+};
+
+/**
+ * 장부 JSON을 검사해 오행별 대응표에 덮어쓴 복사본을 만든다 (검사 규칙은 override.ts).
+ *
+ * @param overrides JSON.parse 결과 (HealthOverrides 형식)
+ * @param base 병합 기준 대응표 (기본: 내장 데이터)
+ * @returns 병합된 대응표
+ * @throws TypeError 형식이 잘못되었거나 알 수 없는 오행 키가 있는 경우
+ */
+export function mergeHealthData(overrides: unknown, base: Readonly<HealthTable> = HEALTH_DATA): HealthTable {
+  const entries = expectObject(overrides, 'health data must be an object keyed by element (e.g. "Wood" or "木")');
+  const merged: HealthTable = {
+    Wood: cloneEntry(base.Wood),
+    Fire: cloneEntry(base.Fire),
+    Earth: cloneEntry(base.Earth),
+    Metal: cloneEntry(base.Metal),
+    Water: cloneEntry(base.Water),
+  };
+  for (const [key, raw] of Object.entries(entries)) {
+    const element = parseElementName(key);
+    if (!element) throw new TypeError(`unknown element key: ${key}`);
+    applyOverride(merged[element], raw, HEALTH_SCHEMA, key);
+  }
+  return merged;
+}
+
+/**
+ * 오행 과부족 분석 결과에 장부 대응과 주의 문구를 붙인다.
+ *
+ * @param advice balance.elementAdvisory 결과
+ * @param table 장부 대응표 (기본: 내장 데이터)
+ * @returns 과부족 오행별 건강 주의 (입력 순서 유지)
+ */
+export function healthNotes(advice: readonly ElementAdvice[], table: Readonly<HealthTable> = HEALTH_DATA): HealthNote[] {
+  return advice.map((a) => {
+    const entry = table[a.element];
+    return {
+      element: a.element,
+      count: a.count,
+      status: a.status,
+      organs: entry.organs,
+      note: a.status === 'Lacking' ? entry.lacking : entry.excess,
+    };
+  });
+}
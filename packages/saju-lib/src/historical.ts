@@ -0,0 +1,123 @@
+/**
+ * @fileoverview 개력(改曆) 이전 날짜의 역법 해석
+ *
+ * 계산은 모두 역산 그레고리력(proleptic Gregorian)으로 한다. 그레고리력을 늦게 채택한
+ * 나라의 개력 이전 민간 날짜는 당시 쓰던 역법으로 읽어 그레고리력으로 바꿔야 한다:
+ * - 율리우스력을 쓰던 나라(러시아 1918, 그리스 1923 등)는 날짜를 율리우스력으로 읽는다.
+ * - 태음태양력을 쓰던 나라(한국 1896, 일본 1873, 중국 1912)는 음력 입력을 쓰게 한다.
+ * - 개력으로 건너뛴 날짜(러시아 1918-02-01~13 등)는 존재하지 않는다.
+ *
+ * 시간대(IANA 이름)로 나라를 정하므로 auto 해석은 표에 있는 시간대에만 적용된다.
+ */
+
+/**
+ * 양력 입력 날짜를 읽는 역법.
+ * - Gregorian: 그대로 그레고리력 (기본)
+ * - Julian: 항상 율리우스력으로 읽는다
+ * - Auto: 시간대의 나라가 개력 전이면 당시 역법으로 읽는다
+ */
+export type HistoricalCalendar = 'Gregorian' | 'Julian' | 'Auto';
+
+/** 나라별 그레고리력 채택 */
+export interface CalendarReform {
+  /** 지역 이름 (영문) */
+  region: string;
+  /** 해당 IANA 시간대 */
+  zones: readonly string[];
+  /** 그레고리력 첫날 (YYYY-MM-DD) */
+  gregorianFrom: string;
+  /** 개력 전 민간 역법 */
+  before: 'Julian' | 'Lunisolar';
+}
+
+/** 그레고리력 채택 표 (지원 연도 범위와 --force 근처만) */
+export const CALENDAR_REFORMS: readonly CalendarReform[] = [
+  { region: 'Korea', zones: ['Asia/Seoul', 'Asia/Pyongyang'], gregorianFrom: '1896-01-01', before: 'Lunisolar' },
+  { region: 'Japan', zones: ['Asia/Tokyo'], gregorianFrom: '1873-01-01', before: 'Lunisolar' },
+  { region: 'China', zones: ['Asia/Shanghai', 'Asia/Urumqi'], gregorianFrom: '1912-01-01', before: 'Lunisolar' },
+  {
+    region: 'Russia',
+    zones: ['Europe/Moscow', 'Europe/Samara', 'Asia/Yekaterinburg', 'Asia/Omsk', 'Asia/Novosibirsk', 'Asia/Irkutsk', 'Asia/Vladivostok'],
+    gregorianFrom: '1918-02-14',
+    before: 'Julian',
+  },
+  { region: 'Greece', zones: ['Europe/Athens'], gregorianFrom: '1923-03-01', before: 'Julian' },
+  { region: 'Bulgaria', zones: ['Europe/Sofia'], gregorianFrom: '1916-04-14', before: 'Julian' },
+  { region: 'Romania', zones: ['Europe/Bucharest'], gregorianFrom: '1919-04-14', before: 'Julian' },
+  { region: 'Serbia', zones: ['Europe/Belgrade'], gregorianFrom: '1919-01-28', before: 'Julian' },
+  { region: 'Great Britain', zones: ['Europe/London', 'Europe/Dublin'], gregorianFrom: '1752-09-14', before: 'Julian' },
+];
+
+/** 율리우스력으로 읽은 입력 (결과에 기록하는 가정) */
+export interface HistoricalDate {
+  /** 입력한 율리우스력 날짜 (YYYY-MM-DD) */
+  julianDate: string;
+  /** 계산에 쓴 그레고리력 날짜 (YYYY-MM-DD) */
+  gregorianDate: string;
+  /** auto로 판단한 지역 (julian을 직접 지정했으면 null) */
+  region: string | null;
+  /** 그 지역의 그레고리력 첫날 (region이 없으면 null) */
+  gregorianFrom: string | null;
+}
+
+/** 입력 날짜를 그레고리력으로 해석한 결과 */
+export type HistoricalResolution =
+  /** 그레고리력 그대로 */
+  | { kind: 'Gregorian' }
+  /** 율리우스력으로 읽어 바꿨다 (reform은 auto로 판단했을 때만) */
+  | { kind: 'Julian'; gregorian: [number, number, number]; reform: CalendarReform | null }
+  /** 개력으로 건너뛴, 존재하지 않는 날짜 */
+  | { kind: 'Gap'; reform: CalendarReform }
+  /** 태음태양력을 쓰던 때의 날짜 (음력으로 입력해야 한다) */
+  | { kind: 'Lunisolar'; reform: CalendarReform };
+
+/** 시간대 이름에 해당하는 개력 정보 (표에 없으면 null) */
+export function reformForZone(zone: string): CalendarReform | null {
+  return CALENDAR_REFORMS.find((r) => r.zones.includes(zone.trim())) ?? null;
+}
+
+/** 율리우스력 날짜가 유효한지 (4년마다 윤년) */
+export function isValidJulianDate(year: number, month: number, day: number): boolean {
+  if (month < 1 || month > 12 || day < 1) return false;
+  const leap = year % 4 === 0;
+  const days = [31, leap ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+  return day <= days[month - 1];
+}
+
+/** 율리우스력 날짜를 역산 그레고리력 날짜로 바꾼다 (JDN을 거친다) */
+export function julianToGregorian(year: number, month: number, day: number): [number, number, number] {
+  const a = Math.floor((14 - month) / 12);
+  const y = year + 4800 - a;
+  const m = month + 12 * a - 3;
+  const jdn = day + Math.floor((153 * m + 2) / 5) + 365 * y + Math.floor(y / 4) - 32083;
+  // JS Date는 역산 그레고리력이다 (JDN 2440588 = 1970-01-01)
+  const date = new Date((jdn - 2440588) * 86_400_000);
+  return [date.getUTCFullYear(), date.getUTCMonth() + 1, date.getUTCDate()];
+}
+
+/**
+ * 양력 입력 날짜를 지정한 역법으로 해석한다.
+ * @param date 입력 날짜 (연·월·일)
+ * @param mode 해석 방식
+ * @param zone 출생지 시간대 이름 (Auto에서 나라를 정한다)
+ */
+export function resolveHistoricalDate(
+  date: [number, number, number],
+  mode: HistoricalCalendar,
+  zone: string,
+): HistoricalResolution {
+  if (mode === 'Gregorian') return { kind: 'Gregorian' };
+  if (mode === 'Julian') return { kind: 'Julian', gregorian: julianToGregorian(...date), reform: null };
+  const reform = reformForZone(zone);
+  if (reform === null || formatYmd(date) >= reform.gregorianFrom) return { kind: 'Gregorian' };
+  if (reform.before === 'Lunisolar') return { kind: 'Lunisolar', reform };
+  const gregorian = julianToGregorian(...date);
+  // 율리우스력으로 읽어도 개력일 이후라면 개력 때 건너뛴 날짜다
+  if (formatYmd(gregorian) >= reform.gregorianFrom) return { kind: 'Gap', reform };
+  return { kind: 'Julian', gregorian, reform };
+}
+
+/** 날짜를 YYYY-MM-DD로 표기한다 */
+export function formatYmd([year, month, day]: [number, number, number]): string {
+  return `${String(year).padStart(4, '0')}-${String(month).padStart(2, '0')}-${String(day).padStart(2, '0')}`;
+}
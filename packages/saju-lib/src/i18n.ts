@@ -4,36 +4,70 @@
  * 사주 용어의 한국어(Ko)·영문(En) 표기를 제공한다.
  * 천간·지지·십성·12운성·신살·오행 등 명리학 용어와
  * UI 레이블을 포함한다.
+ *
+ * 다른 용어집(회사 표기 지침 등)을 쓰려면 I18n을 상속하거나 withLabels()로 일부 레이블만 바꾼다.
+ * 레이블은 서로를 this로 불러 쓰므로(eraValue → pillarLabel 등) 바꾼 레이블이 조합 레이블에도 반영된다.
+ * 렌더링 함수는 공개 멤버만 요구하는 Localizer 타입을 받는다.
  */
 
 import type {
+  BoundaryAlternative,
   BranchRelationType,
+  DayBoundary,
   Direction,
   Element,
   Gender,
+  Hemisphere,
+  HiddenRank,
+  LmtBoundary,
   Pillar,
+  PillarKind,
   PillarPosition,
+  Relation,
+  ShinsalBase,
   ShinsalKind,
   StemRelationType,
   StrengthClass,
+  StrengthLevel,
   TenGod,
   TermDef,
 } from './types.js';
+import type { ZodiacSign } from './astro.js';
+import type { EraYears } from './era.js';
+import type { GyeokgukKind } from './gyeokguk.js';
+import type { HistoricalDate } from './historical.js';
+import type { DaewonDirectionMode, DaewonRounding, DaewonStartSpan, DaewonYearBasis } from './luck.js';
+import type { NameGridKind, StrokeBasis } from './naming.js';
+import type { SchoolSettings } from './school.js';
+import { type SajuWarning, SUPPORTED_YEAR_RANGES } from './service.js';
 
 /** 지원 언어: 한국어(Ko) 또는 영어(En) */
 export type Lang = 'Ko' | 'En';
 
-/** 기둥 종류 (연/월/일/시) */
-export type PillarKind = 'Year' | 'Month' | 'Day' | 'Hour';
+/**
+ * 나이 표기 관례.
+ * - Man: 만 나이 (출생 시 0세)
+ * - Korean: 세는나이 (출생 시 1세, 만 나이 + 1)
+ */
+export type AgeConvention = 'Man' | 'Korean';
+
+/**
+ * 영문 출력의 천간·지지 로마자 표기.
+ * - Korean: 한국어 로마자 (Gap, Ja …)
+ * - Pinyin: 중국어 병음 (Jia, Zi …) — 영문 명리 문헌 대부분이 쓰는 표기
+ */
+export type Romanization = 'Korean' | 'Pinyin';
 
 // ── 천간(天干) 이름 ──
 const STEMS_KO = ['갑', '을', '병', '정', '무', '기', '경', '신', '임', '계'];
 const STEMS_EN = ['Gap', 'Eul', 'Byeong', 'Jeong', 'Mu', 'Gi', 'Gyeong', 'Sin', 'Im', 'Gye'];
+const STEMS_PINYIN = ['Jia', 'Yi', 'Bing', 'Ding', 'Wu', 'Ji', 'Geng', 'Xin', 'Ren', 'Gui'];
 const STEMS_HANJA = ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'];
 
 // ── 지지(地支) 이름 ──
 const BRANCHES_KO = ['자', '축', '인', '묘', '진', '사', '오', '미', '신', '유', '술', '해'];
 const BRANCHES_EN = ['Ja', 'Chuk', 'In', 'Myo', 'Jin', 'Sa', 'O', 'Mi', 'Sin', 'Yu', 'Sul', 'Hae'];
+const BRANCHES_PINYIN = ['Zi', 'Chou', 'Yin', 'Mao', 'Chen', 'Si', 'Wu', 'Wei', 'Shen', 'You', 'Xu', 'Hai'];
 const BRANCHES_HANJA = ['子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥'];
 
 // ── 12운성(十二運星) 이름 ──
@@ -109,6 +143,22 @@ const BRANCH_KIND_LABELS: Record<Lang, Record<PillarKind, string>> = {
   En: { Year: 'Year branch', Month: 'Month branch', Day: 'Day branch', Hour: 'Hour branch' },
 };
 
+const BRANCH_ANIMALS: Record<Lang, readonly string[]> = {
+  Ko: ['쥐', '소', '호랑이', '토끼', '용', '뱀', '말', '양', '원숭이', '닭', '개', '돼지'],
+  En: ['Rat', 'Ox', 'Tiger', 'Rabbit', 'Dragon', 'Snake', 'Horse', 'Goat', 'Monkey', 'Rooster', 'Dog', 'Pig'],
+};
+
+const ZODIAC_SIGN_LABELS: Record<Lang, Record<ZodiacSign, string>> = {
+  Ko: {
+    Aries: '양자리', Taurus: '황소자리', Gemini: '쌍둥이자리', Cancer: '게자리', Leo: '사자자리', Virgo: '처녀자리',
+    Libra: '천칭자리', Scorpio: '전갈자리', Sagittarius: '사수자리', Capricorn: '염소자리', Aquarius: '물병자리', Pisces: '물고기자리',
+  },
+  En: {
+    Aries: 'Aries', Taurus: 'Taurus', Gemini: 'Gemini', Cancer: 'Cancer', Leo: 'Leo', Virgo: 'Virgo',
+    Libra: 'Libra', Scorpio: 'Scorpio', Sagittarius: 'Sagittarius', Capricorn: 'Capricorn', Aquarius: 'Aquarius', Pisces: 'Pisces',
+  },
+};
+
 const DIRECTION_LABELS: Record<Lang, Record<Direction, string>> = {
   Ko: { Forward: '순행', Backward: '역행' },
   En: { Forward: 'Forward', Backward: 'Backward' },
@@ -124,6 +174,26 @@ const STRENGTH_VERDICT_LABELS: Record<Lang, Record<StrengthClass, string>> = {
   En: { Strong: 'Strong', Weak: 'Weak', Neutral: 'Balanced' },
 };
 
+const STRENGTH_LEVEL_LABELS: Record<Lang, Record<StrengthLevel, string>> = {
+  Ko: { VeryStrong: '극신강', Strong: '신강', Neutral: '중화', Weak: '신약', VeryWeak: '극신약' },
+  En: { VeryStrong: 'Very Strong', Strong: 'Strong', Neutral: 'Balanced', Weak: 'Weak', VeryWeak: 'Very Weak' },
+};
+
+const GYEOKGUK_LABELS: Record<Lang, Record<GyeokgukKind, string>> = {
+  Ko: {
+    SikShin: '식신격(食神格)', SangGwan: '상관격(傷官格)', PyeonJae: '편재격(偏財格)', JeongJae: '정재격(正財格)',
+    ChilSal: '편관격(偏官格)', JeongGwan: '정관격(正官格)', PyeonIn: '편인격(偏印格)', JeongIn: '정인격(正印格)',
+    GeonRok: '건록격(建祿格)', YangIn: '양인격(羊刃格)', WolGeop: '월겁격(月劫格)', HwaGi: '화기격(化氣格)',
+  },
+  En: {
+    SikShin: 'Eating God (食神格)', SangGwan: 'Hurting Officer (傷官格)', PyeonJae: 'Indirect Wealth (偏財格)',
+    JeongJae: 'Direct Wealth (正財格)', ChilSal: 'Seven Killings (偏官格)', JeongGwan: 'Direct Officer (正官格)',
+    PyeonIn: 'Indirect Resource (偏印格)', JeongIn: 'Direct Resource (正印格)',
+    GeonRok: 'Thriving Salary (建祿格)', YangIn: 'Yang Blade (羊刃格)', WolGeop: 'Month Rob (月劫格)',
+    HwaGi: 'Transformation (化氣格)',
+  },
+};
+
 const STEM_RELATION_LABELS: Record<Lang, Record<StemRelationType, string>> = {
   Ko: { Hap: '천간합(天干合)', Chung: '천간충(天干沖)' },
   En: { Hap: 'Stem Combine (天干合)', Chung: 'Stem Clash (天干沖)' },
@@ -139,37 +209,193 @@ const YONGSHIN_METHOD_LABELS: Record<Lang, Record<string, string>> = {
  * 사주 관련 모든 용어와 UI 텍스트를 Lang에 따라 반환한다.
  */
 export class I18n {
-  constructor(public readonly lang: Lang) {}
+  constructor(
+    public readonly lang: Lang,
+    public readonly ageConvention: AgeConvention = 'Man',
+    public readonly romanization: Romanization = 'Korean',
+  ) {}
 
   /** 메인 제목 */
   title(): string {
     return this.lang === 'Ko' ? '사주팔자 (입춘 기준)' : 'Saju Palja (Lichun 기준)';
   }
+  /** 지원 범위 밖 연도를 강제로 계산한 결과 표시 */
+  approximateTag(): string { return this.lang === 'Ko' ? '[근사값]' : '[approximate]'; }
 
   // ── 입력/출력 섹션 레이블 ──
 
   inputLabel(): string { return this.lang === 'Ko' ? '입력' : 'Input'; }
+  rawInputLabel(): string { return this.lang === 'Ko' ? '입력 원문' : 'As entered'; }
   convertedSolarLabel(): string { return this.lang === 'Ko' ? '변환 양력' : 'Converted solar'; }
   convertedLunarLabel(): string { return this.lang === 'Ko' ? '변환 음력' : 'Converted lunar'; }
+  historicalDateLabel(): string { return this.lang === 'Ko' ? '역법 가정' : 'Calendar assumption'; }
+  /** 율리우스력 해석 표기 (예: '율리우스력 1918-01-31 → 그레고리력 1918-02-13 (Russia, 1918-02-14 개력 전)') */
+  historicalDateValue(h: HistoricalDate): string {
+    const base = this.lang === 'Ko'
+      ? `율리우스력 ${h.julianDate} → 그레고리력 ${h.gregorianDate}`
+      : `Julian ${h.julianDate} → Gregorian ${h.gregorianDate}`;
+    if (h.region === null) return base;
+    return this.lang === 'Ko'
+      ? `${base} (${h.region}, ${h.gregorianFrom} 개력 전)`
+      : `${base} (${h.region}, before the ${h.gregorianFrom} reform)`;
+  }
   leapSuffix(): string { return this.lang === 'Ko' ? ' (윤달)' : ' (Leap)'; }
+  eraLabel(): string { return this.lang === 'Ko' ? '연기(年紀)' : 'Era years'; }
+  eraBirthLabel(): string { return this.lang === 'Ko' ? '출생' : 'Birth'; }
+  eraTargetLabel(): string { return this.lang === 'Ko' ? '대상' : 'Target'; }
+  sunSignLabel(): string { return this.lang === 'Ko' ? '태양 별자리' : 'Sun sign'; }
+  zodiacSignLabel(sign: ZodiacSign): string { return ZODIAC_SIGN_LABELS[this.lang][sign]; }
+  eclipticLongitudeLabel(): string { return this.lang === 'Ko' ? '황경' : 'ecliptic longitude'; }
+  birthTermLabel(): string { return this.lang === 'Ko' ? '절입' : 'Solar term'; }
+  julianDayLabel(): string { return this.lang === 'Ko' ? '율리우스일' : 'Julian Day'; }
+  /** 절입 후 경과 일수 (예: '3.4일째') */
+  daysSinceTerm(days: number): string {
+    return this.lang === 'Ko' ? `${days.toFixed(1)}일째` : `${days.toFixed(1)} days in`;
+  }
+  /** 다음 절입까지 남은 일수 (예: '다음 청명(清明) 2024-04-04 16:02, 26.2일 후') */
+  nextTermNote(term: string, time: string, days: number): string {
+    return this.lang === 'Ko'
+      ? `다음 ${term} ${time}, ${days.toFixed(1)}일 후`
+      : `next ${term} at ${time}, in ${days.toFixed(1)} days`;
+  }
+  cuspNote(sign: ZodiacSign, time: string): string {
+    return this.lang === 'Ko'
+      ? `경계 근처: ${time} ${this.zodiacSignLabel(sign)} 진입`
+      : `near cusp: enters ${this.zodiacSignLabel(sign)} at ${time}`;
+  }
+  leapCandidateHeading(isLeap: boolean, solarDate: string): string {
+    if (this.lang === 'Ko') return `── ${isLeap ? '윤달' : '평달'}로 해석 (양력 ${solarDate}) ──`;
+    return `── Read as ${isLeap ? 'leap' : 'regular'} month (solar ${solarDate}) ──`;
+  }
+  sijinHeading(): string { return this.lang === 'Ko' ? '시진(時辰)' : 'Double hour (時辰)'; }
+  branchAnimalLabel(branch: number): string { return BRANCH_ANIMALS[this.lang][branch]; }
+  sijinLabel(branch: number): string {
+    return this.lang === 'Ko'
+      ? `${this.branchName(branch)}시(${BRANCHES_HANJA[branch]}時)`
+      : `${this.branchName(branch)} hour (${BRANCHES_HANJA[branch]}時)`;
+  }
+  sijinShiftNote(minutes: number): string {
+    const signed = `${minutes >= 0 ? '+' : '−'}${Math.abs(minutes)}`;
+    return this.lang === 'Ko' ? `평태양시 보정으로 ${signed}분 이동` : `shifted ${signed} min by local mean time`;
+  }
   localMeanTimeLabel(): string { return this.lang === 'Ko' ? '지역시 보정(평태양시)' : 'Local mean time correction'; }
+  lmtBoundaryHeading(): string { return this.lang === 'Ko' ? '시·일 경계' : 'Hour/day boundaries'; }
+  lmtBoundaryLabel(boundary: LmtBoundary): string {
+    if (this.lang === 'Ko') return boundary === 'Corrected' ? '보정 시각 기준' : '시계 시각 기준';
+    return boundary === 'Corrected' ? 'by corrected time' : 'by clock time';
+  }
+  lmtAlternativeNote(boundary: LmtBoundary, dayPillar: string, hourPillar: string): string {
+    return this.lang === 'Ko'
+      ? `${this.lmtBoundaryLabel(boundary)}이면 일주 ${dayPillar}, 시주 ${hourPillar}`
+      : `${this.lmtBoundaryLabel(boundary)}: day ${dayPillar}, hour ${hourPillar}`;
+  }
+  boundarySensitivityHeading(window: number): string {
+    return this.lang === 'Ko'
+      ? `경계 민감도 (출생 시각 ±${window}분 안에 기둥 경계가 있음)`
+      : `Boundary sensitivity (a pillar boundary lies within ±${window} min of the birth time)`;
+  }
+  /** 경계 너머 명식 줄의 앞부분 (예: '12분 전 시주 경계를 넘으면') */
+  boundaryAlternativeLead(alt: BoundaryAlternative): string {
+    const kinds: Record<Lang, Record<PillarKind, string>> = {
+      Ko: { Year: '입춘(연주)', Month: '절입(월주)', Day: '일주', Hour: '시주' },
+      En: { Year: 'Ipchun (year)', Month: 'solar term (month)', Day: 'day', Hour: 'hour' },
+    };
+    const kind = kinds[this.lang][alt.boundary];
+    return this.lang === 'Ko'
+      ? `${alt.minutes}분 ${alt.side === 'Earlier' ? '전' : '후'} ${kind} 경계를 넘으면`
+      : `${kind} boundary ${alt.minutes} min ${alt.side === 'Earlier' ? 'earlier' : 'later'}`;
+  }
+  changedPillarsLabel(): string { return this.lang === 'Ko' ? '바뀌는 기둥' : 'changes'; }
   correctedTimeLabel(): string { return this.lang === 'Ko' ? '보정 시각' : 'Corrected time'; }
   genderLabel(): string { return this.lang === 'Ko' ? '성별' : 'Gender'; }
+  chartTypeLabel(): string { return this.lang === 'Ko' ? '차트 유형' : 'Chart type'; }
+  eventChartValue(): string { return this.lang === 'Ko' ? '이벤트(시점) 차트 — 성별·대운 생략' : 'Event chart — no gender or luck pillars'; }
   dayBoundaryLabel(): string { return this.lang === 'Ko' ? '일주 경계' : 'Day boundary'; }
+  /** 일주 경계 시각 (0시 경계면 23시대가 야자시임을 덧붙인다) */
+  dayBoundaryValue(boundary: DayBoundary): string {
+    if (boundary === 'Jasi') return '23:00';
+    return this.lang === 'Ko' ? '00:00 (23시대 야자시)' : '00:00 (23:00-24:00 as late Ja hour)';
+  }
+  hemisphereLabel(): string { return this.lang === 'Ko' ? '반구' : 'Hemisphere'; }
+  hemisphereValue(hemisphere: Hemisphere): string {
+    if (hemisphere === 'North') return this.lang === 'Ko' ? '북반구' : 'northern';
+    return this.lang === 'Ko' ? '남반구 (월지 6지 이동, 비표준 관례)' : 'southern (month branch shifted by six, non-default school option)';
+  }
+  shinsalBaseLabel(base: ShinsalBase): string {
+    if (this.lang === 'Ko') return base === 'Year' ? '연지 기준' : '일지 기준';
+    return base === 'Year' ? 'by year branch' : 'by day branch';
+  }
+  schoolLabel(): string { return this.lang === 'Ko' ? '학파' : 'School'; }
+  /** 학파 설정 요약 (학파마다 같은 연 경계·지장간·12운성 관례도 함께 밝힌다) */
+  schoolSettingsText(settings: SchoolSettings): string {
+    const scoring = settings.monthScoring === 'Matrix' ? this.monthMatrixLabel() : this.monthStageLabel();
+    const parts = [
+      `${this.dayBoundaryLabel()} ${this.dayBoundaryValue(settings.dayBoundary)}`,
+      `${this.twelveShinsalLabel()} ${this.shinsalBaseLabel(settings.shinsalBase)}`,
+      `${this.daewonHeading()} ${this.startLabel()} ${this.roundingLabel(settings.daewonRounding)}`,
+      settings.transform ? `${scoring}, ${this.transformedNote()}` : scoring,
+    ];
+    const fixed = this.lang === 'Ko'
+      ? '연 경계 입춘, 지장간 여기·중기·정기, 12운성 양순음역'
+      : 'year boundary Ipchun, hidden stems residual/middle/main, 12 stages yang-forward yin-reverse';
+    return `${parts.join(' | ')} | ${fixed}`;
+  }
+  warningLabel(): string { return this.lang === 'Ko' ? '주의' : 'Warning'; }
+  warningText(warning: SajuWarning): string {
+    if (this.lang === 'En') return warning.message;
+    const [solarMin, solarMax] = SUPPORTED_YEAR_RANGES.Solar;
+    const [lunarMin, lunarMax] = SUPPORTED_YEAR_RANGES.Lunar;
+    switch (warning.code) {
+      case 'EPHEMERIS_RANGE':
+        return `${warning.year}년은 절기·운 계산의 검증 범위(${solarMin}~${solarMax}년) 밖이라 절입 경계 근처의 기둥이 틀릴 수 있음`;
+      case 'LUNAR_TABLE_RANGE':
+        return `${warning.year}년 날짜는 내장 음력 표(${lunarMin}~${lunarMax}년) 밖이라 음력 변환 생략`;
+      case 'TIMEZONE_HISTORY':
+        return `${warning.year}년은 표준시 채택 이전이라 시간대 오프셋이 평균태양시 추정값이며 당시 시계 시각과 다를 수 있음`;
+      case 'APPROXIMATE_TIME':
+        return `시진으로만 받은 출생 시각이라 ${warning.resolvedTime}(으)로 어림잡아 계산함 (시진 경계 근처면 기둥이 달라질 수 있음)`;
+    }
+  }
 
   // ── 사주 테이블 섹션 제목 ──
 
+  chartHeading(): string { return this.lang === 'Ko' ? '명식(命式)' : 'Chart'; }
   pillarsHeading(): string { return this.lang === 'Ko' ? '천간/지지' : 'Stems/Branches'; }
   hiddenStemsHeading(): string { return this.lang === 'Ko' ? '지장간' : 'Hidden Stems'; }
   tenGodsHeading(): string { return this.lang === 'Ko' ? '십성(일간 기준)' : 'Ten Gods (Day stem)'; }
   twelveStagesHeading(): string { return this.lang === 'Ko' ? '12운성(일간 기준)' : '12 Stages (Day stem)'; }
-  twelveShinsalHeading(): string { return this.lang === 'Ko' ? '12신살(연지 삼합 기준)' : '12 Shinsal (Year branch trine)'; }
+  twelveShinsalHeading(base: ShinsalBase = 'Year'): string {
+    if (this.lang === 'Ko') return base === 'Year' ? '12신살(연지 삼합 기준)' : '12신살(일지 삼합 기준)';
+    return base === 'Year' ? '12 Shinsal (Year branch trine)' : '12 Shinsal (Day branch trine)';
+  }
   strengthHeading(): string { return this.lang === 'Ko' ? '신강/신약(간단 판정)' : 'Strength (simple)'; }
+  dayFlowLabel(dayElement: Element): string {
+    return this.lang === 'Ko' ? `일간(${this.elementShortLabel(dayElement)}) 기준` : `Toward day master (${this.elementShortLabel(dayElement)})`;
+  }
+  generatesLabel(): string { return this.lang === 'Ko' ? '생' : 'generates'; }
+  controlsLabel(): string { return this.lang === 'Ko' ? '극' : 'controls'; }
   elementsHeading(): string { return this.lang === 'Ko' ? '오행 분포(천간+지지)' : 'Five Elements (stems + branches)'; }
+  balanceHeading(): string { return this.lang === 'Ko' ? '오행 과부족(過不足)' : 'Element Balance'; }
+  lackingLabel(): string { return this.lang === 'Ko' ? '없음' : 'absent'; }
+  excessLabel(count: number): string { return this.lang === 'Ko' ? `과다 ${count}개` : `excess (${count})`; }
+  supplyLabel(): string { return this.lang === 'Ko' ? '보충' : 'Supplied by'; }
+  excessSourceLabel(): string { return this.lang === 'Ko' ? '과다 원천' : 'Sources'; }
+  tenGodDistributionHeading(): string { return this.lang === 'Ko' ? '십성 분포(천간+지지 정기)' : 'Ten God Distribution (stems + main hidden stems)'; }
+  weightedLabel(): string { return this.lang === 'Ko' ? '지장간 포함 가중' : 'Weighted with hidden stems'; }
+  dominantLabel(): string { return this.lang === 'Ko' ? '우세' : 'Dominant'; }
+  absentTenGodsLabel(): string { return this.lang === 'Ko' ? '없는 십성' : 'Absent'; }
+  aptitudeHeading(): string { return this.lang === 'Ko' ? '적성 경향(십성 기준)' : 'Aptitude (Ten Gods)'; }
+  fieldsLabel(): string { return this.lang === 'Ko' ? '분야' : 'Fields'; }
   daewonHeading(): string { return this.lang === 'Ko' ? '대운' : 'Decennial Luck'; }
   yearlyLuckHeading(): string { return this.lang === 'Ko' ? '세운 (입춘 기준)' : 'Yearly Luck (Lichun)'; }
+  beforeDaewonLabel(): string { return this.lang === 'Ko' ? '대운 이전' : 'Before first decennial luck'; }
   monthlyLuckHeading(year: number): string { return this.lang === 'Ko' ? `월운 (${year}년)` : `Monthly Luck (${year})`; }
   termsHeading(): string { return this.lang === 'Ko' ? '절기' : 'Solar Terms'; }
+  iljuHeading(label: string): string { return this.lang === 'Ko' ? `일주론 (${label} 일주)` : `Day Pillar Notes (${label})`; }
+  ilganHeading(name: string, verdict: StrengthClass): string {
+    const v = STRENGTH_VERDICT_LABELS[this.lang][verdict];
+    return this.lang === 'Ko' ? `일간론 (${name}, ${v})` : `Day Master Notes (${name}, ${v})`;
+  }
   tzLabel(): string { return this.lang === 'Ko' ? '기준' : 'time zone'; }
 
   // ── 기둥(柱) 위치 레이블 ──
@@ -204,6 +430,11 @@ export class I18n {
   }
   tenGodsLabel(): string { return this.lang === 'Ko' ? '십성' : 'Ten Gods'; }
   yearLuckLabel(): string { return this.lang === 'Ko' ? '세운' : 'Annual Pillar'; }
+  twelveStagesLabel(): string { return this.lang === 'Ko' ? '12운성' : '12 Stages'; }
+  twelveShinsalLabel(): string { return this.lang === 'Ko' ? '12신살' : '12 Shinsal'; }
+  keywordsLabel(): string { return this.lang === 'Ko' ? '키워드' : 'Keywords'; }
+  temperamentLabel(): string { return this.lang === 'Ko' ? '성향' : 'Temperament'; }
+  imageLabel(): string { return this.lang === 'Ko' ? '물상' : 'Image'; }
 
   // ── 대운 관련 ──
 
@@ -212,9 +443,24 @@ export class I18n {
     return DIRECTION_LABELS[this.lang][direction];
   }
 
+  /** 대운 방향을 기본 판정(입춘 기준 연간)과 다르게 정했을 때 덧붙이는 표시 (기본이면 빈 문자열) */
+  directionModeNote(mode: DaewonDirectionMode, basis: DaewonYearBasis): string {
+    if (mode !== 'Auto') return this.lang === 'Ko' ? ' [고정]' : ' [fixed]';
+    if (basis === 'LunarNewYear') return this.lang === 'Ko' ? ' [설 기준 연간]' : ' [year stem by lunar new year]';
+    return '';
+  }
+
+  genderUnknownValue(): string { return this.lang === 'Ko' ? '미상' : 'Unknown'; }
+  daewonBothDirectionsNote(): string {
+    return this.lang === 'Ko'
+      ? '성별 미상: 순행·역행 두 가지 대운을 나란히 표시합니다 (성별 확인 후 해당 방향을 사용)'
+      : 'Gender unknown: forward and backward sequences shown side by side (use the one matching the actual gender)';
+  }
   startLabel(): string { return this.lang === 'Ko' ? '시작' : 'start'; }
   yearUnit(): string { return this.lang === 'Ko' ? '년' : 'y'; }
   monthUnit(): string { return this.lang === 'Ko' ? '개월' : 'm'; }
+  dayUnit(): string { return this.lang === 'Ko' ? '일' : 'd'; }
+  hourUnit(): string { return this.lang === 'Ko' ? '시간' : 'h'; }
 
   // ── 역법·성별 ──
 
@@ -239,20 +485,47 @@ export class I18n {
 
   // ── 나이·연도 포맷 ──
 
+  /** 나이의 년 단위 (만 나이: '년'/'y', 세는나이: '세'/'sui') */
+  ageYearUnit(): string {
+    if (this.ageConvention === 'Man') return this.yearUnit();
+    return this.lang === 'Ko' ? '세' : ' sui';
+  }
+
+  /** 만 나이(년)를 나이 표기 관례에 맞춘다 (세는나이면 +1) */
+  displayAge(years: number): number {
+    return this.ageConvention === 'Korean' ? years + 1 : years;
+  }
+
   /**
    * 개월 수를 '00년 00개월' 형식으로 포맷한다.
-   * @param months 개월 수
+   * 세는나이 관례면 년에 1을 더해 '00세 00개월'로 표기한다.
+   * @param months 개월 수 (만 나이 기준)
    * @param aligned 정렬 모드 (true면 숫자 패딩 적용)
    */
   formatAge(months: number, aligned: boolean): string {
-    const years = Math.floor(months / 12);
+    const years = this.displayAge(Math.floor(months / 12));
     const rem = months % 12;
     if (rem === 0) {
-      return aligned ? `${String(years).padStart(2)}${this.yearUnit()}` : `${years}${this.yearUnit()}`;
+      return aligned ? `${String(years).padStart(2)}${this.ageYearUnit()}` : `${years}${this.ageYearUnit()}`;
     }
     return aligned
-      ? `${String(years).padStart(2)}${this.yearUnit()} ${rem}${this.monthUnit()}`
-      : `${years}${this.yearUnit()} ${rem}${this.monthUnit()}`;
+      ? `${String(years).padStart(2)}${this.ageYearUnit()} ${rem}${this.monthUnit()}`
+      : `${years}${this.ageYearUnit()} ${rem}${this.monthUnit()}`;
+  }
+
+  /** 대운 시작 시기를 년·개월·일·시간으로 포맷한다 (예: '3년 4개월 12일 6시간') */
+  formatSpan(span: DaewonStartSpan): string {
+    return `${this.displayAge(span.years)}${this.ageYearUnit()} ${span.months}${this.monthUnit()} `
+      + `${span.days}${this.dayUnit()} ${span.hours}${this.hourUnit()}`;
+  }
+
+  /** 대운 개월 수 반올림 규칙 레이블 */
+  roundingLabel(rounding: DaewonRounding): string {
+    const map: Record<Lang, Record<DaewonRounding, string>> = {
+      Ko: { Round: '개월 반올림', Floor: '개월 버림', Ceil: '개월 올림', Traditional: '전통(1일 버림·2일 올림)' },
+      En: { Round: 'months rounded', Floor: 'months floored', Ceil: 'months ceiled', Traditional: 'traditional (drop 1 day, round up 2)' },
+    };
+    return map[this.lang][rounding];
   }
 
   /** 연도 레이블 (예: '2024년' 또는 '2024') */
@@ -284,6 +557,15 @@ export class I18n {
     return map[this.lang][element];
   }
 
+  /** 일간 기준 관계(십성 묶음) 레이블 (예: '재성(財星)') */
+  relationGroupLabel(rel: Relation): string {
+    const map: Record<Lang, Record<Relation, string>> = {
+      Ko: { Same: '비겁(比劫)', Output: '식상(食傷)', Wealth: '재성(財星)', Officer: '관성(官星)', Resource: '인성(印星)' },
+      En: { Same: 'Peers (比劫)', Output: 'Output (食傷)', Wealth: 'Wealth (財星)', Officer: 'Officer (官星)', Resource: 'Resource (印星)' },
+    };
+    return map[this.lang][rel];
+  }
+
   /** 오행 단축 레이블 (예: '목', 'Wood') */
   elementShortLabel(element: Element): string {
     const map: Record<Lang, Record<Element, string>> = {
@@ -306,6 +588,12 @@ export class I18n {
     return TEN_GOD_LABELS[this.lang][god];
   }
 
+  /** 지장간 순위 레이블 (정기·여기·중기) */
+  hiddenRankLabel(rank: HiddenRank): string {
+    if (this.lang === 'Ko') return rank === 'JeongGi' ? '정기' : rank === 'YeoGi' ? '여기' : '중기';
+    return rank === 'JeongGi' ? 'main' : rank === 'YeoGi' ? 'residual' : 'middle';
+  }
+
   // ── 12운성·12신살 ──
 
   /** 12운성 레이블 (인덱스 0~11) */
@@ -326,11 +614,36 @@ export class I18n {
     return STRENGTH_CLASS_LABELS[this.lang][cls];
   }
 
+  /** 격국 레이블 */
+  gyeokgukLabel(kind: GyeokgukKind): string {
+    return GYEOKGUK_LABELS[this.lang][kind];
+  }
+
+  /** 요약용 일간 표기 (예: '신약한 갑목(甲木)', 'Weak Yang Wood (甲)') */
+  summaryDayMaster(verdict: StrengthClass, name: string): string {
+    if (this.lang === 'Ko') {
+      const prefix: Record<StrengthClass, string> = { Strong: '신강한', Weak: '신약한', Neutral: '중화된' };
+      return `${prefix[verdict]} ${name}`;
+    }
+    return `${STRENGTH_VERDICT_LABELS.En[verdict]} ${name}`;
+  }
+
+  /** 요약용 현재 대운 표기 (예: '현재 대운 경오(庚午) 32–41세') */
+  summaryDaewon(pillarLabel: string, fromAge: number): string {
+    return this.lang === 'Ko'
+      ? `현재 대운 ${pillarLabel} ${fromAge}–${fromAge + 9}세`
+      : `Current luck ${pillarLabel} age ${fromAge}–${fromAge + 9}`;
+  }
+
   /** 최종 강약 판정 레이블 (신강/신약/중화) */
   strengthVerdictLabel(verdict: StrengthClass): string {
     return STRENGTH_VERDICT_LABELS[this.lang][verdict];
   }
 
+  strengthLevelLabel(level: StrengthLevel): string {
+    return STRENGTH_LEVEL_LABELS[this.lang][level];
+  }
+
   // ── 용신(用神) ──
 
   yongshinHeading(): string { return this.lang === 'Ko' ? '용신(用神)' : 'Use God (Yongshin)'; }
@@ -342,6 +655,53 @@ export class I18n {
     return YONGSHIN_METHOD_LABELS[this.lang][method];
   }
 
+  // ── 생활 개운(開運) ──
+
+  gaeunHeading(): string { return this.lang === 'Ko' ? '생활 개운(開運)' : 'Everyday Fortune Boosters'; }
+  colorsLabel(): string { return this.lang === 'Ko' ? '색상' : 'Colors'; }
+  directionsLabel(): string { return this.lang === 'Ko' ? '방위' : 'Directions'; }
+  numbersLabel(): string { return this.lang === 'Ko' ? '숫자' : 'Numbers'; }
+  seasonsLabel(): string { return this.lang === 'Ko' ? '계절' : 'Seasons'; }
+
+  batchStatsHeading(): string { return this.lang === 'Ko' ? '일괄 처리 통계' : 'Batch statistics'; }
+
+  // ── 성명 분석 ──
+
+  namingHeading(): string { return this.lang === 'Ko' ? '성명 분석(姓名)' : 'Name Analysis'; }
+  nameGridLabel(kind: NameGridKind): string {
+    const map: Record<Lang, Record<NameGridKind, string>> = {
+      Ko: { Won: '원격(元)', Hyeong: '형격(亨)', I: '이격(利)', Jeong: '정격(貞)' },
+      En: { Won: 'Won (given name)', Hyeong: 'Hyeong (surname + first)', I: 'I (surname + last)', Jeong: 'Jeong (total)' },
+    };
+    return map[this.lang][kind];
+  }
+  strokeBasisLabel(basis: StrokeBasis): string {
+    const map: Record<Lang, Record<StrokeBasis, string>> = {
+      Ko: { Hanja: '한자 원획', Hangul: '한글 필획', Given: '입력 획수' },
+      En: { Hanja: 'hanja strokes', Hangul: 'Hangul strokes', Given: 'given strokes' },
+    };
+    return map[this.lang][basis];
+  }
+  luckyNumberLabel(lucky: boolean): string {
+    if (this.lang === 'Ko') return lucky ? '길' : '흉';
+    return lucky ? 'lucky' : 'unlucky';
+  }
+  soundElementsLabel(): string { return this.lang === 'Ko' ? '발음오행' : 'Sound elements'; }
+  nameChartLabel(): string { return this.lang === 'Ko' ? '원국 대조' : 'Against the chart'; }
+  nameSuppliesLabel(): string { return this.lang === 'Ko' ? '없는 오행 보충' : 'Fills absent'; }
+  nameUnsuppliedLabel(): string { return this.lang === 'Ko' ? '보충 안 됨' : 'Still absent'; }
+  presenceLabel(present: boolean): string {
+    if (this.lang === 'Ko') return present ? '있음' : '없음';
+    return present ? 'present' : 'absent';
+  }
+
+  // ── 오행 건강 ──
+
+  healthHeading(): string { return this.lang === 'Ko' ? '오행 건강(臟腑)' : 'Element Health (Organs)'; }
+  healthDisclaimer(): string {
+    return this.lang === 'Ko' ? '※ 전통 해석에 따른 참고 사항이며 의학적 진단이 아닙니다.' : '* Traditional correspondences for reference only, not medical advice.';
+  }
+
   // ── 신강/신약 상세 ──
 
   scoreLabel(): string { return this.lang === 'Ko' ? '점수' : 'Score'; }
@@ -351,6 +711,8 @@ export class I18n {
   supportLabel(): string { return this.lang === 'Ko' ? '지원' : 'Support'; }
   drainLabel(): string { return this.lang === 'Ko' ? '억제' : 'Drain'; }
   monthStageLabel(): string { return this.lang === 'Ko' ? '월지 운성' : 'Month branch stage'; }
+  perPillarLabel(): string { return this.lang === 'Ko' ? '기둥별' : 'By pillar'; }
+  monthMatrixLabel(): string { return this.lang === 'Ko' ? '월지 점수표' : 'Month score table'; }
   locationLabel(): string { return this.lang === 'Ko' ? '위치' : 'Location'; }
   longitudeLabel(): string { return this.lang === 'Ko' ? '경도' : 'Longitude'; }
   stdMeridianLabel(): string { return this.lang === 'Ko' ? '표준경도' : 'Std meridian'; }
@@ -365,11 +727,40 @@ export class I18n {
       : `${term.nameEn} (${term.nameHanja})`;
   }
 
-  /** 기둥 표기 (예: '갑자(甲子)') */
+  /** 기둥 표기 (예: '갑자(甲子)', 병음이면 'Jia Zi(甲子)') */
   pillarLabel(pillar: Pillar): string {
     const stem = this.stemName(pillar.stem);
     const branch = this.branchName(pillar.branch);
-    return `${stem}${branch}(${STEMS_HANJA[pillar.stem]}${BRANCHES_HANJA[pillar.branch]})`;
+    const sep = this.usesPinyin() ? ' ' : '';
+    return `${stem}${sep}${branch}(${STEMS_HANJA[pillar.stem]}${BRANCHES_HANJA[pillar.branch]})`;
+  }
+
+  /** 한 줄 출력용 4기둥 (예: '갑오년 병인월 갑자일 을축시', 'GapO year, ByeongIn month, …') */
+  onelinePillars(pillars: readonly Pillar[]): string {
+    const sep = this.usesPinyin() ? ' ' : '';
+    const names = pillars.map((p) => `${this.stemName(p.stem)}${sep}${this.branchName(p.branch)}`);
+    if (this.lang === 'Ko') return names.map((name, i) => `${name}${'년월일시'[i]}`).join(' ');
+    return names.map((name, i) => `${name} ${['year', 'month', 'day', 'hour'][i]}`).join(', ');
+  }
+
+  /** 한 줄 출력용 용신 (예: '용신:수') */
+  onelineYongshin(element: Element): string {
+    return `${this.lang === 'Ko' ? '용신' : 'Yongshin'}:${this.elementShortLabel(element)}`;
+  }
+
+  /** 한 줄 출력용 대운 방향과 시작 나이 (예: '대운:역행 4세') */
+  onelineDaewon(direction: Direction, startAge: number): string {
+    return this.lang === 'Ko'
+      ? `대운:${this.directionLabel(direction)} ${startAge}세`
+      : `Luck:${this.directionLabel(direction)} from ${startAge}`;
+  }
+
+  /** 연기 표기 (예: '서기 2025년 · 단기 4358년 · 불기 2569년 · 세차 을사(乙巳)년') */
+  eraValue(era: EraYears): string {
+    if (this.lang === 'Ko') {
+      return `서기 ${era.year}년 · 단기 ${era.dangun}년 · 불기 ${era.buddhist}년 · 세차 ${this.pillarLabel(era.secha)}년`;
+    }
+    return `AD ${era.year} · Dangun ${era.dangun} · Buddhist ${era.buddhist} · ${this.pillarLabel(era.secha)} year`;
   }
 
   /** 천간 표기 (예: '갑(甲)') */
@@ -412,6 +803,35 @@ export class I18n {
 
   relationsHeading(): string { return this.lang === 'Ko' ? '합충형파해(合沖刑破害)' : 'Interactions'; }
   shinsalExtraHeading(): string { return this.lang === 'Ko' ? '주요 신살(神殺)' : 'Key Spirits'; }
+  shinsalNotesHeading(): string { return this.lang === 'Ko' ? '신살 풀이' : 'Shinsal Meanings'; }
+  branchPairsHeading(): string { return this.lang === 'Ko' ? '원진·귀문(怨嗔·鬼門)' : 'Grudge / Ghost Gate Pairs'; }
+  stemCombinationsHeading(): string { return this.lang === 'Ko' ? '천간합 합화(合化)' : 'Stem Combination Transformation'; }
+  transformedLabel(transformed: boolean): string {
+    if (this.lang === 'Ko') return transformed ? '화(化)' : '합이불화(合而不化)';
+    return transformed ? 'transforms' : 'combines without transforming';
+  }
+  monthSupportLabel(support: boolean): string {
+    if (this.lang === 'Ko') return support ? '월령 득령' : '월령 실령';
+    return support ? 'month supports' : 'month does not support';
+  }
+  contestedLabel(): string { return this.lang === 'Ko' ? '쟁합·투합' : 'contested'; }
+  obstructedLabel(): string { return this.lang === 'Ko' ? '극신 투출' : 'controller revealed'; }
+  dayBranchHeading(label: string): string { return this.lang === 'Ko' ? `일지(배우자궁): ${label}` : `Day Branch (Spouse Palace): ${label}`; }
+  dayBranchRelationsLabel(): string { return this.lang === 'Ko' ? '관계' : 'Relations'; }
+  keyShinsalLabel(): string { return this.lang === 'Ko' ? '신살' : 'Spirits'; }
+  formationsHeading(): string { return this.lang === 'Ko' ? '특수 구조' : 'Notable Formations'; }
+  combinationsHeading(): string { return this.lang === 'Ko' ? '삼합·방합 국(局)' : 'Triple / Directional Combinations'; }
+  completeLabel(complete: boolean): string {
+    if (this.lang === 'Ko') return complete ? '완전' : '반합';
+    return complete ? 'complete' : 'partial';
+  }
+  transformedNote(): string { return this.lang === 'Ko' ? '합화 반영' : 'transformed'; }
+  /** 운 기둥 기준 공망에 든 원국 위치 (예: '원국 공망 연·시') */
+  natalVoidedLabel(positions: string): string {
+    return this.lang === 'Ko' ? `원국 공망 ${positions}` : `voids natal ${positions}`;
+  }
+  noneLabel(): string { return this.lang === 'Ko' ? '없음' : 'None'; }
+  glossaryHeading(): string { return this.lang === 'Ko' ? '용어 풀이' : 'Glossary'; }
 
   /** '~주 기준' 레이블 (예: '일주 기준') */
   basisPositionLabel(pos: PillarPosition): string {
@@ -422,11 +842,40 @@ export class I18n {
 
   // ── private helpers ──
 
+  private usesPinyin(): boolean {
+    return this.lang === 'En' && this.romanization === 'Pinyin';
+  }
+
   private stemName(stem: number): string {
-    return this.lang === 'Ko' ? STEMS_KO[stem] : STEMS_EN[stem];
+    if (this.lang === 'Ko') return STEMS_KO[stem];
+    return this.usesPinyin() ? STEMS_PINYIN[stem] : STEMS_EN[stem];
   }
 
   private branchName(branch: number): string {
-    return this.lang === 'Ko' ? BRANCHES_KO[branch] : BRANCHES_EN[branch];
+    if (this.lang === 'Ko') return BRANCHES_KO[branch];
+    return this.usesPinyin() ? BRANCHES_PINYIN[branch] : BRANCHES_EN[branch];
   }
 }
+
+/** 레이블 제공자의 공개 멤버 (I18n을 상속하지 않은 구현도 렌더링 함수에 넘길 수 있다) */
+export type Localizer = Pick<I18n, keyof I18n>;
+
+/** 문자열을 돌려주는 I18n 메서드 이름 */
+export type LabelMethod = {
+  [K in keyof I18n]: I18n[K] extends (...args: never[]) => string ? K : never;
+}[keyof I18n];
+
+/** withLabels()로 바꿀 레이블 (this는 새 제공자라 다른 레이블을 불러 쓸 수 있다) */
+export type LabelOverrides = {
+  [K in LabelMethod]?: I18n[K] extends (...args: infer A) => string ? (this: I18n, ...args: A) => string : never;
+};
+
+/**
+ * 기존 제공자에서 일부 레이블만 바꾼 제공자를 만든다.
+ * 나머지 레이블과 언어·나이·로마자 설정은 base를 따른다.
+ * @param base 바탕 제공자 (예: new I18n('Ko'))
+ * @param overrides 바꿀 레이블 메서드
+ */
+export function withLabels(base: I18n, overrides: LabelOverrides): I18n {
+  return Object.assign(Object.create(base) as I18n, overrides);
+}
@@ -0,0 +1,191 @@
+/**
+ * @fileoverview 일간론(日干論) 참고 데이터 모듈
+ *
+ * 10천간 일간별 물상(物象)과 기질 설명을 내장하고,
+ * 신강/신약/중화 판정에 따라 달라지는 해설을 함께 제공한다.
+ */
+
+import type { StrengthClass } from './types.js';
+import type { Lang } from './i18n.js';
+
+/** 일간 하나의 해설 데이터 */
+export interface IlganEntry {
+  /** 일간 이름 (예: '갑목(甲木)') */
+  name: Record<Lang, string>;
+  /** 물상 — 자연물에 빗댄 이미지 */
+  image: Record<Lang, string>;
+  /** 기본 기질 */
+  note: Record<Lang, string>;
+  /** 신강/신약/중화별 해설 */
+  byVerdict: Record<StrengthClass, Record<Lang, string>>;
+}
+
+function text(ko: string, en: string): Record<Lang, string> {
+  return { Ko: ko, En: en };
+}
+
+/** 천간 순서(甲=0 … 癸=9)의 내장 일간론 데이터 */
+export const ILGAN_DATA: readonly IlganEntry[] = [
+  // 甲
+  {
+    name: text('갑목(甲木)', 'Yang Wood (甲)'),
+    image: text('곧게 뻗은 큰 나무', 'A tall, straight tree'),
+    note: text('곧고 진취적이며 앞장서기를 좋아한다. 자존심이 강하고 굽히기를 싫어한다.',
+      'Upright and enterprising, likes to lead. Proud and unwilling to bend.'),
+    byVerdict: {
+      Strong: text('신강한 갑목은 추진력과 리더십이 돋보이나 독단으로 흐르기 쉬우니 가지치기(관성·식상)가 필요하다.',
+        'A strong Yang Wood drives and leads well but turns headstrong; it needs pruning (Officer/Output).'),
+      Weak: text('신약한 갑목은 뜻은 크나 뿌리가 얕아 쉽게 지치니 물(인성)과 숲(비겁)의 도움을 받아야 한다.',
+        'A weak Yang Wood aims high on shallow roots and tires easily; it needs water (Resource) and peers.'),
+      Neutral: text('중화된 갑목은 곧은 기상을 유지하면서도 주변과 조화를 이룬다.',
+        'A balanced Yang Wood keeps its straight bearing while staying in harmony with others.'),
+    },
+  },
+  // 乙
+  {
+    name: text('을목(乙木)', 'Yin Wood (乙)'),
+    image: text('덩굴과 화초', 'Vines and flowering plants'),
+    note: text('부드럽고 유연하며 환경 적응력이 뛰어나다. 끈질긴 생명력으로 실속을 챙긴다.',
+      'Soft and flexible, adapts well. Tenacious and practical.'),
+    byVerdict: {
+      Strong: text('신강한 을목은 생활력이 강하고 고집스럽게 자기 영역을 넓혀 간다.',
+        'A strong Yin Wood is resourceful and stubbornly widens its ground.'),
+      Weak: text('신약한 을목은 기댈 곳(큰 나무·물)을 찾으며 관계 속에서 힘을 얻는다.',
+        'A weak Yin Wood seeks something to lean on and draws strength from relationships.'),
+      Neutral: text('중화된 을목은 유연함과 끈기를 고루 갖춰 어디서든 뿌리내린다.',
+        'A balanced Yin Wood pairs flexibility with persistence and takes root anywhere.'),
+    },
+  },
+  // 丙
+  {
+    name: text('병화(丙火)', 'Yang Fire (丙)'),
+    image: text('하늘의 태양', 'The sun in the sky'),
+    note: text('밝고 열정적이며 숨김이 없다. 명예를 중시하고 베풀기를 좋아한다.',
+      'Bright, passionate and open. Values honor and likes to give.'),
+    byVerdict: {
+      Strong: text('신강한 병화는 카리스마가 넘치나 성급하고 과시적이기 쉬우니 물(관성)로 절제해야 한다.',
+        'A strong Yang Fire is charismatic but hasty and showy; water (Officer) tempers it.'),
+      Weak: text('신약한 병화는 구름 낀 해처럼 의욕이 들쭉날쭉하니 나무(인성)의 지원이 필요하다.',
+        'A weak Yang Fire is a clouded sun with wavering drive; it needs wood (Resource).'),
+      Neutral: text('중화된 병화는 따뜻한 빛으로 주변을 고르게 비춘다.',
+        'A balanced Yang Fire shines warmly and evenly on those around it.'),
+    },
+  },
+  // 丁
+  {
+    name: text('정화(丁火)', 'Yin Fire (丁)'),
+    image: text('촛불과 등불', 'A candle or lamp'),
+    note: text('섬세하고 따뜻하며 속정이 깊다. 집중력과 예민한 감수성을 지녔다.',
+      'Delicate, warm and deeply caring. Focused and sensitive.'),
+    byVerdict: {
+      Strong: text('신강한 정화는 집념이 강하고 한 분야를 깊이 파고드나 예민함이 날카로워질 수 있다.',
+        'A strong Yin Fire is persistent and digs deep, though its sensitivity can turn sharp.'),
+      Weak: text('신약한 정화는 바람 앞의 촛불처럼 불안하기 쉬우니 장작(인성)과 동료(비겁)가 힘이 된다.',
+        'A weak Yin Fire flickers like a candle in the wind; fuel (Resource) and peers steady it.'),
+      Neutral: text('중화된 정화는 은은한 빛으로 사람을 이끄는 배려형이다.',
+        'A balanced Yin Fire guides others with a gentle, considerate glow.'),
+    },
+  },
+  // 戊
+  {
+    name: text('무토(戊土)', 'Yang Earth (戊)'),
+    image: text('큰 산과 둑', 'A great mountain or dyke'),
+    note: text('묵직하고 신뢰감이 있으며 포용력이 크다. 변화보다 안정을 중시한다.',
+      'Solid, trustworthy and accommodating. Prefers stability to change.'),
+    byVerdict: {
+      Strong: text('신강한 무토는 듬직하나 완고하고 둔해지기 쉬우니 나무(관성)로 소통시켜야 한다.',
+        'A strong Yang Earth is dependable but rigid and slow; wood (Officer) opens it up.'),
+      Weak: text('신약한 무토는 무너지기 쉬운 둑처럼 책임감에 짓눌리니 불(인성)의 지원이 필요하다.',
+        'A weak Yang Earth is a crumbling dyke weighed down by duty; it needs fire (Resource).'),
+      Neutral: text('중화된 무토는 중심을 잡아 주는 든든한 중재자다.',
+        'A balanced Yang Earth is a steady mediator that holds the center.'),
+    },
+  },
+  // 己
+  {
+    name: text('기토(己土)', 'Yin Earth (己)'),
+    image: text('논밭과 정원의 흙', 'Fields and garden soil'),
+    note: text('온순하고 실속 있으며 남을 잘 기른다. 세심하고 현실적이다.',
+      'Gentle and practical, good at nurturing others. Careful and realistic.'),
+    byVerdict: {
+      Strong: text('신강한 기토는 수완이 좋고 자기 것을 잘 지키나 속내를 드러내지 않는다.',
+        'A strong Yin Earth is shrewd and guards its own, but keeps its thoughts hidden.'),
+      Weak: text('신약한 기토는 쉽게 휩쓸리는 흙처럼 남의 요구에 지치기 쉬우니 불(인성)로 단단히 해야 한다.',
+        'A weak Yin Earth washes away under demands; fire (Resource) firms it.'),
+      Neutral: text('중화된 기토는 비옥한 밭처럼 사람과 일을 고루 길러 낸다.',
+        'A balanced Yin Earth is fertile soil that grows people and work alike.'),
+    },
+  },
+  // 庚
+  {
+    name: text('경금(庚金)', 'Yang Metal (庚)'),
+    image: text('무쇠와 바위', 'Raw iron or rock'),
+    note: text('강직하고 결단력이 있으며 의리를 중시한다. 승부욕이 강하다.',
+      'Firm and decisive, values loyalty. Highly competitive.'),
+    byVerdict: {
+      Strong: text('신강한 경금은 결단이 빠르나 거칠기 쉬우니 불(관성)로 제련해야 그릇이 된다.',
+        'A strong Yang Metal decides fast but runs rough; fire (Officer) forges it into a tool.'),
+      Weak: text('신약한 경금은 무른 쇠처럼 결정을 미루기 쉬우니 흙(인성)의 뒷받침이 필요하다.',
+        'A weak Yang Metal is soft iron that hesitates; it needs earth (Resource).'),
+      Neutral: text('중화된 경금은 원칙과 융통성을 겸비한 실행가다.',
+        'A balanced Yang Metal is a doer with both principle and flexibility.'),
+    },
+  },
+  // 辛
+  {
+    name: text('신금(辛金)', 'Yin Metal (辛)'),
+    image: text('보석과 칼날', 'Jewels or a fine blade'),
+    note: text('예리하고 깔끔하며 자존심이 강하다. 완벽을 추구하고 미적 감각이 있다.',
+      'Sharp, neat and proud. A perfectionist with an eye for beauty.'),
+    byVerdict: {
+      Strong: text('신강한 신금은 날카로운 판단력을 지녔으나 까다롭기 쉬우니 물(식상)로 씻어 빛내야 한다.',
+        'A strong Yin Metal judges keenly but gets fussy; water (Output) polishes it.'),
+      Weak: text('신약한 신금은 상처받기 쉬운 보석이니 흙(인성)의 보호 속에서 빛난다.',
+        'A weak Yin Metal is a fragile jewel that shines under earth’s (Resource) protection.'),
+      Neutral: text('중화된 신금은 섬세함과 냉철함이 균형을 이룬다.',
+        'A balanced Yin Metal balances refinement with a cool head.'),
+    },
+  },
+  // 壬
+  {
+    name: text('임수(壬水)', 'Yang Water (壬)'),
+    image: text('강과 바다', 'Rivers and the sea'),
+    note: text('지혜롭고 포용력이 크며 활동 범위가 넓다. 자유롭고 기획력이 있다.',
+      'Wise, broad-minded and far-ranging. Free-spirited and strategic.'),
+    byVerdict: {
+      Strong: text('신강한 임수는 스케일이 크나 범람하기 쉬우니 흙(관성)의 제방이 필요하다.',
+        'A strong Yang Water thinks big but floods easily; it needs earth (Officer) as a levee.'),
+      Weak: text('신약한 임수는 마르기 쉬운 물줄기처럼 기복이 있으니 금(인성)의 수원이 필요하다.',
+        'A weak Yang Water is a stream prone to drying; it needs metal (Resource) as a source.'),
+      Neutral: text('중화된 임수는 흐름을 읽고 유연하게 방향을 잡는 전략가다.',
+        'A balanced Yang Water reads the currents and steers flexibly.'),
+    },
+  },
+  // 癸
+  {
+    name: text('계수(癸水)', 'Yin Water (癸)'),
+    image: text('비와 이슬', 'Rain and dew'),
+    note: text('조용하고 총명하며 직관이 뛰어나다. 스며들 듯 사람을 움직인다.',
+      'Quiet, bright and intuitive. Moves people by quietly seeping in.'),
+    byVerdict: {
+      Strong: text('신강한 계수는 지략이 깊으나 생각이 많아 우울해지기 쉬우니 나무(식상)로 흘려보내야 한다.',
+        'A strong Yin Water is deeply shrewd but broods; wood (Output) lets it flow.'),
+      Weak: text('신약한 계수는 금세 증발하는 이슬처럼 예민하니 금(인성)과 물(비겁)의 도움이 필요하다.',
+        'A weak Yin Water evaporates like dew and is sensitive; it needs metal (Resource) and peers.'),
+      Neutral: text('중화된 계수는 만물을 적시는 비처럼 조용히 도움을 준다.',
+        'A balanced Yin Water helps quietly, like rain that nourishes everything.'),
+    },
+  },
+];
+
+/**
+ * 일간 인덱스의 일간론 데이터를 조회한다.
+ * @param dayStem 일간 인덱스 (0~9)
+ * @param dataset 조회할 데이터셋 (기본: 내장 데이터)
+ */
+export function ilganEntry(dayStem: number, dataset: readonly IlganEntry[] = ILGAN_DATA): IlganEntry {
+  if (!Number.isInteger(dayStem) || dayStem < 0 || dayStem > 9) {
+    throw new RangeError(`dayStem index must be 0-9, got ${dayStem}`);
+  }
+  return dataset[dayStem];
+}
@@ -0,0 +1,271 @@
+/**
+ * @fileoverview 일주론(日柱論) 참고 데이터 모듈
+ *
+ * 60갑자 일주별 키워드와 성향 설명을 내장한다.
+ * 외부 JSON 파일 등으로 받은 데이터를 병합(override)하여
+ * 학파·저자별 해설로 교체할 수 있다.
+ */
+
+import type { Pillar } from './types.js';
+import type { Lang } from './i18n.js';
+import { sexagenaryIndex } from './bazi.js';
+import { applyOverride, expectObject, type OverrideSchema } from './override.js';
+
+/** 일주 하나의 해설 데이터 */
+export interface IljuEntry {
+  /** 핵심 키워드 */
+  keywords: Record<Lang, string[]>;
+  /** 성향(기질) 설명 한 줄 */
+  note: Record<Lang, string>;
+}
+
+/**
+ * --ilju-data JSON. 일주 한자(예: '甲子') 아래에 바꿀 키워드·해설만 언어별로 둔다.
+ *
+ * ```json
+ * { "甲子": { "keywords": { "Ko": ["지혜"] }, "note": { "En": "..." } } }
+ * ```
+ */
+export type IljuOverrides = Record<string, {
+  keywords?: Partial<Record<Lang, string[]>>;
+  note?: Partial<Record<Lang, string>>;
+}>;
+
+const STEMS_HANJA = '甲乙丙丁戊己庚辛壬癸';
+const BRANCHES_HANJA = '子丑寅卯辰巳午未申酉戌亥';
+
+function entry(ko: string[], noteKo: string, en: string[], noteEn: string): IljuEntry {
+  return { keywords: { Ko: ko, En: en }, note: { Ko: noteKo, En: noteEn } };
+}
+
+/** 60갑자 순서(甲子=0 … 癸亥=59)의 내장 일주론 데이터 */
+export const ILJU_DATA: readonly IljuEntry[] = [
+  // 甲子
+  entry(['지혜', '학구열', '자존심'], '물 위의 큰 나무 — 총명하고 학문을 좋아하나 고집이 세다.',
+    ['wisdom', 'studiousness', 'pride'], 'A great tree over water — bright and studious, but stubborn.'),
+  // 乙丑
+  entry(['인내', '실속', '끈기'], '언 땅의 화초 — 조용하지만 끈질기고 실속을 챙긴다.',
+    ['patience', 'practicality', 'tenacity'], 'A plant in frozen soil — quiet yet tenacious and practical.'),
+  // 丙寅
+  entry(['열정', '리더십', '명예'], '숲을 비추는 태양 — 밝고 적극적이며 명예를 중시한다.',
+    ['passion', 'leadership', 'honor'], 'The sun over a forest — bright, proactive and honor-minded.'),
+  // 丁卯
+  entry(['섬세', '예술', '온화'], '풀숲의 등불 — 감성이 풍부하고 예술적 재능이 있다.',
+    ['delicacy', 'artistry', 'gentleness'], 'A lamp among grasses — sensitive with artistic talent.'),
+  // 戊辰
+  entry(['포용', '야망', '중후'], '저수지를 품은 큰 산 — 스케일이 크고 우직하며 야망이 있다.',
+    ['tolerance', 'ambition', 'gravity'], 'A mountain holding a reservoir — broad-minded, steady and ambitious.'),
+  // 己巳
+  entry(['재치', '실리', '근면'], '햇볕 든 밭 — 영리하고 부지런하며 실리에 밝다.',
+    ['wit', 'pragmatism', 'diligence'], 'A sunlit field — clever, diligent and shrewd about gains.'),
+  // 庚午
+  entry(['원칙', '명예', '결단'], '불에 단련되는 쇠 — 원칙적이고 명예를 중시하며 결단력이 있다.',
+    ['principle', 'honor', 'decisiveness'], 'Metal tempered by fire — principled, honor-bound and decisive.'),
+  // 辛未
+  entry(['예민', '끈기', '자기관리'], '흙 속의 보석 — 섬세하고 자존심이 강하며 꾸준하다.',
+    ['sensitivity', 'persistence', 'self-discipline'], 'A gem in the earth — refined, proud and steady.'),
+  // 壬申
+  entry(['지략', '활동', '유연'], '바위에서 솟는 샘 — 두뇌 회전이 빠르고 활동적이다.',
+    ['strategy', 'activity', 'flexibility'], 'A spring from the rock — quick-witted and active.'),
+  // 癸酉
+  entry(['청결', '분석', '냉정'], '바위틈의 맑은 물 — 깔끔하고 분석적이며 감정 표현이 절제된다.',
+    ['clarity', 'analysis', 'composure'], 'Clear water between rocks — neat, analytical and reserved.'),
+  // 甲戌
+  entry(['책임감', '개척', '고집'], '메마른 땅의 큰 나무 — 책임감이 강하고 개척 정신이 있다.',
+    ['responsibility', 'pioneering', 'obstinacy'], 'A great tree on dry land — responsible with a pioneering spirit.'),
+  // 乙亥
+  entry(['유연', '인정', '순수'], '물가의 화초 — 인정이 많고 순수하며 적응력이 좋다.',
+    ['flexibility', 'warmth', 'innocence'], 'A plant by the water — kind-hearted, pure and adaptable.'),
+  // 丙子
+  entry(['표현', '사교', '기복'], '호수 위의 태양 — 밝고 사교적이나 감정 기복이 있다.',
+    ['expression', 'sociability', 'mood swings'], 'The sun over a lake — bright and sociable, with emotional ups and downs.'),
+  // 丁丑
+  entry(['헌신', '인내', '내실'], '겨울밤의 촛불 — 헌신적이고 참을성이 많으며 내실을 다진다.',
+    ['devotion', 'endurance', 'substance'], 'A candle on a winter night — devoted, patient and grounded.'),
+  // 戊寅
+  entry(['추진력', '위엄', '도전'], '숲이 우거진 산 — 추진력과 위엄이 있고 도전을 즐긴다.',
+    ['drive', 'dignity', 'challenge'], 'A forested mountain — driven, dignified and fond of challenges.'),
+  // 己卯
+  entry(['섬세', '교육', '예민'], '새싹이 돋는 밭 — 섬세하고 가르치는 재능이 있으나 예민하다.',
+    ['delicacy', 'teaching', 'nervousness'], 'A field of sprouts — delicate with a gift for teaching, but sensitive.'),
+  // 庚辰
+  entry(['강직', '카리스마', '승부욕'], '괴강(魁罡)의 쇠 — 강직하고 카리스마가 있으며 승부욕이 강하다.',
+    ['integrity', 'charisma', 'competitiveness'], 'Kuigang metal — upright, charismatic and competitive.'),
+  // 辛巳
+  entry(['품위', '야심', '긴장'], '불빛에 빛나는 보석 — 품위와 야심이 있으나 긴장을 잘 한다.',
+    ['elegance', 'ambition', 'tension'], 'A gem gleaming in firelight — elegant and ambitious, yet tense.'),
+  // 壬午
+  entry(['열정', '모험', '변화'], '햇빛 받는 강물 — 열정적이고 모험을 즐기며 변화가 많다.',
+    ['passion', 'adventure', 'change'], 'A river in sunlight — passionate, adventurous and changeable.'),
+  // 癸未
+  entry(['배려', '인내', '내성'], '메마른 땅의 단비 — 배려심이 깊고 참을성이 있으나 속을 드러내지 않는다.',
+    ['consideration', 'patience', 'introversion'], 'Rain on dry land — considerate and patient, but keeps feelings hidden.'),
+  // 甲申
+  entry(['개혁', '결단', '긴장'], '바위 위의 큰 나무 — 결단력과 개혁 성향이 있으나 스트레스가 많다.',
+    ['reform', 'decisiveness', 'stress'], 'A great tree on rock — decisive and reform-minded, under constant pressure.'),
+  // 乙酉
+  entry(['절제', '예리', '완벽'], '바위틈의 화초 — 예리하고 완벽을 추구하며 자기 절제가 강하다.',
+    ['restraint', 'sharpness', 'perfectionism'], 'A flower between rocks — sharp, perfectionist and self-restrained.'),
+  // 丙戌
+  entry(['의리', '봉사', '고독'], '노을 지는 태양 — 의리 있고 봉사 정신이 있으나 고독을 느낀다.',
+    ['loyalty', 'service', 'solitude'], 'The setting sun — loyal and giving, yet often lonely.'),
+  // 丁亥
+  entry(['직관', '지성', '온정'], '밤바다의 등대 — 직관과 지성이 뛰어나며 온정이 있다.',
+    ['intuition', 'intellect', 'compassion'], 'A lighthouse on the night sea — intuitive, intelligent and warm.'),
+  // 戊子
+  entry(['재물', '신중', '실속'], '호수를 품은 산 — 신중하고 재물 관리에 밝다.',
+    ['wealth', 'prudence', 'practicality'], 'A mountain holding a lake — prudent and good with money.'),
+  // 己丑
+  entry(['성실', '고집', '축적'], '겨울 논밭 — 성실하고 고집이 있으며 꾸준히 쌓아 간다.',
+    ['sincerity', 'stubbornness', 'accumulation'], 'A winter field — sincere, stubborn and steadily accumulating.'),
+  // 庚寅
+  entry(['용맹', '행동', '성급'], '숲 속의 호랑이 — 용감하고 행동이 빠르나 성급하다.',
+    ['bravery', 'action', 'impatience'], 'A tiger in the forest — brave and quick to act, but hasty.'),
+  // 辛卯
+  entry(['섬세', '미적 감각', '날카로움'], '풀을 다듬는 가위 — 섬세하고 날카로우며 미적 감각이 있다.',
+    ['finesse', 'aesthetics', 'sharpness'], 'Shears trimming grass — fine, sharp and aesthetically gifted.'),
+  // 壬辰
+  entry(['포부', '지도력', '괴강'], '용이 사는 큰 바다 — 포부가 크고 지도력이 있다.',
+    ['aspiration', 'leadership', 'kuigang'], 'A great sea with a dragon — grand aspirations and leadership.'),
+  // 癸巳
+  entry(['총명', '현실감', '변덕'], '햇볕에 오르는 이슬 — 총명하고 현실적이나 변덕이 있다.',
+    ['brilliance', 'realism', 'fickleness'], 'Dew rising in the sun — bright and realistic, but fickle.'),
+  // 甲午
+  entry(['표현', '열정', '독립'], '불타는 나무 — 표현력과 열정이 넘치고 독립적이다.',
+    ['expression', 'passion', 'independence'], 'A burning tree — expressive, passionate and independent.'),
+  // 乙未
+  entry(['생활력', '끈기', '실속'], '사막의 풀 — 생활력이 강하고 끈질기다.',
+    ['resilience', 'tenacity', 'practicality'], 'Grass in the desert — resilient and tenacious.'),
+  // 丙申
+  entry(['다재다능', '사교', '분주'], '산 너머로 지는 해 — 재주가 많고 사교적이며 분주하다.',
+    ['versatility', 'sociability', 'busyness'], 'The sun setting over a mountain — versatile, sociable and always busy.'),
+  // 丁酉
+  entry(['세련', '재치', '예민'], '보석을 비추는 촛불 — 세련되고 재치 있으나 예민하다.',
+    ['sophistication', 'wit', 'sensitivity'], 'A candle lighting jewels — refined and witty, but sensitive.'),
+  // 戊戌
+  entry(['뚝심', '고집', '정신세계'], '거대한 바위산(괴강) — 뚝심과 고집이 세고 정신세계가 깊다.',
+    ['grit', 'stubbornness', 'spirituality'], 'A vast rocky mountain (kuigang) — gritty, stubborn and spiritually deep.'),
+  // 己亥
+  entry(['온순', '재물', '유연'], '물을 머금은 논 — 온순하고 재물복이 있으며 유연하다.',
+    ['mildness', 'wealth', 'flexibility'], 'A well-watered paddy — mild, blessed with wealth and flexible.'),
+  // 庚子
+  entry(['냉철', '지혜', '독설'], '찬 물 속의 쇠 — 냉철하고 영리하나 말이 날카롭다.',
+    ['coolness', 'wisdom', 'sharp tongue'], 'Metal in cold water — cool-headed and clever, with a sharp tongue.'),
+  // 辛丑
+  entry(['인내', '고집', '내공'], '언 땅 속의 보석 — 인내심과 내공이 있으나 고집이 세다.',
+    ['endurance', 'stubbornness', 'inner strength'], 'A gem in frozen earth — enduring with inner strength, but stubborn.'),
+  // 壬寅
+  entry(['포용', '기획', '진취'], '숲을 키우는 강 — 포용력과 기획력이 있고 진취적이다.',
+    ['tolerance', 'planning', 'enterprise'], 'A river nurturing a forest — tolerant, a planner and enterprising.'),
+  // 癸卯
+  entry(['온화', '감성', '봉사'], '새싹을 적시는 봄비 — 온화하고 감성이 풍부하며 남을 돕는다.',
+    ['gentleness', 'emotion', 'service'], 'Spring rain on sprouts — gentle, emotional and helpful.'),
+  // 甲辰
+  entry(['성취', '리더십', '재물'], '비옥한 땅의 큰 나무 — 성취욕과 리더십이 있고 재물운이 있다.',
+    ['achievement', 'leadership', 'wealth'], 'A great tree in fertile soil — achieving, leading and prosperous.'),
+  // 乙巳
+  entry(['화술', '재능', '활동'], '햇볕 아래 핀 꽃 — 화술과 재능이 뛰어나고 활동적이다.',
+    ['eloquence', 'talent', 'activity'], 'A flower in full sun — eloquent, talented and active.'),
+  // 丙午
+  entry(['열정', '자존심', '과감'], '한낮의 태양(양인) — 열정과 자존심이 강하고 과감하다.',
+    ['passion', 'pride', 'boldness'], 'The noon sun (blade) — passionate, proud and bold.'),
+  // 丁未
+  entry(['헌신', '희생', '내면의 열기'], '사막의 모닥불 — 헌신적이며 내면에 뜨거운 열정을 품는다.',
+    ['devotion', 'sacrifice', 'inner heat'], 'A campfire in the desert — devoted, with a fierce inner fire.'),
+  // 戊申
+  entry(['활동', '재주', '실용'], '광맥을 품은 산 — 활동적이고 재주가 많으며 실용적이다.',
+    ['activity', 'skill', 'utility'], 'A mountain with ore veins — active, skilled and practical.'),
+  // 己酉
+  entry(['섬세', '기술', '깔끔'], '결실을 맺은 밭 — 섬세하고 손재주가 있으며 깔끔하다.',
+    ['delicacy', 'craft', 'tidiness'], 'A harvested field — delicate, handy and tidy.'),
+  // 庚戌
+  entry(['의리', '강인', '괴강'], '무기고의 쇠(괴강) — 의리 있고 강인하며 통솔력이 있다.',
+    ['loyalty', 'toughness', 'kuigang'], 'Metal in an armory (kuigang) — loyal, tough and commanding.'),
+  // 辛亥
+  entry(['총명', '예술', '고독'], '물에 씻긴 보석 — 총명하고 예술적이나 고독을 느낀다.',
+    ['brilliance', 'artistry', 'solitude'], 'A gem washed by water — brilliant and artistic, yet lonely.'),
+  // 壬子
+  entry(['지략', '독립', '양인'], '한겨울의 큰 물(양인) — 지략이 뛰어나고 독립심이 강하다.',
+    ['strategy', 'independence', 'blade'], 'Great water in midwinter (blade) — strategic and fiercely independent.'),
+  // 癸丑
+  entry(['인내', '비밀', '저력'], '언 땅 속의 물 — 속이 깊고 참을성과 저력이 있다.',
+    ['endurance', 'secrecy', 'latent power'], 'Water under frozen ground — deep, patient and quietly powerful.'),
+  // 甲寅
+  entry(['독립', '자존', '추진'], '숲 속의 거목(간여지동) — 독립심과 자존심이 강하고 추진력이 있다.',
+    ['independence', 'self-respect', 'drive'], 'A giant in the forest — independent, proud and driven.'),
+  // 乙卯
+  entry(['유연', '생명력', '고집'], '무성한 풀밭(간여지동) — 유연하지만 생명력과 고집이 강하다.',
+    ['flexibility', 'vitality', 'stubbornness'], 'A lush meadow — flexible, yet vital and stubborn.'),
+  // 丙辰
+  entry(['관대', '표현', '명랑'], '들판을 비추는 햇살 — 관대하고 명랑하며 표현력이 좋다.',
+    ['generosity', 'expression', 'cheerfulness'], 'Sunlight over a field — generous, cheerful and expressive.'),
+  // 丁巳
+  entry(['열정', '직관', '예민'], '타오르는 불꽃(간여지동) — 열정과 직관이 강하나 예민하다.',
+    ['passion', 'intuition', 'sensitivity'], 'A blazing flame — passionate and intuitive, but sensitive.'),
+  // 戊午
+  entry(['에너지', '중심', '고집'], '화산(양인) — 에너지가 강하고 중심이 확고하나 고집이 세다.',
+    ['energy', 'centeredness', 'stubbornness'], 'A volcano (blade) — energetic and centered, but stubborn.'),
+  // 己未
+  entry(['실속', '인내', '고집'], '메마른 들판(간여지동) — 실속 있고 인내하나 고집이 세다.',
+    ['practicality', 'patience', 'stubbornness'], 'A dry plain — practical and patient, but stubborn.'),
+  // 庚申
+  entry(['강인', '결단', '의리'], '단단한 쇳덩이(간여지동) — 강인하고 결단력 있으며 의리가 있다.',
+    ['toughness', 'decisiveness', 'loyalty'], 'A solid ingot — tough, decisive and loyal.'),
+  // 辛酉
+  entry(['예리', '완벽', '냉정'], '정제된 보석(간여지동) — 예리하고 완벽주의이며 냉정하다.',
+    ['sharpness', 'perfectionism', 'coolness'], 'A refined jewel — sharp, perfectionist and cool.'),
+  // 壬戌
+  entry(['포부', '통솔', '괴강'], '둑 안의 큰 물(괴강) — 포부가 크고 통솔력이 있다.',
+    ['aspiration', 'command', 'kuigang'], 'Great water behind a dam (kuigang) — ambitious and commanding.'),
+  // 癸亥
+  entry(['지혜', '직관', '포용'], '끝없는 바다(간여지동) — 지혜롭고 직관적이며 포용력이 크다.',
+    ['wisdom', 'intuition', 'tolerance'], 'The boundless sea — wise, intuitive and all-embracing.'),
+];
+
+/**
+ * 60갑자 한자 표기를 반환한다 (예: '甲子').
+ * @param pillar 기둥
+ */
+export function iljuKey(pillar: Pillar): string {
+  return `${STEMS_HANJA[pillar.stem]}${BRANCHES_HANJA[pillar.branch]}`;
+}
+
+/**
+ * 일주에 해당하는 해설을 조회한다.
+ * @param dayPillar 일주
+ * @param dataset 사용할 데이터셋 (기본: 내장 데이터)
+ */
+export function iljuEntry(dayPillar: Pillar, dataset: readonly IljuEntry[] = ILJU_DATA): IljuEntry {
+  return dataset[sexagenaryIndex(dayPillar)];
+}
+
+/** 일주 항목의 덮어쓸 수 있는 필드 */
+const ILJU_SCHEMA: OverrideSchema = {
+  keywords: { value: 'strings', localized: true },
+  note: { value: 'string', localized: true },
+};
+
+/**
+ * 일주 해설 JSON을 검사해 60일주 데이터셋에 덮어쓴 복사본을 만든다 (검사 규칙은 override.ts).
+ *
+ * @param overrides JSON.parse 결과 (IljuOverrides 형식)
+ * @param base 병합 기준 데이터셋 (기본: 내장 데이터)
+ * @returns 병합된 60개 데이터셋
+ * @throws TypeError 형식이 잘못되었거나 알 수 없는 일주 키가 있는 경우
+ */
+export function mergeIljuData(overrides: unknown, base: readonly IljuEntry[] = ILJU_DATA): IljuEntry[] {
+  const entries = expectObject(overrides, 'ilju data must be an object keyed by day pillar (e.g. "甲子")');
+  const merged = base.map((e) => ({
+    keywords: { ...e.keywords },
+    note: { ...e.note },
+  }));
+  for (const [key, raw] of Object.entries(entries)) {
+    const stem = STEMS_HANJA.indexOf(key.charAt(0));
+    const branch = BRANCHES_HANJA.indexOf(key.charAt(1));
+    if (key.length !== 2 || stem < 0 || branch < 0 || stem % 2 !== branch % 2) {
+      throw new TypeError(`unknown day pillar key: ${key}`);
+    }
+    applyOverride(merged[sexagenaryIndex({ stem, branch })], raw, ILJU_SCHEMA, key);
+  }
+  return merged;
+}
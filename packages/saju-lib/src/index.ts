@@ -11,27 +11,59 @@ export * as astro from './astro.js';
 export * as bazi from './bazi.js';
 export * as interactions from './interactions.js';
 export * as shinsal from './shinsal.js';
+export * as shinsalNotes from './shinsalNotes.js';
 export * as strength from './strength.js';
 export * as lunar from './lunar.js';
 export * as luck from './luck.js';
 export * as location from './location.js';
 export * as timezone from './timezone.js';
 export * as utils from './utils.js';
-export { I18n } from './i18n.js';
-export type { Lang, PillarKind } from './i18n.js';
+export * as ilju from './ilju.js';
+export * as ilgan from './ilgan.js';
+export * as gyeokguk from './gyeokguk.js';
+export * as formation from './formation.js';
+export * as dayBranch from './dayBranch.js';
+export * as tenGodStats from './tenGodStats.js';
+export * as era from './era.js';
+export * as render from './render.js';
+export * as balance from './balance.js';
+export * as gaeun from './gaeun.js';
+export * as health from './health.js';
+export * as naming from './naming.js';
+export * as aptitude from './aptitude.js';
+export * as glossary from './glossary.js';
+export * as input from './input.js';
+export * as timings from './timings.js';
+export * as school from './school.js';
+export * as historical from './historical.js';
+export { I18n, withLabels } from './i18n.js';
+export type { AgeConvention, LabelMethod, LabelOverrides, Lang, Localizer, Romanization } from './i18n.js';
 export {
   calculate,
+  calculateCached,
+  calculatePillars,
+  clearResultCache,
+  FINGERPRINT_VERSION,
+  RESULT_CACHE_SIZE,
+  SUPPORTED_YEAR_RANGES,
   validateRequest,
   SajuValidationError,
   isSajuValidationError,
 } from './service.js';
 export type {
+  BirthTermContext,
   CalendarType,
+  PillarAccessors,
+  PillarsRequest,
+  PillarsResult,
   SajuRequest,
   SajuResult,
+  SajuWarning,
+  SajuWarningCode,
   ValidationErrorCode,
 } from './service.js';
 // 웹 컴포넌트에서 직접 import하는 타입 재수출
 export type { StrengthResult } from './strength.js';
-export type { DaewonItem, YearLuck, MonthLuck, MonthlyLuck } from './luck.js';
+export type { DaewonAgeRange, DaewonDirectionMode, DaewonItem, DaewonRounding, DaewonSequence, DaewonStartSpan, DaewonYearBasis, YearLuck, YearLuckGroup, MonthLuck, MonthlyLuck } from './luck.js';
 export type { TimeZoneSpec } from './timezone.js';
+export type { IljuEntry } from './ilju.js';
@@ -0,0 +1,250 @@
+/**
+ * @fileoverview 날짜·시각 입력 정규화
+ *
+ * 사용자가 흔히 쓰는 날짜·시각 표기를 계산 입력의 표준 형식
+ * (YYYY-MM-DD, HH:MM 또는 HH:MM:SS)으로 바꾼다.
+ *
+ * - 날짜: 1990-03-05, 1990-3-5, 1990.3.5, 1990. 3. 5., 1990/03/05, 19900305,
+ *   1990년 3월 5일, 음력 1990년 윤5월 3일 (앞의 '음력'·'양력'과 '윤'은 역법·윤달을 뜻한다)
+ * - 시각: 06:05, 6:5, 6:05:30, 0605, 060530, 6시 30분, 오전 6시 30분, 오후 6시 반,
+ *   6:30pm, 6:30 PM, 6 p.m., 오후 6:30 (오전·오후, am·pm은 12시간제로 읽는다)
+ * - 시진: 자시, 축시초, 미시말, 子時 (parseSijin — 시각은 계산 단계에서 시진 구간으로 정한다)
+ * - 상대 날짜: today, yesterday, tomorrow, 오늘, 어제, 내일, +3d, -2w (resolveRelativeDate — 기준일은 호출자가 정한다)
+ *
+ * 알아보지 못한 표기는 그대로 돌려주며, 형식·범위 검증은 계산 단계에서 한다.
+ */
+
+import type { CalendarType } from './service.js';
+
+/** 날짜 문구를 해석한 결과 */
+export interface DateInput {
+  /** YYYY-MM-DD (알아보지 못하면 앞뒤 공백만 뺀 입력) */
+  date: string;
+  /** '음력'·'양력'으로 밝힌 역법 (없으면 null) */
+  calendar: CalendarType | null;
+  /** '윤5월'처럼 밝힌 윤달 여부 (없으면 null) */
+  leapMonth: boolean | null;
+}
+
+/** 날짜 앞의 역법 표시 */
+const CALENDAR_PREFIX = /^(음력|양력)\s*/;
+
+/** 구분자(., /, -)로 나눈 연·월·일 (마지막 점 허용: '1990. 3. 5.') */
+const SEPARATED_DATE = /^(\d{4})\s*([./-])\s*(\d{1,2})\s*\2\s*(\d{1,2})\s*\.?$/;
+
+/** 구분자 없는 8자리 날짜 */
+const COMPACT_DATE = /^(\d{4})(\d{2})(\d{2})$/;
+
+/** 한국어 날짜: 1990년 (윤)3월 5일 */
+const KOREAN_DATE = /^(\d{4})\s*년\s*(윤\s*)?(\d{1,2})\s*월\s*(\d{1,2})\s*일$/;
+
+/** 콜론으로 나눈 시·분(·초) */
+const SEPARATED_TIME = /^(\d{1,2}):(\d{1,2})(?::(\d{1,2}))?$/;
+
+/** 구분자 없는 4자리(HHMM) 또는 6자리(HHMMSS) 시각 */
+const COMPACT_TIME = /^(\d{2})(\d{2})(\d{2})?$/;
+
+/** 한국어 시각: 6시 (30분|반) (15초) */
+const KOREAN_TIME = /^(\d{1,2})\s*시(?:\s*(?:(\d{1,2})\s*분|(반)))?(?:\s*(\d{1,2})\s*초)?$/;
+
+/** 시각 앞의 오전·오후 */
+const KOREAN_MERIDIEM = /^(오전|오후)\s*/;
+
+/** 시각 뒤의 am·pm (a.m., PM 등) */
+const ENGLISH_MERIDIEM = /\s*([ap])\.?\s*m\.?$/i;
+
+/** 12시간제 표시와 함께 쓴 시만 있는 시각 (예: '6pm') */
+const HOUR_ONLY = /^(\d{1,2})$/;
+
+/** ISO-8601 날짜·시각 (소수 초는 버림): 1990-03-05T06:30:00+09:00 */
+const ISO_DATETIME = /^(\d{4}-\d{2}-\d{2})[T ](\d{2}:\d{2}(?::\d{2})?)(?:\.\d+)?(Z|[+-]\d{2}(?::?\d{2})?)?$/i;
+
+/** 상대 날짜 키워드와 기준일로부터의 일수 */
+const RELATIVE_DATE_WORDS: ReadonlyMap<string, number> = new Map([
+  ['today', 0],
+  ['yesterday', -1],
+  ['tomorrow', 1],
+  ['오늘', 0],
+  ['어제', -1],
+  ['내일', 1],
+]);
+
+/** 기준일로부터의 일·주 오프셋: +3d, -2w */
+const RELATIVE_DATE_OFFSET = /^([+-])(\d{1,5})([dw])$/i;
+
+/** 시진 표기: 자시, 축시초, 미시 말, 子時初 */
+const SIJIN_TIME = /^([자축인묘진사오미신유술해子丑寅卯辰巳午未申酉戌亥])\s*[시時]\s*([초말初末])?$/;
+
+const SIJIN_BRANCHES = '자축인묘진사오미신유술해';
+const SIJIN_BRANCHES_HANJA = '子丑寅卯辰巳午未申酉戌亥';
+
+/** 시진 안에서 고를 시점: 초(시작), 한가운데, 말(끝) */
+export type SijinPart = 'Early' | 'Middle' | 'Late';
+
+/** 시진으로 적은 출생 시각 */
+export interface SijinInput {
+  /** 시지 인덱스 (0 = 자) */
+  branch: number;
+  part: SijinPart;
+}
+
+function pad2(value: string | number): string {
+  return String(value).padStart(2, '0');
+}
+
+/**
+ * 날짜 문구를 YYYY-MM-DD와 문구에 밝힌 역법·윤달로 나눈다.
+ * @param raw 입력 날짜 (예: '1990.3.5', '음력 1990년 윤5월 3일')
+ */
+export function parseDateInput(raw: string): DateInput {
+  let text = raw.trim();
+  let calendar: CalendarType | null = null;
+  const prefix = CALENDAR_PREFIX.exec(text);
+  if (prefix) {
+    calendar = prefix[1] === '음력' ? 'Lunar' : 'Solar';
+    text = text.slice(prefix[0].length);
+  }
+  const korean = KOREAN_DATE.exec(text);
+  if (korean) {
+    return {
+      date: `${korean[1]}-${pad2(korean[3])}-${pad2(korean[4])}`,
+      calendar,
+      leapMonth: korean[2] !== undefined ? true : null,
+    };
+  }
+  const separated = SEPARATED_DATE.exec(text);
+  if (separated) return { date: `${separated[1]}-${pad2(separated[3])}-${pad2(separated[4])}`, calendar, leapMonth: null };
+  const compact = COMPACT_DATE.exec(text);
+  if (compact) return { date: `${compact[1]}-${compact[2]}-${compact[3]}`, calendar, leapMonth: null };
+  return { date: raw.trim(), calendar: null, leapMonth: null };
+}
+
+/**
+ * 날짜 표기를 YYYY-MM-DD로 바꾼다 (역법·윤달 표시는 버린다).
+ * @param raw 입력 날짜
+ * @returns 표준 형식 날짜 (알아보지 못하면 앞뒤 공백만 뺀 입력)
+ */
+export function normalizeDate(raw: string): string {
+  return parseDateInput(raw).date;
+}
+
+/**
+ * 시각 표기를 HH:MM 또는 HH:MM:SS로 바꾼다.
+ * 12시간제에서 12시 am(오전 12시)은 0시, 12시 pm(오후 12시)은 12시이며,
+ * 오전·오후(am·pm)와 0시나 13시 이상을 함께 쓰면 알아보지 않는다.
+ * @param raw 입력 시각
+ * @returns 표준 형식 시각 (알아보지 못하면 앞뒤 공백만 뺀 입력)
+ */
+export function normalizeTime(raw: string): string {
+  const text = raw.trim();
+  let rest = text;
+  let pm: boolean | null = null;
+  const meridiem = KOREAN_MERIDIEM.exec(rest) ?? ENGLISH_MERIDIEM.exec(rest);
+  if (meridiem) {
+    pm = meridiem[1] === '오후' || meridiem[1].toLowerCase() === 'p';
+    rest = meridiem.index === 0 ? rest.slice(meridiem[0].length) : rest.slice(0, meridiem.index);
+  }
+
+  let parts: [hour: string, minute: string, second: string | undefined];
+  const korean = KOREAN_TIME.exec(rest);
+  const match = SEPARATED_TIME.exec(rest) ?? COMPACT_TIME.exec(rest);
+  const hourOnly = pm !== null ? HOUR_ONLY.exec(rest) : null;
+  if (korean) {
+    parts = [korean[1], korean[3] !== undefined ? '30' : korean[2] ?? '0', korean[4]];
+  } else if (match) {
+    parts = [match[1], match[2], match[3]];
+  } else if (hourOnly) {
+    parts = [hourOnly[1], '0', undefined];
+  } else {
+    return text;
+  }
+
+  const hour = to24Hour(Number(parts[0]), pm);
+  if (hour === null) return text;
+  const hm = `${pad2(hour)}:${pad2(parts[1])}`;
+  return parts[2] !== undefined ? `${hm}:${pad2(parts[2])}` : hm;
+}
+
+/**
+ * 12시간제 시(1~12)를 24시간제로 바꾼다.
+ * @param hour 입력한 시
+ * @param pm 오후 여부 (null이면 24시간제 입력으로 보고 그대로 둔다)
+ * @returns 0~23시, 12시간제 범위를 벗어나면 null
+ */
+function to24Hour(hour: number, pm: boolean | null): number | null {
+  if (pm === null) return hour;
+  if (hour < 1 || hour > 12) return null;
+  return (hour % 12) + (pm ? 12 : 0);
+}
+
+/** ISO-8601 날짜·시각을 나눈 결과 */
+export interface IsoDateTime {
+  /** YYYY-MM-DD */
+  date: string;
+  /** HH:MM 또는 HH:MM:SS */
+  time: string;
+  /** 붙어 있던 오프셋 ('+09:00', Z는 '+00:00'; 없으면 null) */
+  tz: string | null;
+}
+
+/**
+ * 상대 날짜('today', '어제', '+3d', '-2w')를 기준일에서 센 양력 날짜로 바꾼다.
+ * @param raw 입력 날짜
+ * @param today 기준일 (YYYY-MM-DD, 보통 선택한 시간대의 오늘)
+ * @returns YYYY-MM-DD (상대 날짜가 아니면 null)
+ */
+export function resolveRelativeDate(raw: string, today: string): string | null {
+  const text = raw.trim().toLowerCase();
+  let days = RELATIVE_DATE_WORDS.get(text);
+  if (days === undefined) {
+    const offset = RELATIVE_DATE_OFFSET.exec(text);
+    if (!offset) return null;
+    days = (offset[1] === '-' ? -1 : 1) * Number(offset[2]) * (offset[3] === 'w' ? 7 : 1);
+  }
+  const [year, month, day] = today.split('-').map(Number);
+  const date = new Date(Date.UTC(year, month - 1, day + days));
+  return date.toISOString().slice(0, 10);
+}
+
+/**
+ * ISO-8601 날짜·시각('1990-03-05T06:30:00+09:00')을 날짜·시각·오프셋으로 나눈다.
+ * @param raw 입력 문자열
+ * @returns 나눈 결과 (형식이 다르면 null)
+ */
+export function parseIsoDateTime(raw: string): IsoDateTime | null {
+  const match = ISO_DATETIME.exec(raw.trim());
+  if (!match) return null;
+  const offset = match[3];
+  let tz: string | null = null;
+  if (offset !== undefined) {
+    const digits = offset.replace(':', '');
+    tz = offset.toUpperCase() === 'Z' ? '+00:00' : `${digits.slice(0, 3)}:${digits.slice(3) || '00'}`;
+  }
+  return { date: match[1], time: match[2], tz };
+}
+
+/**
+ * 시진 표기('자시', '축시초', '미시말')를 읽는다.
+ * 초는 시진의 첫 분, 말은 마지막 분, 아무것도 없으면 한가운데를 뜻한다.
+ * @param raw 입력 시각
+ * @returns 시진 (시진 표기가 아니면 null)
+ */
+export function parseSijin(raw: string): SijinInput | null {
+  const match = SIJIN_TIME.exec(raw.trim());
+  if (!match) return null;
+  const branch = Math.max(SIJIN_BRANCHES.indexOf(match[1]), SIJIN_BRANCHES_HANJA.indexOf(match[1]));
+  const part: SijinPart = match[2] === '초' || match[2] === '初' ? 'Early' : match[2] === undefined ? 'Middle' : 'Late';
+  return { branch, part };
+}
+
+/**
+ * 시진 안에서 고른 시점을 (보정) 시각으로 바꾼다.
+ * 자시는 전날 23:00에 시작하므로 자시초는 dayOffset −1이다.
+ * @returns 자정부터의 분(0~1439)과 입력 날짜 대비 날짜 이동
+ */
+export function sijinMinutes(sijin: SijinInput): { minutes: number; dayOffset: number } {
+  const start = sijin.branch * 120 - 60;
+  const offset = sijin.part === 'Early' ? 0 : sijin.part === 'Middle' ? 60 : 119;
+  const minutes = start + offset;
+  return minutes < 0 ? { minutes: minutes + 1440, dayOffset: -1 } : { minutes, dayOffset: 0 };
+}
@@ -5,12 +5,16 @@
  */
 
 import type {
+  BranchCombination,
   BranchInteraction,
   Element,
+  ElementOverrides,
   Pillar,
   PillarPosition,
+  StemCombination,
   StemInteraction,
 } from './types.js';
+import { branchElement, elementControls, elementGenerates, stemElement } from './bazi.js';
 
 // ── 천간 합/충 (天干 合沖) ──
 
@@ -81,6 +85,59 @@ export function findStemInteractions(pillars: Pillar[]): StemInteraction[] {
   return result;
 }
 
+// ── 천간합 합화(合化) 평가 ──
+
+/**
+ * 이웃한 천간(연-월, 월-일, 일-시)의 합을 찾아 합화 성립 여부를 평가한다.
+ *
+ * 다음을 모두 만족하면 화(化)한 것으로 본다.
+ * 1. 월령: 월지 오행이 합화 오행과 같거나 합화 오행을 생한다
+ * 2. 쟁합·투합 없음: 두 글자 중 하나가 다른 이웃 천간과도 합하지 않는다
+ * 3. 극신 없음: 합에 참여하지 않은 천간 중 합화 오행을 극하는 것이 없다
+ *
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ * @returns StemCombination 배열 (기둥 순서)
+ */
+export function evaluateStemCombinations(pillars: Pillar[]): StemCombination[] {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const stems = pillars.map((p) => p.stem);
+  const monthElement = branchElement(pillars[1].branch);
+  const result: StemCombination[] = [];
+  for (let i = 0; i < 3; i++) {
+    const element = stemHap(stems[i], stems[i + 1]);
+    if (element === null) continue;
+    const monthSupport = monthElement === element || elementGenerates(monthElement) === element;
+    const contested = (i > 0 && stemHap(stems[i - 1], stems[i]) !== null)
+      || (i + 2 < 4 && stemHap(stems[i + 1], stems[i + 2]) !== null);
+    const obstructed = stems.some((s, k) => k !== i && k !== i + 1 && elementControls(stemElement(s)) === element);
+    result.push({
+      positions: [POS[i], POS[i + 1]],
+      stems: [stems[i], stems[i + 1]],
+      element,
+      monthSupport,
+      contested,
+      obstructed,
+      transformed: monthSupport && !contested && !obstructed,
+    });
+  }
+  return result;
+}
+
+/**
+ * 합화가 성립한 천간합을 위치별 오행 치환으로 바꾼다.
+ * @param combos evaluateStemCombinations 결과
+ * @returns 화(化)한 천간만 합화 오행, 지지는 모두 null
+ */
+export function stemTransformations(combos: readonly StemCombination[]): ElementOverrides {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const overrides: ElementOverrides = { stems: [null, null, null, null], branches: [null, null, null, null] };
+  for (const combo of combos) {
+    if (!combo.transformed) continue;
+    for (const pos of combo.positions) overrides.stems[POS.indexOf(pos)] = combo.element;
+  }
+  return overrides;
+}
+
 // ── 지지 관계 (地支 關係) ──
 
 /**
@@ -216,3 +273,64 @@ export function findBranchInteractions(pillars: Pillar[]): BranchInteraction[] {
 
   return result;
 }
+
+// ── 삼합/방합 국(局) ──
+
+/** 왕지(旺支): 子午卯酉 — 반합(半合)이 성립하려면 반드시 포함되어야 한다 */
+const WANG_JI = [0, 3, 6, 9];
+
+/**
+ * 원국 지지에서 삼합·방합 국을 찾는다.
+ *
+ * - 완전(complete): 세 지지가 모두 있는 경우
+ * - 반합(partial): 왕지를 포함한 두 지지만 있는 경우
+ *   (같은 국이 이미 완전하게 성립했으면 반합은 따로 보고하지 않는다)
+ *
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ * @returns 완전한 국이 먼저, 이어서 반합 (각각 기둥 순서)
+ */
+export function findBranchCombinations(pillars: Pillar[]): BranchCombination[] {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const branches = pillars.map((p) => p.branch);
+  const complete: BranchCombination[] = [];
+  const partial: BranchCombination[] = [];
+
+  const groups = [
+    ...SAM_HAP.map(([el, g]) => ['SamHap', el, g] as const),
+    ...BANG_HAP.map(([el, g]) => ['BangHap', el, g] as const),
+  ];
+  for (const [relation, element, group] of groups) {
+    const idx = [0, 1, 2, 3].filter((i) => group.includes(branches[i]));
+    const present = new Set(idx.map((i) => branches[i]));
+    if (present.size === 3) {
+      // 같은 지지가 중복되면 첫 번째 위치만 국에 참여한 것으로 본다
+      const used = group.map((b) => idx.find((i) => branches[i] === b)!).sort((a, b) => a - b);
+      complete.push({ relation, complete: true, positions: used.map((i) => POS[i]), branches: used.map((i) => branches[i]), element });
+    } else if (present.size === 2 && [...present].some((b) => WANG_JI.includes(b))) {
+      const used = [...present].map((b) => idx.find((i) => branches[i] === b)!).sort((a, b) => a - b);
+      partial.push({ relation, complete: false, positions: used.map((i) => POS[i]), branches: used.map((i) => branches[i]), element });
+    }
+  }
+  return [...complete, ...partial];
+}
+
+/**
+ * 완전한 삼합·방합 국을 위치별 오행 치환으로 바꾼다.
+ * 반합은 기세만 모일 뿐 화(化)하지 않는 것으로 보아 반영하지 않는다.
+ * 한 지지가 여러 국에 걸리면 먼저 나온 국(삼합 우선)을 따른다.
+ *
+ * @param combos findBranchCombinations 결과
+ * @returns 천간은 모두 null, 국에 참여한 지지만 국의 오행
+ */
+export function branchTransformations(combos: readonly BranchCombination[]): ElementOverrides {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const overrides: ElementOverrides = { stems: [null, null, null, null], branches: [null, null, null, null] };
+  for (const combo of combos) {
+    if (!combo.complete) continue;
+    for (const pos of combo.positions) {
+      const i = POS.indexOf(pos);
+      overrides.branches[i] ??= combo.element;
+    }
+  }
+  return overrides;
+}
@@ -1,36 +1,40 @@
 /**
  * @fileoverview 지역(Location) 및 평태양시(LMT) 보정 모듈
  *
- * 한국 주요 도시의 경도 정보를 내장하고, 이름으로 검색할 수 있다.
+ * 한국 주요 도시의 경도·위도·시간대를 내장하고, 이름으로 검색할 수 있다.
  * 평태양시(Local Mean Time) 보정은 실제 지역 경도와 표준자오선의
  * 차이를 시간(초)으로 환산하여 생시 보정에 활용한다.
  */
 
-/** 내장 지역 정의 (도시명, 경도, 별칭) */
+/** 내장 지역 정의 (도시명, 경도·위도, 시간대, 별칭) */
 interface LocationDef {
   key: string;
   display: string;
   longitude: number;
+  latitude: number;
+  tz: string;
   aliases: string[];
 }
 
-/** 한국 주요 도시 경도 데이터 */
+const KST = 'Asia/Seoul';
+
+/** 한국 주요 도시 경도·위도 데이터 */
 const LOCATIONS: LocationDef[] = [
-  { key: 'seoul', display: 'Seoul/서울', longitude: 126.978, aliases: ['seoul', '서울'] },
-  { key: 'busan', display: 'Busan/부산', longitude: 129.0756, aliases: ['busan', '부산'] },
-  { key: 'daegu', display: 'Daegu/대구', longitude: 128.6014, aliases: ['daegu', '대구'] },
-  { key: 'incheon', display: 'Incheon/인천', longitude: 126.7052, aliases: ['incheon', '인천'] },
-  { key: 'gwangju', display: 'Gwangju/광주', longitude: 126.8514, aliases: ['gwangju', '광주'] },
-  { key: 'daejeon', display: 'Daejeon/대전', longitude: 127.3845, aliases: ['daejeon', '대전'] },
-  { key: 'ulsan', display: 'Ulsan/울산', longitude: 129.3114, aliases: ['ulsan', '울산'] },
-  { key: 'sejong', display: 'Sejong/세종', longitude: 127.289, aliases: ['sejong', '세종'] },
-  { key: 'suwon', display: 'Suwon/수원', longitude: 127.0078, aliases: ['suwon', '수원'] },
-  { key: 'changwon', display: 'Changwon/창원', longitude: 128.6811, aliases: ['changwon', '창원'] },
-  { key: 'cheongju', display: 'Cheongju/청주', longitude: 127.489, aliases: ['cheongju', '청주'] },
-  { key: 'jeonju', display: 'Jeonju/전주', longitude: 127.148, aliases: ['jeonju', '전주'] },
-  { key: 'jeju', display: 'Jeju/제주', longitude: 126.5312, aliases: ['jeju', '제주'] },
-  { key: 'gangneung', display: 'Gangneung/강릉', longitude: 128.8761, aliases: ['gangneung', '강릉'] },
-  { key: 'pohang', display: 'Pohang/포항', longitude: 129.365, aliases: ['pohang', '포항'] },
+  { key: 'seoul', display: 'Seoul/서울', longitude: 126.978, latitude: 37.5665, tz: KST, aliases: ['seoul', '서울'] },
+  { key: 'busan', display: 'Busan/부산', longitude: 129.0756, latitude: 35.1796, tz: KST, aliases: ['busan', '부산'] },
+  { key: 'daegu', display: 'Daegu/대구', longitude: 128.6014, latitude: 35.8714, tz: KST, aliases: ['daegu', '대구'] },
+  { key: 'incheon', display: 'Incheon/인천', longitude: 126.7052, latitude: 37.4563, tz: KST, aliases: ['incheon', '인천'] },
+  { key: 'gwangju', display: 'Gwangju/광주', longitude: 126.8514, latitude: 35.1595, tz: KST, aliases: ['gwangju', '광주'] },
+  { key: 'daejeon', display: 'Daejeon/대전', longitude: 127.3845, latitude: 36.3504, tz: KST, aliases: ['daejeon', '대전'] },
+  { key: 'ulsan', display: 'Ulsan/울산', longitude: 129.3114, latitude: 35.5384, tz: KST, aliases: ['ulsan', '울산'] },
+  { key: 'sejong', display: 'Sejong/세종', longitude: 127.289, latitude: 36.48, tz: KST, aliases: ['sejong', '세종'] },
+  { key: 'suwon', display: 'Suwon/수원', longitude: 127.0078, latitude: 37.2636, tz: KST, aliases: ['suwon', '수원'] },
+  { key: 'changwon', display: 'Changwon/창원', longitude: 128.6811, latitude: 35.2281, tz: KST, aliases: ['changwon', '창원'] },
+  { key: 'cheongju', display: 'Cheongju/청주', longitude: 127.489, latitude: 36.6424, tz: KST, aliases: ['cheongju', '청주'] },
+  { key: 'jeonju', display: 'Jeonju/전주', longitude: 127.148, latitude: 35.8242, tz: KST, aliases: ['jeonju', '전주'] },
+  { key: 'jeju', display: 'Jeju/제주', longitude: 126.5312, latitude: 33.4996, tz: KST, aliases: ['jeju', '제주'] },
+  { key: 'gangneung', display: 'Gangneung/강릉', longitude: 128.8761, latitude: 37.7519, tz: KST, aliases: ['gangneung', '강릉'] },
+  { key: 'pohang', display: 'Pohang/포항', longitude: 129.365, latitude: 36.019, tz: KST, aliases: ['pohang', '포항'] },
 ];
 
 /** 지역 검색 결과 */
@@ -44,6 +48,10 @@ export interface LocationInfo {
   key: string;
   display: string;
   longitude: number;
+  latitude: number;
+  /** IANA 시간대 */
+  tz: string;
+  aliases: string[];
 }
 
 /**
@@ -97,9 +105,23 @@ export function locationList(): LocationInfo[] {
     key: loc.key,
     display: loc.display,
     longitude: loc.longitude,
+    latitude: loc.latitude,
+    tz: loc.tz,
+    aliases: [...loc.aliases],
   }));
 }
 
+/**
+ * 검색어를 포함하는 지역 목록을 반환한다.
+ * key, display, aliases 중 하나라도 검색어를 포함하면 고른다 (대소문자·공백 무시).
+ * @param query 검색어 (빈 문자열이면 전체)
+ */
+export function searchLocations(query: string): LocationInfo[] {
+  const norm = normalizeLocation(query);
+  return locationList().filter((loc) =>
+    [loc.key, loc.display, ...loc.aliases].some((name) => normalizeLocation(name).includes(norm)));
+}
+
 /**
  * 평태양시(LMT) 보정값을 계산한다.
  *
@@ -6,19 +6,76 @@
  * - 월운(月運): 매월의 운세. 절기(節氣) 기준으로 월주를 산출.
  */
 
-import { computeSolarTerms } from './astro.js';
-import { monthBranchFromTermKey, monthStemFromYear, yearPillar } from './bazi.js';
-import type { Direction, Gender, Pillar, SolarTerm } from './types.js';
-import { remEuclid } from './utils.js';
+import { computeSolarTerms, monthBoundaryJd, nextTerm, prevTerm, termJd } from './astro.js';
+import { branchElement, monthStemFromYear, stemElement, yearPillar } from './bazi.js';
+import type { Direction, Element, Gender, Pillar, SolarTerm, YongshinResult } from './types.js';
+import { registerCache, timed } from './timings.js';
+import { LruCache, remEuclid } from './utils.js';
 
 /** 대운 항목: 시작 시기(개월)와 해당 기둥 */
 export interface DaewonItem {
-  /** 대운 시작 시기 (출생 후 개월 수) */
+  /** 대운 시작 시기 (출생 후 개월 수, 정수로 반올림) */
   startMonths: number;
+  /** 반올림하지 않은 대운 시작 시기 (개월, 없으면 startMonths) */
+  exactStartMonths?: number;
   /** 대운 기둥 (천간·지지) */
   pillar: Pillar;
 }
 
+/** 한 방향으로 전개한 대운 (성별 미상 시 순행·역행 후보로 사용) */
+export interface DaewonSequence {
+  /** 진행 방향 */
+  direction: Direction;
+  /** 첫 대운 시작 시기 (개월) */
+  startMonths: number;
+  /** 첫 대운 시작 시기 (년·개월·일·시간) */
+  startSpan: DaewonStartSpan;
+  /** 대운 목록 */
+  items: DaewonItem[];
+}
+
+/**
+ * 대운 시작 개월 수의 반올림 규칙.
+ * - Round: 개월 단위 반올림
+ * - Floor: 개월 단위 버림
+ * - Ceil: 개월 단위 올림
+ * - Traditional: 3일=1년 나눗셈의 나머지 1일은 버리고 2일은 올려 년 단위로 맞춤
+ */
+export type DaewonRounding = 'Round' | 'Floor' | 'Ceil' | 'Traditional';
+
+/**
+ * 대운 방향 결정 방식.
+ * - Auto: 성별과 연간의 음양으로 정한다 (양남음녀 순행, 음남양녀 역행)
+ * - Forward / Backward: 성별·연간과 무관하게 고정한다 (학파 차이 재현·명식 검증용, 성별 미상이어도 적용)
+ */
+export type DaewonDirectionMode = 'Auto' | 'Forward' | 'Backward';
+
+/**
+ * Auto 방향 판정에 쓰는 연간의 연 경계.
+ * - Lichun: 입춘 기준 (연주의 천간과 같다)
+ * - LunarNewYear: 음력 설 기준 (설과 입춘 사이 출생에서만 Lichun과 갈린다)
+ */
+export type DaewonYearBasis = 'Lichun' | 'LunarNewYear';
+
+/**
+ * 대운 시작 시기를 년·개월·일·시간으로 나눈 값.
+ * 출생~절입 간격을 3일=1년, 1일=4개월, 1시진(2시간)=10일로 환산한다.
+ */
+export interface DaewonStartSpan {
+  years: number;
+  months: number;
+  days: number;
+  hours: number;
+  /** 반올림하지 않은 전체 개월 수 */
+  exactMonths: number;
+}
+
+/** 대운 나이 범위 (만 나이, 양 끝 포함) */
+export interface DaewonAgeRange {
+  fromAge: number;
+  toAge: number;
+}
+
 /** 세운(연운) 항목: 특정 연도의 입춘~입춘 구간 */
 export interface YearLuck {
   year: number;
@@ -30,6 +87,12 @@ export interface YearLuck {
   pillar: Pillar;
 }
 
+/** 소속 대운별로 묶은 세운 (첫 대운 이전 해는 daewon이 null) */
+export interface YearLuckGroup {
+  daewon: DaewonItem | null;
+  years: YearLuck[];
+}
+
 /** 월운 항목: 절기 기준 한 달 구간 */
 export interface MonthLuck {
   /** 해당 절기 시점 (JD) */
@@ -54,20 +117,11 @@ export interface MonthlyLuck {
 // ── 절기 캐시 ──
 
 const MAX_TERMS_CACHE_SIZE = 50
-const termsCache = new Map<number, SolarTerm[]>()
+const termsCache = registerCache('terms', new LruCache<number, SolarTerm[]>(MAX_TERMS_CACHE_SIZE))
 
-/** 절기 계산 결과를 캐싱하여 반복 호출 시 재계산을 방지한다 */
+/** 절기 계산 결과를 캐싱하여 반복 호출 시 재계산을 방지한다 (최근 사용 순으로 유지) */
 export function getCachedTerms(year: number): SolarTerm[] {
-  let t = termsCache.get(year)
-  if (!t) {
-    if (termsCache.size >= MAX_TERMS_CACHE_SIZE) {
-      const oldest = termsCache.keys().next().value
-      if (oldest !== undefined) termsCache.delete(oldest)
-    }
-    t = computeSolarTerms(year)
-    termsCache.set(year, t)
-  }
-  return t
+  return termsCache.getOrCompute(year, () => timed('Terms', () => computeSolarTerms(year)))
 }
 
 /**
@@ -88,53 +142,84 @@ export function daewonDirection(gender: Gender, yearStem: number): Direction {
   return 'Backward';
 }
 
+/**
+ * 출생 시점부터 대운 기준 절기(節)까지의 일수를 구한다.
+ * 월주가 바뀌는 12절만 세고 중기(中氣)는 건너뛴다.
+ * - 순행: 출생 이후 첫 절까지의 일수
+ * - 역행: 출생 이전 마지막 절까지의 일수
+ *
+ * @param birthJd 출생 시점 (JD)
+ * @param direction 대운 진행 방향
+ * @returns 절기까지의 일수 (소수 포함)
+ */
+export function daewonStartDays(birthJd: number, direction: Direction): number {
+  const target = direction === 'Forward' ? nextTerm(birthJd, true) : prevTerm(birthJd, true);
+  return Math.abs(target.jd - birthJd);
+}
+
 /**
  * 대운 시작 시기(개월)를 계산한다.
  *
  * 출생일(birthJd)에서 가장 가까운 절기까지의 일수를 구한 뒤,
- * "3일 = 1년" 비율로 환산한다.
- * - 순행: 출생 이후 첫 절기까지의 일수
- * - 역행: 출생 이전 마지막 절기까지의 일수
+ * "3일 = 1년" 비율로 환산하여 정수 개월로 맞춘다.
  *
  * @param birthJd 출생 시점 (JD)
- * @param termsPrev 전년도 절기 배열
- * @param termsCurr 당해 절기 배열
- * @param termsNext 다음해 절기 배열
  * @param direction 대운 진행 방향
- * @returns 대운 시작까지의 개월 수, 또는 null
+ * @param rounding 개월 수 반올림 규칙 (기본: 반올림)
+ * @returns 대운 시작까지의 개월 수
  */
 export function daewonStartMonths(
   birthJd: number,
-  termsPrev: SolarTerm[],
-  termsCurr: SolarTerm[],
-  termsNext: SolarTerm[],
   direction: Direction,
-): number | null {
-  const allTerms = [...termsPrev, ...termsCurr, ...termsNext];
-
-  let target: SolarTerm | undefined;
-  if (direction === 'Forward') {
-    // 순행: 출생 이후 가장 가까운 절기 (선형 탐색)
-    for (const t of allTerms) {
-      if (t.jd > birthJd && (!target || t.jd < target.jd)) {
-        target = t;
-      }
-    }
-  } else {
-    // 역행: 출생 이전 가장 가까운 절기 (선형 탐색)
-    for (const t of allTerms) {
-      if (t.jd < birthJd && (!target || t.jd > target.jd)) {
-        target = t;
-      }
-    }
+  rounding: DaewonRounding = 'Round',
+): number {
+  return roundDaewonMonths(daewonStartSpan(daewonStartDays(birthJd, direction)).exactMonths, rounding);
+}
+
+/**
+ * 반올림하지 않은 대운 시작 개월 수를 규칙에 따라 정수 개월로 맞춘다.
+ * @param exactMonths 반올림하지 않은 개월 수
+ * @param rounding 반올림 규칙
+ */
+export function roundDaewonMonths(exactMonths: number, rounding: DaewonRounding): number {
+  switch (rounding) {
+    case 'Round': return Math.round(exactMonths);
+    case 'Floor': return Math.floor(exactMonths);
+    case 'Ceil': return Math.ceil(exactMonths);
+    // 1년(12개월) = 3일이므로 년 단위 반올림이 '1일 버림, 2일 올림'과 같다
+    case 'Traditional': return Math.round(exactMonths / 12) * 12;
   }
+}
+
+/** 대운 환산에서 한 달을 30일로 본다 */
+const SPAN_MONTH_DAYS = 30;
+/** 실제 1일에 해당하는 대운 환산 일수 (1일 = 4개월) */
+const SPAN_DAYS_PER_DAY = 4 * SPAN_MONTH_DAYS;
 
-  if (!target) return null;
+/**
+ * 절입까지의 일수를 대운 시작 시기(년·개월·일·시간)로 환산한다.
+ *
+ * 3일 = 1년, 1일 = 4개월, 1시진(2시간) = 10일이므로
+ * 실제 1일은 대운 환산 120일에 해당한다. 년·개월·일·시간은 환산 1시간 단위로 반올림한다.
+ *
+ * @param diffDays 출생~절입 일수 (daewonStartDays 결과)
+ */
+export function daewonStartSpan(diffDays: number): DaewonStartSpan {
+  const totalHours = Math.round(diffDays * SPAN_DAYS_PER_DAY * 24);
+  const totalDays = Math.floor(totalHours / 24);
+  const totalMonths = Math.floor(totalDays / SPAN_MONTH_DAYS);
+  return {
+    years: Math.floor(totalMonths / 12),
+    months: totalMonths % 12,
+    days: totalDays % SPAN_MONTH_DAYS,
+    hours: totalHours % 24,
+    exactMonths: diffDays * 4,
+  };
+}
 
-  // 3일 = 1년(12개월) 비율 환산
-  const diffDays = Math.abs(target.jd - birthJd);
-  const months = Math.round((diffDays / 3.0) * 12.0);
-  return months;
+/** 대운 항목의 반올림하지 않은 시작 시기 (개월) */
+export function exactStartMonths(item: DaewonItem): number {
+  return item.exactStartMonths ?? item.startMonths;
 }
 
 /**
@@ -174,19 +259,74 @@ export function buildDaewonPillars(
  *
  * @param startMonths 첫 대운 시작 시기 (개월)
  * @param pillars 대운 기둥 배열
+ * @param exactMonths 반올림하지 않은 첫 대운 시작 시기 (기본: startMonths)
  * @returns DaewonItem 배열
  */
-export function buildDaewonItems(startMonths: number, pillars: Pillar[]): DaewonItem[] {
+export function buildDaewonItems(startMonths: number, pillars: Pillar[], exactMonths: number = startMonths): DaewonItem[] {
   return pillars.map((pillar, idx) => ({
     startMonths: startMonths + idx * 120,
+    exactStartMonths: exactMonths + idx * 120,
     pillar,
   }));
 }
 
+/**
+ * 대운을 개수 제한 없이 순서대로 생성한다 (지연 평가).
+ * 소비 측에서 필요한 만큼만 꺼내 쓴다.
+ *
+ * @param startMonths 첫 대운 시작 시기 (개월)
+ * @param monthPillar 월주 (대운의 시작점)
+ * @param direction 진행 방향
+ * @param exactMonths 반올림하지 않은 첫 대운 시작 시기 (기본: startMonths)
+ */
+export function* iterateDaewon(
+  startMonths: number,
+  monthPillar: Pillar,
+  direction: Direction,
+  exactMonths: number = startMonths,
+): Generator<DaewonItem> {
+  const step = direction === 'Forward' ? 1 : -1;
+  let stem = monthPillar.stem;
+  let branch = monthPillar.branch;
+  for (let idx = 0; ; idx++) {
+    stem = remEuclid(stem + step, 10);
+    branch = remEuclid(branch + step, 12);
+    yield { startMonths: startMonths + idx * 120, exactStartMonths: exactMonths + idx * 120, pillar: { stem, branch } };
+  }
+}
+
+/**
+ * 나이 범위와 겹치는 대운만 골라낸다.
+ * 각 대운은 시작 시기부터 10년(120개월) 동안 유효하다고 본다.
+ *
+ * @param startMonths 첫 대운 시작 시기 (개월)
+ * @param monthPillar 월주
+ * @param direction 진행 방향
+ * @param range 만 나이 범위 (양 끝 포함)
+ * @param exactMonths 반올림하지 않은 첫 대운 시작 시기 (기본: startMonths)
+ */
+export function daewonItemsForAges(
+  startMonths: number,
+  monthPillar: Pillar,
+  direction: Direction,
+  range: DaewonAgeRange,
+  exactMonths: number = startMonths,
+): DaewonItem[] {
+  const fromMonths = range.fromAge * 12;
+  const toMonths = (range.toAge + 1) * 12;
+  const items: DaewonItem[] = [];
+  for (const item of iterateDaewon(startMonths, monthPillar, direction, exactMonths)) {
+    if (item.startMonths >= toMonths) break;
+    if (item.startMonths + 120 > fromMonths) items.push(item);
+  }
+  return items;
+}
+
 /**
  * 세운(연운) 배열을 생성한다.
  *
  * 입춘(立春) 기준으로 연주를 산출하고, 각 연도의 입춘~다음 입춘 구간을 설정한다.
+ * 입춘만 필요하므로 연도별 24절기 전체 대신 termJd로 입춘만 구한다.
  *
  * @param startYear 시작 연도
  * @param count 연도 수
@@ -196,67 +336,94 @@ export function yearlyLuck(startYear: number, count: number): YearLuck[] {
   const results: YearLuck[] = [];
   for (let idx = 0; idx < count; idx++) {
     const year = startYear + idx;
-    const termsCurr = getCachedTerms(year);
-    const termsNext = getCachedTerms(year + 1);
-    const lichunCurr = termsCurr.find((t) => t.def.key === 'lichun');
-    if (!lichunCurr) throw new Error('failed to find lichun for yearly luck');
-    const lichunNext = termsNext.find((t) => t.def.key === 'lichun');
-    if (!lichunNext) throw new Error('failed to find next lichun for yearly luck');
     const [yStem, yBranch] = yearPillar(year);
     results.push({
       year,
-      startJd: lichunCurr.jd,
-      endJd: lichunNext.jd,
+      startJd: termJd(year, 'lichun'),
+      endJd: termJd(year + 1, 'lichun'),
       pillar: { stem: yStem, branch: yBranch },
     });
   }
   return results;
 }
 
+/** 평균 한 달 일수 (회귀년 / 12) */
+const MEAN_MONTH_DAYS = 365.2425 / 12;
+
+/**
+ * 대운 시작 시기(출생 후 개월)를 시점(JD)으로 환산한다.
+ * 한 달은 평균 길이(회귀년/12)로 본다.
+ */
+export function daewonStartJd(birthJd: number, startMonths: number): number {
+  return birthJd + startMonths * MEAN_MONTH_DAYS;
+}
+
+/**
+ * 주어진 시점(JD)에 진행 중인 대운을 찾는다.
+ * @returns 해당 대운 (첫 대운 이전이거나 목록 범위를 벗어나면 null)
+ */
+export function daewonAt(items: readonly DaewonItem[], birthJd: number, jd: number): DaewonItem | null {
+  return items.find((item) =>
+    daewonStartJd(birthJd, exactStartMonths(item)) <= jd && jd < daewonStartJd(birthJd, exactStartMonths(item) + 120),
+  ) ?? null;
+}
+
+/**
+ * 세운을 소속 대운별로 묶는다.
+ *
+ * 각 세운은 입춘~입춘 구간의 중간 시점에 유효한 대운에 속한다
+ * (한 해의 대부분을 지배하는 대운). 첫 대운 이전의 해는 daewon=null 그룹이 된다.
+ *
+ * @param years 세운 배열 (연도 오름차순)
+ * @param items 대운 배열 (시작 시기 오름차순)
+ * @param birthJd 출생 시점 (JD)
+ */
+export function groupYearlyByDaewon(
+  years: YearLuck[],
+  items: DaewonItem[],
+  birthJd: number,
+): YearLuckGroup[] {
+  const groups: YearLuckGroup[] = [];
+  for (const y of years) {
+    const midJd = (y.startJd + y.endJd) / 2;
+    let daewon: DaewonItem | null = null;
+    for (const item of items) {
+      if (daewonStartJd(birthJd, exactStartMonths(item)) <= midJd) daewon = item;
+      else break;
+    }
+    const last = groups[groups.length - 1];
+    if (last && last.daewon === daewon) {
+      last.years.push(y);
+    } else {
+      groups.push({ daewon, years: [y] });
+    }
+  }
+  return groups;
+}
+
 /**
  * 특정 연도의 월운 데이터를 생성한다.
  *
- * 절입일(節入日) 기준으로 12개월을 구성한다. 각 월은
- * 해당 절기 시작부터 다음 절기 시작까지의 구간이다.
+ * 절입일(節入日) 기준으로 12개월을 구성한다. 입춘부터 다음 해 입춘까지의
+ * 절(節) 13개를 monthBoundaryJd로 차례로 구해 이어 붙이며, 월지는 인(寅)월부터 센다.
  *
  * @param year 대상 연도
  * @returns MonthlyLuck (연주 + 12개월 월운)
  */
 export function monthlyLuck(year: number): MonthlyLuck {
-  const termsCurr = getCachedTerms(year);
-  const termsNext = getCachedTerms(year + 1);
-  const lichunCurr = termsCurr.find((t) => t.def.key === 'lichun');
-  if (!lichunCurr) throw new Error('failed to find lichun term for monthly luck');
-  const lichunNext = termsNext.find((t) => t.def.key === 'lichun');
-  if (!lichunNext) throw new Error('failed to find next lichun term for monthly luck');
-
-  // 입춘~다음 입춘 사이의 절기 경계 추출 (절기만, 중기 제외)
-  let boundaries = [...termsCurr, ...termsNext]
-    .filter((t) => monthBranchFromTermKey(t.def.key) !== null)
-    .sort((a, b) => a.jd - b.jd)
-    .filter((t) => t.jd >= lichunCurr.jd && t.jd <= lichunNext.jd);
-
-  if (boundaries.length === 0) throw new Error('failed to build monthly boundaries');
-  if (boundaries[0].def.key !== 'lichun') {
-    const idx = boundaries.findIndex((t) => t.def.key === 'lichun');
-    if (idx >= 0) boundaries = boundaries.slice(idx);
-  }
-  if (boundaries.length < 13) throw new Error('monthly boundary count insufficient');
-
   const [yStem, yBranch] = yearPillar(year);
-  const months: MonthLuck[] = [];
+  const months = new Array<MonthLuck>(12);
+  let startJd = monthBoundaryJd(year, 0);
   for (let idx = 0; idx < 12; idx++) {
-    const start = boundaries[idx];
-    const end = boundaries[idx + 1];
-    const branch = monthBranchFromTermKey(start.def.key);
-    if (branch === null) throw new Error('invalid month boundary for monthly luck');
-    const stem = monthStemFromYear(yStem, branch);
-    months.push({
-      startJd: start.jd,
-      endJd: end.jd,
-      pillar: { stem, branch },
+    const endJd = monthBoundaryJd(year, idx + 1);
+    const branch = (idx + 2) % 12;
+    months[idx] = {
+      startJd,
+      endJd,
+      pillar: { stem: monthStemFromYear(yStem, branch), branch },
       branch,
-    });
+    };
+    startJd = endJd;
   }
 
   return {
@@ -265,3 +432,43 @@ export function monthlyLuck(year: number): MonthlyLuck {
     months,
   };
 }
+
+/**
+ * 연속한 여러 해의 월운을 생성한다.
+ *
+ * 한 해의 마지막 달은 다음 해 입춘에서 끝나므로 연도끼리 빈틈없이 이어진다.
+ *
+ * @param startYear 시작 연도
+ * @param count 연도 수
+ * @returns 연도 순 MonthlyLuck 배열
+ */
+export function monthlyLuckRange(startYear: number, count: number): MonthlyLuck[] {
+  const results: MonthlyLuck[] = [];
+  for (let idx = 0; idx < count; idx++) {
+    results.push(monthlyLuck(startYear + idx));
+  }
+  return results;
+}
+
+// ── 운 길흉 점수 ──
+
+/**
+ * 오행의 용신 기준 점수.
+ * 용신 +2, 희신 +1, 기신 -2, 구신 -1, 그 외(한신) 0.
+ */
+export function elementFavorability(element: Element, yongshin: YongshinResult): number {
+  if (element === yongshin.yongshin) return 2;
+  if (element === yongshin.heeshin) return 1;
+  if (element === yongshin.gishin) return -2;
+  if (element === yongshin.gushin) return -1;
+  return 0;
+}
+
+/**
+ * 운 기둥의 길흉 점수 (-4 ~ +4).
+ * 천간 오행과 지지 오행의 용신 기준 점수를 합산한다.
+ */
+export function pillarFavorability(pillar: Pillar, yongshin: YongshinResult): number {
+  return elementFavorability(stemElement(pillar.stem), yongshin)
+    + elementFavorability(branchElement(pillar.branch), yongshin);
+}
@@ -0,0 +1,255 @@
+/**
+ * @fileoverview 성명학(姓名學) 보조 모듈
+ *
+ * 한글(과 한자) 이름에서 다음을 구하고, 원국의 없는 오행·용신과 대조한다.
+ * - 수리(數理): 원형이정(元亨利貞) 4격의 획수와 81수 길흉
+ *   - 원격 = 이름 글자 합, 형격 = 성 + 이름 첫 글자,
+ *     이격 = 성 + 이름 끝 글자(외자 이름이면 성만), 정격 = 전체 합
+ * - 수리오행: 격 획수의 끝자리 (1·2 목, 3·4 화, 5·6 토, 7·8 금, 9·0 수)
+ * - 발음오행: 각 음절 초성의 오행
+ *
+ * 한자가 있으면 원획법(부수 본래 획수: 氵=水 4, 艹=艸 6 등) 획수를 쓰고,
+ * 없으면 한글 자모의 필획 수를 쓴다. 내장 한자 획수표는 흔한 성씨와 이름자만 담으므로
+ * 표에 없는 한자는 strokes로 획수를 직접 준다.
+ */
+
+import { elementIndex } from './bazi.js';
+import type { ElementAdvice } from './balance.js';
+import type { Element, YongshinResult } from './types.js';
+
+/** 원형이정 4격 */
+export type NameGridKind = 'Won' | 'Hyeong' | 'I' | 'Jeong';
+
+/**
+ * 발음오행 배속 관례.
+ * - Modern: 현대 성명학 통용 (ㅇㅎ 土, ㅁㅂㅍ 水)
+ * - Haerye: 훈민정음 해례본 (ㅇㅎ 水, ㅁㅂㅍ 土)
+ */
+export type SoundConvention = 'Modern' | 'Haerye';
+
+/** 격 하나 */
+export interface NameGrid {
+  kind: NameGridKind;
+  /** 획수 합 */
+  strokes: number;
+  /** 81수 길흉 (81 초과는 80을 빼서 본다) */
+  lucky: boolean;
+  /** 수리오행 */
+  element: Element;
+}
+
+/** 음절 하나의 발음오행 */
+export interface NameSound {
+  syllable: string;
+  /** 초성 자모 */
+  initial: string;
+  element: Element;
+}
+
+/** 이름 분석 입력 */
+export interface NameInput {
+  /** 한글 이름 (예: '김민준') */
+  hangul: string;
+  /** 같은 길이의 한자 이름 (예: '金民俊', 없으면 한글 획수) */
+  hanja?: string | null;
+  /** 글자별 획수 (주면 내장 표 대신 쓴다) */
+  strokes?: readonly number[] | null;
+  /** 성의 글자 수 (기본: 복성 목록에 있으면 2, 아니면 1) */
+  surnameLength?: number | null;
+  /** 발음오행 배속 (기본 Modern) */
+  soundConvention?: SoundConvention;
+}
+
+/** 획수를 어디서 얻었는지 */
+export type StrokeBasis = 'Hanja' | 'Hangul' | 'Given';
+
+/** 이름 분석 결과 */
+export interface NameAnalysis {
+  hangul: string;
+  hanja: string | null;
+  surnameLength: number;
+  /** 글자별 획수 */
+  strokes: number[];
+  strokeBasis: StrokeBasis;
+  /** 원·형·이·정 순 */
+  grids: NameGrid[];
+  sounds: NameSound[];
+  /** 발음오행과 수리오행을 합친 오행별 개수 (목화토금수 순) */
+  elementCounts: number[];
+}
+
+/** 이름과 원국의 대조 결과 */
+export interface NameChartCheck {
+  /** 원국에 없는 오행 중 이름에 들어 있는 것 */
+  supplied: Element[];
+  /** 원국에 없고 이름에도 없는 것 */
+  unsupplied: Element[];
+  /** 이름에 용신 오행이 있는지 */
+  hasYongshin: boolean;
+  /** 이름에 기신 오행이 있는지 */
+  hasGishin: boolean;
+}
+
+const ELEMENTS: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+
+/** 81수 중 길수 */
+const LUCKY_NUMBERS: ReadonlySet<number> = new Set([
+  1, 3, 5, 6, 7, 8, 11, 13, 15, 16, 17, 18, 21, 23, 24, 25, 29, 31, 32, 33, 35, 37, 38, 39,
+  41, 45, 47, 48, 52, 57, 61, 63, 65, 67, 68, 81,
+]);
+
+/** 두 글자 성씨 */
+export const DOUBLE_SURNAMES: readonly string[] = ['남궁', '황보', '제갈', '선우', '독고', '사공', '서문', '동방', '망절'];
+
+/** 원획법 획수: 흔한 성씨와 이름자 */
+export const HANJA_STROKES: Readonly<Record<string, number>> = {
+  // 성씨
+  金: 8, 李: 7, 朴: 6, 崔: 11, 鄭: 19, 姜: 9, 趙: 14, 尹: 4, 張: 11, 林: 8,
+  韓: 17, 吳: 7, 徐: 10, 申: 5, 權: 22, 黃: 12, 安: 6, 宋: 7, 柳: 9, 洪: 10,
+  全: 6, 高: 10, 文: 4, 孫: 10, 許: 11, 南: 9, 白: 5, 田: 5, 丁: 2, 千: 3,
+  方: 4, 孔: 4, 玄: 5, 元: 4, 成: 7, 車: 7, 朱: 6, 任: 6, 河: 9, 沈: 8,
+  梁: 11, 劉: 15, 郭: 15, 盧: 16, 陳: 16, 池: 7, 禹: 9, 辛: 7, 閔: 12, 楊: 13,
+  康: 11, 具: 8, 咸: 9,
+  // 이름자
+  民: 5, 俊: 9, 賢: 15, 秀: 7, 英: 11, 美: 9, 智: 12, 恩: 10, 浩: 11, 洙: 10,
+  永: 5, 正: 5, 仁: 4, 善: 12, 眞: 10, 珍: 10, 貞: 9, 熙: 13, 炫: 9, 宇: 6,
+  在: 6, 允: 4, 東: 8, 志: 7, 勇: 9, 大: 3, 一: 1, 海: 11, 國: 11, 夏: 10,
+  哲: 10, 昊: 8, 植: 12, 鎬: 18, 錫: 16, 鉉: 13, 基: 11, 載: 13, 晶: 12, 妍: 7,
+  娟: 10, 惠: 12, 慧: 15, 潤: 16, 然: 12,
+};
+
+const INITIALS = ['ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ'];
+const INITIAL_STROKES = [1, 2, 1, 2, 4, 3, 3, 4, 8, 2, 4, 1, 2, 4, 3, 2, 3, 4, 3];
+/** ㅏㅐㅑㅒㅓㅔㅕㅖㅗㅘㅙㅚㅛㅜㅝㅞㅟㅠㅡㅢㅣ */
+const MEDIAL_STROKES = [2, 3, 3, 4, 2, 3, 3, 4, 2, 4, 5, 3, 3, 2, 4, 5, 3, 3, 1, 2, 1];
+/** (없음)ㄱㄲㄳㄴㄵㄶㄷㄹㄺㄻㄼㄽㄾㄿㅀㅁㅂㅄㅅㅆㅇㅈㅊㅋㅌㅍㅎ */
+const FINAL_STROKES = [0, 1, 2, 3, 1, 3, 4, 2, 3, 4, 6, 7, 5, 6, 7, 6, 3, 4, 6, 2, 4, 1, 2, 3, 2, 3, 4, 3];
+
+/** 초성별 발음오행 (현대 통용) */
+const MODERN_SOUND: Readonly<Record<string, Element>> = {
+  ㄱ: 'Wood', ㄲ: 'Wood', ㅋ: 'Wood',
+  ㄴ: 'Fire', ㄷ: 'Fire', ㄸ: 'Fire', ㄹ: 'Fire', ㅌ: 'Fire',
+  ㅇ: 'Earth', ㅎ: 'Earth',
+  ㅅ: 'Metal', ㅆ: 'Metal', ㅈ: 'Metal', ㅉ: 'Metal', ㅊ: 'Metal',
+  ㅁ: 'Water', ㅂ: 'Water', ㅃ: 'Water', ㅍ: 'Water',
+};
+
+const HANGUL_BASE = 0xac00;
+const HANGUL_LAST = 0xd7a3;
+
+/** 한글 음절을 초성·중성·종성 인덱스로 나눈다 (음절이 아니면 null) */
+function decompose(syllable: string): [initial: number, medial: number, final: number] | null {
+  const code = syllable.codePointAt(0) ?? 0;
+  if (code < HANGUL_BASE || code > HANGUL_LAST) return null;
+  const offset = code - HANGUL_BASE;
+  return [Math.floor(offset / 588), Math.floor((offset % 588) / 28), offset % 28];
+}
+
+/**
+ * 한글 음절의 필획 수 (초성 + 중성 + 종성).
+ * @throws 한글 음절이 아니면 RangeError
+ */
+export function hangulStrokes(syllable: string): number {
+  const parts = decompose(syllable);
+  if (!parts) throw new RangeError(`not a Hangul syllable: ${syllable}`);
+  const [i, m, f] = parts;
+  return INITIAL_STROKES[i] + MEDIAL_STROKES[m] + FINAL_STROKES[f];
+}
+
+/**
+ * 음절 초성의 발음오행.
+ * @throws 한글 음절이 아니면 RangeError
+ */
+export function soundElement(syllable: string, convention: SoundConvention = 'Modern'): Element {
+  const parts = decompose(syllable);
+  if (!parts) throw new RangeError(`not a Hangul syllable: ${syllable}`);
+  const element = MODERN_SOUND[INITIALS[parts[0]]];
+  if (convention === 'Haerye' && element === 'Earth') return 'Water';
+  if (convention === 'Haerye' && element === 'Water') return 'Earth';
+  return element;
+}
+
+/** 획수 끝자리의 수리오행 (1·2 목, 3·4 화, 5·6 토, 7·8 금, 9·0 수) */
+export function strokeElement(strokes: number): Element {
+  return ELEMENTS[Math.floor((((strokes % 10) + 9) % 10) / 2)];
+}
+
+/** 81수 길흉 (81을 넘으면 80을 빼서 본다) */
+export function isLuckyNumber(strokes: number): boolean {
+  return LUCKY_NUMBERS.has(((strokes - 1) % 80) + 1);
+}
+
+function grid(kind: NameGridKind, strokes: number): NameGrid {
+  return { kind, strokes, lucky: isLuckyNumber(strokes), element: strokeElement(strokes) };
+}
+
+/**
+ * 이름의 수리 4격·수리오행·발음오행을 구한다.
+ * @throws 한글이 아닌 글자, 한자·획수 길이 불일치, 획수표에 없는 한자, 잘못된 성 길이
+ */
+export function analyzeName(input: NameInput): NameAnalysis {
+  const syllables = [...input.hangul.trim()];
+  const hanja = input.hanja ? [...input.hanja.trim()] : null;
+  if (syllables.length < 2) throw new RangeError('name must have a surname and a given name');
+  if (hanja && hanja.length !== syllables.length) throw new RangeError('hanja name must have as many characters as the Hangul name');
+  if (input.strokes && input.strokes.length !== syllables.length) throw new RangeError('strokes must be given for every character');
+
+  const surnameLength = input.surnameLength
+    ?? (syllables.length > 2 && DOUBLE_SURNAMES.includes(syllables.slice(0, 2).join('')) ? 2 : 1);
+  if (surnameLength < 1 || surnameLength >= syllables.length) throw new RangeError(`surname length must be between 1 and ${syllables.length - 1}`);
+
+  let strokeBasis: StrokeBasis;
+  let strokes: number[];
+  if (input.strokes) {
+    strokeBasis = 'Given';
+    strokes = [...input.strokes];
+  } else if (hanja) {
+    strokeBasis = 'Hanja';
+    strokes = hanja.map((ch) => {
+      const n = HANJA_STROKES[ch];
+      if (n === undefined) throw new RangeError(`no stroke count for ${ch}; give the strokes explicitly`);
+      return n;
+    });
+  } else {
+    strokeBasis = 'Hangul';
+    strokes = syllables.map(hangulStrokes);
+  }
+
+  const surname = strokes.slice(0, surnameLength).reduce((a, b) => a + b, 0);
+  const given = strokes.slice(surnameLength);
+  const givenTotal = given.reduce((a, b) => a + b, 0);
+  const grids = [
+    grid('Won', givenTotal),
+    grid('Hyeong', surname + given[0]),
+    grid('I', given.length > 1 ? surname + given[given.length - 1] : surname),
+    grid('Jeong', surname + givenTotal),
+  ];
+  const convention = input.soundConvention ?? 'Modern';
+  const sounds = syllables.map((syllable) => ({
+    syllable,
+    initial: INITIALS[decompose(syllable)![0]],
+    element: soundElement(syllable, convention),
+  }));
+
+  const elementCounts = [0, 0, 0, 0, 0];
+  for (const e of [...sounds.map((s) => s.element), ...grids.map((g) => g.element)]) elementCounts[elementIndex(e)]++;
+
+  return { hangul: syllables.join(''), hanja: hanja?.join('') ?? null, surnameLength, strokes, strokeBasis, grids, sounds, elementCounts };
+}
+
+/**
+ * 이름의 오행을 원국의 없는 오행·용신·기신과 대조한다.
+ * @param name analyzeName 결과
+ * @param advice 원국 오행 과부족 (balance.elementAdvisory)
+ * @param yongshin 용신 판정
+ */
+export function checkNameAgainstChart(name: NameAnalysis, advice: readonly ElementAdvice[], yongshin: YongshinResult): NameChartCheck {
+  const has = (e: Element) => name.elementCounts[elementIndex(e)] > 0;
+  const lacking = advice.filter((a) => a.status === 'Lacking').map((a) => a.element);
+  return {
+    supplied: lacking.filter(has),
+    unsupplied: lacking.filter((e) => !has(e)),
+    hasYongshin: has(yongshin.yongshin),
+    hasGishin: has(yongshin.gishin),
+  };
+}
@@ -0,0 +1,80 @@
+/**
+ * @fileoverview 참고 데이터 덮어쓰기(JSON) 검증
+ *
+ * 일주 해설처럼 사용자가 JSON으로 내장 값을 일부 바꿀 수 있는 참고 데이터의 입력 검사 규칙을 둔다.
+ * - 최상위와 항목은 객체이고, 항목에는 스키마에 적은 필드만 올 수 있다.
+ * - 언어별 필드는 Ko/En 키만 가진 객체다.
+ * - 값은 스키마가 정한 모양(OverrideValue)이어야 한다.
+ * 항목에 없는 필드와 언어는 바꾸지 않는다.
+ */
+
+import type { Lang } from './i18n.js';
+
+/** 필드 값의 모양 */
+export type OverrideValue = 'string' | 'strings' | 'integers';
+
+/** 항목 필드 스키마 (localized: Ko/En별 값) */
+export type OverrideSchema = Readonly<Record<string, { value: OverrideValue; localized: boolean }>>;
+
+const LANGS: readonly Lang[] = ['Ko', 'En'];
+
+const VALUE_CHECKS: Record<OverrideValue, { ok: (value: unknown) => boolean; expected: string }> = {
+  string: { ok: (v) => typeof v === 'string', expected: 'a string' },
+  strings: { ok: (v) => Array.isArray(v) && v.every((s) => typeof s === 'string'), expected: 'a string array' },
+  integers: { ok: (v) => Array.isArray(v) && v.every((n) => Number.isInteger(n)), expected: 'an integer array' },
+};
+
+/**
+ * JSON 값이 배열이 아닌 객체인지 확인한다.
+ * @throws TypeError 객체가 아니면 message로
+ */
+export function expectObject(value: unknown, message: string): Record<string, unknown> {
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) throw new TypeError(message);
+  return value as Record<string, unknown>;
+}
+
+function checkValue(value: unknown, kind: OverrideValue, path: string, key: string): void {
+  const check = VALUE_CHECKS[kind];
+  if (!check.ok(value)) throw new TypeError(`${path} for ${key} must be ${check.expected}`);
+}
+
+/**
+ * Ko/En별 값 객체를 검사해 그대로 돌려준다.
+ * @param value 검사할 JSON 값
+ * @param kind 언어별 값의 모양
+ * @param field 오류 메시지의 필드 이름
+ * @param key 오류 메시지의 항목 키
+ * @throws TypeError 객체가 아니거나, 모르는 언어가 있거나, 값 모양이 다를 때
+ */
+export function langValues(value: unknown, kind: OverrideValue, field: string, key: string): Partial<Record<Lang, unknown>> {
+  const langs = expectObject(value, `${field} for ${key} must be an object of Ko/En values`);
+  for (const [lang, v] of Object.entries(langs)) {
+    if (!(LANGS as readonly string[]).includes(lang)) throw new TypeError(`unknown language ${lang} in ${field} for ${key}`);
+    checkValue(v, kind, `${field}.${lang}`, key);
+  }
+  return langs;
+}
+
+/**
+ * 항목 하나를 스키마로 검사해 target(내장 항목의 복사본)에 덮어쓴다.
+ * 언어별 필드는 준 언어만, 나머지 필드는 값 전체를 바꾼다.
+ * @param target 덮어쓸 항목 (언어별 필드는 복사된 객체여야 한다)
+ * @param raw 항목 JSON
+ * @param schema 필드 스키마
+ * @param key 오류 메시지의 항목 키 (예: '甲子')
+ * @throws TypeError 항목이 객체가 아니거나 스키마에 없는 필드·잘못된 값이 있을 때
+ */
+export function applyOverride(target: object, raw: unknown, schema: OverrideSchema, key: string): void {
+  const fields = expectObject(raw, `entry for ${key} must be an object`);
+  const slots = target as Record<string, unknown>;
+  for (const [field, value] of Object.entries(fields)) {
+    const spec = Object.hasOwn(schema, field) ? schema[field] : undefined;
+    if (!spec) throw new TypeError(`unknown field ${field} for ${key}`);
+    if (spec.localized) {
+      Object.assign(slots[field] as object, langValues(value, spec.value, field, key));
+    } else {
+      checkValue(value, spec.value, field, key);
+      slots[field] = value;
+    }
+  }
+}
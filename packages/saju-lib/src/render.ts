@@ -0,0 +1,242 @@
+/**
+ * @fileoverview SVG 렌더링 모듈
+ *
+ * 계산 결과를 외부 의존성 없는 SVG 문자열로 그린다.
+ * - 명식: 시·일·월·연 네 열에 천간(위)·지지(아래)와 십성·지장간, 오행별 색
+ * - 운 타임라인(Gantt): 대운 막대 + 세운 눈금, 용신 기준 길흉 점수로 색칠
+ */
+
+import { branchElement, branchPolarity, hiddenStems, stemElement, stemPolarity, tenGod, tenGodBranch } from './bazi.js';
+import { I18n, type Lang, type Localizer } from './i18n.js';
+import { daewonStartJd, exactStartMonths, pillarFavorability } from './luck.js';
+import { iljuKey } from './ilju.js';
+import type { PillarsResult, SajuResult } from './service.js';
+import type { Element, PillarKind, YongshinResult, Pillar } from './types.js';
+
+/** 타임라인 렌더링 옵션 */
+export interface TimelineSvgOptions {
+  /** 전체 폭 (px, 기본 960) */
+  width?: number;
+  /** 레이블 언어 (기본 Ko) */
+  lang?: Lang;
+  /** 레이블 제공자 (지정하면 lang 대신 쓴다) */
+  i18n?: Localizer;
+}
+
+/** 길흉 점수(-4~+4)별 채움 색: 흉(붉은색) → 평(회색) → 길(녹색) */
+const SCORE_COLORS = [
+  '#c0392b', '#d9534f', '#e8877f', '#f2b8b0',
+  '#d5d8dc',
+  '#b7e1c1', '#7fc99a', '#4caf72', '#2e8b57',
+];
+
+/** 이보다 좁은 구간은 간지 레이블을 생략한다 (px) */
+const MIN_LABEL_WIDTH = 24;
+
+/** 회귀년 일수 */
+const TROPICAL_YEAR_DAYS = 365.2425;
+
+const MARGIN = 16;
+const TITLE_HEIGHT = 28;
+const AXIS_HEIGHT = 20;
+const DAEWON_HEIGHT = 44;
+const YEAR_HEIGHT = 36;
+const ROW_GAP = 8;
+
+/** 길흉 점수의 채움 색 */
+export function scoreColor(score: number): string {
+  const idx = Math.max(0, Math.min(SCORE_COLORS.length - 1, Math.round(score) + 4));
+  return SCORE_COLORS[idx];
+}
+
+/** XML 텍스트/속성 이스케이프 */
+function escapeXml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+/** 소수 둘째 자리까지 반올림한 좌표 문자열 */
+function num(value: number): string {
+  return String(Math.round(value * 100) / 100);
+}
+
+/** 명식 렌더링 옵션 */
+export interface ChartSvgOptions {
+  /** 레이블 언어 (기본 Ko) */
+  lang?: Lang;
+  /** 레이블 제공자 (지정하면 lang 대신 쓴다) */
+  i18n?: Localizer;
+}
+
+/** 명식에 그릴 네 기둥 (SajuResult·PillarsResult 모두 받는다) */
+export type ChartPillars = Pick<PillarsResult, 'yearPillar' | 'monthPillar' | 'dayPillar' | 'hourPillar'>;
+
+/** 명식의 열 순서 (왼쪽→오른쪽: 시·일·월·연, 인쇄된 명식과 같다) */
+const CHART_COLUMNS: readonly PillarKind[] = ['Hour', 'Day', 'Month', 'Year'];
+
+/** 오행별 글자·테두리 색과 칸 채움 색 (목 청, 화 적, 토 황, 금 백, 수 흑) */
+const ELEMENT_COLORS: Record<Element, { ink: string; fill: string }> = {
+  Wood: { ink: '#2e7d4f', fill: '#e3f2e8' },
+  Fire: { ink: '#c0392b', fill: '#fbe5e2' },
+  Earth: { ink: '#a9791c', fill: '#f8efd9' },
+  Metal: { ink: '#6c7a89', fill: '#eef1f4' },
+  Water: { ink: '#1f3a5f', fill: '#e1e8f2' },
+};
+
+const CHART_COLUMN_WIDTH = 120;
+const CHART_BOX = 76;
+const CHART_LINE = 16;
+
+/**
+ * 네 기둥 명식 SVG를 생성한다.
+ *
+ * 열마다 위에서부터 기둥 이름, 천간 십성(일간은 '일간'), 천간, 지지, 지지 십성(정기),
+ * 지장간과 그 십성을 놓는다. 간지 칸은 큰 한자와 오행·음양 캡션을 오행 색으로 그린다.
+ *
+ * @param pillars 네 기둥 (계산 결과를 그대로 넘길 수 있다)
+ * @param options 렌더링 옵션
+ * @returns SVG 문서 문자열
+ */
+export function renderChartSvg(pillars: ChartPillars, options: ChartSvgOptions = {}): string {
+  const i18n = options.i18n ?? new I18n(options.lang ?? 'Ko');
+  const byKind: Record<PillarKind, Pillar> = {
+    Year: pillars.yearPillar, Month: pillars.monthPillar, Day: pillars.dayPillar, Hour: pillars.hourPillar,
+  };
+  const dayStem = pillars.dayPillar.stem;
+  const top = MARGIN + TITLE_HEIGHT;
+  const stemTop = top + CHART_LINE * 2 + 4;
+  const branchTop = stemTop + CHART_BOX + 6;
+  const hiddenTop = branchTop + CHART_BOX + CHART_LINE + 4;
+
+  /** 간지 한 칸: 큰 한자, 오행·음양 캡션, 마우스를 올리면 보이는 이름 */
+  const box = (cx: number, y: number, hanja: string, name: string, element: Element, yang: boolean, day: boolean): string => {
+    const color = ELEMENT_COLORS[element];
+    return `<g><title>${escapeXml(name)}</title>`
+      + `<rect x="${num(cx - CHART_BOX / 2)}" y="${y}" width="${CHART_BOX}" height="${CHART_BOX}" rx="6" fill="${color.fill}" stroke="${color.ink}" stroke-width="${day ? 3 : 1}"/>`
+      + `<text x="${num(cx)}" y="${y + 44}" text-anchor="middle" class="glyph" style="fill:${color.ink}">${escapeXml(hanja)}</text>`
+      + `<text x="${num(cx)}" y="${y + CHART_BOX - 10}" text-anchor="middle" class="caption">`
+      + `${escapeXml(`${i18n.elementShortLabel(element)} ${i18n.polarityLabel(yang)}`)}</text></g>`;
+  };
+
+  const parts: string[] = [];
+  let maxHidden = 0;
+  CHART_COLUMNS.forEach((kind, col) => {
+    const pillar = byKind[kind];
+    const [stemHanja, branchHanja] = iljuKey(pillar);
+    const cx = MARGIN + CHART_COLUMN_WIDTH * (col + 0.5);
+    const text = (y: number, cls: string, value: string) =>
+      `<text x="${num(cx)}" y="${y}" text-anchor="middle" class="${cls}">${escapeXml(value)}</text>`;
+    const hidden = hiddenStems(pillar.branch);
+    maxHidden = Math.max(maxHidden, hidden.length);
+    parts.push(
+      text(top + CHART_LINE - 2, 'heading', i18n.pillarKindLabel(kind)),
+      text(top + CHART_LINE * 2, 'label', kind === 'Day' ? i18n.dayStemWord() : i18n.tenGodLabel(tenGod(dayStem, pillar.stem))),
+      box(cx, stemTop, stemHanja, i18n.stemLabel(pillar.stem), stemElement(pillar.stem), stemPolarity(pillar.stem), kind === 'Day'),
+      box(cx, branchTop, branchHanja, i18n.branchLabel(pillar.branch), branchElement(pillar.branch), branchPolarity(pillar.branch), false),
+      text(branchTop + CHART_BOX + CHART_LINE, 'label', i18n.tenGodLabel(tenGodBranch(dayStem, pillar.branch))),
+      ...hidden.map((stem, i) => text(hiddenTop + CHART_LINE * (i + 1), 'axis', `${i18n.stemLabel(stem)} ${i18n.tenGodLabel(tenGod(dayStem, stem))}`)),
+    );
+  });
+
+  const width = MARGIN * 2 + CHART_COLUMN_WIDTH * CHART_COLUMNS.length;
+  const height = hiddenTop + CHART_LINE * (maxHidden + 1) + MARGIN;
+  return [
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${width}" height="${height}" viewBox="0 0 ${width} ${height}">`,
+    '<style>text{font-family:sans-serif;fill:#222222}.heading{font-size:13px;font-weight:bold}.label{font-size:12px}'
+      + '.axis{font-size:11px}.caption{font-size:10px}.glyph{font-family:serif;font-size:36px}</style>',
+    `<text x="${MARGIN}" y="${MARGIN + 4}" class="heading">${escapeXml(i18n.title())}</text>`,
+    ...parts,
+    '</svg>',
+  ].join('\n');
+}
+
+/** 타임라인의 한 구간 (나이 기준, 세) */
+interface Span {
+  fromAge: number;
+  toAge: number;
+  pillar: Pillar;
+  label: string;
+}
+
+/**
+ * 대운 막대와 세운 눈금으로 이루어진 운 타임라인 SVG를 생성한다.
+ *
+ * 가로축은 출생 후 나이(세)이며, 각 구간은 용신 기준 길흉 점수
+ * (luck.pillarFavorability)로 색칠한다. 성별이 없어 대운이 정해지지 않으면
+ * 세운 행만 그린다.
+ *
+ * @param result 사주 계산 결과
+ * @param options 렌더링 옵션
+ * @returns SVG 문서 문자열
+ */
+export function renderLuckTimelineSvg(result: SajuResult, options: TimelineSvgOptions = {}): string {
+  const width = options.width ?? 960;
+  const i18n = options.i18n ?? new I18n(options.lang ?? 'Ko');
+  const yongshin: YongshinResult = result.yongshin;
+  const ageAt = (jd: number): number => (jd - result.birthJd) / TROPICAL_YEAR_DAYS;
+
+  const daewonSpans: Span[] = result.daewonItems.map((item) => ({
+    fromAge: ageAt(daewonStartJd(result.birthJd, exactStartMonths(item))),
+    toAge: ageAt(daewonStartJd(result.birthJd, exactStartMonths(item) + 120)),
+    pillar: item.pillar,
+    label: `${iljuKey(item.pillar)} ${i18n.formatAge(item.startMonths, false)}`,
+  }));
+  const yearSpans: Span[] = result.yearlyLuck.map((y) => ({
+    fromAge: ageAt(y.startJd),
+    toAge: ageAt(y.endJd),
+    pillar: y.pillar,
+    label: `${y.year} ${iljuKey(y.pillar)}`,
+  }));
+
+  const all = [...daewonSpans, ...yearSpans];
+  const minAge = all.length > 0 ? Math.min(0, ...all.map((s) => s.fromAge)) : 0;
+  const maxAge = all.length > 0 ? Math.max(...all.map((s) => s.toAge)) : 10;
+  const plotWidth = width - MARGIN * 2;
+  const x = (age: number): number => MARGIN + ((age - minAge) / (maxAge - minAge)) * plotWidth;
+
+  const rows: { heading: string; spans: Span[]; height: number }[] = [];
+  if (daewonSpans.length > 0) rows.push({ heading: i18n.daewonHeading(), spans: daewonSpans, height: DAEWON_HEIGHT });
+  if (yearSpans.length > 0) rows.push({ heading: i18n.yearlyLuckHeading(), spans: yearSpans, height: YEAR_HEIGHT });
+
+  const parts: string[] = [];
+  let y = MARGIN + TITLE_HEIGHT;
+  for (const row of rows) {
+    parts.push(`<text x="${MARGIN}" y="${num(y - 4)}" class="heading">${escapeXml(row.heading)}</text>`);
+    for (const span of row.spans) {
+      const x0 = x(span.fromAge);
+      const w = Math.max(1, x(span.toAge) - x0);
+      const score = pillarFavorability(span.pillar, yongshin);
+      const label = w >= MIN_LABEL_WIDTH
+        ? `<text x="${num(x0 + w / 2)}" y="${num(y + row.height / 2 + 4)}" text-anchor="middle" class="label">${escapeXml(iljuKey(span.pillar))}</text>`
+        : '';
+      parts.push(
+        `<g><title>${escapeXml(`${span.label} (${score >= 0 ? '+' : ''}${score})`)}</title>`
+        + `<rect x="${num(x0)}" y="${num(y)}" width="${num(w)}" height="${row.height}" fill="${scoreColor(score)}" stroke="#ffffff"/>`
+        + `${label}</g>`,
+      );
+    }
+    y += row.height + ROW_GAP + TITLE_HEIGHT / 2;
+  }
+
+  // 나이 눈금 (10세 간격)
+  const axisY = y;
+  parts.push(`<line x1="${MARGIN}" y1="${num(axisY)}" x2="${num(width - MARGIN)}" y2="${num(axisY)}" stroke="#555555"/>`);
+  for (let age = 0; age <= maxAge; age += 10) {
+    parts.push(
+      `<line x1="${num(x(age))}" y1="${num(axisY)}" x2="${num(x(age))}" y2="${num(axisY + 5)}" stroke="#555555"/>`
+      + `<text x="${num(x(age))}" y="${num(axisY + 16)}" text-anchor="middle" class="axis">${escapeXml(i18n.formatAge(age * 12, false))}</text>`,
+    );
+  }
+
+  const height = axisY + AXIS_HEIGHT + MARGIN;
+  return [
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${width}" height="${num(height)}" viewBox="0 0 ${width} ${num(height)}">`,
+    '<style>text{font-family:sans-serif;fill:#222222}.heading{font-size:13px;font-weight:bold}.label{font-size:11px}.axis{font-size:10px}</style>',
+    `<text x="${MARGIN}" y="${MARGIN + 4}" class="heading">${escapeXml(`${i18n.title()} — ${iljuKey(result.dayPillar)}`)}</text>`,
+    ...parts,
+    '</svg>',
+  ].join('\n');
+}
@@ -0,0 +1,64 @@
+/**
+ * @fileoverview 학파(學派) 설정 묶음
+ *
+ * 유파마다 다르게 쓰는 계산 관례를 이름 하나로 묶는다 (CLI --school).
+ * - yeonhae: 연해자평 계열 전통 관례 — 23시 자시에 일주 교체, 연지 기준 12신살,
+ *   대운수 3일=1년 전통 환산, 월지 12운성 가감
+ * - japyeong-modern: 현대 자평 관례 — 0시 일주 교체(야자시), 일지 기준 12신살,
+ *   대운수 반올림, 월지 점수표(왕상휴수사), 삼합·방합·천간합 합화 반영(오행 분포·신강약·격국)
+ *
+ * 학파 설정은 라이브러리가 관례를 여럿 구현한 항목만 묶는다. 연 경계(입춘), 지장간 표
+ * (여기·중기·정기), 12운성(양순음역)은 관례를 하나만 구현하므로 설정 항목이 아니며
+ * 학파를 바꿔도 달라지지 않는다. 출력 헤더는 이 고정 관례를 함께 적어 두 부류를 구분한다
+ * (I18n.schoolSettingsText).
+ */
+
+import type { DaewonRounding } from './luck.js';
+import type { MonthScoring } from './strength.js';
+import type { DayBoundary, ShinsalBase } from './types.js';
+
+/** 학파 이름 */
+export type SchoolName = 'yeonhae' | 'japyeong-modern';
+
+export const SCHOOL_NAMES: readonly SchoolName[] = ['yeonhae', 'japyeong-modern'];
+
+/** 학파가 정하는 계산 설정 */
+export interface SchoolSettings {
+  /** 일주가 바뀌는 시각 */
+  dayBoundary: DayBoundary;
+  /** 12신살 기준 지지 */
+  shinsalBase: ShinsalBase;
+  /** 대운수 환산 반올림 규칙 */
+  daewonRounding: DaewonRounding;
+  /** 신강/신약 월지 기여 방식 */
+  monthScoring: MonthScoring;
+  /** 삼합·방합·천간합 합화 반영 여부 */
+  transform: boolean;
+}
+
+/** 학파별 설정 */
+export const SCHOOL_PRESETS: Readonly<Record<SchoolName, Readonly<SchoolSettings>>> = {
+  yeonhae: {
+    dayBoundary: 'Jasi',
+    shinsalBase: 'Year',
+    daewonRounding: 'Traditional',
+    monthScoring: 'Stage',
+    transform: false,
+  },
+  'japyeong-modern': {
+    dayBoundary: 'Midnight',
+    shinsalBase: 'Day',
+    daewonRounding: 'Round',
+    monthScoring: 'Matrix',
+    transform: true,
+  },
+};
+
+/**
+ * 학파 이름을 설정으로 바꾼다.
+ * @param name 학파 이름 (예: 'yeonhae')
+ * @returns 설정 (모르는 이름이면 null)
+ */
+export function schoolSettings(name: string): Readonly<SchoolSettings> | null {
+  return (SCHOOL_NAMES as readonly string[]).includes(name) ? SCHOOL_PRESETS[name as SchoolName] : null;
+}
@@ -4,6 +4,7 @@
  * `calculate()` 함수가 SajuRequest를 받아 SajuResult를 반환하는
  * 통합 계산 파이프라인을 제공한다. 내부적으로 astro, bazi, lunar,
  * luck, timezone, location 모듈을 조합하여 사주팔자를 산출한다.
+ * `calculateCached()`는 같은 요청의 결과를 메모리에 보관해 재사용한다.
  */
 
 import dayjs from 'dayjs';
@@ -11,27 +12,62 @@ import dayjs from 'dayjs';
 
 import * as astro from './astro.js';
 import * as bazi from './bazi.js';
-import { findStemInteractions, findBranchInteractions } from './interactions.js';
+import { normalizeDate, normalizeTime, parseDateInput, parseSijin, sijinMinutes } from './input.js';
+import type { SijinInput } from './input.js';
+import {
+  branchTransformations,
+  evaluateStemCombinations,
+  findBranchCombinations,
+  findStemInteractions,
+  findBranchInteractions,
+  stemTransformations,
+} from './interactions.js';
+import * as historical from './historical.js';
+import type { HistoricalCalendar, HistoricalDate } from './historical.js';
 import * as location from './location.js';
 import * as luck from './luck.js';
 import * as lunar from './lunar.js';
 import { findShinsal } from './shinsal.js';
-import { assessStrength, determineYongshin } from './strength.js';
-import type { StrengthResult } from './strength.js';
+import { assessStrength, DEFAULT_STRENGTH_PROFILE, determineYongshin, MONTH_SCORE_MATRIX } from './strength.js';
+import type { StrengthProfile, StrengthResult } from './strength.js';
 import * as tz from './timezone.js';
+import { registerCache, timed } from './timings.js';
+import { fnv1a64, LruCache, lazy } from './utils.js';
 import type {
+  BoundaryAlternative,
+  BranchCombination,
   BranchInteraction,
+  DayBoundary,
   Direction,
+  Element,
+  ElementOverrides,
   Gender,
+  Hemisphere,
+  LmtAlternative,
+  LmtBoundary,
   LmtInfo,
   LunarDate,
   Pillar,
+  PillarKind,
+  ShinsalBase,
   ShinsalEntry,
   SolarTerm,
+  StemCombination,
   StemInteraction,
+  TermDef,
   YongshinResult,
 } from './types.js';
-import type { DaewonItem, MonthlyLuck, YearLuck } from './luck.js';
+import type {
+  DaewonAgeRange,
+  DaewonDirectionMode,
+  DaewonItem,
+  DaewonRounding,
+  DaewonSequence,
+  DaewonStartSpan,
+  DaewonYearBasis,
+  MonthlyLuck,
+  YearLuck,
+} from './luck.js';
 import type { TimeZoneSpec } from './timezone.js';
 
 /** 역법 유형: 양력(Solar) 또는 음력(Lunar) */
@@ -45,10 +81,18 @@ export interface SajuRequest {
   time: string;
   /** 역법 유형 */
   calendar: CalendarType;
+  /**
+   * 양력 입력 날짜를 읽는 역법 (기본 Gregorian). Auto면 시간대의 나라가 그레고리력을 채택하기 전
+   * 날짜를 당시 역법으로 읽는다 (율리우스력은 그레고리력으로 바꾸고, 태음태양력 시기는 음력 입력을 요구한다).
+   */
+  historicalCalendar?: HistoricalCalendar;
   /** 윤달 여부 (음력 입력 시에만 유효) */
   leapMonth: boolean;
-  /** 성별 */
-  gender: Gender;
+  /**
+   * 성별. null이면 성별 미상(또는 이벤트 차트)으로 보고 대운 방향을 정하지 않는다.
+   * 이때 주 대운 필드는 비우고 순행·역행 두 후보를 daewonCandidates로 제공한다.
+   */
+  gender: Gender | null;
   /** 시간대 (IANA명 또는 오프셋) */
   tz: string;
   /** 평태양시(LMT) 보정 사용 여부 */
@@ -57,21 +101,128 @@ export interface SajuRequest {
   longitude: number | null;
   /** LMT 보정용 지역명 */
   location: string | null;
+  /** LMT 보정 시 시·일 경계 비교 기준 (기본 Corrected) */
+  lmtBoundary?: LmtBoundary;
+  /** 일주가 바뀌는 시각 (기본 Jasi: 23시) */
+  dayBoundary?: DayBoundary;
+  /** 출생 반구 (기본 North). South면 월지를 6지 옮긴다 (비표준 관례) */
+  hemisphere?: Hemisphere;
+  /**
+   * 경계 민감도 창 (1~119분). 지정하면 출생 시각 앞뒤 이 범위 안의 시·일·절입·입춘 경계를 찾아
+   * 경계 너머의 4기둥을 boundaryAlternatives로 제공한다 (기본 null: 검사하지 않음).
+   */
+  boundaryWindow?: number | null;
+  /** 12신살 기준 지지 (기본 Year). 계산에는 쓰지 않고 결과에 실어 표시 쪽이 따른다 */
+  shinsalBase?: ShinsalBase;
   /** 대운 개수 (기본 10) */
   daewonCount: number;
+  /**
+   * 대운 나이 범위 (만 나이, 양 끝 포함).
+   * 지정하면 daewonCount 대신 이 범위와 겹치는 대운을 모두 산출한다.
+   */
+  daewonAges?: DaewonAgeRange | null;
+  /** 강약 판정 설정 (기본 DEFAULT_STRENGTH_PROFILE) */
+  strengthProfile?: StrengthProfile;
+  /** 대운 시작 개월 수 반올림 규칙 (기본 Round) */
+  daewonRounding?: DaewonRounding;
+  /** 대운 방향 결정 방식 (기본 Auto). Forward/Backward면 성별과 무관하게 그 방향으로 산출한다 */
+  daewonDirection?: DaewonDirectionMode;
+  /** Auto 방향 판정에 쓰는 연간의 연 경계 (기본 Lichun) */
+  daewonYearBasis?: DaewonYearBasis;
   /** 월운 대상 연도 (null이면 현재 연도) */
   monthYear: number | null;
+  /** 월운 연도 수 (monthYear부터 연속, 기본 1) */
+  monthYearCount?: number;
   /** 세운 시작 연도 (null이면 월운 연도 -3) */
   yearStart: number | null;
   /** 세운 연도 수 */
   yearCount: number;
+  /**
+   * 평생 세운: 지정하면 yearStart/yearCount 대신
+   * 출생 연도(입춘 기준)부터 이 나이가 되는 해까지 세운을 산출한다.
+   */
+  yearlyUntilAge?: number | null;
+  /**
+   * 합화(合化) 반영: 완전한 삼합·방합 국의 오행으로 지지 오행을,
+   * 화(化)가 성립한 천간합의 오행으로 천간 오행을 바꿔
+   * 오행 분포·신강/신약 판정과 격국에 반영한다 (기본 false).
+   */
+  transform?: boolean;
+  /**
+   * 지원 범위 밖 양력 연도 강제 계산: 출생 연도 검사(SUPPORTED_YEAR_RANGES.Solar)를 건너뛰고
+   * 결과를 근사값(approximate)으로 표시한다. 음력 입력은 음력 표가 필요하므로 해당 없음 (기본 false).
+   */
+  force?: boolean;
+}
+
+/** 4기둥만 계산하는 요청 (운·분석 관련 필드 제외) */
+export type PillarsRequest = Pick<
+  SajuRequest,
+  | 'date' | 'time' | 'calendar' | 'historicalCalendar' | 'leapMonth' | 'tz' | 'useLmt' | 'longitude' | 'location' | 'lmtBoundary' | 'dayBoundary'
+  | 'hemisphere' | 'force'
+>;
+
+/** 네 기둥 필드 */
+type FourPillars = Pick<PillarsResult, 'yearPillar' | 'monthPillar' | 'dayPillar' | 'hourPillar'>;
+
+/** 기둥 종류별 필드 이름 */
+const PILLAR_FIELDS: Readonly<Record<PillarKind, keyof FourPillars>> = {
+  Year: 'yearPillar',
+  Month: 'monthPillar',
+  Day: 'dayPillar',
+  Hour: 'hourPillar',
+};
+
+/** 기둥을 역할(연/월/일/시)로 가리키는 접근자 (SajuResult·PillarsResult 공통) */
+export interface PillarAccessors {
+  /** 해당 기둥 */
+  pillar(kind: PillarKind): Pillar;
+  /** 해당 기둥의 천간 인덱스 */
+  stemOf(kind: PillarKind): number;
+  /** 해당 기둥의 지지 인덱스 */
+  branchOf(kind: PillarKind): number;
+}
+
+/** 결과 객체마다 같은 함수를 나눠 쓰는 접근자 구현 (결과끼리 toEqual 비교가 그대로 성립한다) */
+const PILLAR_ACCESSORS = {
+  pillar(this: FourPillars, kind: PillarKind): Pillar { return this[PILLAR_FIELDS[kind]]; },
+  stemOf(this: FourPillars, kind: PillarKind): number { return this[PILLAR_FIELDS[kind]].stem; },
+  branchOf(this: FourPillars, kind: PillarKind): number { return this[PILLAR_FIELDS[kind]].branch; },
+};
+
+/** 4기둥 계산 결과 (calculatePillars) */
+export interface PillarsResult extends PillarAccessors {
+  yearPillar: Pillar;
+  monthPillar: Pillar;
+  dayPillar: Pillar;
+  hourPillar: Pillar;
+  /** 출생 시점 (JD, LMT 보정 반영) */
+  birthJd: number;
+  /** 평태양시 보정 정보 */
+  lmtInfo: LmtInfo | null;
+  tzName: string;
+  /** 입력 날짜를 율리우스력으로 읽었으면 그 가정 (아니면 null) */
+  historicalDate: HistoricalDate | null;
+  /** 월지 판정에 쓴 반구 */
+  hemisphere: Hemisphere;
+  /** 계산 신뢰도 경고 */
+  warnings: SajuWarning[];
+  /** 지원 범위 밖 연도를 force로 계산한 근사 결과인지 여부 */
+  approximate: boolean;
 }
 
-/** 사주 계산 결과 */
-export interface SajuResult {
+/**
+ * 사주 계산 결과.
+ * 운(대운·세운·월운), 당해 절기, 합충형파해·신살 필드는 처음 읽을 때 계산되는 getter이다.
+ */
+export interface SajuResult extends PillarAccessors {
   // ── 입력 정보 ──
+  /** 입력 날짜 (YYYY-MM-DD로 정규화) */
   inputDate: string;
+  /** 입력 시각 (HH:MM 또는 HH:MM:SS로 정규화) */
   inputTime: string;
+  /** 정규화 전 입력 (표준 형식과 다르게 입력했을 때만, 아니면 null) */
+  rawInput: { date: string; time: string } | null;
   calendarIsLunar: boolean;
   leapMonth: boolean;
   tzName: string;
@@ -79,9 +230,28 @@ export interface SajuResult {
   convertedSolar: string | null;
   /** 양력 입력 시 변환된 음력 날짜 */
   convertedLunar: LunarDate | null;
+  /** 입력 날짜를 율리우스력으로 읽었으면 그 가정 (아니면 null) */
+  historicalDate: HistoricalDate | null;
   /** 평태양시 보정 정보 */
   lmtInfo: LmtInfo | null;
-  gender: Gender;
+  /** 시·일 경계 비교 기준 */
+  lmtBoundary: LmtBoundary;
+  /** 다른 경계 기준이면 일주·시주가 달라질 때 그 결과 (LMT 미적용이거나 같으면 null) */
+  lmtAlternative: LmtAlternative | null;
+  /** 경계 민감도 창 (분, 검사하지 않으면 null) */
+  boundaryWindow: number | null;
+  /** 창 안의 앞뒤 경계 너머 4기둥 (창 안에 경계가 없거나 검사하지 않으면 빈 배열) */
+  boundaryAlternatives: BoundaryAlternative[];
+  /** 일주가 바뀌는 시각 */
+  dayBoundary: DayBoundary;
+  /** 월지 판정에 쓴 반구 (South면 비표준 관례) */
+  hemisphere: Hemisphere;
+  /** 12신살 기준 지지 */
+  shinsalBase: ShinsalBase;
+  /** 성별 (미상·이벤트 차트면 null) */
+  gender: Gender | null;
+  /** 출생 시점 (JD, LMT 보정 반영) */
+  birthJd: number;
 
   // ── 사주 네 기둥 ──
   yearPillar: Pillar;
@@ -98,26 +268,80 @@ export interface SajuResult {
   stemInteractions: StemInteraction[];
   /** 지지 합/충/형/파/해 */
   branchInteractions: BranchInteraction[];
+  /** 이웃한 천간합과 합화 성립 평가 */
+  stemCombinations: StemCombination[];
+  /** 삼합·방합 국 (완전·반합) */
+  branchCombinations: BranchCombination[];
+  /** 합화 반영 시 위치별 오행 치환 (transform이 꺼져 있으면 null) */
+  elementOverrides: ElementOverrides | null;
   /** 주요 신살 */
   shinsalEntries: ShinsalEntry[];
 
   // ── 운(運) ──
-  /** 대운 방향 */
-  daewonDirection: Direction;
-  /** 대운 시작 시기 (개월) */
-  daewonStartMonths: number;
-  /** 대운 목록 */
+  /** 대운 방향 (성별이 없고 방향을 고정하지 않았으면 null) */
+  daewonDirection: Direction | null;
+  /** 대운 방향 결정 방식 */
+  daewonDirectionMode: DaewonDirectionMode;
+  /** Auto 방향 판정에 쓴 연간의 연 경계 */
+  daewonYearBasis: DaewonYearBasis;
+  /** 대운 시작 시기 (개월, 성별이 없으면 null) */
+  daewonStartMonths: number | null;
+  /** 대운 시작 시기 (년·개월·일·시간, 성별이 없으면 null) */
+  daewonStartSpan: DaewonStartSpan | null;
+  /** 대운 시작 개월 수에 적용한 반올림 규칙 */
+  daewonRounding: DaewonRounding;
+  /** 대운 목록 (성별이 없으면 빈 배열) */
   daewonItems: DaewonItem[];
+  /** 성별 미상일 때 순행·역행 대운 후보 (성별이 있으면 빈 배열) */
+  daewonCandidates: DaewonSequence[];
   /** 세운(연운) 목록 */
   yearlyLuck: YearLuck[];
   /** 월운 데이터 */
   monthlyLuck: MonthlyLuck;
+  /** 여러 해 월운 (첫 항목은 monthlyLuck과 같다) */
+  monthlyLuckYears: MonthlyLuck[];
 
   // ── 메타 ──
   /** 시간대 명세 */
   tzSpec: TimeZoneSpec;
   /** 당해 절기 목록 */
   solarTerms: SolarTerm[];
+  /** 출생 시점이 속한 절월 구간 (절입 후 경과·다음 절입까지 남은 일수) */
+  birthTerm: BirthTermContext;
+  /** 계산 신뢰도 경고 (모델 범위 밖 연도, 음력 표 범위, 표준시 이전 시간대 등) */
+  warnings: SajuWarning[];
+  /** 지원 범위 밖 연도를 force로 계산한 근사 결과인지 여부 */
+  approximate: boolean;
+  /**
+   * 정규화한 입력과 계산 관례의 지문 (16자리 16진수).
+   * 같은 명식이면 입력 표기와 무관하게 같고, 관례(FINGERPRINT_VERSION 포함)가 바뀌면 달라진다.
+   */
+  fingerprint: string;
+}
+
+/** 계산 신뢰도 경고 코드 */
+export type SajuWarningCode =
+  /** 절기·천체 위치 계산의 정확도 보장 범위(1900~2100년) 밖 */
+  | 'EPHEMERIS_RANGE'
+  /** 내장 음력 표(1900~2099년) 밖이라 음력 날짜를 표시하지 않음 */
+  | 'LUNAR_TABLE_RANGE'
+  /** 표준시 채택 이전이라 시간대 오프셋이 tz 데이터베이스의 평균태양시 추정값 */
+  | 'TIMEZONE_HISTORY'
+  /** 시진(자시·축시초 등)으로만 받은 시각이라 시진 안의 한 시점으로 어림잡음 */
+  | 'APPROXIMATE_TIME';
+
+/**
+ * 계산은 진행했지만 결과의 정확도를 보장하기 어려운 사유.
+ * 값을 조용히 내놓는 대신 출력에 함께 표시한다.
+ */
+export interface SajuWarning {
+  code: SajuWarningCode;
+  /** 경고 대상 양력 연도 */
+  year: number;
+  /** 영문 설명 */
+  message: string;
+  /** APPROXIMATE_TIME에서 계산에 쓴 시계 시각 (YYYY-MM-DD HH:mm) */
+  resolvedTime?: string;
 }
 
 /** 입력 검증 실패 사유 코드 */
@@ -132,6 +356,8 @@ export type ValidationErrorCode =
   | 'DATE_SOLAR_YEAR_RANGE'
   | 'DATE_LUNAR_YEAR_RANGE'
   | 'DATE_SOLAR_INVALID'
+  | 'DATE_CALENDAR_GAP'
+  | 'DATE_LUNISOLAR_ERA'
   | 'DATE_LUNAR_MONTH_RANGE'
   | 'DATE_LUNAR_DAY_RANGE'
   | 'DATE_LUNAR_LEAP_MISMATCH'
@@ -145,10 +371,15 @@ export type ValidationErrorCode =
   | 'LMT_LONGITUDE_RANGE'
   | 'MONTH_YEAR_RANGE'
   | 'YEAR_START_RANGE'
+  | 'MONTH_YEAR_COUNT'
   | 'YEAR_LUCK_RANGE'
   | 'DAEWON_COUNT_MIN'
   | 'DAEWON_COUNT_MAX'
+  | 'DAEWON_AGE_RANGE'
+  | 'DAEWON_LUNAR_YEAR_RANGE'
+  | 'YEARLY_AGE_RANGE'
   | 'YEAR_COUNT_MIN'
+  | 'BOUNDARY_WINDOW_RANGE'
   | 'YEAR_COUNT_MAX';
 
 /** 입력 검증 실패를 나타내는 도메인 에러 */
@@ -174,20 +405,30 @@ function raiseValidationError(code: ValidationErrorCode, message: string): never
 }
 
 const DAEWON_COUNT_MAX = 120;
+/** 대운 나이 범위 상한 (세) */
+const DAEWON_AGE_MAX = 150;
 const YEAR_COUNT_MAX = 120;
+/** 경계 민감도 창의 최대 분 (시주 하나의 길이보다 짧아 한 쪽에 시 경계가 하나만 들어온다) */
+const BOUNDARY_WINDOW_MAX = 119;
 const SOLAR_YEAR_MIN = 1900;
 const SOLAR_YEAR_MAX = 2100;
-const LUNAR_YEAR_MIN = 1900;
-const LUNAR_YEAR_MAX = 2099;
+const LUNAR_YEAR_MIN = lunar.LUNAR_MIN_YEAR;
+const LUNAR_YEAR_MAX = lunar.LUNAR_MAX_YEAR;
+
+/** 입력 역법별 지원 연도 범위 (양 끝 포함): 양력은 절기 계산, 음력은 내장 음력 표 기준 */
+export const SUPPORTED_YEAR_RANGES: Readonly<Record<CalendarType, readonly [number, number]>> = {
+  Solar: [SOLAR_YEAR_MIN, SOLAR_YEAR_MAX],
+  Lunar: [LUNAR_YEAR_MIN, LUNAR_YEAR_MAX],
+};
 
 /**
  * 시간 문자열을 파싱한다.
- * @param input 'HH:MM' 또는 'HH:MM:SS' 형식
+ * @param input 'HH:MM' 또는 'HH:MM:SS' 형식 (normalizeTime이 알아보는 표기 포함)
  * @returns 시, 분, 초 객체
  * @throws 형식이 잘못된 경우
  */
 function parseTime(input: string): { hour: number; minute: number; second: number } {
-  const match = input.match(/^(\d{2}):(\d{2})(?::(\d{2}))?$/);
+  const match = normalizeTime(input).match(/^(\d{2}):(\d{2})(?::(\d{2}))?$/);
   if (!match) {
     raiseValidationError('TIME_FORMAT', 'time format must be HH:MM or HH:MM:SS');
   }
@@ -254,11 +495,12 @@ interface DateResolution {
   solarDay: number
   convertedSolar: string | null
   convertedLunar: LunarDate | null
+  historicalDate: HistoricalDate | null
 }
 
 /** 1단계: 입력 날짜 파싱 및 음양력 변환 */
-function resolveDate(req: SajuRequest): DateResolution {
-  const dateMatch = req.date.match(/^(\d{4})-(\d{2})-(\d{2})$/);
+function resolveDate(req: PillarsRequest): DateResolution {
+  const dateMatch = normalizeDate(req.date).match(/^(\d{4})-(\d{2})-(\d{2})$/);
   if (!dateMatch) {
     raiseValidationError('DATE_FORMAT', 'date must be valid YYYY-MM-DD format');
   }
@@ -275,35 +517,61 @@ function resolveDate(req: SajuRequest): DateResolution {
   if (req.calendar === 'Lunar' && (inputYear < LUNAR_YEAR_MIN || inputYear > LUNAR_YEAR_MAX)) {
     raiseValidationError('DATE_LUNAR_YEAR_RANGE', `음력 변환은 ${LUNAR_YEAR_MIN}-${LUNAR_YEAR_MAX}년 범위만 지원합니다`);
   }
-  if (req.calendar === 'Solar' && (inputYear < SOLAR_YEAR_MIN || inputYear > SOLAR_YEAR_MAX)) {
+  if (req.calendar === 'Solar' && !req.force && (inputYear < SOLAR_YEAR_MIN || inputYear > SOLAR_YEAR_MAX)) {
     raiseValidationError('DATE_SOLAR_YEAR_RANGE', `양력 절기 계산은 ${SOLAR_YEAR_MIN}-${SOLAR_YEAR_MAX}년 범위만 지원합니다`);
   }
-  if (req.calendar === 'Solar' && !isValidSolarDate(inputYear, inputMonth, inputDay)) {
-    raiseValidationError('DATE_SOLAR_INVALID', 'solar date must be a valid calendar date');
-  }
 
   if (req.calendar === 'Solar') {
-    const sDate = new Date(Date.UTC(inputYear, inputMonth - 1, inputDay));
-    if (inputYear >= 0 && inputYear < 100) sDate.setUTCFullYear(inputYear);
+    const history = historical.resolveHistoricalDate([inputYear, inputMonth, inputDay], req.historicalCalendar ?? 'Gregorian', req.tz);
+    if (history.kind === 'Gap') {
+      raiseValidationError(
+        'DATE_CALENDAR_GAP',
+        `${normalizeDate(req.date)} did not exist in ${history.reform.region}: the Gregorian calendar started on ${history.reform.gregorianFrom}`,
+      );
+    }
+    if (history.kind === 'Lunisolar') {
+      raiseValidationError(
+        'DATE_LUNISOLAR_ERA',
+        `${history.reform.region} used the lunisolar calendar before ${history.reform.gregorianFrom}; enter the date as a lunar date`,
+      );
+    }
+    const valid = history.kind === 'Julian'
+      ? historical.isValidJulianDate(inputYear, inputMonth, inputDay)
+      : isValidSolarDate(inputYear, inputMonth, inputDay);
+    if (!valid) {
+      raiseValidationError('DATE_SOLAR_INVALID', 'solar date must be a valid calendar date');
+    }
+    const [solarYear, solarMonth, solarDay] = history.kind === 'Julian' ? history.gregorian : [inputYear, inputMonth, inputDay];
+    const historicalDate: HistoricalDate | null = history.kind === 'Julian'
+      ? {
+        julianDate: historical.formatYmd([inputYear, inputMonth, inputDay]),
+        gregorianDate: historical.formatYmd(history.gregorian),
+        region: history.reform?.region ?? null,
+        gregorianFrom: history.reform?.gregorianFrom ?? null,
+      }
+      : null;
+    const sDate = new Date(Date.UTC(solarYear, solarMonth - 1, solarDay));
+    if (solarYear >= 0 && solarYear < 100) sDate.setUTCFullYear(solarYear);
     let convertedLunar: LunarDate | null = null;
     try {
-      convertedLunar = lunar.solarToLunar(sDate);
+      convertedLunar = timed('Lunar', () => lunar.solarToLunar(sDate));
     } catch (err: unknown) {
       // 절기 계산 가능한 연도여도 음력 데이터 테이블 범위를 넘는 날짜가 있다.
       // 이 경우 계산은 진행하고 음력 표기만 생략한다.
       if (!isSolarToLunarRangeError(err)) mapLunarError(err);
     }
     return {
-      solarYear: inputYear,
-      solarMonth: inputMonth,
-      solarDay: inputDay,
+      solarYear,
+      solarMonth,
+      solarDay,
       convertedSolar: null,
       convertedLunar,
+      historicalDate,
     };
   } else {
     let sDate: Date;
     try {
-      sDate = lunar.lunarToSolar(inputYear, inputMonth, inputDay, req.leapMonth);
+      sDate = timed('Lunar', () => lunar.lunarToSolar(inputYear, inputMonth, inputDay, req.leapMonth));
     } catch (err: unknown) {
       mapLunarError(err);
     }
@@ -319,6 +587,7 @@ function resolveDate(req: SajuRequest): DateResolution {
       solarDay,
       convertedSolar: `${yy}-${mm}-${dd}`,
       convertedLunar: null,
+      historicalDate: null,
     };
   }
 }
@@ -326,14 +595,23 @@ function resolveDate(req: SajuRequest): DateResolution {
 /** 시간대/LMT 보정 결과 */
 interface TimezoneResolution {
   finalLocalDt: dayjs.Dayjs
+  /** 보정 전 시계 시각 */
+  clockLocalDt: dayjs.Dayjs
   lmtInfo: LmtInfo | null
   tzSpec: tz.TimeZoneSpec
 }
 
-interface InputResolution {
+/** 1~2단계 결과: 출생 날짜와 시각 */
+interface BirthResolution {
   dateRes: DateResolution
   tzRes: TimezoneResolution
+  /** 시진으로 받은 시각 (HH:MM 입력이면 null) */
+  sijin: SijinInput | null
+}
+
+interface InputResolution extends BirthResolution {
   monthYear: number
+  monthYearCount: number
   yearStart: number
 }
 
@@ -343,7 +621,7 @@ function applyTimezone(
   solarMonth: number,
   solarDay: number,
   time: { hour: number; minute: number; second: number },
-  req: SajuRequest,
+  req: PillarsRequest,
 ): TimezoneResolution {
   const solarDateStr = `${String(solarYear).padStart(4, '0')}-${String(solarMonth).padStart(2, '0')}-${String(solarDay).padStart(2, '0')}`;
   const timeStr = `${String(time.hour).padStart(2, '0')}:${String(time.minute).padStart(2, '0')}:${String(time.second).padStart(2, '0')}`;
@@ -397,19 +675,59 @@ function applyTimezone(
     };
   }
 
-  return { finalLocalDt, lmtInfo, tzSpec };
+  return { finalLocalDt, clockLocalDt: localDt, lmtInfo, tzSpec };
 }
 
-/** 계산 전 입력값을 파싱·검증하고 1~2단계 결과를 반환한다. */
-function resolveInput(req: SajuRequest): InputResolution {
-  const time = parseTime(req.time);
+/** 출생 날짜·시각을 파싱·검증하고 1~2단계 결과를 반환한다. */
+function resolveBirth(req: PillarsRequest): BirthResolution {
+  const sijin = parseSijin(req.time);
+  const time = sijin ?? parseTime(req.time);
 
   if (req.calendar === 'Solar' && req.leapMonth) {
     raiseValidationError('LEAP_MONTH_WITH_SOLAR', 'leap-month is only valid with calendar=lunar');
   }
 
   const dateRes = resolveDate(req);
-  const tzRes = applyTimezone(dateRes.solarYear, dateRes.solarMonth, dateRes.solarDay, time, req);
+  const tzRes = 'branch' in time
+    ? applySijin(dateRes, time, req)
+    : applyTimezone(dateRes.solarYear, dateRes.solarMonth, dateRes.solarDay, time, req);
+  return { dateRes, tzRes, sijin };
+}
+
+/**
+ * 시진으로 받은 시각을 시진 안의 한 시점으로 정해 시간대를 적용한다.
+ * 평태양시 보정 시각으로 시주를 가르면(lmtBoundary 'Corrected') 보정 시각이
+ * 그 시점에 오도록 시계 시각을 거꾸로 맞춘다.
+ */
+function applySijin(dateRes: DateResolution, sijin: SijinInput, req: PillarsRequest): TimezoneResolution {
+  const { minutes, dayOffset } = sijinMinutes(sijin);
+  const ymd = `${String(dateRes.solarYear).padStart(4, '0')}-${String(dateRes.solarMonth).padStart(2, '0')}-${String(dateRes.solarDay).padStart(2, '0')}`;
+  const target = dayjs.utc(ymd)
+    .add(dayOffset, 'day')
+    .add(minutes, 'minute');
+  const at = (dt: dayjs.Dayjs) => applyTimezone(
+    dt.year(), dt.month() + 1, dt.date(),
+    { hour: dt.hour(), minute: dt.minute(), second: dt.second() },
+    req,
+  );
+  const tzRes = at(target);
+  if (!tzRes.lmtInfo || (req.lmtBoundary ?? 'Corrected') === 'Clock') return tzRes;
+  return at(target.subtract(tzRes.lmtInfo.correctionSeconds, 'second'));
+}
+
+/**
+ * 날짜 문구에 밝힌 역법·윤달(예: '음력 1990년 윤5월 3일')을 요청에 반영한다.
+ * 문구에 적힌 쪽이 calendar·leapMonth 필드보다 우선한다.
+ */
+function withDateTextFlags<T extends PillarsRequest>(req: T): T {
+  const parsed = parseDateInput(req.date);
+  if (parsed.calendar === null && parsed.leapMonth === null) return req;
+  return { ...req, calendar: parsed.calendar ?? req.calendar, leapMonth: parsed.leapMonth ?? req.leapMonth };
+}
+
+/** 계산 전 입력값을 파싱·검증하고 1~2단계 결과를 반환한다. */
+function resolveInput(req: SajuRequest): InputResolution {
+  const { dateRes, tzRes } = resolveBirth(req);
   if (
     req.monthYear !== null
     && (!Number.isInteger(req.monthYear) || req.monthYear < SOLAR_YEAR_MIN || req.monthYear > SOLAR_YEAR_MAX)
@@ -434,6 +752,27 @@ function resolveInput(req: SajuRequest): InputResolution {
   if (req.daewonCount > DAEWON_COUNT_MAX) {
     raiseValidationError('DAEWON_COUNT_MAX', `daewon-count must be <= ${DAEWON_COUNT_MAX}`);
   }
+  if (req.daewonAges != null) {
+    const { fromAge, toAge } = req.daewonAges;
+    if (
+      !Number.isInteger(fromAge) || !Number.isInteger(toAge)
+      || fromAge < 0 || toAge > DAEWON_AGE_MAX || fromAge > toAge
+    ) {
+      raiseValidationError(
+        'DAEWON_AGE_RANGE',
+        `daewon ages must be integers with 0 <= from <= to <= ${DAEWON_AGE_MAX}`,
+      );
+    }
+  }
+  if (
+    (req.daewonDirection ?? 'Auto') === 'Auto' && req.daewonYearBasis === 'LunarNewYear'
+    && req.gender !== null && req.calendar === 'Solar' && dateRes.convertedLunar === null
+  ) {
+    raiseValidationError(
+      'DAEWON_LUNAR_YEAR_RANGE',
+      `lunar-new-year daewon basis needs the ${LUNAR_YEAR_MIN}-${LUNAR_YEAR_MAX} lunar table`,
+    );
+  }
   if (!Number.isInteger(req.yearCount) || req.yearCount < 1) {
     raiseValidationError('YEAR_COUNT_MIN', 'year-count must be an integer >= 1');
   }
@@ -441,8 +780,35 @@ function resolveInput(req: SajuRequest): InputResolution {
     raiseValidationError('YEAR_COUNT_MAX', `year-count must be <= ${YEAR_COUNT_MAX}`);
   }
 
+  if (req.yearlyUntilAge != null) {
+    const age = req.yearlyUntilAge;
+    if (!Number.isInteger(age) || age < 0 || age > DAEWON_AGE_MAX || tzRes.finalLocalDt.year() + age > SOLAR_YEAR_MAX) {
+      raiseValidationError(
+        'YEARLY_AGE_RANGE',
+        `yearly-until-age must be an integer between 0 and ${DAEWON_AGE_MAX} ending by ${SOLAR_YEAR_MAX}`,
+      );
+    }
+  }
+
+  if (
+    req.boundaryWindow != null
+    && (!Number.isInteger(req.boundaryWindow) || req.boundaryWindow < 1 || req.boundaryWindow > BOUNDARY_WINDOW_MAX)
+  ) {
+    raiseValidationError('BOUNDARY_WINDOW_RANGE', `boundary window must be an integer between 1 and ${BOUNDARY_WINDOW_MAX} minutes`);
+  }
+
   const nowLocal = tz.toLocal(tzRes.tzSpec, dayjs.utc());
   const monthYr = req.monthYear ?? nowLocal.year();
+  const monthYearCount = req.monthYearCount ?? 1;
+  if (
+    !Number.isInteger(monthYearCount) || monthYearCount < 1
+    || monthYearCount > YEAR_COUNT_MAX || monthYr + monthYearCount - 1 > SOLAR_YEAR_MAX
+  ) {
+    raiseValidationError(
+      'MONTH_YEAR_COUNT',
+      `month-year count must be between 1 and ${YEAR_COUNT_MAX} ending by ${SOLAR_YEAR_MAX}`,
+    );
+  }
   const yearStart = req.yearStart ?? monthYr - 3;
   const yearEnd = yearStart + req.yearCount - 1;
   if (yearStart < SOLAR_YEAR_MIN || yearEnd > SOLAR_YEAR_MAX) {
@@ -451,7 +817,7 @@ function resolveInput(req: SajuRequest): InputResolution {
       `yearly luck range must stay between ${SOLAR_YEAR_MIN} and ${SOLAR_YEAR_MAX}`,
     );
   }
-  return { dateRes, tzRes, monthYear: monthYr, yearStart };
+  return { dateRes, tzRes, monthYear: monthYr, monthYearCount, yearStart };
 }
 
 /** 4기둥 산출 결과 */
@@ -462,110 +828,300 @@ interface PillarResolution {
   hourPillar: Pillar
   birthJd: number
   yearStem: number
-  termsPrev: SolarTerm[]
-  termsCurr: SolarTerm[]
-  termsNext: SolarTerm[]
+  /** 연주 기준 연도 (입춘 전 출생이면 전년도) */
+  lichunYear: number
+}
+
+/**
+ * 4~5단계: 일주·시주 산출 (23시 자시 경계 처리)
+ * @param localDt 경계 비교에 쓰는 지역 시각
+ * @param dayBoundary 일주가 바뀌는 시각 (Midnight면 23시대는 야자시)
+ */
+function dayHourPillars(localDt: dayjs.Dayjs, dayBoundary: DayBoundary = 'Jasi'): { dayPillar: Pillar; hourPillar: Pillar } {
+  const localHour = localDt.hour();
+  const localMinute = localDt.minute();
+
+  let adjustedYear = localDt.year();
+  let adjustedMonth = localDt.month() + 1;
+  let adjustedDay = localDt.date();
+  const yajasi = localHour >= 23 && dayBoundary === 'Midnight';
+  if (localHour >= 23 && !yajasi) {
+    const nextDay = localDt.add(1, 'day');
+    adjustedYear = nextDay.year();
+    adjustedMonth = nextDay.month() + 1;
+    adjustedDay = nextDay.date();
+  }
+
+  const jdn = bazi.jdnFromDate(adjustedYear, adjustedMonth, adjustedDay);
+  const [dayStem, dayBranch] = bazi.dayPillarFromJdn(jdn);
+  const dayPillar: Pillar = { stem: dayStem, branch: dayBranch };
+
+  // 시주 결정 (야자시는 다음 날 일간으로 시간을 세운다)
+  const hourBranch = bazi.hourBranchIndex(localHour, localMinute);
+  const hourDayStem = yajasi ? bazi.dayPillarFromJdn(jdn + 1)[0] : dayStem;
+  const hourStem = bazi.hourStemFromDay(hourDayStem, hourBranch);
+  const hourPillar: Pillar = { stem: hourStem, branch: hourBranch };
+
+  return { dayPillar, hourPillar };
 }
 
-/** 3~5단계: 절기 기반 4기둥(연·월·일·시) 산출 */
-function computePillars(finalLocalDt: dayjs.Dayjs): PillarResolution {
+/**
+ * 3~5단계: 절기 기반 4기둥(연·월·일·시) 산출.
+ * 입춘 시각과 출생 시점이 속한 절기 구간만 구하며 24절기 전체는 계산하지 않는다.
+ * @param finalLocalDt 출생 시각 (LMT 보정 반영, 절기·연주·월주 판정용)
+ * @param boundaryDt 시주·일주 경계 비교용 시각 (기본 finalLocalDt)
+ * @param dayBoundary 일주가 바뀌는 시각
+ * @param hemisphere 출생 반구 (South면 월지를 6지 옮긴다)
+ */
+function computePillars(
+  finalLocalDt: dayjs.Dayjs,
+  boundaryDt: dayjs.Dayjs = finalLocalDt,
+  dayBoundary: DayBoundary = 'Jasi',
+  hemisphere: Hemisphere = 'North',
+): PillarResolution {
   const utcDt = finalLocalDt.utc();
   const birthJd = astro.jdFromDatetime(utcDt.toDate());
 
   const year = finalLocalDt.year();
-  const termsPrev = luck.getCachedTerms(year - 1);
-  const termsCurr = luck.getCachedTerms(year);
-  const termsNext = luck.getCachedTerms(year + 1);
 
   // 입춘(立春) 기준 연주 결정
-  const lichunTerm = termsCurr.find((t) => t.def.key === 'lichun');
-  if (!lichunTerm) throw new Error('failed to find lichun term');
-  const yearForPillar = birthJd >= lichunTerm.jd ? year : year - 1;
+  const yearForPillar = birthJd >= astro.termJd(year, 'lichun') ? year : year - 1;
   const [yearStem, yearBranch] = bazi.yearPillar(yearForPillar);
   const yearPillar: Pillar = { stem: yearStem, branch: yearBranch };
 
-  // 월주 결정
-  const monthBranch = bazi.monthBranchForBirth(birthJd, termsPrev, termsCurr);
+  // 월주 결정 (남반구 관례는 계절을 뒤집어 월지를 충하는 지지로 옮기고 월간은 옮긴 월지로 다시 세운다)
+  const termBranch = bazi.monthBranchForBirth(birthJd);
+  const monthBranch = hemisphere === 'South' ? (termBranch + 6) % 12 : termBranch;
   const monthStem = bazi.monthStemFromYear(yearStem, monthBranch);
   const monthPillar: Pillar = { stem: monthStem, branch: monthBranch };
 
-  // 일주 결정 (23시 자시 경계 처리)
-  const localHour = finalLocalDt.hour();
-  const localMinute = finalLocalDt.minute();
+  // 일주·시주 결정
+  const { dayPillar, hourPillar } = dayHourPillars(boundaryDt, dayBoundary);
 
-  let adjustedYear = finalLocalDt.year();
-  let adjustedMonth = finalLocalDt.month() + 1;
-  let adjustedDay = finalLocalDt.date();
-  if (localHour >= 23) {
-    const nextDay = finalLocalDt.add(1, 'day');
-    adjustedYear = nextDay.year();
-    adjustedMonth = nextDay.month() + 1;
-    adjustedDay = nextDay.date();
+  return {
+    yearPillar, monthPillar, dayPillar, hourPillar, birthJd, yearStem, lichunYear: yearForPillar,
+  };
+}
+
+/** 경계 기준에 맞는 비교 시각 */
+function boundaryTime(tzRes: TimezoneResolution, boundary: LmtBoundary): dayjs.Dayjs {
+  return boundary === 'Clock' ? tzRes.clockLocalDt : tzRes.finalLocalDt;
+}
+
+const PILLAR_KINDS: readonly PillarKind[] = ['Year', 'Month', 'Day', 'Hour'];
+
+/** 두 4기둥 산출에서 달라진 기둥 (연·월·일·시 순) */
+function changedPillars(a: PillarResolution, b: PillarResolution): PillarKind[] {
+  return PILLAR_KINDS.filter((kind) => {
+    const field = PILLAR_FIELDS[kind];
+    return a[field].stem !== b[field].stem || a[field].branch !== b[field].branch;
+  });
+}
+
+/**
+ * 출생 시각 앞뒤 window분 안에 있는 가장 가까운 기둥 경계를 찾는다.
+ * 창 끝에서 기둥이 달라지는 쪽만 분 단위 이분 탐색으로 경계를 넘는 첫 분을 구한다.
+ * 창은 시주 하나(120분)보다 짧아(BOUNDARY_WINDOW_MAX) 한 쪽에 시 경계가 하나뿐이다.
+ * 같은 쪽에 다른 경계(절입·입춘)가 겹치면 가까운 것 하나만 본다.
+ * @param pillarsAt 출생 시각을 주어진 분만큼 옮겨 구한 4기둥
+ */
+function findBoundaryAlternatives(
+  pillarsAt: (minutes: number) => PillarResolution,
+  base: PillarResolution,
+  window: number,
+): BoundaryAlternative[] {
+  const alternatives: BoundaryAlternative[] = [];
+  for (const sign of [-1, 1]) {
+    if (changedPillars(base, pillarsAt(sign * window)).length === 0) continue;
+    let lo = 0;
+    let hi = window;
+    while (hi - lo > 1) {
+      const mid = Math.floor((lo + hi) / 2);
+      if (changedPillars(base, pillarsAt(sign * mid)).length > 0) hi = mid;
+      else lo = mid;
+    }
+    const alt = pillarsAt(sign * hi);
+    const changed = changedPillars(base, alt);
+    alternatives.push({
+      side: sign < 0 ? 'Earlier' : 'Later',
+      minutes: hi,
+      boundary: changed[0],
+      changed,
+      yearPillar: alt.yearPillar,
+      monthPillar: alt.monthPillar,
+      dayPillar: alt.dayPillar,
+      hourPillar: alt.hourPillar,
+    });
   }
+  return alternatives;
+}
 
-  const jdn = bazi.jdnFromDate(adjustedYear, adjustedMonth, adjustedDay);
-  const [dayStem, dayBranch] = bazi.dayPillarFromJdn(jdn);
-  const dayPillar: Pillar = { stem: dayStem, branch: dayBranch };
+/** 다른 경계 기준의 일주·시주를 구한다 (선택한 기준과 같으면 null) */
+function alternativeBoundary(
+  tzRes: TimezoneResolution,
+  boundary: LmtBoundary,
+  pillars: PillarResolution,
+  dayBoundary: DayBoundary,
+): LmtAlternative | null {
+  const other: LmtBoundary = boundary === 'Clock' ? 'Corrected' : 'Clock';
+  const alt = dayHourPillars(boundaryTime(tzRes, other), dayBoundary);
+  const same = (a: Pillar, b: Pillar) => a.stem === b.stem && a.branch === b.branch;
+  if (same(alt.dayPillar, pillars.dayPillar) && same(alt.hourPillar, pillars.hourPillar)) return null;
+  return { boundary: other, ...alt };
+}
 
-  // 시주 결정
-  const hourBranch = bazi.hourBranchIndex(localHour, localMinute);
-  const hourStem = bazi.hourStemFromDay(dayStem, hourBranch);
-  const hourPillar: Pillar = { stem: hourStem, branch: hourBranch };
+/** 출생 시점이 속한 절월 구간: 월주를 정한 절(節)과 다음 절 */
+export interface BirthTermContext {
+  /** 구간을 연 절 */
+  term: TermDef;
+  /** 절입 시점 (JD) */
+  startJd: number;
+  /** 다음 절 */
+  nextTerm: TermDef;
+  /** 다음 절입 시점 (JD) */
+  endJd: number;
+  /** 절입 후 경과 일수 */
+  daysSinceStart: number;
+  /** 다음 절입까지 남은 일수 */
+  daysUntilNext: number;
+}
+
+/**
+ * 결과 지문의 관례 버전.
+ * 같은 입력이라도 계산 방식이 바뀌어 저장해 둔 결과가 더 이상 맞지 않게 되면 올린다.
+ */
+export const FINGERPRINT_VERSION = 1;
+
+const ELEMENT_ORDER: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
+
+/**
+ * 정규화한 입력과 계산 관례로 결과 지문을 만든다.
+ * 대운 개수·세운 연도처럼 보여 줄 범위만 정하는 옵션은 명식이 같으므로 넣지 않는다.
+ */
+function chartFingerprint(req: SajuRequest, birth: BirthResolution, inputDate: string, inputTime: string): string {
+  const profile = req.strengthProfile ?? DEFAULT_STRENGTH_PROFILE;
+  const { thresholds } = profile;
+  const lmt = birth.tzRes.lmtInfo;
+  const canonical = {
+    version: FINGERPRINT_VERSION,
+    date: inputDate,
+    time: inputTime,
+    sijin: birth.sijin ? [birth.sijin.branch, birth.sijin.part] : null,
+    calendar: req.calendar,
+    leapMonth: req.calendar === 'Lunar' && req.leapMonth,
+    tz: tz.tzName(birth.tzRes.tzSpec),
+    lmt: lmt ? [lmt.longitude, req.lmtBoundary ?? 'Corrected'] : null,
+    gender: req.gender,
+    dayBoundary: req.dayBoundary ?? 'Jasi',
+    // 나중에 더한 관례는 기본값이면 넣지 않아 이전에 저장한 지문을 유지한다
+    ...(birth.dateRes.historicalDate ? { historicalCalendar: 'Julian' } : {}),
+    ...(req.hemisphere === 'South' ? { hemisphere: req.hemisphere } : {}),
+    shinsalBase: req.shinsalBase ?? 'Year',
+    daewonRounding: req.daewonRounding ?? 'Round',
+    ...(req.daewonDirection != null && req.daewonDirection !== 'Auto' ? { daewonDirection: req.daewonDirection } : {}),
+    ...(req.daewonYearBasis === 'LunarNewYear' ? { daewonYearBasis: req.daewonYearBasis } : {}),
+    transform: req.transform ?? false,
+    monthScoring: profile.monthScoring,
+    monthMatrix: profile.monthScoring === 'Matrix'
+      ? ELEMENT_ORDER.map((e) => (profile.monthMatrix ?? MONTH_SCORE_MATRIX)[e])
+      : null,
+    thresholds: [thresholds.veryWeak, thresholds.weak, thresholds.strong, thresholds.veryStrong],
+  };
+  return fnv1a64(JSON.stringify(canonical));
+}
 
-  return { yearPillar, monthPillar, dayPillar, hourPillar, birthJd, yearStem, termsPrev, termsCurr, termsNext };
+/** 출생 시점이 속한 절월 구간을 구한다 (월주 판정과 같은 보정 시각 기준) */
+function birthTermContext(birthJd: number): BirthTermContext {
+  const period = astro.termContaining(birthJd, true);
+  // 절은 TERM_DEFS에서 한 칸씩 건너 놓인다
+  const nextTerm = astro.TERM_DEFS[(astro.TERM_DEFS.indexOf(period.def) + 2) % astro.TERM_DEFS.length];
+  return {
+    term: period.def,
+    startJd: period.startJd,
+    nextTerm,
+    endJd: period.endJd,
+    daysSinceStart: birthJd - period.startJd,
+    daysUntilNext: period.endJd - birthJd,
+  };
 }
 
-/** 운(運) 계산 결과 */
-interface LuckResolution {
-  direction: Direction
-  startMonths: number
+/** 대운 계산 결과 */
+interface DaewonResolution {
+  direction: Direction | null
+  startMonths: number | null
+  startSpan: DaewonStartSpan | null
   daewonItems: DaewonItem[]
-  yearlyLuckResult: YearLuck[]
-  monthlyLuckResult: luck.MonthlyLuck
+  daewonCandidates: DaewonSequence[]
 }
 
-/** 6단계: 대운/세운/월운 산출 */
-function computeLuck(
-  req: SajuRequest,
-  gender: Gender,
-  yearStem: number,
+/**
+ * 대운 방향을 정한다.
+ * 고정 방향이 있으면 그대로 쓰고, Auto면 성별과 연 경계(입춘 또는 설)에 따른 연간 음양으로 판정한다.
+ * 성별이 없고 고정하지 않았으면 null.
+ */
+function resolveDaewonDirection(req: SajuRequest, dateRes: DateResolution, lichunYearStem: number): Direction | null {
+  const mode = req.daewonDirection ?? 'Auto';
+  if (mode !== 'Auto') return mode;
+  if (req.gender === null) return null;
+  if (req.daewonYearBasis !== 'LunarNewYear') return luck.daewonDirection(req.gender, lichunYearStem);
+  // 음력 입력은 입력 연도가 곧 음력 연도다 (범위 밖이면 resolveInput에서 걸러진다)
+  const lunarYear = dateRes.convertedLunar?.year ?? Number(normalizeDate(req.date).slice(0, 4));
+  return luck.daewonDirection(req.gender, bazi.yearPillar(lunarYear)[0]);
+}
+
+/** 한 방향의 대운 전개를 산출한다 */
+function buildDaewonSequence(
+  direction: Direction,
   monthPillar: Pillar,
   birthJd: number,
-  termsPrev: SolarTerm[],
-  termsCurr: SolarTerm[],
-  termsNext: SolarTerm[],
-  monthYear: number,
-  yearStart: number,
-): LuckResolution {
-  const direction = luck.daewonDirection(gender, yearStem);
-  const startMonths = luck.daewonStartMonths(birthJd, termsPrev, termsCurr, termsNext, direction);
-  if (startMonths === null) throw new Error('failed to find solar term for daewon start');
-
+  req: SajuRequest,
+): DaewonSequence {
+  const diffDays = luck.daewonStartDays(birthJd, direction);
+  const startSpan = luck.daewonStartSpan(diffDays);
+  const startMonths = luck.roundDaewonMonths(startSpan.exactMonths, req.daewonRounding ?? 'Round');
+  if (req.daewonAges != null) {
+    const items = luck.daewonItemsForAges(startMonths, monthPillar, direction, req.daewonAges, startSpan.exactMonths);
+    return { direction, startMonths, startSpan, items };
+  }
   const daewonPillars = luck.buildDaewonPillars(monthPillar, direction, req.daewonCount);
-  const daewonItems = luck.buildDaewonItems(startMonths, daewonPillars);
-  const yearlyLuckResult = luck.yearlyLuck(yearStart, req.yearCount);
-  const monthlyLuckResult = luck.monthlyLuck(monthYear);
+  return { direction, startMonths, startSpan, items: luck.buildDaewonItems(startMonths, daewonPillars, startSpan.exactMonths) };
+}
 
-  return { direction, startMonths, daewonItems, yearlyLuckResult, monthlyLuckResult };
+/** 6단계: 대운 산출 (성별 미상이면 순행·역행 후보를 모두 산출) */
+function computeDaewon(
+  req: SajuRequest,
+  direction: Direction | null,
+  monthPillar: Pillar,
+  birthJd: number,
+): DaewonResolution {
+  if (direction === null) {
+    const daewonCandidates = (['Forward', 'Backward'] as const).map((d) =>
+      buildDaewonSequence(d, monthPillar, birthJd, req));
+    return { direction: null, startMonths: null, startSpan: null, daewonItems: [], daewonCandidates };
+  }
+  const seq = buildDaewonSequence(direction, monthPillar, birthJd, req);
+  return { direction, startMonths: seq.startMonths, startSpan: seq.startSpan, daewonItems: seq.items, daewonCandidates: [] };
 }
 
-/** 분석 결과 */
+/** 분석 결과 중 강약 판정에 필요한 부분 (합화 반영 포함) */
 interface AnalysisResult {
   strength: StrengthResult
   yongshin: YongshinResult
-  stemInteractions: StemInteraction[]
-  branchInteractions: BranchInteraction[]
-  shinsalEntries: ShinsalEntry[]
+  stemCombinations: StemCombination[]
+  branchCombinations: BranchCombination[]
+  elementOverrides: ElementOverrides | null
 }
 
-/** 7단계: 신강/신약, 용신, 합충형파해, 신살 분석 */
-function analyze(fourPillars: Pillar[], dayStem: number): AnalysisResult {
-  const strength = assessStrength(dayStem, fourPillars);
+/** 7단계: 신강/신약, 용신 분석 (합충형파해·신살은 결과에서 처음 읽을 때 산출) */
+function analyze(fourPillars: Pillar[], dayStem: number, transform: boolean, profile?: StrengthProfile): AnalysisResult {
+  const stemCombinations = evaluateStemCombinations(fourPillars);
+  const branchCombinations = findBranchCombinations(fourPillars);
+  const elementOverrides: ElementOverrides | null = transform
+    ? { stems: stemTransformations(stemCombinations).stems, branches: branchTransformations(branchCombinations).branches }
+    : null;
+  const strength = assessStrength(dayStem, fourPillars, elementOverrides, profile);
   const yongshin = determineYongshin(dayStem, strength.verdict);
-  const stemInteractions = findStemInteractions(fourPillars);
-  const branchInteractions = findBranchInteractions(fourPillars);
-  const shinsalEntries = findShinsal(fourPillars);
-  return { strength, yongshin, stemInteractions, branchInteractions, shinsalEntries };
+  return { strength, yongshin, stemCombinations, branchCombinations, elementOverrides };
 }
 
 /**
@@ -580,51 +1136,189 @@ function analyze(fourPillars: Pillar[], dayStem: number): AnalysisResult {
  * 6. 대운/세운/월운 산출
  * 7. 신강/신약, 용신, 합충형파해, 신살 분석
  *
+ * 입력 검증과 1~5단계, 강약·용신 판정은 바로 계산한다. 비용이 큰 대운·세운·월운,
+ * 당해 절기 목록, 합충형파해·신살 탐색은 해당 필드를 처음 읽을 때 계산해 보관하므로
+ * 출력하지 않는 섹션의 비용은 들지 않는다.
+ *
  * @param req 계산 요청 파라미터
  * @returns 사주 계산 결과
  */
-export function calculate(req: SajuRequest): SajuResult {
+export function calculate(request: SajuRequest): SajuResult {
+  const req = withDateTextFlags(request);
   const input = resolveInput(req);
 
   // 3~5단계: 4기둥 산출
-  const pillars = computePillars(input.tzRes.finalLocalDt);
+  const lmtBoundary = req.lmtBoundary ?? 'Corrected';
+  const dayBoundary = req.dayBoundary ?? 'Jasi';
+  const pillars = timed('Pillars', () => computePillars(
+    input.tzRes.finalLocalDt, boundaryTime(input.tzRes, lmtBoundary), dayBoundary, req.hemisphere ?? 'North',
+  ));
+  const lmtAlternative = input.tzRes.lmtInfo ? alternativeBoundary(input.tzRes, lmtBoundary, pillars, dayBoundary) : null;
+  const boundaryWindow = req.boundaryWindow ?? null;
+  const boundaryAlternatives = lazy(() => boundaryWindow === null ? [] : timed('Pillars', () => findBoundaryAlternatives(
+    (minutes) => computePillars(
+      input.tzRes.finalLocalDt.add(minutes, 'minute'),
+      boundaryTime(input.tzRes, lmtBoundary).add(minutes, 'minute'),
+      dayBoundary,
+      req.hemisphere ?? 'North',
+    ),
+    pillars,
+    boundaryWindow,
+  )));
 
-  // 6단계: 운 계산
-  const luckRes = computeLuck(
-    req, req.gender, pillars.yearStem, pillars.monthPillar,
-    pillars.birthJd, pillars.termsPrev, pillars.termsCurr, pillars.termsNext, input.monthYear, input.yearStart,
-  );
+  // 6단계: 운 계산 (평생 세운이면 출생 연도부터)
+  const [yearStart, yearCount] = req.yearlyUntilAge != null
+    ? [pillars.lichunYear, req.yearlyUntilAge + 1]
+    : [input.yearStart, req.yearCount];
+  const direction = resolveDaewonDirection(req, input.dateRes, pillars.yearStem);
+  const daewon = lazy(() => timed('Luck', () => computeDaewon(req, direction, pillars.monthPillar, pillars.birthJd)));
+  const yearlyLuck = lazy(() => timed('Luck', () => luck.yearlyLuck(yearStart, yearCount)));
+  const monthlyLuckYears = lazy(() => timed('Luck', () => luck.monthlyLuckRange(input.monthYear, input.monthYearCount)));
+  const solarTerms = lazy(() => luck.getCachedTerms(input.tzRes.finalLocalDt.year()));
+  const birthTerm = lazy(() => timed('Terms', () => birthTermContext(pillars.birthJd)));
 
   // 7단계: 분석
   const fourPillars = [pillars.yearPillar, pillars.monthPillar, pillars.dayPillar, pillars.hourPillar];
-  const analysis = analyze(fourPillars, pillars.dayPillar.stem);
+  const analysis = timed('Analysis', () => analyze(fourPillars, pillars.dayPillar.stem, req.transform ?? false, req.strengthProfile));
+  const stemInteractions = lazy(() => timed('Analysis', () => findStemInteractions(fourPillars)));
+  const branchInteractions = lazy(() => timed('Analysis', () => findBranchInteractions(fourPillars)));
+  const shinsalEntries = lazy(() => timed('Analysis', () => findShinsal(fourPillars)));
+  const warnings = collectWarnings(input);
+  const inputDate = normalizeDate(req.date);
+  const inputTime = input.sijin ? input.tzRes.clockLocalDt.format('HH:mm') : normalizeTime(req.time);
+  const fingerprint = lazy(() => chartFingerprint(req, input, inputDate, inputTime));
 
   return {
-    inputDate: req.date,
-    inputTime: req.time,
+    inputDate,
+    inputTime,
+    rawInput: inputDate === req.date && inputTime === req.time ? null : { date: req.date, time: req.time },
     calendarIsLunar: req.calendar === 'Lunar',
     leapMonth: req.leapMonth,
     tzName: tz.tzName(input.tzRes.tzSpec),
     convertedSolar: input.dateRes.convertedSolar,
     convertedLunar: input.dateRes.convertedLunar,
+    historicalDate: input.dateRes.historicalDate,
     lmtInfo: input.tzRes.lmtInfo,
+    lmtBoundary,
+    lmtAlternative,
+    boundaryWindow,
+    get boundaryAlternatives() { return boundaryAlternatives(); },
+    dayBoundary,
+    hemisphere: req.hemisphere ?? 'North',
+    shinsalBase: req.shinsalBase ?? 'Year',
     gender: req.gender,
+    birthJd: pillars.birthJd,
     yearPillar: pillars.yearPillar,
     monthPillar: pillars.monthPillar,
     dayPillar: pillars.dayPillar,
     hourPillar: pillars.hourPillar,
     strength: analysis.strength,
     yongshin: analysis.yongshin,
-    stemInteractions: analysis.stemInteractions,
-    branchInteractions: analysis.branchInteractions,
-    shinsalEntries: analysis.shinsalEntries,
-    daewonDirection: luckRes.direction,
-    daewonStartMonths: luckRes.startMonths,
-    daewonItems: luckRes.daewonItems,
-    yearlyLuck: luckRes.yearlyLuckResult,
-    monthlyLuck: luckRes.monthlyLuckResult,
+    get stemInteractions() { return stemInteractions(); },
+    get branchInteractions() { return branchInteractions(); },
+    stemCombinations: analysis.stemCombinations,
+    branchCombinations: analysis.branchCombinations,
+    elementOverrides: analysis.elementOverrides,
+    get shinsalEntries() { return shinsalEntries(); },
+    daewonDirection: direction,
+    daewonDirectionMode: req.daewonDirection ?? 'Auto',
+    daewonYearBasis: req.daewonYearBasis ?? 'Lichun',
+    get daewonStartMonths() { return daewon().startMonths; },
+    get daewonStartSpan() { return daewon().startSpan; },
+    daewonRounding: req.daewonRounding ?? 'Round',
+    get daewonItems() { return daewon().daewonItems; },
+    get daewonCandidates() { return daewon().daewonCandidates; },
+    get yearlyLuck() { return yearlyLuck(); },
+    get monthlyLuck() { return monthlyLuckYears()[0]; },
+    get monthlyLuckYears() { return monthlyLuckYears(); },
     tzSpec: input.tzRes.tzSpec,
-    solarTerms: pillars.termsCurr,
+    get solarTerms() { return solarTerms(); },
+    get birthTerm() { return birthTerm(); },
+    warnings,
+    approximate: warnings.some((w) => w.code === 'EPHEMERIS_RANGE'),
+    get fingerprint() { return fingerprint(); },
+    ...PILLAR_ACCESSORS,
+  };
+}
+
+/** 시간대 오프셋이 15분 단위가 아니면 표준시 이전(평균태양시)으로 본다 */
+const STANDARD_OFFSET_STEP_MINUTES = 15;
+
+/**
+ * 입력 연도와 시간대로 계산 신뢰도 경고를 모은다.
+ * - 출생 연도가 절기 계산 범위 밖이면 EPHEMERIS_RANGE
+ * - 양력 입력인데 음력 표 범위 밖이라 음력 변환을 생략했으면 LUNAR_TABLE_RANGE
+ * - IANA 시간대가 표준시 채택 이전의 평균태양시 오프셋이면 TIMEZONE_HISTORY
+ */
+function collectWarnings(input: BirthResolution): SajuWarning[] {
+  const { dateRes, tzRes } = input;
+  const year = dateRes.solarYear;
+  const warnings: SajuWarning[] = [];
+  if (year < SOLAR_YEAR_MIN || year > SOLAR_YEAR_MAX) {
+    warnings.push({
+      code: 'EPHEMERIS_RANGE',
+      year,
+      message: `solar term and luck calculations are verified for ${SOLAR_YEAR_MIN}-${SOLAR_YEAR_MAX} only; pillars near term boundaries may be off`,
+    });
+  }
+  if (dateRes.convertedSolar === null && dateRes.convertedLunar === null) {
+    warnings.push({
+      code: 'LUNAR_TABLE_RANGE',
+      year,
+      message: `lunar date omitted: the built-in lunar table covers ${LUNAR_YEAR_MIN}-${LUNAR_YEAR_MAX} only`,
+    });
+  }
+  if (tzRes.tzSpec.type === 'named' && tzRes.clockLocalDt.utcOffset() % STANDARD_OFFSET_STEP_MINUTES !== 0) {
+    warnings.push({
+      code: 'TIMEZONE_HISTORY',
+      year,
+      message: `${tzRes.tzSpec.name} had no standard time yet; the offset is the tz database local mean time estimate and the recorded clock time may differ`,
+    });
+  }
+  if (input.sijin) {
+    const resolvedTime = tzRes.clockLocalDt.format('YYYY-MM-DD HH:mm');
+    warnings.push({
+      code: 'APPROXIMATE_TIME',
+      year,
+      message: `birth time was given only as a double-hour (시진); computed at ${resolvedTime}, so pillars near its edges are approximate`,
+      resolvedTime,
+    });
+  }
+  return warnings;
+}
+
+/**
+ * 4기둥만 계산하는 경량 함수.
+ * 대운·세운·월운과 신강/신약 등 분석을 모두 건너뛰고, 절기도 입춘과
+ * 출생 시점이 속한 구간만 구한다. 대량 일괄 처리에서 기둥만 필요할 때 쓴다.
+ *
+ * @param req 출생 정보 (calculate()와 같은 검증 규칙)
+ * @returns 4기둥과 보정·경고 정보
+ * @throws SajuValidationError 입력이 잘못된 경우
+ */
+export function calculatePillars(request: PillarsRequest): PillarsResult {
+  const req = withDateTextFlags(request);
+  const birth = resolveBirth(req);
+  const pillars = timed('Pillars', () => computePillars(
+    birth.tzRes.finalLocalDt,
+    boundaryTime(birth.tzRes, req.lmtBoundary ?? 'Corrected'),
+    req.dayBoundary ?? 'Jasi',
+    req.hemisphere ?? 'North',
+  ));
+  const warnings = collectWarnings(birth);
+  return {
+    yearPillar: pillars.yearPillar,
+    monthPillar: pillars.monthPillar,
+    dayPillar: pillars.dayPillar,
+    hourPillar: pillars.hourPillar,
+    birthJd: pillars.birthJd,
+    lmtInfo: birth.tzRes.lmtInfo,
+    tzName: tz.tzName(birth.tzRes.tzSpec),
+    historicalDate: birth.dateRes.historicalDate,
+    hemisphere: req.hemisphere ?? 'North',
+    warnings,
+    approximate: warnings.some((w) => w.code === 'EPHEMERIS_RANGE'),
+    ...PILLAR_ACCESSORS,
   };
 }
 
@@ -633,5 +1327,53 @@ export function calculate(req: SajuRequest): SajuResult {
  * 성공 시 반환값은 없고, 오류 시 예외를 던진다.
  */
 export function validateRequest(req: SajuRequest): void {
-  resolveInput(req);
+  resolveInput(withDateTextFlags(req));
+}
+
+// ── 결과 캐시 ──
+
+/** 최근 계산 결과를 보관하는 최대 개수 */
+export const RESULT_CACHE_SIZE = 64;
+
+const resultCache = registerCache('result', new LruCache<string, SajuResult>(RESULT_CACHE_SIZE));
+
+/**
+ * 요청의 캐시 키를 만든다.
+ * 정의된 필드를 이름순으로 직렬화하고, 월운·세운 연도를 현재 시각으로 정하는 요청이면
+ * 요청 시간대의 현재 연도를 더한다. 시간대가 잘못됐으면 null (캐시하지 않음).
+ */
+function requestKey(req: SajuRequest): string | null {
+  const fields = (Object.keys(req) as (keyof SajuRequest)[])
+    .filter((k) => req[k] !== undefined)
+    .sort()
+    .map((k) => [k, req[k]]);
+  let currentYear: number | null = null;
+  if (req.monthYear === null || req.yearStart === null) {
+    try {
+      currentYear = tz.toLocal(tz.parseTimezone(req.tz), dayjs.utc()).year();
+    } catch {
+      return null;
+    }
+  }
+  return JSON.stringify([fields, currentYear]);
+}
+
+/**
+ * calculate()의 결과를 최근 사용 순으로 캐시해 같은 요청이면 재계산 없이 반환한다.
+ * 화면을 다시 그릴 때마다 같은 요청을 보내는 프런트엔드·서버용이다.
+ * 반환값은 캐시와 공유되므로 수정하지 않는다.
+ *
+ * @param req 계산 요청 파라미터
+ * @returns 사주 계산 결과 (캐시된 객체일 수 있음)
+ * @throws SajuValidationError 입력이 잘못된 경우 (오류는 캐시하지 않음)
+ */
+export function calculateCached(req: SajuRequest): SajuResult {
+  const key = requestKey(req);
+  if (key === null) return calculate(req);
+  return resultCache.getOrCompute(key, () => calculate(req));
+}
+
+/** 결과 캐시를 비운다 */
+export function clearResultCache(): void {
+  resultCache.clear();
 }
@@ -10,6 +10,7 @@ import type {
   Pillar,
   PillarPosition,
   ShinsalEntry,
+  ShinsalKind,
 } from './types.js';
 import { gongmang } from './bazi.js';
 
@@ -124,17 +125,105 @@ function baekhoBranch(yearBranch: number): number | null {
   return map[yearBranch] ?? null;
 }
 
-/** 원진살(怨嗔殺) 대상 지지 — 기준 지지의 7번째(대충 다음) */
+/** 원진살(怨嗔殺) 대상 지지 — 子未·丑午·寅酉·卯申·辰亥·巳戌 */
 function wonjinBranch(basisBranch: number): number {
-  return [7, 6, 5, 4, 3, 2, 1, 0, 11, 10, 9, 8][basisBranch];
+  return [7, 6, 9, 8, 11, 10, 1, 0, 3, 2, 5, 4][basisBranch];
 }
 
-/** 귀문관살(鬼門關殺) 대상 지지 */
+/** 귀문관살(鬼門關殺) 대상 지지 — 子酉·丑午·寅未·卯申·辰亥·巳戌 */
 function gwimunBranch(basisBranch: number): number | null {
-  const map = [9, 10, 7, 8, 5, 4, 3, 2, 3, 0, 1, 6];
+  const map = [9, 6, 7, 8, 11, 10, 1, 2, 3, 0, 5, 4];
   return map[basisBranch] ?? null;
 }
 
+// ── 원진·귀문 지지 쌍 ──
+
+/** 지지 쌍으로 성립하는 신살 */
+export type PairShinsalKind = Extract<ShinsalKind, 'WonJinSal' | 'GwiMunGwanSal'>;
+
+/** 원국 안에서 성립한 지지 쌍 */
+export interface BranchPair {
+  kind: PairShinsalKind;
+  positions: [PillarPosition, PillarPosition];
+}
+
+/** 운(運)의 지지와 원국 지지 사이에 성립한 쌍 */
+export interface LuckBranchPair {
+  kind: PairShinsalKind;
+  /** 운의 지지와 쌍을 이루는 원국 위치 */
+  positions: PillarPosition[];
+}
+
+/** 기준 지지와 쌍을 이루는 지지 */
+function pairTarget(kind: PairShinsalKind, branch: number): number | null {
+  return kind === 'WonJinSal' ? wonjinBranch(branch) : gwimunBranch(branch);
+}
+
+/** 두 지지가 해당 신살 쌍을 이루는지 판정한다 */
+export function isBranchPair(kind: PairShinsalKind, a: number, b: number): boolean {
+  return pairTarget(kind, a) === b;
+}
+
+const PAIR_KINDS: readonly PairShinsalKind[] = ['WonJinSal', 'GwiMunGwanSal'];
+
+/**
+ * 원국 네 지지 사이의 원진·귀문 쌍을 모두 찾는다 (위치 순).
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ */
+export function natalBranchPairs(pillars: Pillar[]): BranchPair[] {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const pairs: BranchPair[] = [];
+  for (const kind of PAIR_KINDS) {
+    for (let i = 0; i < pillars.length; i++) {
+      for (let j = i + 1; j < pillars.length; j++) {
+        if (isBranchPair(kind, pillars[i].branch, pillars[j].branch)) {
+          pairs.push({ kind, positions: [POS[i], POS[j]] });
+        }
+      }
+    }
+  }
+  return pairs;
+}
+
+/**
+ * 운의 지지가 원국 지지와 이루는 원진·귀문 쌍을 찾는다.
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ * @param branch 대운·세운·월운의 지지
+ */
+export function luckBranchPairs(pillars: Pillar[], branch: number): LuckBranchPair[] {
+  const branches = pillars.map((p) => p.branch);
+  const pairs: LuckBranchPair[] = [];
+  for (const kind of PAIR_KINDS) {
+    const target = pairTarget(kind, branch);
+    const positions = target === null ? [] : matchBranch(branches, target);
+    if (positions.length > 0) pairs.push({ kind, positions });
+  }
+  return pairs;
+}
+
+/** 운(運) 기둥과 원국 사이의 공망 관계 */
+export interface LuckGongmang {
+  /** 운의 지지가 원국 일주 기준 공망에 드는지 */
+  luckVoided: boolean;
+  /** 운 기둥 기준 공망에 드는 원국 지지 위치 */
+  natalVoided: PillarPosition[];
+}
+
+/**
+ * 운 기둥과 원국 사이의 공망을 양방향으로 판정한다.
+ * @param pillars 네 기둥 배열 [연, 월, 일, 시]
+ * @param luckPillar 대운·세운·월운 기둥
+ */
+export function luckGongmang(pillars: Pillar[], luckPillar: Pillar): LuckGongmang {
+  const POS: PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+  const natal = gongmang(pillars[2].stem, pillars[2].branch);
+  const luck = gongmang(luckPillar.stem, luckPillar.branch);
+  return {
+    luckVoided: natal.includes(luckPillar.branch),
+    natalVoided: POS.filter((_, i) => luck.includes(pillars[i].branch)),
+  };
+}
+
 /**
  * 네 기둥에서 주요 신살을 검출한다.
  *
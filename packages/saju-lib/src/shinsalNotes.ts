@@ -0,0 +1,106 @@
+/**
+ * @fileoverview 신살(神殺) 풀이 카탈로그 모듈
+ *
+ * 주요 신살 13종과 12신살의 한 줄 의미를 내장한다.
+ * 외부 JSON 데이터로 문구를 교체할 수 있다.
+ */
+
+import type { ShinsalKind } from './types.js';
+import type { Lang } from './i18n.js';
+import { expectObject, langValues } from './override.js';
+
+/** 신살 풀이 카탈로그 */
+export interface ShinsalCatalog {
+  /** 주요 신살별 의미 */
+  kinds: Record<ShinsalKind, Record<Lang, string>>;
+  /** 12신살 의미 (인덱스 0=지살 … 11=천살) */
+  twelve: Record<Lang, string>[];
+}
+
+/**
+ * --shinsal-data JSON. 주요 신살은 종류 ID, 12신살은 한자 이름 아래에 바꿀 언어의 문구를 둔다.
+ *
+ * ```json
+ * { "kinds": { "YeokMaSal": { "Ko": "..." } }, "twelve": { "華蓋殺": { "En": "..." } } }
+ * ```
+ */
+export interface ShinsalCatalogOverrides {
+  kinds?: Partial<Record<ShinsalKind, Partial<Record<Lang, string>>>>;
+  twelve?: Record<string, Partial<Record<Lang, string>>>;
+}
+
+/** 12신살 한자 이름 (인덱스 순) */
+export const TWELVE_SHINSAL_HANJA = [
+  '地殺', '年殺', '月殺', '亡身殺', '將星殺', '攀鞍殺',
+  '驛馬殺', '六害殺', '華蓋殺', '劫殺', '災殺', '天殺',
+] as const;
+
+function text(ko: string, en: string): Record<Lang, string> {
+  return { Ko: ko, En: en };
+}
+
+/** 내장 신살 풀이 카탈로그 */
+export const SHINSAL_CATALOG: Readonly<ShinsalCatalog> = {
+  kinds: {
+    DoHwaSal: text('매력·인기, 이성 관계가 활발함', 'charm and popularity; active romantic life'),
+    CheonEulGwiIn: text('위기에 귀인의 도움을 받는 최고 길신', 'the highest auspicious star; help from benefactors in trouble'),
+    YeokMaSal: text('이동·변동, 해외·출장이 잦음', 'movement and change; frequent travel'),
+    MunChangGwiIn: text('학문·문서·시험에 유리함', 'favors study, documents and exams'),
+    HakDangGwiIn: text('배움에 대한 열의와 학업 성취', 'eagerness to learn; academic achievement'),
+    CheonDeokGwiIn: text('하늘의 덕으로 재앙이 흉을 면함', 'heavenly virtue wards off misfortune'),
+    WolDeokGwiIn: text('달의 덕으로 재앙이 흉을 면함', 'monthly virtue wards off misfortune'),
+    YangInSal: text('강한 추진력과 과격함, 칼날 같은 에너지', 'fierce drive and harshness; blade-like energy'),
+    GongMang: text('비어 있는 기운, 해당 자리의 힘이 약함', 'empty energy; the affected position is weakened'),
+    BaekHoSal: text('사고·부상·수술에 주의', 'beware of accidents, injury and surgery'),
+    GoeGangSal: text('강한 개성과 카리스마, 극단적 성향', 'strong personality and charisma; extremes'),
+    WonJinSal: text('원망·불화, 이유 없는 미움', 'resentment and discord; unexplained dislike'),
+    GwiMunGwanSal: text('예민한 신경과 직관, 영감', 'sensitive nerves, intuition and inspiration'),
+  },
+  twelve: [
+    text('새로운 출발·이동의 기운', 'new beginnings and movement'),
+    text('도화 — 매력과 이성 인연', 'peach blossom; charm and romance'),
+    text('막힘·고립, 인내가 필요함', 'blockage and isolation; patience needed'),
+    text('체면 손상, 구설 주의', 'loss of face; beware of gossip'),
+    text('리더십·권위, 장수의 별', 'leadership and authority; the general'),
+    text('안정·승진, 말안장에 오름', 'stability and promotion; mounting the saddle'),
+    text('이동·변동, 분주함', 'movement, change and busyness'),
+    text('질병·장애, 막힘', 'illness and obstacles'),
+    text('예술·종교·학문, 고독', 'arts, religion and scholarship; solitude'),
+    text('빼앗김·손실, 강제적 변화', 'robbery and loss; forced change'),
+    text('재난·관재, 갇힘', 'disaster and legal trouble; confinement'),
+    text('하늘의 재앙, 불가항력', 'heavenly misfortune; force majeure'),
+  ],
+};
+
+/**
+ * 신살 풀이 JSON을 검사해 카탈로그에 덮어쓴 복사본을 만든다 (검사 규칙은 override.ts).
+ *
+ * @param overrides JSON.parse 결과 (ShinsalCatalogOverrides 형식)
+ * @param base 병합 기준 카탈로그 (기본: 내장 데이터)
+ * @returns 병합된 카탈로그
+ * @throws TypeError 형식이 잘못되었거나 알 수 없는 신살 키가 있는 경우
+ */
+export function mergeShinsalCatalog(overrides: unknown, base: Readonly<ShinsalCatalog> = SHINSAL_CATALOG): ShinsalCatalog {
+  const sections = expectObject(overrides, 'shinsal data must be an object with "kinds" and/or "twelve"');
+  const kinds = Object.fromEntries(
+    Object.entries(base.kinds).map(([k, v]) => [k, { ...v }]),
+  ) as ShinsalCatalog['kinds'];
+  const twelve = base.twelve.map((v) => ({ ...v }));
+
+  for (const section of ['kinds', 'twelve'] as const) {
+    const raw = sections[section];
+    if (raw === undefined) continue;
+    for (const [key, note] of Object.entries(expectObject(raw, `shinsal data "${section}" must be an object`))) {
+      const value = langValues(note, 'string', 'shinsal note', key);
+      if (section === 'kinds') {
+        if (!Object.hasOwn(kinds, key)) throw new TypeError(`unknown shinsal kind: ${key}`);
+        Object.assign(kinds[key as ShinsalKind], value);
+      } else {
+        const idx = (TWELVE_SHINSAL_HANJA as readonly string[]).indexOf(key);
+        if (idx < 0) throw new TypeError(`unknown twelve shinsal: ${key}`);
+        Object.assign(twelve[idx], value);
+      }
+    }
+  }
+  return { kinds, twelve };
+}
@@ -7,8 +7,11 @@
 
 import type {
   Element,
+  ElementOverrides,
   Pillar,
+  PillarPosition,
   StrengthClass,
+  StrengthLevel,
   YongshinResult,
 } from './types.js';
 import {
@@ -35,16 +38,89 @@ export const STRENGTH_WEIGHTS = {
   STRONG_THRESHOLD: 3,
   /** 신약 판정 임계값 */
   WEAK_THRESHOLD: -3,
+  /** 극신강 판정 임계값 */
+  VERY_STRONG_THRESHOLD: 10,
+  /** 극신약 판정 임계값 */
+  VERY_WEAK_THRESHOLD: -10,
 } as const;
 
+/** 5단계 판정 임계값 (점수가 이상/이하이면 해당 단계) */
+export interface StrengthThresholds {
+  veryStrong: number;
+  strong: number;
+  weak: number;
+  veryWeak: number;
+}
+
+/**
+ * 월지(월령) 기여 점수 방식.
+ * - Stage: 월지 12운성 강약 분류에 따른 가감 (±STAGE_BONUS)
+ * - Matrix: 일간 오행 × 월지 점수표 (왕상휴수사)
+ */
+export type MonthScoring = 'Stage' | 'Matrix';
+
+/**
+ * 월지 점수표: 일간 오행별로 월지(子~亥)에 주는 점수.
+ * 왕(旺, 같은 오행) +3, 상(相, 월지가 생함) +2, 휴(休, 일간이 생함) -1,
+ * 수(囚, 일간이 극함) -2, 사(死, 월지가 극함) -3.
+ */
+export const MONTH_SCORE_MATRIX: Readonly<Record<Element, readonly number[]>> = {
+  //       子  丑  寅  卯  辰  巳  午  未  申  酉  戌  亥
+  Wood:  [2, -2, 3, 3, -2, -1, -1, -2, -3, -3, -2, 2],
+  Fire:  [-3, -1, 2, 2, -1, 3, 3, -1, -2, -2, -1, -3],
+  Earth: [-2, 3, -3, -3, 3, 2, 2, 3, -1, -1, 3, -2],
+  Metal: [-1, 2, -2, -2, 2, -3, -3, 2, 3, 3, 2, -1],
+  Water: [3, -3, -1, -1, -3, -2, -2, -3, 2, 2, -3, 3],
+};
+
+/** 강약 판정 방식 설정 */
+export interface StrengthProfile {
+  thresholds: StrengthThresholds;
+  /** 월지 기여 점수 방식 (기본 Stage) */
+  monthScoring: MonthScoring;
+  /** Matrix 방식에서 쓸 월지 점수표 (기본 MONTH_SCORE_MATRIX) */
+  monthMatrix?: Readonly<Record<Element, readonly number[]>>;
+}
+
+/** 기본 강약 판정 설정 */
+export const DEFAULT_STRENGTH_PROFILE: Readonly<StrengthProfile> = {
+  monthScoring: 'Stage',
+  thresholds: {
+    veryStrong: STRENGTH_WEIGHTS.VERY_STRONG_THRESHOLD,
+    strong: STRENGTH_WEIGHTS.STRONG_THRESHOLD,
+    weak: STRENGTH_WEIGHTS.WEAK_THRESHOLD,
+    veryWeak: STRENGTH_WEIGHTS.VERY_WEAK_THRESHOLD,
+  },
+};
+
+/** 기둥 위치 (pillars 배열 순서) */
+const PILLAR_POSITIONS: readonly PillarPosition[] = ['Year', 'Month', 'Day', 'Hour'];
+
 // ── 신강/신약 판정 결과 ──
 
+/** 기둥 하나가 종합 점수에 더한 몫 */
+export interface PillarStrength {
+  position: PillarPosition;
+  /** 천간 기여 (지원 +STEM_WEIGHT, 소모 -STEM_WEIGHT) */
+  stem: number;
+  /** 지장간 기여 합 (지원 +HIDDEN_WEIGHT, 소모 -HIDDEN_WEIGHT) */
+  hidden: number;
+  /** 통근 여부 (뿌리면 +1) */
+  root: boolean;
+  /** 기둥 합계 (stem + hidden + 뿌리) */
+  total: number;
+}
+
 /** 신강/신약 판정 상세 결과 */
 export interface StrengthResult {
   /** 월지 12운성 인덱스 (0~11) */
   stageIndex: number;
   /** 12운성 기반 강약 분류 */
   stageClass: StrengthClass;
+  /** 월지 기여 점수 방식 */
+  monthScoring: MonthScoring;
+  /** 월지 기여 점수 (운성 가감 또는 점수표 값) */
+  monthScore: number;
   /** 뿌리(통근) 개수 — 지지에 일간과 같은 오행이 있는 기둥 수 */
   rootCount: number;
   /** 천간 중 일간을 지원하는 수 (비겁/인성) */
@@ -55,10 +131,14 @@ export interface StrengthResult {
   drainStems: number;
   /** 지장간 중 일간을 소모하는 수 */
   drainHidden: number;
+  /** 기둥별 기여 (연·월·일·시). 합계에 monthScore를 더하면 total */
+  pillars: PillarStrength[];
   /** 종합 점수 */
   total: number;
   /** 최종 판정 (Strong/Weak/Neutral) */
   verdict: StrengthClass;
+  /** 5단계 판정 (극신강~극신약) */
+  level: StrengthLevel;
 }
 
 // ── 내부 헬퍼 ──
@@ -86,21 +166,34 @@ function elementControlledBy(element: Element): Element {
  *
  * 판정 요소:
  * 1. 월지 12운성 (장생~제왕=+2, 쇠~절=-2, 태양=0)
+ *    — profile.monthScoring이 Matrix면 월지 점수표(왕상휴수사) 값
  * 2. 통근(通根) 수 — 지지에 일간과 같은 오행이 있는 기둥 수
  * 3. 천간 지원/소모 (비겁·인성=지원, 식상·재성·관성=소모)
  * 4. 지장간 지원/소모 (위와 동일 기준)
  *
  * 종합 점수: 운성보너스 + 통근수 + (지원천간×2 + 지원지장간) - (소모천간×2 + 소모지장간)
- * - 3 이상: 신강(Strong)
- * - -3 이하: 신약(Weak)
+ * - 10 이상: 극신강, 3 이상: 신강(Strong)
+ * - -10 이하: 극신약, -3 이하: 신약(Weak)
  * - 그 외: 중화(Neutral)
+ * (임계값은 profile로 바꿀 수 있다)
+ *
+ * 합화 치환(overrides)이 주어지면 바뀐 천간은 그 오행으로,
+ * 바뀐 지지는 지장간 전체를 국(局)의 오행으로 보아 통근·지원/소모를 판정한다.
+ * 일간이 화(化)했으면 일간 오행도 합화 오행으로 본다.
  *
  * @param dayStem 일간 인덱스
  * @param pillars 네 기둥 배열
+ * @param overrides 합화로 바뀐 위치별 오행 (생략 시 본래 오행)
+ * @param profile 판정 설정 (기본: DEFAULT_STRENGTH_PROFILE)
  * @returns 상세 판정 결과
  */
-export function assessStrength(dayStem: number, pillars: Pillar[]): StrengthResult {
-  const dayElement = stemElement(dayStem);
+export function assessStrength(
+  dayStem: number,
+  pillars: Pillar[],
+  overrides?: ElementOverrides | null,
+  profile: Readonly<StrengthProfile> = DEFAULT_STRENGTH_PROFILE,
+): StrengthResult {
+  const dayElement = overrides?.stems[2] ?? stemElement(dayStem);
   const stageIdx = twelveStageIndex(dayStem, pillars[1].branch);
   const stageClass = stageStrengthClass(stageIdx);
 
@@ -109,11 +202,13 @@ export function assessStrength(dayStem: number, pillars: Pillar[]): StrengthResu
   let drainStems = 0;
   let supportHidden = 0;
   let drainHidden = 0;
+  const perPillar: PillarStrength[] = [];
 
-  for (const pillar of pillars) {
+  pillars.forEach((pillar, i) => {
     // 천간 지원/소모 판정
-    const stemRel = relation(dayElement, stemElement(pillar.stem));
-    if (stemRel === 'Same' || stemRel === 'Resource') {
+    const stemRel = relation(dayElement, overrides?.stems[i] ?? stemElement(pillar.stem));
+    const stemSupports = stemRel === 'Same' || stemRel === 'Resource';
+    if (stemSupports) {
       supportStems++;
     } else {
       drainStems++;
@@ -121,41 +216,75 @@ export function assessStrength(dayStem: number, pillars: Pillar[]): StrengthResu
 
     // 지장간 통근 및 지원/소모 판정
     let hasRoot = false;
-    for (const hidden of hiddenStems(pillar.branch)) {
-      if (stemElement(hidden) === dayElement) {
+    let hidden = 0;
+    for (const hiddenStem of hiddenStems(pillar.branch)) {
+      const hiddenElement = overrides?.branches[i] ?? stemElement(hiddenStem);
+      if (hiddenElement === dayElement) {
         hasRoot = true;
       }
-      const rel = relation(dayElement, stemElement(hidden));
+      const rel = relation(dayElement, hiddenElement);
       if (rel === 'Same' || rel === 'Resource') {
         supportHidden++;
+        hidden += STRENGTH_WEIGHTS.HIDDEN_WEIGHT;
       } else {
         drainHidden++;
+        hidden -= STRENGTH_WEIGHTS.HIDDEN_WEIGHT;
       }
     }
     if (hasRoot) rootCount++;
-  }
+
+    const stem = stemSupports ? STRENGTH_WEIGHTS.STEM_WEIGHT : -STRENGTH_WEIGHTS.STEM_WEIGHT;
+    perPillar.push({ position: PILLAR_POSITIONS[i], stem, hidden, root: hasRoot, total: stem + hidden + (hasRoot ? 1 : 0) });
+  });
 
   // 종합 점수 계산
-  const stageBonus = stageClass === 'Strong' ? STRENGTH_WEIGHTS.STAGE_BONUS : stageClass === 'Weak' ? -STRENGTH_WEIGHTS.STAGE_BONUS : 0;
+  const monthScore = profile.monthScoring === 'Matrix'
+    ? (profile.monthMatrix ?? MONTH_SCORE_MATRIX)[dayElement][pillars[1].branch]
+    : stageClass === 'Strong' ? STRENGTH_WEIGHTS.STAGE_BONUS : stageClass === 'Weak' ? -STRENGTH_WEIGHTS.STAGE_BONUS : 0;
   const supportTotal = supportStems * STRENGTH_WEIGHTS.STEM_WEIGHT + supportHidden * STRENGTH_WEIGHTS.HIDDEN_WEIGHT;
   const drainTotal = drainStems * STRENGTH_WEIGHTS.STEM_WEIGHT + drainHidden * STRENGTH_WEIGHTS.HIDDEN_WEIGHT;
-  const total = stageBonus + rootCount + supportTotal - drainTotal;
+  const total = monthScore + rootCount + supportTotal - drainTotal;
 
-  const verdict: StrengthClass = total >= STRENGTH_WEIGHTS.STRONG_THRESHOLD ? 'Strong' : total <= STRENGTH_WEIGHTS.WEAK_THRESHOLD ? 'Weak' : 'Neutral';
+  const level = strengthLevel(total, profile.thresholds);
+  const verdict = levelClass(level);
 
   return {
     stageIndex: stageIdx,
     stageClass,
+    monthScoring: profile.monthScoring,
+    monthScore,
     rootCount,
     supportStems,
     supportHidden,
     drainStems,
     drainHidden,
+    pillars: perPillar,
     total,
     verdict,
+    level,
   };
 }
 
+/**
+ * 종합 점수를 5단계 판정으로 바꾼다.
+ * @param total 종합 점수
+ * @param thresholds 판정 임계값
+ */
+export function strengthLevel(total: number, thresholds: Readonly<StrengthThresholds>): StrengthLevel {
+  if (total >= thresholds.veryStrong) return 'VeryStrong';
+  if (total >= thresholds.strong) return 'Strong';
+  if (total <= thresholds.veryWeak) return 'VeryWeak';
+  if (total <= thresholds.weak) return 'Weak';
+  return 'Neutral';
+}
+
+/** 5단계 판정을 3단계 분류로 묶는다 (극신강→신강, 극신약→신약) */
+export function levelClass(level: StrengthLevel): StrengthClass {
+  if (level === 'VeryStrong') return 'Strong';
+  if (level === 'VeryWeak') return 'Weak';
+  return level;
+}
+
 // ── 용신(用神) 결정 — 억부용신법(抑扶用神法) ──
 
 /**
@@ -0,0 +1,101 @@
+/**
+ * @fileoverview 십성 분포 집계 모듈
+ *
+ * 원국에서 일간을 제외한 천간과 지지 정기의 십성을 세어,
+ * 없는 십성과 가장 많은 십성 그룹(비겁·식상·재성·관성·인성)을 찾는다.
+ * 지장간 전체를 순위별 가중치로 더한 가중 분포도 제공한다 (십성 원그래프용).
+ */
+
+import { tenGod, tenGodBranch, tenGodsFull } from './bazi.js';
+import type { HiddenRank, Pillar, Relation, TenGod } from './types.js';
+
+/** 십성 순서 (비견→정인). 둘씩 한 그룹을 이룬다 */
+export const TEN_GOD_ORDER: readonly TenGod[] = [
+  'BiGyeon', 'GeopJae', 'SikShin', 'SangGwan', 'PyeonJae',
+  'JeongJae', 'ChilSal', 'JeongGwan', 'PyeonIn', 'JeongIn',
+];
+
+/** 그룹 순서 (TEN_GOD_ORDER의 두 십성마다 하나) */
+const GROUP_ORDER: readonly Relation[] = ['Same', 'Output', 'Wealth', 'Officer', 'Resource'];
+
+/** 십성이 속한 그룹 (비겁·식상·재성·관성·인성) */
+export function tenGodGroup(god: TenGod): Relation {
+  return GROUP_ORDER[Math.floor(TEN_GOD_ORDER.indexOf(god) / 2)];
+}
+
+/** 가중 분포에서 지장간 순위별 가중치 (천간은 1) */
+export const HIDDEN_RANK_WEIGHTS: Readonly<Record<HiddenRank, number>> = {
+  JeongGi: 1,
+  YeoGi: 0.5,
+  JungGi: 0.5,
+};
+
+/** 원국의 십성 분포 */
+export interface TenGodDistribution {
+  /** 십성별 개수 (가중 분포면 가중치 합) */
+  counts: Record<TenGod, number>;
+  /** 그룹별 개수 (가중 분포면 가중치 합) */
+  groups: Record<Relation, number>;
+  /** 하나도 없는 십성 (TEN_GOD_ORDER 순) */
+  absent: TenGod[];
+  /** 개수가 가장 많은 그룹 (동률이면 모두, 그룹 순) */
+  dominant: Relation[];
+}
+
+/** 십성별 값에서 그룹 합계·없는 십성·우세 그룹을 채운다 */
+function summarizeTenGods(counts: Record<TenGod, number>): TenGodDistribution {
+  const groups: Record<Relation, number> = { Same: 0, Output: 0, Wealth: 0, Officer: 0, Resource: 0 };
+  for (const god of TEN_GOD_ORDER) groups[tenGodGroup(god)] += counts[god];
+  const max = Math.max(...GROUP_ORDER.map((g) => groups[g]));
+  return {
+    counts,
+    groups,
+    absent: TEN_GOD_ORDER.filter((god) => counts[god] === 0),
+    dominant: max > 0 ? GROUP_ORDER.filter((g) => groups[g] === max) : [],
+  };
+}
+
+/** 모든 십성이 0인 집계표 */
+function emptyTenGodCounts(): Record<TenGod, number> {
+  return Object.fromEntries(TEN_GOD_ORDER.map((god) => [god, 0])) as Record<TenGod, number>;
+}
+
+/**
+ * 일간을 제외한 천간 3자와 지지 정기 4자의 십성 분포를 집계한다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ */
+export function tenGodDistribution(pillars: Pillar[], dayStem: number): TenGodDistribution {
+  const counts = emptyTenGodCounts();
+  pillars.forEach((pillar, idx) => {
+    if (idx !== 2) counts[tenGod(dayStem, pillar.stem)]++;
+    counts[tenGodBranch(dayStem, pillar.branch)]++;
+  });
+  return summarizeTenGods(counts);
+}
+
+/**
+ * 일간을 제외한 천간과 모든 지장간의 십성 분포를 가중 집계한다.
+ *
+ * 천간은 1, 지장간은 순위별 HIDDEN_RANK_WEIGHTS(정기 1, 여기·중기 0.5)를 더한다.
+ *
+ * @param pillars 네 기둥 (연·월·일·시)
+ * @param dayStem 일간 인덱스
+ * @param weights 지장간 순위별 가중치
+ */
+export function weightedTenGodDistribution(
+  pillars: Pillar[],
+  dayStem: number,
+  weights: Readonly<Record<HiddenRank, number>> = HIDDEN_RANK_WEIGHTS,
+): TenGodDistribution {
+  const counts = emptyTenGodCounts();
+  for (const entry of tenGodsFull(dayStem, pillars)) {
+    if (entry.layer === 'Stem') {
+      if (entry.position !== 'Day') counts[entry.tenGod] += 1;
+    } else if (entry.rank) {
+      counts[entry.tenGod] += weights[entry.rank];
+    }
+  }
+  return summarizeTenGods(counts);
+}
@@ -1,8 +1,11 @@
 /**
  * @fileoverview 시간대(Timezone) 처리 모듈
  *
- * IANA 시간대명(예: 'Asia/Seoul')과 고정 오프셋(예: '+09:00')을 파싱하고,
+ * IANA 시간대명(예: 'Asia/Seoul'), 고정 오프셋(예: '+09:00'), 흔한 약어(예: 'KST')를 파싱하고,
  * 날짜·시간 문자열을 해당 시간대 기준 dayjs 객체로 변환한다.
+ *
+ * 약어는 그 약어가 뜻하는 고정 오프셋으로 바꾼다 (KST는 +09:00, PDT는 -07:00).
+ * 여러 지역에서 다른 뜻으로 쓰는 약어(CST 등)는 추측하지 않고 오류로 알린다.
  */
 
 import dayjs from 'dayjs';
@@ -22,23 +25,76 @@ export type TimeZoneSpec =
 
 /**
  * 시간대 문자열을 파싱하여 TimeZoneSpec을 반환한다.
- * @param input IANA 시간대명 또는 고정 오프셋 문자열 (예: 'Asia/Seoul', '+09:00')
+ * @param input IANA 시간대명, 고정 오프셋 또는 약어 (예: 'Asia/Seoul', '+09:00', 'KST')
  * @returns 파싱된 시간대 명세
- * @throws 유효하지 않은 시간대 문자열인 경우
+ * @throws 유효하지 않은 시간대 문자열이거나 뜻이 여럿인 약어인 경우
  */
 export function parseTimezone(input: string): TimeZoneSpec {
   const fixed = parseFixedOffset(input);
   if (fixed !== null) {
     return { type: 'fixed', offsetSeconds: fixed };
   }
+  const abbreviation = input.trim().toUpperCase();
+  const meanings = AMBIGUOUS_TZ_ABBREVIATIONS[abbreviation];
+  if (meanings) {
+    throw new Error(`ambiguous timezone abbreviation ${abbreviation} (${meanings.join(', ')}); use an IANA name or offset`);
+  }
+  const abbreviated = abbreviation in TZ_ABBREVIATIONS ? parseFixedOffset(TZ_ABBREVIATIONS[abbreviation]) : null;
+  if (abbreviated !== null) {
+    return { type: 'fixed', offsetSeconds: abbreviated };
+  }
   // IANA 시간대명으로 검증
   const d = dayjs.tz('2000-01-01 00:00', input);
   if (d.isValid()) {
     return { type: 'named', name: input };
   }
-  throw new Error('timezone must be IANA name (e.g., Asia/Seoul) or offset (+09:00)');
+  throw new Error('timezone must be IANA name (e.g., Asia/Seoul), offset (+09:00) or abbreviation (KST)');
 }
 
+/** 시간대 약어 → 고정 오프셋 (대문자 기준, 표준시·일광절약시간 약어를 따로 둔다) */
+export const TZ_ABBREVIATIONS: Readonly<Record<string, string>> = {
+  UTC: '+00:00',
+  GMT: '+00:00',
+  KST: '+09:00',
+  KDT: '+10:00',
+  JST: '+09:00',
+  HKT: '+08:00',
+  SGT: '+08:00',
+  WIB: '+07:00',
+  ICT: '+07:00',
+  AWST: '+08:00',
+  ACST: '+09:30',
+  AEST: '+10:00',
+  AEDT: '+11:00',
+  NZST: '+12:00',
+  NZDT: '+13:00',
+  WET: '+00:00',
+  CET: '+01:00',
+  CEST: '+02:00',
+  EET: '+02:00',
+  EEST: '+03:00',
+  MSK: '+03:00',
+  EST: '-05:00',
+  EDT: '-04:00',
+  CDT: '-05:00',
+  MST: '-07:00',
+  MDT: '-06:00',
+  PST: '-08:00',
+  PDT: '-07:00',
+  AKST: '-09:00',
+  AKDT: '-08:00',
+  HST: '-10:00',
+};
+
+/** 여러 뜻으로 쓰여 받지 않는 약어와 그 뜻들 (오류 메시지용) */
+export const AMBIGUOUS_TZ_ABBREVIATIONS: Readonly<Record<string, readonly string[]>> = {
+  CST: ['US Central -06:00', 'China +08:00', 'Cuba -05:00'],
+  IST: ['India +05:30', 'Israel +02:00', 'Ireland +01:00'],
+  BST: ['British Summer +01:00', 'Bangladesh +06:00'],
+  AST: ['Atlantic -04:00', 'Arabia +03:00'],
+  SST: ['Samoa -11:00', 'Singapore +08:00'],
+};
+
 /**
  * 고정 오프셋 문자열을 초 단위로 파싱한다.
  * @param input '+09:00', '-0530' 등의 문자열
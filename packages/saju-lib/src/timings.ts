@@ -0,0 +1,124 @@
+/**
+ * @fileoverview 계산 단계별 소요 시간 측정 (--timings)
+ *
+ * 절기 계산, 음양력 변환, 4기둥 산출, 운 산출, 분석, 출력 렌더링에 쓴 시간을
+ * 단계별로 누적하고, 절기 캐시의 적중률을 함께 보고한다.
+ * 기본으로 꺼져 있으며, 꺼져 있을 때 timed()는 함수를 그대로 호출한다.
+ *
+ * 단계가 중첩되면(예: 4기둥 산출 중의 절기 계산) 안쪽 단계의 시간은 바깥 단계에서 빼므로
+ * 각 단계의 시간은 자기 몫만 담고, 모두 더하면 측정한 전체 시간이 된다.
+ */
+
+/** 측정 단계 */
+export type TimingPhase = 'Terms' | 'Lunar' | 'Pillars' | 'Luck' | 'Analysis' | 'Render';
+
+export const TIMING_PHASES: readonly TimingPhase[] = ['Terms', 'Lunar', 'Pillars', 'Luck', 'Analysis', 'Render'];
+
+/** 단계 하나의 누적 시간 */
+export interface PhaseTiming {
+  /** 자기 몫의 누적 시간 (밀리초) */
+  ms: number;
+  /** 호출 횟수 */
+  calls: number;
+}
+
+/** 캐시 적중 통계 */
+export interface CacheStats {
+  hits: number;
+  misses: number;
+}
+
+/** 측정 결과 */
+export interface TimingReport {
+  phases: Record<TimingPhase, PhaseTiming>;
+  /** 캐시 이름별 적중 통계 (측정을 시작한 뒤의 변화량) */
+  caches: Record<string, CacheStats>;
+}
+
+/** 진행 중인 단계 (중첩 시 안쪽 시간을 빼기 위한 스택 항목) */
+interface Frame {
+  phase: TimingPhase;
+  start: number;
+  /** 안쪽 단계에서 쓴 시간 */
+  nested: number;
+}
+
+let enabled = false;
+let phases = emptyPhases();
+const stack: Frame[] = [];
+/** 등록한 캐시 (LruCache처럼 hits·misses 계수를 가진 객체) */
+const caches = new Map<string, Readonly<CacheStats>>();
+/** resetTimings() 시점의 캐시 통계 (보고는 이후 변화량만) */
+const cacheBaseline = new Map<string, CacheStats>();
+
+function emptyPhases(): Record<TimingPhase, PhaseTiming> {
+  return Object.fromEntries(TIMING_PHASES.map((p) => [p, { ms: 0, calls: 0 }])) as Record<TimingPhase, PhaseTiming>;
+}
+
+/**
+ * 적중률을 보고할 캐시를 등록한다.
+ * @returns 등록한 캐시 (모듈 상수 선언에 바로 쓸 수 있도록)
+ */
+export function registerCache<C extends Readonly<CacheStats>>(name: string, cache: C): C {
+  caches.set(name, cache);
+  return cache;
+}
+
+/** 측정을 켜거나 끈다. 켤 때 누적값을 비운다. */
+export function enableTimings(on = true): void {
+  enabled = on;
+  if (on) resetTimings();
+}
+
+/** 누적 시간과 캐시 통계 기준점을 비운다 */
+export function resetTimings(): void {
+  phases = emptyPhases();
+  for (const [name, cache] of caches) cacheBaseline.set(name, { hits: cache.hits, misses: cache.misses });
+}
+
+/**
+ * fn 실행 시간을 phase에 누적한다. 측정이 꺼져 있으면 fn만 호출한다.
+ * 예외가 나도 시간은 기록한다.
+ */
+export function timed<T>(phase: TimingPhase, fn: () => T): T {
+  if (!enabled) return fn();
+  const frame: Frame = { phase, start: performance.now(), nested: 0 };
+  stack.push(frame);
+  try {
+    return fn();
+  } finally {
+    stack.pop();
+    const elapsed = performance.now() - frame.start;
+    const entry = phases[phase];
+    entry.ms += elapsed - frame.nested;
+    entry.calls++;
+    const parent = stack[stack.length - 1];
+    if (parent) parent.nested += elapsed;
+  }
+}
+
+/** 지금까지의 측정 결과 */
+export function timingReport(): TimingReport {
+  const cacheStats: Record<string, CacheStats> = {};
+  for (const [name, cache] of caches) {
+    const base = cacheBaseline.get(name) ?? { hits: 0, misses: 0 };
+    cacheStats[name] = { hits: cache.hits - base.hits, misses: cache.misses - base.misses };
+  }
+  return {
+    phases: Object.fromEntries(TIMING_PHASES.map((p) => [p, { ...phases[p] }])) as Record<TimingPhase, PhaseTiming>,
+    caches: cacheStats,
+  };
+}
+
+/** 두 측정 결과를 더한다 (여러 작업자 스레드의 결과 합산용) */
+export function mergeTimingReports(a: TimingReport, b: TimingReport): TimingReport {
+  const merged: TimingReport = { phases: emptyPhases(), caches: {} };
+  for (const p of TIMING_PHASES) {
+    merged.phases[p] = { ms: a.phases[p].ms + b.phases[p].ms, calls: a.phases[p].calls + b.phases[p].calls };
+  }
+  for (const name of new Set([...Object.keys(a.caches), ...Object.keys(b.caches)])) {
+    const [x, y] = [a.caches[name] ?? { hits: 0, misses: 0 }, b.caches[name] ?? { hits: 0, misses: 0 }];
+    merged.caches[name] = { hits: x.hits + y.hits, misses: x.misses + y.misses };
+  }
+  return merged;
+}
@@ -4,6 +4,10 @@
  * 사주(四柱) 계산에 사용되는 모든 인터페이스와 타입을 정의한다.
  * 천간(天干), 지지(地支), 오행(五行), 십성(十星), 신살(神殺) 등
  * 명리학 도메인의 기본 구조를 포함한다.
+ *
+ * 이 타입들과 운(luck.ts)·강약(strength.ts) 결과 타입은 클래스·Date·Map 없이 숫자·문자열·
+ * 배열·객체로만 이루어져 JSON.stringify/JSON.parse로 그대로 저장·전송할 수 있다.
+ * __tests__/plainData.test.ts가 이 성질을 검사하므로 새 필드도 같은 규칙을 따른다.
  */
 
 /**
@@ -21,6 +25,9 @@ export interface Pillar {
 /** 사주 네 기둥의 위치: 연주(Year), 월주(Month), 일주(Day), 시주(Hour) */
 export type PillarPosition = 'Year' | 'Month' | 'Day' | 'Hour';
 
+/** 기둥 종류 (연/월/일/시). 명식에서 기둥을 역할로 가리킬 때 쓴다 (SajuResult.pillar 등) */
+export type PillarKind = PillarPosition;
+
 /** 천간 관계 유형: 합(Hap, 天干合) 또는 충(Chung, 天干沖) */
 export type StemRelationType = 'Hap' | 'Chung';
 
@@ -55,6 +62,39 @@ export interface StemInteraction {
   resultElement: Element | null;
 }
 
+/**
+ * 이웃한 두 천간의 합(合)과 합화(合化) 성립 평가.
+ * 화(化)가 성립하지 않으면 합이불화(合而不化)로 본다.
+ */
+export interface StemCombination {
+  /** 합에 참여한 두 기둥 (이웃한 위치) */
+  positions: [PillarPosition, PillarPosition];
+  stems: [number, number];
+  /** 합화 오행 */
+  element: Element;
+  /** 월령(月令)이 합화 오행과 같거나 생하는지 */
+  monthSupport: boolean;
+  /** 다른 이웃 천간과 쟁합(爭合)·투합(妬合)하는지 */
+  contested: boolean;
+  /** 합화 오행을 극하는 천간이 다른 자리에 있는지 */
+  obstructed: boolean;
+  /** 합화 성립 여부 */
+  transformed: boolean;
+}
+
+/**
+ * 삼합·방합 국(局). 세 지지가 모두 있으면 완전(complete),
+ * 왕지(子午卯酉)를 포함한 두 지지만 있으면 반합(partial)이다.
+ */
+export interface BranchCombination {
+  relation: Extract<BranchRelationType, 'SamHap' | 'BangHap'>;
+  complete: boolean;
+  positions: PillarPosition[];
+  branches: number[];
+  /** 국이 이루는 오행 */
+  element: Element;
+}
+
 /** 지지 상호작용 (합/충/형/파/해) 결과 */
 export interface BranchInteraction {
   /** 관계 유형 */
@@ -105,6 +145,15 @@ export type Direction = 'Forward' | 'Backward';
 /** 오행(五行): 목(Wood), 화(Fire), 토(Earth), 금(Metal), 수(Water) */
 export type Element = 'Wood' | 'Fire' | 'Earth' | 'Metal' | 'Water';
 
+/**
+ * 합화(合化)로 오행이 바뀐 글자 (기둥 위치 순 [연, 월, 일, 시]).
+ * null이면 본래 오행을 쓴다.
+ */
+export interface ElementOverrides {
+  stems: (Element | null)[];
+  branches: (Element | null)[];
+}
+
 /**
  * 일간 기준 오행 관계 (五行 關係)
  * - Same: 비겁(比劫) — 같은 오행
@@ -131,9 +180,27 @@ export type TenGod =
   | 'PyeonIn'   // 편인(偏印) — 생받는 오행, 같은 음양
   | 'JeongIn';  // 정인(正印) — 생받는 오행, 다른 음양
 
+/** 지장간 순위: 정기(正氣)·여기(餘氣)·중기(中氣) */
+export type HiddenRank = 'JeongGi' | 'YeoGi' | 'JungGi';
+
+/** 원국의 글자 하나(천간 또는 지장간)에 대한 십성 */
+export interface TenGodEntry {
+  position: PillarPosition;
+  /** 천간이면 'Stem', 지장간이면 'Hidden' */
+  layer: 'Stem' | 'Hidden';
+  /** 천간 인덱스 */
+  stem: number;
+  tenGod: TenGod;
+  /** 지장간 순위 (천간이면 없음) */
+  rank?: HiddenRank;
+}
+
 /** 강약 분류: 12운성 기반 분류(stageClass) 및 최종 판정(verdict) 공통 타입 */
 export type StrengthClass = 'Strong' | 'Weak' | 'Neutral';
 
+/** 5단계 강약 판정: 극신강/신강/중화/신약/극신약 */
+export type StrengthLevel = 'VeryStrong' | 'Strong' | 'Neutral' | 'Weak' | 'VeryWeak';
+
 /** 24절기 정의 */
 export interface TermDef {
   /** 절기 키 (예: 'lichun', 'jingzhe') */
@@ -198,3 +265,53 @@ export interface LmtInfo {
   /** 지역명 (예: 'Seoul/서울'), 경도 직접 입력 시 null */
   locationLabel: string | null;
 }
+
+/**
+ * LMT 보정 시 시주·일주(23시 자시) 경계를 비교하는 기준.
+ * - Corrected: 보정 시각으로 비교 (경계가 보정만큼 함께 이동)
+ * - Clock: 보정 전 시계 시각으로 비교 (보정은 절기·연주·월주에만 반영)
+ */
+export type LmtBoundary = 'Corrected' | 'Clock';
+
+/**
+ * 일주가 바뀌는 시각.
+ * - Jasi: 23시 자시 시작에 다음 날로 넘어감 (자시 일괄)
+ * - Midnight: 0시에 넘어감. 23시대(야자시)는 당일 일주에 다음 날 일간으로 세운 자시를 쓴다
+ */
+export type DayBoundary = 'Jasi' | 'Midnight';
+
+/**
+ * 출생 반구.
+ * - North: 표준 관례 (절기 그대로 월지를 정한다)
+ * - South: 남반구 출생은 계절이 반대라며 월지를 6지 옮기는 일부 술사의 관례 (비표준)
+ */
+export type Hemisphere = 'North' | 'South';
+
+/** 12신살을 세는 기준 지지 */
+export type ShinsalBase = 'Year' | 'Day';
+
+/**
+ * 출생 시각 가까이에 있는 기둥 경계와 그 너머의 4기둥.
+ * 기록된 시각이 몇 분만 틀려도 명식이 바뀌는지 알려 준다.
+ */
+export interface BoundaryAlternative {
+  /** 경계가 출생 전(Earlier)에 있는지 후(Later)에 있는지 */
+  side: 'Earlier' | 'Later';
+  /** 출생 시각을 이만큼(분) 옮기면 경계를 넘는다 */
+  minutes: number;
+  /** 넘는 경계 중 가장 큰 단위 (Year: 입춘, Month: 절입, Day: 일 경계, Hour: 시 경계) */
+  boundary: PillarKind;
+  /** 바뀌는 기둥 (연·월·일·시 순) */
+  changed: PillarKind[];
+  yearPillar: Pillar;
+  monthPillar: Pillar;
+  dayPillar: Pillar;
+  hourPillar: Pillar;
+}
+
+/** 다른 경계 기준으로 본 일주·시주 (선택한 기준과 결과가 다를 때) */
+export interface LmtAlternative {
+  boundary: LmtBoundary;
+  dayPillar: Pillar;
+  hourPillar: Pillar;
+}
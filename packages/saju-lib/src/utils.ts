@@ -16,3 +16,94 @@
 export function remEuclid(a: number, b: number): number {
   return ((a % b) + b) % b
 }
+
+/**
+ * 처음 호출할 때 한 번만 계산하고, 이후에는 보관한 값을 돌려주는 함수를 만든다.
+ * 결과 객체의 getter와 함께 써서 비용이 큰 필드를 처음 읽을 때 계산한다.
+ *
+ * @param compute 값을 계산하는 함수 (최대 한 번 호출)
+ */
+export function lazy<T>(compute: () => T): () => T {
+  let computed = false
+  let value: T
+  return () => {
+    if (!computed) {
+      value = compute()
+      computed = true
+    }
+    return value
+  }
+}
+
+/**
+ * 문자열의 64비트 FNV-1a 해시 (16자리 16진수).
+ * 암호용이 아니며, 같은 입력이면 실행 환경과 무관하게 같은 값을 낸다.
+ *
+ * @param text 해시할 문자열 (UTF-16 코드 단위로 읽는다)
+ */
+export function fnv1a64(text: string): string {
+  let hash = 0xcbf29ce484222325n
+  for (let i = 0; i < text.length; i++) {
+    hash ^= BigInt(text.charCodeAt(i))
+    hash = (hash * 0x100000001b3n) & 0xffffffffffffffffn
+  }
+  return hash.toString(16).padStart(16, '0')
+}
+
+/**
+ * 최근 사용 순(LRU) 캐시.
+ * Map의 삽입 순서를 사용 순서로 삼아, 조회·저장 시 항목을 맨 뒤로 옮기고
+ * 용량을 넘으면 가장 오래 쓰지 않은 항목(맨 앞)을 버린다.
+ */
+export class LruCache<K, V> {
+  private readonly entries = new Map<K, V>()
+  /** getOrCompute()에서 캐시된 값을 돌려준 횟수 */
+  hits = 0
+  /** getOrCompute()에서 새로 계산한 횟수 */
+  misses = 0
+
+  /** @param capacity 최대 항목 수 (1 이상) */
+  constructor(readonly capacity: number) {
+    if (!Number.isInteger(capacity) || capacity < 1) throw new RangeError(`capacity must be a positive integer, got ${capacity}`)
+  }
+
+  get size(): number {
+    return this.entries.size
+  }
+
+  /** 값을 조회하고, 있으면 가장 최근 사용으로 표시한다 */
+  get(key: K): V | undefined {
+    const value = this.entries.get(key)
+    if (value === undefined) return undefined
+    this.entries.delete(key)
+    this.entries.set(key, value)
+    return value
+  }
+
+  /** 값을 저장하고, 용량을 넘으면 가장 오래 쓰지 않은 항목을 버린다 */
+  set(key: K, value: V): void {
+    this.entries.delete(key)
+    this.entries.set(key, value)
+    if (this.entries.size > this.capacity) {
+      const oldest = this.entries.keys().next().value
+      if (oldest !== undefined) this.entries.delete(oldest)
+    }
+  }
+
+  /** 캐시된 값이 없으면 compute로 만들어 저장한다 */
+  getOrCompute(key: K, compute: () => V): V {
+    const cached = this.get(key)
+    if (cached !== undefined) {
+      this.hits++
+      return cached
+    }
+    this.misses++
+    const value = compute()
+    this.set(key, value)
+    return value
+  }
+
+  clear(): void {
+    this.entries.clear()
+  }
+}
@@ -3,7 +3,7 @@ import SajuForm from './components/SajuForm'
 import type { SajuFormData } from './components/SajuForm'
 import ResultDashboard from './components/ResultDashboard'
 import CalculationGuide from './components/CalculationGuide'
-import { calculate, type SajuResult, type SajuRequest, type Lang } from 'saju-lib'
+import { calculateCached, type SajuResult, type SajuRequest, type Lang } from 'saju-lib'
 import { invalidInputEffects } from './appInvalidState'
 
 type AppPage = 'calculator' | 'guide'
@@ -43,7 +43,7 @@ export default function App() {
         yearStart: formData.yearStart ?? null,
         yearCount: formData.yearCount ?? 3,
       }
-      const calculated = calculate(req)
+      const calculated = calculateCached(req)
       setResult(calculated)
       setError(null)
     } catch (err: unknown) {
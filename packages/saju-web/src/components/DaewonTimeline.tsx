@@ -41,13 +41,16 @@ interface Props { result: SajuResult; i18n: I18n }
 
 export default function DaewonTimeline({ result, i18n }: Props) {
   const ds = result.dayPillar.stem;
-  const heading = `${i18n.daewonHeading()} (${i18n.directionLabel(result.daewonDirection)} , ${i18n.startLabel()} ${i18n.formatAge(result.daewonStartMonths, false)})`;
   const [ageMonths, setAgeMonths] = useState<number | null>(null);
 
   useEffect(() => {
     setAgeMonths(computeAgeMonths(result));
   }, [result]);
 
+  // 성별 없는 이벤트 차트는 대운이 없다
+  if (result.daewonDirection === null || result.daewonStartMonths === null) return null;
+  const heading = `${i18n.daewonHeading()} (${i18n.directionLabel(result.daewonDirection)} , ${i18n.startLabel()} ${i18n.formatAge(result.daewonStartMonths, false)})`;
+
   return (
     <section className="section">
       <h3>{heading}</h3>
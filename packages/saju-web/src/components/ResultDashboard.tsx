@@ -32,11 +32,9 @@ export default function ResultDashboard({ result, lang, name }: Props) {
   // 프로필 요약 문자열 조합
   const parts: string[] = [];
   if (name) parts.push(name);
-  parts.push(
-    `${calLabel} ${result.inputDate} ${result.inputTime}`,
-    i18n.genderValue(result.gender),
-    result.tzName,
-  );
+  parts.push(`${calLabel} ${result.inputDate} ${result.inputTime}`);
+  if (result.gender) parts.push(i18n.genderValue(result.gender));
+  parts.push(result.tzName);
   if (lmtShort) parts.push(`LMT ${lmtShort}`);
 
   return (
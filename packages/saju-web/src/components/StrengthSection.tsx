@@ -10,7 +10,7 @@ import { strength as str, bazi } from 'saju-lib';
 import type { I18n } from 'saju-lib';
 import type { StrengthClass, Element } from 'saju-lib';
 
-const { STAGE_BONUS, STEM_WEIGHT, HIDDEN_WEIGHT } = str.STRENGTH_WEIGHTS;
+const { STEM_WEIGHT, HIDDEN_WEIGHT } = str.STRENGTH_WEIGHTS;
 const ELEMENTS: readonly Element[] = ['Wood', 'Fire', 'Earth', 'Metal', 'Water'];
 
 interface Props { result: SajuResult; i18n: I18n }
@@ -40,7 +40,7 @@ export default function StrengthSection({ result, i18n }: Props) {
   const resource = elementGeneratedBy(dayEl);
   const officer = elementControlledBy(dayEl);
 
-  const stageBonus = s.stageClass === 'Strong' ? STAGE_BONUS : s.stageClass === 'Weak' ? -STAGE_BONUS : 0;
+  const stageBonus = s.monthScore;
   const supportTotal = s.supportStems * STEM_WEIGHT + s.supportHidden * HIDDEN_WEIGHT;
   const drainTotal = s.drainStems * STEM_WEIGHT + s.drainHidden * HIDDEN_WEIGHT;
 